@@ -43,6 +43,17 @@ fn into_yg_units(dim: impl Into<tf::Dimension>) -> yg::StyleUnit {
         tf::Dimension::Auto => yg::StyleUnit::Auto,
         tf::Dimension::Length(val) => yg::StyleUnit::Point(yg::OrderedFloat(val)),
         tf::Dimension::Percent(val) => yg::StyleUnit::Percent(yg::OrderedFloat(val)),
+        tf::Dimension::Calc(_) => panic!("Yoga does not support calc() expressions"),
+        tf::Dimension::Em(_)
+        | tf::Dimension::Rem(_)
+        | tf::Dimension::Vw(_)
+        | tf::Dimension::Vh(_)
+        | tf::Dimension::Vmin(_)
+        | tf::Dimension::Vmax(_) => panic!("The benchmarks don't use font-relative or viewport-relative lengths"),
+        tf::Dimension::MinContent
+        | tf::Dimension::MaxContent
+        | tf::Dimension::FitContent(_)
+        | tf::Dimension::Stretch => panic!("Yoga does not support intrinsic sizing keywords"),
     }
 }
 
@@ -57,9 +68,14 @@ fn items_into_align(align: Option<tf::AlignSelf>) -> yg::Align {
         Some(tf::AlignSelf::FlexEnd) => yg::Align::FlexEnd,
         Some(tf::AlignSelf::Center) => yg::Align::Center,
         Some(tf::AlignSelf::Baseline) => yg::Align::Baseline,
+        Some(tf::AlignSelf::LastBaseline) => unimplemented!(),
         Some(tf::AlignSelf::Stretch) => yg::Align::Stretch,
         Some(tf::AlignSelf::Start) => unimplemented!(),
         Some(tf::AlignSelf::End) => unimplemented!(),
+        Some(tf::AlignSelf::SafeFlexStart) => yg::Align::FlexStart,
+        Some(tf::AlignSelf::SafeFlexEnd) => yg::Align::FlexEnd,
+        Some(tf::AlignSelf::SafeCenter) => yg::Align::Center,
+        Some(tf::AlignSelf::SafeEnd) => unimplemented!(),
     }
 }
 
@@ -75,6 +91,10 @@ fn content_into_align(align: Option<tf::AlignContent>) -> yg::Align {
         Some(tf::AlignContent::SpaceBetween) => yg::Align::SpaceBetween,
         Some(tf::AlignContent::SpaceAround) => yg::Align::SpaceAround,
         Some(tf::AlignContent::SpaceEvenly) => unimplemented!(),
+        Some(tf::AlignContent::SafeFlexStart) => yg::Align::FlexStart,
+        Some(tf::AlignContent::SafeFlexEnd) => yg::Align::FlexEnd,
+        Some(tf::AlignContent::SafeEnd) => yg::Align::FlexEnd,
+        Some(tf::AlignContent::SafeCenter) => yg::Align::Center,
     }
 }
 
@@ -90,6 +110,10 @@ fn content_into_justify(align: Option<tf::JustifyContent>) -> yg::Justify {
         Some(tf::JustifyContent::SpaceAround) => yg::Justify::SpaceAround,
         Some(tf::JustifyContent::Stretch) => unimplemented!(),
         Some(tf::JustifyContent::SpaceEvenly) => unimplemented!(),
+        Some(tf::JustifyContent::SafeFlexStart) => yg::Justify::FlexStart,
+        Some(tf::JustifyContent::SafeFlexEnd) => yg::Justify::FlexEnd,
+        Some(tf::JustifyContent::SafeEnd) => yg::Justify::FlexEnd,
+        Some(tf::JustifyContent::SafeCenter) => yg::Justify::Center,
     }
 }
 
@@ -100,12 +124,17 @@ pub fn apply_taffy_style(node: &mut yg::Node, style: &tf::Style) {
         tf::Display::Flex => yg::Display::Flex,
         tf::Display::Grid => panic!("Yoga does not support CSS Grid layout"),
         tf::Display::Block => panic!("Yoga does not support CSS Block layout"),
+        tf::Display::Inline | tf::Display::InlineBlock => panic!("Yoga does not support inline layout"),
+        tf::Display::Contents => panic!("Yoga does not support display: contents"),
+        tf::Display::Custom(_) => panic!("Yoga does not support custom layout"),
     });
 
     // position
     node.set_position_type(match style.position {
         tf::Position::Relative => yg::PositionType::Relative,
         tf::Position::Absolute => yg::PositionType::Absolute,
+        tf::Position::Fixed => panic!("Yoga does not support position: fixed"),
+        tf::Position::Sticky => panic!("Yoga does not support position: sticky"),
     });
     // inset
     node.set_position(yg::Edge::Left, into_yg_units(style.inset.left));
@@ -76,6 +76,8 @@ async fn main() {
             let name_ident = Ident::new(name, Span::call_site());
             if name.starts_with("grid") {
                 quote!(#[cfg(feature = "grid")] mod #name_ident;)
+            } else if name.split('_').any(|word| word == "table") {
+                quote!(#[cfg(feature = "table_layout")] mod #name_ident;)
             } else {
                 quote!(mod #name_ident;)
             }
@@ -332,15 +334,33 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
         Value::String(ref value) => match value.as_ref() {
             "none" => quote!(display: taffy::style::Display::None,),
             "block" => quote!(display: taffy::style::Display::Block,),
+            "inline" => quote!(display: taffy::style::Display::Inline,),
+            "inline-block" => quote!(display: taffy::style::Display::InlineBlock,),
             "grid" => quote!(display: taffy::style::Display::Grid,),
+            "table" => quote!(display: taffy::style::Display::Table,),
+            "table-row-group" => quote!(display: taffy::style::Display::TableRowGroup,),
+            "table-row" => quote!(display: taffy::style::Display::TableRow,),
+            "table-cell" => quote!(display: taffy::style::Display::TableCell,),
+            "contents" => quote!(display: taffy::style::Display::Contents,),
             _ => quote!(display: taffy::style::Display::Flex,),
         },
         _ => quote!(),
     };
 
+    let visibility = match style["visibility"] {
+        Value::String(ref value) => match value.as_ref() {
+            "hidden" => quote!(visibility: taffy::style::Visibility::Hidden,),
+            "collapse" => quote!(visibility: taffy::style::Visibility::Collapse,),
+            _ => quote!(),
+        },
+        _ => quote!(),
+    };
+
     let position = match style["position"] {
         Value::String(ref value) => match value.as_ref() {
             "absolute" => quote!(position: taffy::style::Position::Absolute,),
+            "fixed" => quote!(position: taffy::style::Position::Fixed,),
+            "sticky" => quote!(position: taffy::style::Position::Sticky,),
             _ => quote!(),
         },
         _ => quote!(),
@@ -348,8 +368,79 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
 
     let direction = match style["direction"] {
         Value::String(ref value) => match value.as_ref() {
-            "rtl" => quote!(direction: taffy::style::Direction::RTL,),
-            "ltr" => quote!(direction: taffy::style::Direction::LTR,),
+            "rtl" => quote!(direction: taffy::style::Direction::Rtl,),
+            "ltr" => quote!(direction: taffy::style::Direction::Ltr,),
+            _ => quote!(),
+        },
+        _ => quote!(),
+    };
+
+    let writing_mode = match style["writingMode"] {
+        Value::String(ref value) => match value.as_ref() {
+            "vertical-rl" => quote!(writing_mode: taffy::style::WritingMode::VerticalRl,),
+            "vertical-lr" => quote!(writing_mode: taffy::style::WritingMode::VerticalLr,),
+            "horizontal-tb" => quote!(writing_mode: taffy::style::WritingMode::HorizontalTb,),
+            _ => quote!(),
+        },
+        _ => quote!(),
+    };
+
+    let float = match style["float"] {
+        Value::String(ref value) => match value.as_ref() {
+            "left" => quote!(float: taffy::style::Float::Left,),
+            "right" => quote!(float: taffy::style::Float::Right,),
+            _ => quote!(),
+        },
+        _ => quote!(),
+    };
+
+    let clear = match style["clear"] {
+        Value::String(ref value) => match value.as_ref() {
+            "left" => quote!(clear: taffy::style::Clear::Left,),
+            "right" => quote!(clear: taffy::style::Clear::Right,),
+            "both" => quote!(clear: taffy::style::Clear::Both,),
+            _ => quote!(),
+        },
+        _ => quote!(),
+    };
+
+    let border_collapse = match style["borderCollapse"] {
+        Value::String(ref value) => match value.as_ref() {
+            "collapse" => quote!(border_collapse: taffy::style::BorderCollapse::Collapse,),
+            _ => quote!(),
+        },
+        _ => quote!(),
+    };
+    let border_spacing = quote_object_prop("border_spacing", style, generate_gap);
+    let col_span = quote_number_prop("col_span", style, |value: f32| {
+        let value = value as u16;
+        quote!(#value)
+    });
+    let row_span = quote_number_prop("row_span", style, |value: f32| {
+        let value = value as u16;
+        quote!(#value)
+    });
+
+    let text_align = match style["textAlign"] {
+        Value::String(ref value) => match value.as_ref() {
+            "end" => quote!(text_align: taffy::style::TextAlign::End,),
+            "left" => quote!(text_align: taffy::style::TextAlign::Left,),
+            "right" => quote!(text_align: taffy::style::TextAlign::Right,),
+            "center" => quote!(text_align: taffy::style::TextAlign::Center,),
+            "justify" => quote!(text_align: taffy::style::TextAlign::Justify,),
+            _ => quote!(),
+        },
+        _ => quote!(),
+    };
+
+    let column_count = quote_number_prop("column_count", style, |value: f32| {
+        let value = value as u16;
+        quote!(Some(#value))
+    });
+    let column_width = quote_object_prop("column_width", style, generate_length_percentage_auto);
+    let column_fill = match style["columnFill"] {
+        Value::String(ref value) => match value.as_ref() {
+            "auto" => quote!(column_fill: taffy::style::ColumnFill::Auto,),
             _ => quote!(),
         },
         _ => quote!(),
@@ -379,6 +470,7 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
             Value::String(ref value) => match value.as_ref() {
                 "hidden" => Some(quote!(taffy::style::Overflow::Hidden)),
                 "scroll" => Some(quote!(taffy::style::Overflow::Scroll)),
+                "clip" => Some(quote!(taffy::style::Overflow::Clip)),
                 "auto" => Some(quote!(taffy::style::Overflow::Auto)),
                 _ => None,
             },
@@ -396,15 +488,28 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     } else {
         (quote!(), quote!())
     };
+    let scrollbar_gutter = match style["scrollbarGutter"] {
+        Value::String(ref value) => match value.as_ref() {
+            "stable" => quote!(scrollbar_gutter: taffy::style::ScrollbarGutter::Stable,),
+            "stable both-edges" => quote!(scrollbar_gutter: taffy::style::ScrollbarGutter::StableBothEdges,),
+            _ => quote!(),
+        },
+        _ => quote!(),
+    };
 
     let align_items = match style["alignItems"] {
         Value::String(ref value) => match value.as_ref() {
             "start" => quote!(align_items: Some(taffy::style::AlignItems::Start),),
-            "end" => quote!(align_items: Some(taffy::style::AlignItems::End),),
-            "flex-start" => quote!(align_items: Some(taffy::style::AlignItems::FlexStart),),
-            "flex-end" => quote!(align_items: Some(taffy::style::AlignItems::FlexEnd),),
-            "center" => quote!(align_items: Some(taffy::style::AlignItems::Center),),
+            "end" | "unsafe end" => quote!(align_items: Some(taffy::style::AlignItems::End),),
+            "safe end" => quote!(align_items: Some(taffy::style::AlignItems::SafeEnd),),
+            "flex-start" | "unsafe flex-start" => quote!(align_items: Some(taffy::style::AlignItems::FlexStart),),
+            "safe flex-start" => quote!(align_items: Some(taffy::style::AlignItems::SafeFlexStart),),
+            "flex-end" | "unsafe flex-end" => quote!(align_items: Some(taffy::style::AlignItems::FlexEnd),),
+            "safe flex-end" => quote!(align_items: Some(taffy::style::AlignItems::SafeFlexEnd),),
+            "center" | "unsafe center" => quote!(align_items: Some(taffy::style::AlignItems::Center),),
+            "safe center" => quote!(align_items: Some(taffy::style::AlignItems::SafeCenter),),
             "baseline" => quote!(align_items: Some(taffy::style::AlignItems::Baseline),),
+            "last baseline" => quote!(align_items: Some(taffy::style::AlignItems::LastBaseline),),
             "stretch" => quote!(align_items: Some(taffy::style::AlignItems::Stretch),),
             _ => quote!(),
         },
@@ -414,11 +519,16 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     let align_self = match style["alignSelf"] {
         Value::String(ref value) => match value.as_ref() {
             "start" => quote!(align_self: Some(taffy::style::AlignSelf::Start),),
-            "end" => quote!(align_self: Some(taffy::style::AlignSelf::End),),
-            "flex-start" => quote!(align_self: Some(taffy::style::AlignSelf::FlexStart),),
-            "flex-end" => quote!(align_self: Some(taffy::style::AlignSelf::FlexEnd),),
-            "center" => quote!(align_self: Some(taffy::style::AlignSelf::Center),),
+            "end" | "unsafe end" => quote!(align_self: Some(taffy::style::AlignSelf::End),),
+            "safe end" => quote!(align_self: Some(taffy::style::AlignSelf::SafeEnd),),
+            "flex-start" | "unsafe flex-start" => quote!(align_self: Some(taffy::style::AlignSelf::FlexStart),),
+            "safe flex-start" => quote!(align_self: Some(taffy::style::AlignSelf::SafeFlexStart),),
+            "flex-end" | "unsafe flex-end" => quote!(align_self: Some(taffy::style::AlignSelf::FlexEnd),),
+            "safe flex-end" => quote!(align_self: Some(taffy::style::AlignSelf::SafeFlexEnd),),
+            "center" | "unsafe center" => quote!(align_self: Some(taffy::style::AlignSelf::Center),),
+            "safe center" => quote!(align_self: Some(taffy::style::AlignSelf::SafeCenter),),
             "baseline" => quote!(align_self: Some(taffy::style::AlignSelf::Baseline),),
+            "last baseline" => quote!(align_self: Some(taffy::style::AlignSelf::LastBaseline),),
             "stretch" => quote!(align_self: Some(taffy::style::AlignSelf::Stretch),),
             _ => quote!(),
         },
@@ -428,11 +538,16 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     let justify_items = match style["justifyItems"] {
         Value::String(ref value) => match value.as_ref() {
             "start" => quote!(justify_items: Some(taffy::style::JustifyItems::Start),),
-            "end" => quote!(justify_items: Some(taffy::style::JustifyItems::End),),
-            "flex-start" => quote!(justify_items: Some(taffy::style::JustifyItems::FlexStart),),
-            "flex-end" => quote!(justify_items: Some(taffy::style::JustifyItems::FlexEnd),),
-            "center" => quote!(justify_items: Some(taffy::style::JustifyItems::Center),),
+            "end" | "unsafe end" => quote!(justify_items: Some(taffy::style::JustifyItems::End),),
+            "safe end" => quote!(justify_items: Some(taffy::style::JustifyItems::SafeEnd),),
+            "flex-start" | "unsafe flex-start" => quote!(justify_items: Some(taffy::style::JustifyItems::FlexStart),),
+            "safe flex-start" => quote!(justify_items: Some(taffy::style::JustifyItems::SafeFlexStart),),
+            "flex-end" | "unsafe flex-end" => quote!(justify_items: Some(taffy::style::JustifyItems::FlexEnd),),
+            "safe flex-end" => quote!(justify_items: Some(taffy::style::JustifyItems::SafeFlexEnd),),
+            "center" | "unsafe center" => quote!(justify_items: Some(taffy::style::JustifyItems::Center),),
+            "safe center" => quote!(justify_items: Some(taffy::style::JustifyItems::SafeCenter),),
             "baseline" => quote!(justify_items: Some(taffy::style::JustifyItems::Baseline),),
+            "last baseline" => quote!(justify_items: Some(taffy::style::JustifyItems::LastBaseline),),
             "stretch" => quote!(justify_items: Some(taffy::style::JustifyItems::Stretch),),
             _ => quote!(),
         },
@@ -442,11 +557,16 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     let justify_self = match style["justifySelf"] {
         Value::String(ref value) => match value.as_ref() {
             "start" => quote!(justify_self: Some(taffy::style::JustifySelf::Start),),
-            "end" => quote!(justify_self: Some(taffy::style::JustifySelf::End),),
-            "flex-start" => quote!(justify_self: Some(taffy::style::JustifySelf::FlexStart),),
-            "flex-end" => quote!(justify_self: Some(taffy::style::JustifySelf::FlexEnd),),
-            "center" => quote!(justify_self: Some(taffy::style::JustifySelf::Center),),
+            "end" | "unsafe end" => quote!(justify_self: Some(taffy::style::JustifySelf::End),),
+            "safe end" => quote!(justify_self: Some(taffy::style::JustifySelf::SafeEnd),),
+            "flex-start" | "unsafe flex-start" => quote!(justify_self: Some(taffy::style::JustifySelf::FlexStart),),
+            "safe flex-start" => quote!(justify_self: Some(taffy::style::JustifySelf::SafeFlexStart),),
+            "flex-end" | "unsafe flex-end" => quote!(justify_self: Some(taffy::style::JustifySelf::FlexEnd),),
+            "safe flex-end" => quote!(justify_self: Some(taffy::style::JustifySelf::SafeFlexEnd),),
+            "center" | "unsafe center" => quote!(justify_self: Some(taffy::style::JustifySelf::Center),),
+            "safe center" => quote!(justify_self: Some(taffy::style::JustifySelf::SafeCenter),),
             "baseline" => quote!(justify_self: Some(taffy::style::JustifySelf::Baseline),),
+            "last baseline" => quote!(justify_self: Some(taffy::style::JustifySelf::LastBaseline),),
             "stretch" => quote!(justify_self: Some(taffy::style::JustifySelf::Stretch),),
             _ => quote!(),
         },
@@ -456,10 +576,14 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     let align_content = match style["alignContent"] {
         Value::String(ref value) => match value.as_ref() {
             "start" => quote!(align_content: Some(taffy::style::AlignContent::Start),),
-            "end" => quote!(align_content: Some(taffy::style::AlignContent::End),),
-            "flex-start" => quote!(align_content: Some(taffy::style::AlignContent::FlexStart),),
-            "flex-end" => quote!(align_content: Some(taffy::style::AlignContent::FlexEnd),),
-            "center" => quote!(align_content: Some(taffy::style::AlignContent::Center),),
+            "end" | "unsafe end" => quote!(align_content: Some(taffy::style::AlignContent::End),),
+            "safe end" => quote!(align_content: Some(taffy::style::AlignContent::SafeEnd),),
+            "flex-start" | "unsafe flex-start" => quote!(align_content: Some(taffy::style::AlignContent::FlexStart),),
+            "safe flex-start" => quote!(align_content: Some(taffy::style::AlignContent::SafeFlexStart),),
+            "flex-end" | "unsafe flex-end" => quote!(align_content: Some(taffy::style::AlignContent::FlexEnd),),
+            "safe flex-end" => quote!(align_content: Some(taffy::style::AlignContent::SafeFlexEnd),),
+            "center" | "unsafe center" => quote!(align_content: Some(taffy::style::AlignContent::Center),),
+            "safe center" => quote!(align_content: Some(taffy::style::AlignContent::SafeCenter),),
             "stretch" => quote!(align_content: Some(taffy::style::AlignContent::Stretch),),
             "space-between" => quote!(align_content: Some(taffy::style::AlignContent::SpaceBetween),),
             "space-around" => quote!(align_content: Some(taffy::style::AlignContent::SpaceAround),),
@@ -472,10 +596,16 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     let justify_content = match style["justifyContent"] {
         Value::String(ref value) => match value.as_ref() {
             "start" => quote!(justify_content: Some(taffy::style::JustifyContent::Start),),
-            "end" => quote!(justify_content: Some(taffy::style::JustifyContent::End),),
-            "flex-start" => quote!(justify_content: Some(taffy::style::JustifyContent::FlexStart),),
-            "flex-end" => quote!(justify_content: Some(taffy::style::JustifyContent::FlexEnd),),
-            "center" => quote!(justify_content: Some(taffy::style::JustifyContent::Center),),
+            "end" | "unsafe end" => quote!(justify_content: Some(taffy::style::JustifyContent::End),),
+            "safe end" => quote!(justify_content: Some(taffy::style::JustifyContent::SafeEnd),),
+            "flex-start" | "unsafe flex-start" => {
+                quote!(justify_content: Some(taffy::style::JustifyContent::FlexStart),)
+            }
+            "safe flex-start" => quote!(justify_content: Some(taffy::style::JustifyContent::SafeFlexStart),),
+            "flex-end" | "unsafe flex-end" => quote!(justify_content: Some(taffy::style::JustifyContent::FlexEnd),),
+            "safe flex-end" => quote!(justify_content: Some(taffy::style::JustifyContent::SafeFlexEnd),),
+            "center" | "unsafe center" => quote!(justify_content: Some(taffy::style::JustifyContent::Center),),
+            "safe center" => quote!(justify_content: Some(taffy::style::JustifyContent::SafeCenter),),
             "stretch" => quote!(justify_content: Some(taffy::style::AlignContent::Stretch),),
             "space-between" => quote!(justify_content: Some(taffy::style::JustifyContent::SpaceBetween),),
             "space-around" => quote!(justify_content: Some(taffy::style::JustifyContent::SpaceAround),),
@@ -485,10 +615,21 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
         _ => quote!(),
     };
 
+    let order = quote_number_prop("order", style, |value: f32| {
+        let value = value as i32;
+        quote!(#value)
+    });
     let flex_grow = quote_number_prop("flex_grow", style, |value: f32| quote!(#value));
     let flex_shrink = quote_number_prop("flex_shrink", style, |value: f32| quote!(#value));
 
     let flex_basis = quote_object_prop("flex_basis", style, generate_dimension);
+    let box_sizing = match style["boxSizing"] {
+        Value::String(ref value) => match value.as_ref() {
+            "content-box" => quote!(box_sizing: taffy::style::BoxSizing::ContentBox,),
+            _ => quote!(),
+        },
+        _ => quote!(),
+    };
     let size = quote_object_prop("size", style, generate_size);
     let min_size = quote_object_prop("min_size", style, generate_size);
     let max_size = quote_object_prop("max_size", style, generate_size);
@@ -498,6 +639,7 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
 
     let grid_template_rows = quote_array_prop("grid_template_rows", style, generate_track_definition_list);
     let grid_template_columns = quote_array_prop("grid_template_columns", style, generate_track_definition_list);
+    let grid_template_areas = quote_array_prop("grid_template_areas", style, generate_template_areas);
     let grid_auto_rows = quote_array_prop("grid_auto_rows", style, generate_track_definition_list);
     let grid_auto_columns = quote_array_prop("grid_auto_columns", style, generate_track_definition_list);
     let grid_auto_flow = quote_object_prop("grid_auto_flow", style, generate_grid_auto_flow);
@@ -533,10 +675,10 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     };
 
     let text_content = get_string_value("text_content", node);
-    let writing_mode = get_string_value("writingMode", style);
+    let text_writing_mode = get_string_value("writingMode", style);
     let raw_aspect_ratio = get_number_value("aspect_ratio", style);
     let measure_func: Option<_> =
-        text_content.map(|text| generate_measure_function(text, writing_mode, raw_aspect_ratio));
+        text_content.map(|text| generate_measure_function(text, text_writing_mode, raw_aspect_ratio));
 
     edges_quoted!(style, margin, generate_length_percentage_auto, quote!(zero()));
     edges_quoted!(style, padding, generate_length_percentage, quote!(zero()));
@@ -569,29 +711,45 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
 
     let style = quote!(taffy::style::Style {
         #display
+        #visibility
         #direction
+        #writing_mode
+        #text_align
+        #column_count
+        #column_width
+        #column_fill
         #position
+        #float
+        #clear
+        #border_collapse
+        #border_spacing
+        #col_span
+        #row_span
         #flex_direction
         #flex_wrap
         #overflow
         #scrollbar_width
+        #scrollbar_gutter
         #align_items
         #align_self
         #justify_items
         #justify_self
         #align_content
         #justify_content
+        #order
         #flex_grow
         #flex_shrink
         #flex_basis
         #gap
         #grid_template_rows
         #grid_template_columns
+        #grid_template_areas
         #grid_auto_rows
         #grid_auto_columns
         #grid_auto_flow
         #grid_row
         #grid_column
+        #box_sizing
         #size
         #min_size
         #max_size
@@ -691,6 +849,16 @@ fn generate_dimension(dimen: &serde_json::Map<String, Value>) -> TokenStream {
                 let value = value();
                 quote!(taffy::style::Dimension::Percent(#value))
             }
+            "min-content" => quote!(taffy::style::Dimension::MinContent),
+            "max-content" => quote!(taffy::style::Dimension::MaxContent),
+            "stretch" => quote!(taffy::style::Dimension::Stretch),
+            "fit-content" => {
+                let limit = match dimen.get("value").unwrap() {
+                    Value::Object(ref limit) => generate_length_percentage(limit),
+                    _ => unreachable!(),
+                };
+                quote!(taffy::style::Dimension::FitContent(#limit))
+            }
             _ => unreachable!(),
         },
         _ => unreachable!(),
@@ -717,6 +885,7 @@ fn generate_line(start: TokenStream, end: TokenStream) -> TokenStream {
 fn generate_grid_position(grid_position: &serde_json::Map<String, Value>) -> TokenStream {
     let kind = grid_position.get("kind").unwrap();
     let value = || grid_position.get("value").unwrap().as_f64().unwrap() as f32;
+    let name = || grid_position.get("name").unwrap().as_str().unwrap();
 
     match kind {
         Value::String(ref kind) => match kind.as_ref() {
@@ -729,12 +898,31 @@ fn generate_grid_position(grid_position: &serde_json::Map<String, Value>) -> Tok
                 let value = value() as i16;
                 quote!(line(#value))
             }
+            "named-line" => {
+                let name = name();
+                let value = value() as i16;
+                quote!(taffy::style::GridPlacement::NamedLine(#name.into(), #value))
+            }
+            "named-span" => {
+                let name = name();
+                let value = value() as u16;
+                quote!(taffy::style::GridPlacement::NamedSpan(#name.into(), #value))
+            }
+            "named-area" => {
+                let name = name();
+                quote!(taffy::style::GridPlacement::NamedArea(#name.into()))
+            }
             _ => unreachable!(),
         },
         _ => unreachable!(),
     }
 }
 
+fn generate_template_areas(raw_list: &[Value]) -> TokenStream {
+    let rows = raw_list.iter().map(|row| row.as_str().unwrap());
+    quote!(template_areas(&[#(#rows),*]))
+}
+
 fn generate_track_definition_list(raw_list: &[Value]) -> TokenStream {
     let list = raw_list.iter().map(|obj| match obj {
         Value::Object(inner) => generate_track_definition(inner),
@@ -751,6 +939,13 @@ fn generate_track_definition(track_definition: &serde_json::Map<String, Value>)
 
     match kind {
         "scalar" => generate_scalar_definition(track_definition),
+        "subgrid" => quote!(taffy::style::TrackSizingFunction::Subgrid),
+        "line-names" => {
+            let names = track_definition.get("names").unwrap().as_array().unwrap();
+            let names = names.iter().map(|name| name.as_str().unwrap());
+            quote!(line_names(&[#(#names),*]))
+        }
+        "masonry" => quote!(taffy::style::TrackSizingFunction::Masonry),
         "function" => match (name(), arguments()) {
             ("fit-content", Value::Array(arguments)) => {
                 if arguments.len() != 1 {
@@ -38,8 +38,8 @@ class TrackSizingParser {
       // Skip whitespace
       if (char === ' ') { this.index++; continue; }
 
-      if (TrackSizingParser.INITIAL_CHAR_REGEX.test(char)) {
-        const token = this._parseItem();
+      if (char === '[' || TrackSizingParser.INITIAL_CHAR_REGEX.test(char)) {
+        const token = char === '[' ? this._parseLineNames() : this._parseItem();
         tokenList.push(token);
 
         const nextChar = this.input[this.index];
@@ -82,6 +82,13 @@ class TrackSizingParser {
 
   }
 
+  _parseLineNames() {
+    const end = this.input.indexOf(']', this.index);
+    const names = this.input.slice(this.index + 1, end).trim().split(/\s+/).filter(name => name);
+    this.index = end + 1;
+    return { kind: 'line-names', names };
+  }
+
   _parseScalarItem(item) {
     const res = parseRepetition(item) || parseDimension(item, { allowFrUnits: this.options.allowFrUnits });
     if (!res) throw new Error(`Invalid scalar grid track sizing function ${item}`);
@@ -104,6 +111,9 @@ function parseDimension(input, options = { allowFrUnits: false }) {
   if (input === 'auto') return { unit: 'auto' };
   if (input === 'min-content') return { unit: 'min-content' };
   if (input === 'max-content') return { unit: 'max-content' };
+  if (input === 'stretch' || input === '-webkit-fill-available') return { unit: 'stretch' };
+  const fitContent = /^fit-content\((.*)\)$/.exec(input);
+  if (fitContent) return { unit: 'fit-content', value: parseDimension(fitContent[1].trim()) };
   return undefined;
 }
 
@@ -161,11 +171,30 @@ function parseGaps(style) {
 }
 
 
+function parseBorderSpacing(input) {
+  if (input === '') return undefined;
+  // The horizontal spacing comes first, and is also used vertically if there is no second value
+  const spacings = input.trim().split(/\s+/).map(part => parseDimension(part));
+  return { column: spacings[0], row: spacings[1] ?? spacings[0] };
+}
+
+// The `colspan` and `rowspan` attributes of table cells (which are not CSS properties)
+function parseSpan(e, attribute) {
+  return e.hasAttribute(attribute) ? parseNumber(e.getAttribute(attribute)) : undefined;
+}
+
 function parseGridTrackDefinitions(input) {
   if (input === '') return undefined;
+  if (input === 'subgrid') return [{ kind: 'subgrid' }];
+  if (input === 'masonry') return [{ kind: 'masonry' }];
   return new TrackSizingParser(input).parseList();
 }
 
+function parseGridTemplateAreas(input) {
+  if (!input || input === 'none') return undefined;
+  return [...input.matchAll(/"([^"]*)"/g)].map(match => match[1]);
+}
+
 function parseGridAutoFlow(input) {
   if (!/column/.test(input) && !/row/.test(input) && !/dense/.test(input)) return undefined;
   const direction = /column/.test(input) ? 'column' : 'row';
@@ -174,9 +203,23 @@ function parseGridAutoFlow(input) {
 }
 
 function parseGridPosition(input) {
+  const IDENT = '[a-zA-Z_][a-zA-Z0-9_-]*';
   if (input === 'auto') return { kind: 'auto' };
   if (/^span +\d+$/.test(input)) return { kind: 'span', value: parseInt(input.replace(/[^\d]/g, ''), 10) };
   if (/^-?\d+$/.test(input)) return { kind: 'line', value: parseInt(input, 10) };
+
+  // Named spans: "span <ident>", "span <integer> <ident>" or "span <ident> <integer>"
+  let match = new RegExp(`^span +(?:(\\d+) +)?(${IDENT})(?: +(\\d+))?$`).exec(input);
+  if (match) return { kind: 'named-span', name: match[2], value: parseInt(match[1] ?? match[3] ?? '1', 10) };
+
+  // Named lines: "<integer> <ident>" or "<ident> <integer>"
+  match = new RegExp(`^(-?\\d+) +(${IDENT})$`).exec(input);
+  if (match) return { kind: 'named-line', name: match[2], value: parseInt(match[1], 10) };
+  match = new RegExp(`^(${IDENT}) +(-?\\d+)$`).exec(input);
+  if (match) return { kind: 'named-line', name: match[1], value: parseInt(match[2], 10) };
+
+  // A bare identifier refers to a named area (or a named line)
+  if (new RegExp(`^${IDENT}$`).test(input)) return { kind: 'named-area', name: input };
   return undefined;
 }
 
@@ -189,17 +232,30 @@ function describeElement(e) {
   return {
     style: {
       display: parseEnum(e.style.display),
+      visibility: parseEnum(e.style.visibility),
 
       position: parseEnum(e.style.position),
+      float: parseEnum(e.style.float),
+      clear: parseEnum(e.style.clear),
+      borderCollapse: parseEnum(e.style.borderCollapse),
+      borderSpacing: parseBorderSpacing(e.style.borderSpacing),
+      colSpan: parseSpan(e, 'colspan'),
+      rowSpan: parseSpan(e, 'rowspan'),
       direction: parseEnum(e.style.direction),
       flexDirection: parseEnum(e.style.flexDirection),
 
       writingMode: parseEnum(e.style.writingMode),
+      boxSizing: parseEnum(e.style.boxSizing),
+      textAlign: parseEnum(e.style.textAlign),
+      columnCount: parseNumber(e.style.columnCount),
+      columnWidth: parseDimension(e.style.columnWidth),
+      columnFill: parseEnum(e.style.columnFill),
 
       flexWrap: parseEnum(e.style.flexWrap),
       overflowX: parseEnum(e.style.overflowX),
       overflowY: parseEnum(e.style.overflowY),
       scrollbarWidth: getScrollBarWidth(),
+      scrollbarGutter: parseEnum(e.style.scrollbarGutter),
 
       alignItems: parseEnum(e.style.alignItems),
       alignSelf: parseEnum(e.style.alignSelf),
@@ -209,12 +265,14 @@ function describeElement(e) {
       alignContent: parseEnum(e.style.alignContent),
       justifyContent: parseEnum(e.style.justifyContent),
 
+      order: parseNumber(e.style.order),
       flexGrow: parseNumber(e.style.flexGrow),
       flexShrink: parseNumber(e.style.flexShrink),
       flexBasis: parseDimension(e.style.flexBasis),
 
       gridTemplateRows: parseGridTrackDefinitions(e.style.gridTemplateRows),
       gridTemplateColumns: parseGridTrackDefinitions(e.style.gridTemplateColumns),
+      gridTemplateAreas: parseGridTemplateAreas(e.style.gridTemplateAreas),
       gridAutoRows: parseGridTrackDefinitions(e.style.gridAutoRows),
       gridAutoColumns: parseGridTrackDefinitions(e.style.gridAutoColumns),
       gridAutoFlow: parseGridAutoFlow(e.style.gridAutoFlow),
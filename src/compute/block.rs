@@ -1,7 +1,20 @@
-//! Computes the CSS block layout algorithm in the case that the block container being laid out contains only block-level boxes
+//! Computes the CSS block layout algorithm, which stacks the block-level children of a block container (and the lines
+//! that its inline-level children are placed on) vertically, flowing them around its floated children
+use crate::compute::common::absolute::{resolve_absolute_box_sizes, AbsoluteBoxSizes};
+use crate::compute::common::alignment::{compute_alignment_offset, resolve_overflow_alignment};
+use crate::compute::common::content_size::compute_content_size;
+use crate::compute::common::contents::{layout_children, perform_contents_layout};
+use crate::compute::common::intrinsic_size::resolve_intrinsic_sizes;
+use crate::compute::float::{is_floated, resolve_clear_side, resolve_float_side, FloatContext};
+use crate::compute::inline::{
+    compute_inline_content_width, compute_shrink_to_fit_size, is_inline_level, perform_inline_layout,
+};
+use crate::compute::multicol::{fragment_into_columns, ColumnStyles};
 use crate::compute::LayoutAlgorithm;
 use crate::geometry::{Line, Point, Rect, Size};
-use crate::style::{AvailableSpace, Display, LengthPercentageAuto, Overflow, Position};
+use crate::style::{
+    AlignContent, AlignSelf, AvailableSpace, Clear, Display, Float, LengthPercentageAuto, Position, Style, TextAlign,
+};
 use crate::style_helpers::TaffyMaxContent;
 use crate::tree::{CollapsibleMarginSet, Layout, RunMode, SizeBaselinesAndMargins, SizingMode};
 use crate::tree::{LayoutTree, NodeId};
@@ -10,6 +23,9 @@ use crate::util::sys::Vec;
 use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::compute::common::writing_mode::compute_in_vertical_writing_mode;
+
 #[cfg(feature = "debug")]
 use crate::util::debug::NODE_LOGGER;
 
@@ -78,6 +94,22 @@ struct BlockItem {
 
     /// The position style of the item
     position: Position,
+    /// Whether the item should be painted
+    is_visible: bool,
+    /// Whether the item is placed on a line alongside adjacent inline-level items rather than stacked vertically
+    is_inline: bool,
+    /// The side of the container that the item is floated to (as if the container were left-to-right), if any
+    float: Float,
+    /// The floats (as if the container were left-to-right) that the item must be placed below
+    clear: Clear,
+    /// How the item is aligned in the inline axis, if it is a block-level item. `None` is the `normal` alignment, which
+    /// stretches the item like `Stretch` does.
+    justify_self: Option<AlignSelf>,
+    /// Whether the item shrinks to fit its content when its width is auto, rather than filling the available space
+    shrinks_to_fit: bool,
+    /// Whether the item is a block container that doesn't establish a new block formatting context, and so shares the
+    /// floats of the container
+    shares_floats: bool,
     /// The final offset of this item
     inset: Rect<LengthPercentageAuto>,
     /// The margin of this item
@@ -103,19 +135,83 @@ pub fn compute(
     available_space: Size<AvailableSpace>,
     run_mode: RunMode,
     vertical_margins_are_collapsible: Line<bool>,
+) -> SizeBaselinesAndMargins {
+    // The margins of a node with a vertical writing mode never collapse with those of its children,
+    // as they are not in its block axis
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    if tree.style(node_id).writing_mode.is_vertical() {
+        return compute_in_vertical_writing_mode(
+            tree,
+            node_id,
+            known_dimensions,
+            parent_size,
+            available_space,
+            run_mode,
+            |tree, node_id, known_dimensions, parent_size, available_space, run_mode| {
+                compute_horizontal(
+                    tree,
+                    node_id,
+                    known_dimensions,
+                    parent_size,
+                    available_space,
+                    run_mode,
+                    Line::FALSE,
+                    None,
+                )
+            },
+        );
+    }
+    compute_horizontal(
+        tree,
+        node_id,
+        known_dimensions,
+        parent_size,
+        available_space,
+        run_mode,
+        vertical_margins_are_collapsible,
+        None,
+    )
+}
+
+/// Computes the layout of a block container whose inline axis is horizontal. If `float_context` is `Some`, the
+/// container doesn't establish a new block formatting context, and instead shares the floats of its parent (see
+/// [`FloatContext::for_child`]).
+#[allow(clippy::too_many_arguments)]
+fn compute_horizontal(
+    tree: &mut impl LayoutTree,
+    node_id: NodeId,
+    known_dimensions: Size<Option<f32>>,
+    parent_size: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+    vertical_margins_are_collapsible: Line<bool>,
+    float_context: Option<&mut FloatContext>,
 ) -> SizeBaselinesAndMargins {
     let style = tree.style(node_id);
 
     // Pull these out earlier to avoid borrowing issues
     let aspect_ratio = style.aspect_ratio;
     let margin = style.margin.resolve_or_zero(parent_size.width);
-    let min_size = style.min_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
-    let max_size = style.max_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
     let padding = style.padding.resolve_or_zero(parent_size.width);
     let border = style.border.resolve_or_zero(parent_size.width);
     let padding_border_size = (padding + border).sum_axes();
-    let clamped_style_size =
-        style.size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+    let box_sizing_adjustment = style.box_sizing.adjustment(padding_border_size);
+    let min_size = style
+        .min_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let max_size = style
+        .max_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let clamped_style_size = style
+        .size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment)
+        .maybe_clamp(min_size, max_size);
 
     // If both min and max in a given axis are set and max <= min then this determines the size in that axis
     let min_max_definite_size = min_size.zip_map(max_size, |min, max| match (min, max) {
@@ -151,10 +247,12 @@ pub fn compute(
         available_space,
         run_mode,
         vertical_margins_are_collapsible,
+        float_context,
     )
 }
 
 /// Computes the layout of [`LayoutTree`] according to the block layout algorithm
+#[allow(clippy::too_many_arguments)]
 fn compute_inner(
     tree: &mut impl LayoutTree,
     node_id: NodeId,
@@ -163,46 +261,60 @@ fn compute_inner(
     available_space: Size<AvailableSpace>,
     run_mode: RunMode,
     vertical_margins_are_collapsible: Line<bool>,
+    float_context: Option<&mut FloatContext>,
 ) -> SizeBaselinesAndMargins {
     let style = tree.style(node_id);
-    let raw_padding = style.padding;
-    let raw_border = style.border;
+
+    // Right-to-left containers are laid out as if they were left-to-right with the horizontal sides of the container
+    // and its children swapped. The horizontal positions of the children are then mirrored.
+    let is_rtl = style.direction.is_rtl();
+    let raw_padding = style.padding.swap_horizontal_sides_if(is_rtl);
+    let raw_border = style.border.swap_horizontal_sides_if(is_rtl);
     let raw_margin = style.margin;
+    let text_align = style.text_align;
+    let align_content = style.align_content;
+    let justify_items = style.justify_items;
+    let column_styles = ColumnStyles::of(style);
     let aspect_ratio = style.aspect_ratio;
-    let size = style.size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
-    let min_size = style.min_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
-    let max_size = style.max_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
-    let padding = style.padding.resolve_or_zero(parent_size.width);
-    let border = style.border.resolve_or_zero(parent_size.width);
-
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = {
-        let offsets = style.overflow.transpose().map(|overflow| match overflow {
-            Overflow::Scroll => style.scrollbar_width,
-            _ => 0.0,
-        });
-        // TODO: make side configurable based on the `direction` property
-        Rect { top: 0.0, left: 0.0, right: offsets.x, bottom: offsets.y }
-    };
+    let padding = raw_padding.resolve_or_zero(parent_size.width);
+    let border = raw_border.resolve_or_zero(parent_size.width);
+    let box_sizing_adjustment = style.box_sizing.adjustment((padding + border).sum_axes());
+    let size =
+        style.size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio).maybe_add(box_sizing_adjustment);
+    let min_size = style
+        .min_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let max_size = style
+        .max_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+
+    let scrollbar_gutter = style.scrollbar_gutter_inset();
     let padding_border = padding + border;
     let padding_border_size = padding_border.sum_axes();
     let content_box_inset = padding_border + scrollbar_gutter;
     let container_content_box_size = known_dimensions.maybe_sub(content_box_inset.sum_axes());
 
-    // Determine margin collapsing behaviour
+    // Determine margin collapsing behaviour. A container that aligns its content with `align_content` (or divides it into
+    // columns) establishes an independent formatting context, so its margins never collapse with those of its children.
     let own_margins_collapse_with_children = Line {
         start: vertical_margins_are_collapsible.start
             && !style.overflow.x.is_scroll_container()
             && !style.overflow.y.is_scroll_container()
-            && style.position == Position::Relative
+            && !style.position.is_out_of_flow()
+            && align_content.is_none()
+            && column_styles.is_none()
             && padding.top == 0.0
             && border.top == 0.0,
         end: vertical_margins_are_collapsible.end
             && !style.overflow.x.is_scroll_container()
             && !style.overflow.y.is_scroll_container()
-            && style.position == Position::Relative
+            && !style.position.is_out_of_flow()
+            && align_content.is_none()
+            && column_styles.is_none()
             && padding.bottom == 0.0
             && border.bottom == 0.0
             && size.height.is_none(),
@@ -210,20 +322,37 @@ fn compute_inner(
     let has_styles_preventing_being_collapsed_through = style.display != Display::Block
         || style.overflow.x.is_scroll_container()
         || style.overflow.y.is_scroll_container()
-        || style.position == Position::Absolute
+        || style.position.is_out_of_flow()
+        || align_content.is_some()
+        || column_styles.is_some()
         || padding.top > 0.0
         || padding.bottom > 0.0
         || border.top > 0.0
         || border.bottom > 0.0;
 
     // 1. Generate items
-    let mut items = generate_item_list(tree, node_id, container_content_box_size);
+    let mut items = generate_item_list(tree, node_id, container_content_box_size, justify_items, is_rtl);
+    for item in items.iter_mut().filter(|item| item.position != Position::Absolute) {
+        let margin_sum = item.margin.resolve_or_zero(container_content_box_size.width).sum_axes();
+        resolve_intrinsic_sizes(
+            tree,
+            item.node_id,
+            container_content_box_size,
+            container_content_box_size.maybe_sub(margin_sum),
+            &mut item.size,
+            &mut item.min_size,
+            &mut item.max_size,
+        );
+    }
 
     // 2. Compute container width
     let container_outer_width = known_dimensions.width.unwrap_or_else(|| {
         let available_width = available_space.width.maybe_sub(content_box_inset.horizontal_axis_sum());
-        let intrinsic_width = determine_content_based_container_width(tree, &items, available_width)
-            + content_box_inset.horizontal_axis_sum();
+        let content_width = determine_content_based_container_width(tree, &items, available_width);
+        let intrinsic_width = match &column_styles {
+            Some(column_styles) => column_styles.content_based_inner_width(content_width),
+            None => content_width,
+        } + content_box_inset.horizontal_axis_sum();
         intrinsic_width.maybe_clamp(min_size.width, max_size.width).maybe_max(Some(padding_border_size.width))
     });
 
@@ -232,19 +361,45 @@ fn compute_inner(
         return Size { width: container_outer_width, height: container_outer_height }.into();
     }
 
-    // 3. Perform final item layout and return content height
+    // 3. Perform final item layout and return content height. The children of a multi-column container are laid out
+    // in a single column as wide as its columns, which is then divided into columns.
     let resolved_padding = raw_padding.resolve_or_zero(Some(container_outer_width));
     let resolved_border = raw_border.resolve_or_zero(Some(container_outer_width));
     let resolved_content_box_inset = resolved_padding + resolved_border + scrollbar_gutter;
-    let (intrinsic_outer_height, first_child_top_margin_set, last_child_bottom_margin_set) =
+    let columns = column_styles
+        .as_ref()
+        .map(|column_styles| column_styles.resolve(container_outer_width - content_box_inset.horizontal_axis_sum()));
+    let (mut intrinsic_outer_height, first_child_top_margin_set, last_child_bottom_margin_set, mut baselines) =
         perform_final_layout_on_in_flow_children(
             tree,
             &mut items,
-            container_outer_width,
+            columns.map_or(container_outer_width, |columns| columns.width + content_box_inset.horizontal_axis_sum()),
             content_box_inset,
             resolved_content_box_inset,
             own_margins_collapse_with_children,
+            text_align,
+            is_rtl,
+            float_context,
         );
+    if let (Some(columns), Some(column_styles)) = (columns, &column_styles) {
+        let in_flow_children: Vec<NodeId> =
+            items.iter().filter(|item| !item.position.is_out_of_flow()).map(|item| item.node_id).collect();
+        let height_limit = known_dimensions
+            .height
+            .or(max_size.height)
+            .map(|height| f32_max(0.0, height - resolved_content_box_inset.vertical_axis_sum()));
+        let fragmentation = fragment_into_columns(
+            tree,
+            &in_flow_children,
+            columns,
+            column_styles.fill,
+            resolved_content_box_inset.top,
+            intrinsic_outer_height - resolved_content_box_inset.bottom,
+            height_limit,
+        );
+        intrinsic_outer_height = resolved_content_box_inset.vertical_axis_sum() + fragmentation.height;
+        baselines.end = baselines.end.map(|baseline| baseline - fragmentation.last_column_offset);
+    }
     let container_outer_height = known_dimensions
         .height
         .unwrap_or(intrinsic_outer_height.maybe_clamp(min_size.height, max_size.height))
@@ -256,16 +411,43 @@ fn compute_inner(
         return final_outer_size.into();
     }
 
+    // Align the content of the container within its content box per `align_content`. The static positions of
+    // absolutely positioned children are moved along with the in-flow content.
+    let content_offset =
+        compute_content_alignment_offset(align_content, container_outer_height - intrinsic_outer_height);
+    if content_offset != 0.0 {
+        for item in items.iter_mut() {
+            if item.position.is_out_of_flow() {
+                item.static_position.y += content_offset;
+            } else {
+                tree.layout_mut(item.node_id).location.y += content_offset;
+            }
+        }
+        baselines = baselines.map(|baseline| baseline.map(|baseline| baseline + content_offset));
+    }
+
     // 4. Layout absolutely positioned children
     let absolute_position_inset = resolved_border + scrollbar_gutter;
     let absolute_position_area = final_outer_size - absolute_position_inset.sum_axes();
     let absolute_position_offset = Point { x: absolute_position_inset.left, y: absolute_position_inset.top };
-    perform_absolute_layout_on_absolute_children(tree, &mut items, absolute_position_area, absolute_position_offset);
+    perform_absolute_layout_on_absolute_children(
+        tree,
+        &mut items,
+        absolute_position_area,
+        absolute_position_offset,
+        is_rtl,
+    );
+
+    // Mirror the horizontal positions of the children of right-to-left containers
+    if is_rtl {
+        for item in items.iter() {
+            let layout = tree.layout_mut(item.node_id);
+            layout.location.x = final_outer_size.width - layout.location.x - layout.size.width;
+        }
+    }
 
     // 5. Perform hidden layout on hidden children
-    let len = tree.child_count(node_id);
-    for order in 0..len {
-        let child = tree.child(node_id, order);
+    for (order, child) in layout_children(tree, node_id).into_iter().enumerate() {
         if tree.style(child).display == Display::None {
             *tree.layout_mut(child) = Layout::with_order(order as u32);
             tree.perform_child_layout(
@@ -278,16 +460,27 @@ fn compute_inner(
             );
         }
     }
+    perform_contents_layout(tree, node_id);
+
+    // 6. Compute the scrollable overflow from the final positions of the children
+    let content_size = compute_content_size(
+        tree,
+        node_id,
+        final_outer_size,
+        resolved_padding.swap_horizontal_sides_if(is_rtl),
+        resolved_border.swap_horizontal_sides_if(is_rtl),
+    );
 
     // 7. Determine whether this node can be collapsed through
     let all_in_flow_children_can_be_collapsed_through =
-        items.iter().all(|item| item.position == Position::Absolute || item.can_be_collapsed_through);
+        items.iter().all(|item| item.position.is_out_of_flow() || item.can_be_collapsed_through);
     let can_be_collapsed_through =
         !has_styles_preventing_being_collapsed_through && all_in_flow_children_can_be_collapsed_through;
 
     SizeBaselinesAndMargins {
         size: final_outer_size,
-        first_baselines: Point::NONE,
+        first_baselines: Point { x: None, y: baselines.start },
+        last_baselines: Point { x: None, y: baselines.end },
         top_margin: if own_margins_collapse_with_children.start {
             first_child_top_margin_set
         } else {
@@ -301,13 +494,49 @@ fn compute_inner(
             CollapsibleMarginSet::from_margin(margin_bottom)
         },
         margins_can_collapse_through: can_be_collapsed_through,
+        content_size,
     }
 }
 
+/// Whether a child shrinks to fit its content (rather than filling the available space) when its width is `auto`
+#[cfg(feature = "table_layout")]
+fn shrinks_to_fit(style: &Style) -> bool {
+    style.display == Display::Table
+}
+
+/// Whether a child shrinks to fit its content (rather than filling the available space) when its width is `auto`
+#[cfg(not(feature = "table_layout"))]
+fn shrinks_to_fit(_style: &Style) -> bool {
+    false
+}
+
+/// Whether a child is a block container that doesn't establish a new block formatting context, and so shares the floats
+/// of its container (if the container has a horizontal writing mode). Leaves are treated like replaced elements, which
+/// are placed beside floats rather than sharing them.
+fn shares_floats(tree: &impl LayoutTree, node: NodeId, style: &Style) -> bool {
+    style.display == Display::Block
+        && tree.child_count(node) > 0
+        && !style.writing_mode.is_vertical()
+        && !style.overflow.x.is_scroll_container()
+        && !style.overflow.y.is_scroll_container()
+        && style.align_content.is_none()
+        && ColumnStyles::of(style).is_none()
+}
+
 /// Create a `Vec` of `BlockItem` structs where each item in the `Vec` represents a child of the current node
+///
+/// The horizontal sides of each item's inset, margin, float and clear are swapped if the container is right-to-left
 #[inline]
-fn generate_item_list(tree: &impl LayoutTree, node: NodeId, node_inner_size: Size<Option<f32>>) -> Vec<BlockItem> {
-    tree.children(node)
+fn generate_item_list(
+    tree: &impl LayoutTree,
+    node: NodeId,
+    node_inner_size: Size<Option<f32>>,
+    justify_items: Option<AlignSelf>,
+    is_rtl: bool,
+) -> Vec<BlockItem> {
+    let is_vertical = tree.style(node).writing_mode.is_vertical();
+    layout_children(tree, node)
+        .into_iter()
         .map(|child_node_id| (child_node_id, tree.style(child_node_id)))
         .filter(|(_, style)| style.display != Display::None)
         .enumerate()
@@ -315,17 +544,41 @@ fn generate_item_list(tree: &impl LayoutTree, node: NodeId, node_inner_size: Siz
             let aspect_ratio = child_style.aspect_ratio;
             let padding = child_style.padding.resolve_or_zero(node_inner_size);
             let border = child_style.border.resolve_or_zero(node_inner_size);
+            let padding_border_sum = (padding + border).sum_axes();
+            let box_sizing_adjustment = child_style.box_sizing.adjustment(padding_border_sum);
             BlockItem {
                 node_id: child_node_id,
                 order: order as u32,
 
-                size: child_style.size.maybe_resolve(node_inner_size).maybe_apply_aspect_ratio(aspect_ratio),
-                min_size: child_style.min_size.maybe_resolve(node_inner_size).maybe_apply_aspect_ratio(aspect_ratio),
-                max_size: child_style.max_size.maybe_resolve(node_inner_size).maybe_apply_aspect_ratio(aspect_ratio),
+                size: child_style
+                    .size
+                    .maybe_resolve(node_inner_size)
+                    .maybe_apply_aspect_ratio(aspect_ratio)
+                    .maybe_add(box_sizing_adjustment),
+                min_size: child_style
+                    .min_size
+                    .maybe_resolve(node_inner_size)
+                    .maybe_apply_aspect_ratio(aspect_ratio)
+                    .maybe_add(box_sizing_adjustment),
+                max_size: child_style
+                    .max_size
+                    .maybe_resolve(node_inner_size)
+                    .maybe_apply_aspect_ratio(aspect_ratio)
+                    .maybe_add(box_sizing_adjustment),
                 position: child_style.position,
-                inset: child_style.inset,
-                margin: child_style.margin,
-                padding_border_sum: (padding + border).sum_axes(),
+                is_visible: child_style.visibility.is_visible(),
+                is_inline: is_inline_level(child_style),
+                float: match is_floated(child_style) {
+                    true => resolve_float_side(child_style.float, is_rtl),
+                    false => Float::None,
+                },
+                clear: resolve_clear_side(child_style.clear, is_rtl),
+                justify_self: child_style.justify_self.or(justify_items),
+                shrinks_to_fit: shrinks_to_fit(child_style),
+                shares_floats: !is_vertical && shares_floats(tree, child_node_id, child_style),
+                inset: child_style.inset.swap_horizontal_sides_if(is_rtl),
+                margin: child_style.margin.swap_horizontal_sides_if(is_rtl),
+                padding_border_sum,
 
                 // Fields to be computed later (for now we initialise with dummy values)
                 computed_size: Size::zero(),
@@ -345,13 +598,38 @@ fn determine_content_based_container_width(
 ) -> f32 {
     let available_space = Size { width: available_width, height: AvailableSpace::MinContent };
 
+    // Floats are placed side by side, and the content that follows them beside them, unless the container is being
+    // sized under a min-content constraint
+    let are_floats_beside_content = available_width != AvailableSpace::MinContent;
+
     let mut max_child_width = 0.0;
-    for item in items.iter().filter(|item| item.position != Position::Absolute) {
+    let mut float_width = 0.0;
+    let mut inline_run = Vec::new();
+    for item in items.iter().filter(|item| !item.position.is_out_of_flow()) {
+        // Runs of inline-level items are placed on lines, which are as wide as their widest line
+        if item.is_inline {
+            inline_run.push((item.node_id, item.order));
+            continue;
+        }
+        if !inline_run.is_empty() {
+            let inline_width = compute_inline_content_width(tree, &inline_run, available_width);
+            max_child_width = f32_max(max_child_width, float_width + inline_width);
+            inline_run.clear();
+        }
+
+        let item_x_margin_sum = item.margin.resolve_or_zero(available_space.width.into_option()).horizontal_axis_sum();
+        if item.float != Float::None {
+            let margin = item.margin.resolve_or_zero(available_space.width.into_option());
+            let size = compute_shrink_to_fit_size(tree, item.node_id, None, available_width, margin, false).size;
+            let width = f32_max(size.width, item.padding_border_sum.width) + item_x_margin_sum;
+            float_width = if are_floats_beside_content { float_width + width } else { 0.0 };
+            max_child_width = f32_max(max_child_width, f32_max(float_width, width));
+            continue;
+        }
+
         let known_dimensions = item.size.maybe_clamp(item.min_size, item.max_size);
 
         let width = known_dimensions.width.unwrap_or_else(|| {
-            let item_x_margin_sum =
-                item.margin.resolve_or_zero(available_space.width.into_option()).horizontal_axis_sum();
             let size_and_baselines = tree.perform_child_layout(
                 item.node_id,
                 known_dimensions,
@@ -365,14 +643,23 @@ fn determine_content_based_container_width(
         });
         let width = f32_max(width, item.padding_border_sum.width);
 
-        max_child_width = f32_max(max_child_width, width);
+        // Block-level items that clear the preceding floats are placed below them
+        let offset = if item.clear == Clear::None { float_width } else { 0.0 };
+        max_child_width = f32_max(max_child_width, offset + width);
+        float_width = 0.0;
+    }
+    if !inline_run.is_empty() {
+        let inline_width = compute_inline_content_width(tree, &inline_run, available_width);
+        max_child_width = f32_max(max_child_width, float_width + inline_width);
     }
 
     max_child_width
 }
 
-/// Compute each child's final size and position
+/// Compute each child's final size and position. Returns the content height, the collapsible margin sets of the first
+/// and last children, and the first (start) and last (end) baselines of the content.
 #[inline]
+#[allow(clippy::too_many_arguments)]
 fn perform_final_layout_on_in_flow_children(
     tree: &mut impl LayoutTree,
     items: &mut [BlockItem],
@@ -380,37 +667,209 @@ fn perform_final_layout_on_in_flow_children(
     content_box_inset: Rect<f32>,
     resolved_content_box_inset: Rect<f32>,
     own_margins_collapse_with_children: Line<bool>,
-) -> (f32, CollapsibleMarginSet, CollapsibleMarginSet) {
+    text_align: TextAlign,
+    is_rtl: bool,
+    inherited_float_context: Option<&mut FloatContext>,
+) -> (f32, CollapsibleMarginSet, CollapsibleMarginSet, Line<Option<f32>>) {
     // Resolve container_inner_width for sizing child nodes using intial content_box_inset
     let container_inner_width = container_outer_width - content_box_inset.horizontal_axis_sum();
     let parent_size = Size { width: Some(container_outer_width), height: None };
     let available_space =
         Size { width: AvailableSpace::Definite(container_inner_width), height: AvailableSpace::MinContent };
 
+    let content_box_x =
+        Line { start: resolved_content_box_inset.left, end: resolved_content_box_inset.left + container_inner_width };
+    let establishes_formatting_context = inherited_float_context.is_none();
+    let mut own_float_context = None;
+    let float_context = match inherited_float_context {
+        Some(float_context) => {
+            float_context.set_content_box_x(content_box_x);
+            float_context
+        }
+        None => own_float_context.insert(FloatContext::new(content_box_x)),
+    };
     let mut committed_y_offset = resolved_content_box_inset.top;
     let mut first_child_top_margin_set = CollapsibleMarginSet::ZERO;
     let mut active_collapsible_margin_set = CollapsibleMarginSet::ZERO;
     let mut is_collapsing_with_first_margin_set = true;
-    for item in items.iter_mut() {
-        if item.position == Position::Absolute {
+    let mut first_baseline = None;
+    let mut last_baseline = None;
+    let mut index = 0;
+    while index < items.len() {
+        // Place each run of inline-level items (along with any absolutely positioned items amongst them) on lines
+        if items[index].is_inline {
+            let run_length =
+                items[index..].iter().take_while(|item| item.is_inline || item.position.is_out_of_flow()).count();
+            let run = &mut items[index..index + run_length];
+            index += run_length;
+
+            let y_margin_offset = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
+                0.0
+            } else {
+                active_collapsible_margin_set.resolve()
+            };
+            let lines_location = Point { x: resolved_content_box_inset.left, y: committed_y_offset + y_margin_offset };
+            let inline_nodes: Vec<_> =
+                run.iter().filter(|item| item.is_inline).map(|item| (item.node_id, item.order)).collect();
+            let inline_output = perform_inline_layout(
+                tree,
+                &inline_nodes,
+                container_inner_width,
+                lines_location,
+                float_context,
+                text_align,
+                is_rtl,
+            );
+
+            for item in run.iter_mut() {
+                if item.is_inline {
+                    item.computed_size = tree.layout(item.node_id).size;
+                    item.can_be_collapsed_through = inline_output.is_empty;
+                } else {
+                    item.static_position = lines_location;
+                }
+            }
+
+            // Lines that are empty are collapsed through like an empty block
+            if !inline_output.is_empty {
+                first_baseline = first_baseline.or(Some(lines_location.y + inline_output.first_baseline));
+                last_baseline = Some(lines_location.y + inline_output.last_baseline);
+                committed_y_offset = lines_location.y + inline_output.height;
+                active_collapsible_margin_set = CollapsibleMarginSet::ZERO;
+                is_collapsing_with_first_margin_set = false;
+            }
+            continue;
+        }
+
+        let item = &mut items[index];
+        index += 1;
+        if item.position.is_out_of_flow() {
             item.static_position.y = committed_y_offset;
+        } else if item.float != Float::None {
+            // Floats are placed no higher than the top of the next in-flow item, and don't affect the position of
+            // the in-flow items that follow them other than by shortening them
+            let y_margin_offset = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
+                0.0
+            } else {
+                active_collapsible_margin_set.resolve()
+            };
+            let margin = item.margin.resolve_or_zero(Some(container_outer_width));
+            let item_layout = compute_shrink_to_fit_size(
+                tree,
+                item.node_id,
+                Some(container_inner_width),
+                available_space.width,
+                margin,
+                true,
+            );
+            let margin_box_size = Size {
+                width: item_layout.size.width + margin.horizontal_axis_sum(),
+                height: item_layout.size.height + margin.vertical_axis_sum(),
+            };
+            let margin_box_location = float_context.place_float(
+                item.float,
+                margin_box_size,
+                committed_y_offset + y_margin_offset,
+                item.clear,
+            );
+            let inset_offset = resolve_relative_offset(item.position, item.inset, container_inner_width);
+
+            item.computed_size = item_layout.size;
+            item.can_be_collapsed_through = false;
+            item.static_position = margin_box_location;
+            let location = Point {
+                x: margin_box_location.x + margin.left + inset_offset.x,
+                y: margin_box_location.y + margin.top + inset_offset.y,
+            };
+            *tree.layout_mut(item.node_id) = Layout {
+                order: item.order,
+                size: item_layout.size,
+                location,
+                is_visible: item.is_visible,
+                content_size: item_layout.content_size,
+            };
         } else {
             let item_margin = item.margin.map(|margin| margin.resolve_to_option(container_outer_width));
             let item_non_auto_margin = item_margin.map(|m| m.unwrap_or(0.0));
             let item_non_auto_x_margin_sum = item_non_auto_margin.horizontal_axis_sum();
+
+            // Block-level items that establish a new block formatting context are placed beside the floats that they
+            // would otherwise overlap, or below them if there isn't room beside them. Other block-level items overlap
+            // the floats (which only shorten their lines). Both are placed below the floats that they clear.
+            let estimated_y = committed_y_offset
+                + if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
+                    0.0
+                } else {
+                    active_collapsible_margin_set.collapse_with_margin(item_non_auto_margin.top).resolve()
+                };
+            let min_y = match float_context.clearance_position(item.clear) {
+                Some(clearance_position) => f32_max(estimated_y, clearance_position),
+                None => estimated_y,
+            };
+            let required_width = item.size.width.maybe_clamp(item.min_size.width, item.max_size.width).unwrap_or(0.0)
+                + item_non_auto_x_margin_sum;
+            let (float_free_y, space) = match item.shares_floats {
+                true => (min_y, content_box_x),
+                false => float_context.find_space(min_y, required_width, 0.0),
+            };
+            let space_width = space.end - space.start;
+
+            // Items with an auto width fill the available space, unless they are aligned with `justify_self` (other than
+            // `Stretch`) or are tables, in which case they shrink to fit their content
+            let fills_available_width = item.size.width.is_some()
+                || (!item.shrinks_to_fit && matches!(item.justify_self, None | Some(AlignSelf::Stretch)));
+            let auto_width = match fills_available_width {
+                true => space_width - item_non_auto_x_margin_sum,
+                false => {
+                    compute_shrink_to_fit_size(
+                        tree,
+                        item.node_id,
+                        Some(container_inner_width),
+                        AvailableSpace::Definite(space_width),
+                        item_non_auto_margin,
+                        false,
+                    )
+                    .size
+                    .width
+                }
+            };
             let known_dimensions = item
                 .size
-                .map_width(|width| Some(width.unwrap_or(container_inner_width - item_non_auto_x_margin_sum)))
+                .map_width(|width| Some(width.unwrap_or(auto_width)))
                 .maybe_clamp(item.min_size, item.max_size);
 
-            let item_layout = tree.perform_child_layout(
-                item.node_id,
-                known_dimensions,
-                parent_size,
-                available_space.map_width(|w| w.maybe_sub(item_non_auto_x_margin_sum)),
-                SizingMode::InherentSize,
-                Line::TRUE,
+            // Items that share the floats of the container are laid out directly, rather than through the tree, as
+            // their layout depends on their position relative to the floats. They are laid out at their estimated
+            // position, as their final position depends on how their margins collapse with those of their children.
+            let item_available_space =
+                Size { width: AvailableSpace::Definite(space_width - item_non_auto_x_margin_sum), ..available_space };
+            let is_mirrored = tree.style(item.node_id).direction.is_rtl() != is_rtl;
+            let estimated_frame = (
+                Point { x: space.start + item_non_auto_margin.left, y: float_free_y },
+                known_dimensions.width.unwrap_or(0.0),
             );
+            let mut child_float_context =
+                item.shares_floats.then(|| float_context.for_child(estimated_frame.0, estimated_frame.1, is_mirrored));
+            let mut item_layout = match &mut child_float_context {
+                Some(child_float_context) => compute_horizontal(
+                    tree,
+                    item.node_id,
+                    known_dimensions,
+                    parent_size,
+                    item_available_space,
+                    RunMode::PerformLayout,
+                    Line::TRUE,
+                    Some(child_float_context),
+                ),
+                None => tree.perform_child_layout(
+                    item.node_id,
+                    known_dimensions,
+                    parent_size,
+                    item_available_space,
+                    SizingMode::InherentSize,
+                    Line::TRUE,
+                ),
+            };
             let final_size = item_layout.size;
 
             let top_margin_set = item_layout.top_margin.collapse_with_margin(item_margin.top.unwrap_or(0.0));
@@ -419,7 +878,7 @@ fn perform_final_layout_on_in_flow_children(
             // Expand auto margins to fill available space
             // Note: Vertical auto-margins for relatively positioned block items simply resolve to 0.
             // See: https://www.w3.org/TR/CSS21/visudet.html#abs-non-replaced-width
-            let free_x_space = f32_max(0.0, container_inner_width - final_size.width - item_non_auto_x_margin_sum);
+            let free_x_space = f32_max(0.0, space_width - final_size.width - item_non_auto_x_margin_sum);
             let x_axis_auto_margin_size = {
                 let auto_margin_count = item_margin.left.is_none() as u8 + item_margin.right.is_none() as u8;
                 if auto_margin_count == 2 && item.size.width.is_none() {
@@ -437,20 +896,33 @@ fn perform_final_layout_on_in_flow_children(
                 bottom: bottom_margin_set.resolve(),
             };
 
-            // Resolve item inset
-            let inset =
-                item.inset.zip_size(Size { width: container_inner_width, height: 0.0 }, |p, s| p.maybe_resolve(s));
-            let inset_offset = Point {
-                x: inset.left.or(inset.right.map(|x| -x)).unwrap_or(0.0),
-                y: inset.top.or(inset.bottom.map(|x| -x)).unwrap_or(0.0),
+            // Items without auto margins are positioned within the available space per `justify_self`
+            let justify_offset = match item_margin.left.is_some() && item_margin.right.is_some() {
+                true => compute_justify_self_offset(
+                    item.justify_self,
+                    space_width - final_size.width - item_non_auto_x_margin_sum,
+                ),
+                false => 0.0,
             };
 
+            // Resolve item inset
+            let inset_offset = resolve_relative_offset(item.position, item.inset, container_inner_width);
+
             let y_margin_offset = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
                 0.0
             } else {
                 active_collapsible_margin_set.collapse_with_margin(resolved_margin.top).resolve()
             };
 
+            // Items that are moved down to avoid floats are separated from the preceding items by clearance, which
+            // prevents their margins from collapsing through
+            let clearance = match float_free_y > estimated_y {
+                true => f32_max(0.0, float_free_y - committed_y_offset - y_margin_offset),
+                false => 0.0,
+            };
+            let has_clearance = clearance > 0.0;
+            let y_margin_offset = y_margin_offset + clearance;
+
             item.computed_size = item_layout.size;
             item.can_be_collapsed_through = item_layout.margins_can_collapse_through;
             item.static_position = Point {
@@ -458,18 +930,43 @@ fn perform_final_layout_on_in_flow_children(
                 y: committed_y_offset + active_collapsible_margin_set.resolve(),
             };
 
+            let location = Point {
+                x: space.start + inset_offset.x + resolved_margin.left + justify_offset,
+                y: committed_y_offset + inset_offset.y + y_margin_offset,
+            };
+
+            // Items that were laid out at the wrong position relative to the floats are laid out again. The floats
+            // that they place are then shared with the rest of the container.
+            if let Some(mut child_float_context) = child_float_context {
+                let static_location = Point { x: location.x - inset_offset.x, y: location.y - inset_offset.y };
+                if (static_location, final_size.width) != estimated_frame && !float_context.is_empty() {
+                    child_float_context = float_context.for_child(static_location, final_size.width, is_mirrored);
+                    item_layout = compute_horizontal(
+                        tree,
+                        item.node_id,
+                        known_dimensions,
+                        parent_size,
+                        item_available_space,
+                        RunMode::PerformLayout,
+                        Line::TRUE,
+                        Some(&mut child_float_context),
+                    );
+                }
+                float_context.add_child_floats(&child_float_context, static_location, final_size.width, is_mirrored);
+            }
             *tree.layout_mut(item.node_id) = Layout {
                 order: item.order,
                 size: item_layout.size,
-                location: Point {
-                    x: resolved_content_box_inset.left + inset_offset.x + resolved_margin.left,
-                    y: committed_y_offset + inset_offset.y + y_margin_offset,
-                },
+                location,
+                is_visible: item.is_visible,
+                content_size: item_layout.content_size,
             };
+            first_baseline = first_baseline.or(item_layout.first_baselines.y.map(|baseline| location.y + baseline));
+            last_baseline = item_layout.last_baselines.y.map(|baseline| location.y + baseline).or(last_baseline);
 
             // Update first_child_top_margin_set
             if is_collapsing_with_first_margin_set {
-                if item.can_be_collapsed_through {
+                if item.can_be_collapsed_through && !has_clearance {
                     first_child_top_margin_set = first_child_top_margin_set
                         .collapse_with_set(top_margin_set)
                         .collapse_with_set(bottom_margin_set);
@@ -480,7 +977,7 @@ fn perform_final_layout_on_in_flow_children(
             }
 
             // Update active_collapsible_margin_set
-            if item.can_be_collapsed_through {
+            if item.can_be_collapsed_through && !has_clearance {
                 active_collapsible_margin_set = active_collapsible_margin_set
                     .collapse_with_set(top_margin_set)
                     .collapse_with_set(bottom_margin_set);
@@ -491,13 +988,65 @@ fn perform_final_layout_on_in_flow_children(
         }
     }
 
-    let last_child_bottom_margin_set = active_collapsible_margin_set;
+    let mut last_child_bottom_margin_set = active_collapsible_margin_set;
     let bottom_y_margin_offset =
         if own_margins_collapse_with_children.end { 0.0 } else { last_child_bottom_margin_set.resolve() };
+    committed_y_offset += bottom_y_margin_offset;
+
+    // A container that establishes a new block formatting context grows to contain its floats (including those of its
+    // descendants that share them). The bottom margins of its children can't collapse through them.
+    if let (true, Some(floats_bottom)) = (establishes_formatting_context, float_context.floats_bottom()) {
+        if floats_bottom > committed_y_offset {
+            committed_y_offset = floats_bottom;
+            last_child_bottom_margin_set = CollapsibleMarginSet::ZERO;
+        }
+    }
 
-    committed_y_offset += resolved_content_box_inset.bottom + bottom_y_margin_offset;
+    committed_y_offset += resolved_content_box_inset.bottom;
     let content_height = f32_max(0.0, committed_y_offset);
-    (content_height, first_child_top_margin_set, last_child_bottom_margin_set)
+    let baselines = Line { start: first_baseline, end: last_baseline };
+    (content_height, first_child_top_margin_set, last_child_bottom_margin_set, baselines)
+}
+
+/// Compute the offset of the content of a block container from the start of its content box per `align_content`, given
+/// the free space in the content box. The content is aligned as a single unit, so distributed alignments behave like
+/// their fallback alignments. `None` is the `normal` alignment, which behaves like `Start`.
+#[inline]
+fn compute_content_alignment_offset(align_content: Option<AlignContent>, free_space: f32) -> f32 {
+    let alignment = match align_content {
+        None | Some(AlignContent::Stretch | AlignContent::SpaceBetween) => AlignContent::Start,
+        Some(AlignContent::SpaceAround | AlignContent::SpaceEvenly) => AlignContent::SafeCenter,
+        Some(alignment) => alignment,
+    };
+    compute_alignment_offset(free_space, 1, 0.0, alignment, false, true)
+}
+
+/// Compute the offset of a block-level item from the start of the space available to it per `justify_self`, given the
+/// free space left over once the item is placed in it
+#[inline]
+fn compute_justify_self_offset(justify_self: Option<AlignSelf>, free_space: f32) -> f32 {
+    match justify_self.map(|alignment| resolve_overflow_alignment(alignment, free_space)) {
+        Some(AlignSelf::End | AlignSelf::FlexEnd | AlignSelf::LastBaseline) => free_space,
+        Some(AlignSelf::Center) => free_space / 2.0,
+        _ => 0.0,
+    }
+}
+
+/// Resolve the offset of a relatively positioned item from its inset. Sticky items are not offset by their inset.
+#[inline]
+fn resolve_relative_offset(
+    position: Position,
+    inset: Rect<LengthPercentageAuto>,
+    container_inner_width: f32,
+) -> Point<f32> {
+    if position == Position::Sticky {
+        return Point::ZERO;
+    }
+    let left = inset.left.maybe_resolve(container_inner_width);
+    let right = inset.right.maybe_resolve(container_inner_width);
+    let top = inset.top.maybe_resolve(0.0);
+    let bottom = inset.bottom.maybe_resolve(0.0);
+    Point { x: left.or(right.map(|x| -x)).unwrap_or(0.0), y: top.or(bottom.map(|x| -x)).unwrap_or(0.0) }
 }
 
 /// Perform absolute layout on all absolutely positioned children.
@@ -507,10 +1056,8 @@ fn perform_absolute_layout_on_absolute_children(
     items: &mut [BlockItem],
     area_size: Size<f32>,
     area_offset: Point<f32>,
+    is_rtl: bool,
 ) {
-    let area_width = area_size.width;
-    let area_height = area_size.height;
-
     for item in items.iter().filter(|item| item.position == Position::Absolute) {
         let child_style = tree.style(item.node_id);
 
@@ -519,54 +1066,17 @@ fn perform_absolute_layout_on_absolute_children(
             continue;
         }
 
-        let aspect_ratio = child_style.aspect_ratio;
-        let margin = child_style.margin.map(|margin| margin.resolve_to_option(area_width));
-        let padding = child_style.padding.resolve_or_zero(Some(area_width));
-        let border = child_style.border.resolve_or_zero(Some(area_width));
-        let padding_border_sum = (padding + border).sum_axes();
-
-        // Resolve inset
-        let left = child_style.inset.left.maybe_resolve(area_width);
-        let right = child_style.inset.right.maybe_resolve(area_width);
-        let top = child_style.inset.top.maybe_resolve(area_height);
-        let bottom = child_style.inset.bottom.maybe_resolve(area_height);
-
-        // Compute known dimensions from min/max/inherent size styles
-        let style_size = child_style.size.maybe_resolve(area_size).maybe_apply_aspect_ratio(aspect_ratio);
-        let min_size = child_style
-            .min_size
-            .maybe_resolve(area_size)
-            .maybe_apply_aspect_ratio(aspect_ratio)
-            .or(padding_border_sum.map(Some))
-            .maybe_max(padding_border_sum);
-        let max_size = child_style.max_size.maybe_resolve(area_size).maybe_apply_aspect_ratio(aspect_ratio);
-        let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
-
-        // Fill in width from left/right and reapply aspect ratio if:
-        //   - Width is not already known
-        //   - Item has both left and right inset properties set
-        if let (None, Some(left), Some(right)) = (known_dimensions.width, left, right) {
-            let new_width_raw = area_width.maybe_sub(margin.left).maybe_sub(margin.right) - left - right;
-            known_dimensions.width = Some(f32_max(new_width_raw, 0.0));
-            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
-        }
-
-        // Fill in height from top/bottom and reapply aspect ratio if:
-        //   - Height is not already known
-        //   - Item has both top and bottom inset properties set
-        if let (None, Some(top), Some(bottom)) = (known_dimensions.height, top, bottom) {
-            let new_height_raw = area_height.maybe_sub(margin.top).maybe_sub(margin.bottom) - top - bottom;
-            known_dimensions.height = Some(f32_max(new_height_raw, 0.0));
-            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
-        }
+        let AbsoluteBoxSizes { inset, margin, style_size, min_size, max_size, known_dimensions } =
+            resolve_absolute_box_sizes(tree, item.node_id, area_size, is_rtl);
+        let Rect { left, right, top, bottom } = inset;
 
         let measured_size_and_baselines = tree.perform_child_layout(
             item.node_id,
             known_dimensions,
             area_size.map(Some),
             Size {
-                width: AvailableSpace::Definite(area_width.maybe_clamp(min_size.width, max_size.width)),
-                height: AvailableSpace::Definite(area_height.maybe_clamp(min_size.height, max_size.height)),
+                width: AvailableSpace::Definite(area_size.width.maybe_clamp(min_size.width, max_size.width)),
+                height: AvailableSpace::Definite(area_size.height.maybe_clamp(min_size.height, max_size.height)),
             },
             SizingMode::ContentSize,
             Line::FALSE,
@@ -657,7 +1167,12 @@ fn perform_absolute_layout_on_absolute_children(
                 .unwrap_or(item.static_position.y + resolved_margin.top),
         };
 
-        *tree.layout_mut(item.node_id) =
-            Layout { order: item.order, size: final_size, location: area_offset + item_offset };
+        *tree.layout_mut(item.node_id) = Layout {
+            order: item.order,
+            size: final_size,
+            location: area_offset + item_offset,
+            is_visible: item.is_visible,
+            content_size: measured_size_and_baselines.content_size,
+        };
     }
 }
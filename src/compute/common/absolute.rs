@@ -0,0 +1,98 @@
+//! Resolution of the styles of absolutely positioned (and fixed position) nodes, which are sized and positioned against
+//! an area (their containing block, or the viewport) rather than by the layout algorithm of their parent
+use super::intrinsic_size::resolve_intrinsic_sizes;
+use crate::geometry::{Rect, Size};
+use crate::tree::{LayoutTree, NodeId};
+use crate::util::sys::f32_max;
+use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
+
+/// The insets, margins and sizes of an absolutely positioned node, resolved against the area it is positioned in
+pub(crate) struct AbsoluteBoxSizes {
+    /// The node's insets, which are `None` if they are `auto`
+    pub inset: Rect<Option<f32>>,
+    /// The node's margins, which are `None` if they are `auto`
+    pub margin: Rect<Option<f32>>,
+    /// The border-box size set by the node's `size` style (and its aspect ratio)
+    #[cfg(feature = "block_layout")]
+    pub style_size: Size<Option<f32>>,
+    /// The node's minimum border-box size, which is never less than the sum of its padding and border
+    pub min_size: Size<Option<f32>>,
+    /// The node's maximum border-box size
+    pub max_size: Size<Option<f32>>,
+    /// The border-box size of the node in each axis in which it is known before the node is laid out: either from its
+    /// size styles, or from the insets on both sides
+    pub known_dimensions: Size<Option<f32>>,
+}
+
+/// Resolve the insets, margins and sizes of the absolutely positioned `node` against the size of the area it is
+/// positioned in. The horizontal insets and margins are swapped if `is_rtl` is set, so that `left` is always the side
+/// that the node is positioned from in the inline axis.
+pub(crate) fn resolve_absolute_box_sizes(
+    tree: &mut impl LayoutTree,
+    node: NodeId,
+    area_size: Size<f32>,
+    is_rtl: bool,
+) -> AbsoluteBoxSizes {
+    let style = tree.style(node);
+    let aspect_ratio = style.aspect_ratio;
+    let margin = style.margin.map(|margin| margin.resolve_to_option(area_size.width)).swap_horizontal_sides_if(is_rtl);
+    let padding = style.padding.resolve_or_zero(Some(area_size.width));
+    let border = style.border.resolve_or_zero(Some(area_size.width));
+    let padding_border_sum = (padding + border).sum_axes();
+    let box_sizing_adjustment = style.box_sizing.adjustment(padding_border_sum);
+
+    let inset = style.inset.swap_horizontal_sides_if(is_rtl);
+    let inset = Rect {
+        left: inset.left.maybe_resolve(area_size.width),
+        right: inset.right.maybe_resolve(area_size.width),
+        top: inset.top.maybe_resolve(area_size.height),
+        bottom: inset.bottom.maybe_resolve(area_size.height),
+    };
+
+    // Compute known dimensions from min/max/inherent size styles
+    let mut style_size =
+        style.size.maybe_resolve(area_size).maybe_apply_aspect_ratio(aspect_ratio).maybe_add(box_sizing_adjustment);
+    let mut min_size =
+        style.min_size.maybe_resolve(area_size).maybe_apply_aspect_ratio(aspect_ratio).maybe_add(box_sizing_adjustment);
+    let mut max_size =
+        style.max_size.maybe_resolve(area_size).maybe_apply_aspect_ratio(aspect_ratio).maybe_add(box_sizing_adjustment);
+    let margin_sum = margin.map(|margin| margin.unwrap_or(0.0)).sum_axes();
+    resolve_intrinsic_sizes(
+        tree,
+        node,
+        area_size.map(Some),
+        area_size.map(Some).maybe_sub(margin_sum),
+        &mut style_size,
+        &mut min_size,
+        &mut max_size,
+    );
+    let min_size = min_size.or(padding_border_sum.map(Some)).maybe_max(padding_border_sum);
+    let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
+
+    // Fill in the size in each axis from the insets on both sides (and reapply the aspect ratio), if they are set and
+    // the size is not already known
+    if let (None, Some(left), Some(right)) = (known_dimensions.width, inset.left, inset.right) {
+        let new_width_raw = area_size.width.maybe_sub(margin.left).maybe_sub(margin.right) - left - right;
+        known_dimensions.width = Some(f32_max(new_width_raw, 0.0));
+        known_dimensions = known_dimensions
+            .maybe_apply_aspect_ratio_with_adjustment(aspect_ratio, box_sizing_adjustment)
+            .maybe_clamp(min_size, max_size);
+    }
+    if let (None, Some(top), Some(bottom)) = (known_dimensions.height, inset.top, inset.bottom) {
+        let new_height_raw = area_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom) - top - bottom;
+        known_dimensions.height = Some(f32_max(new_height_raw, 0.0));
+        known_dimensions = known_dimensions
+            .maybe_apply_aspect_ratio_with_adjustment(aspect_ratio, box_sizing_adjustment)
+            .maybe_clamp(min_size, max_size);
+    }
+
+    AbsoluteBoxSizes {
+        inset,
+        margin,
+        #[cfg(feature = "block_layout")]
+        style_size,
+        min_size,
+        max_size,
+        known_dimensions,
+    }
+}
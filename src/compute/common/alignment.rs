@@ -1,11 +1,61 @@
 //! Generic CSS alignment code that is shared between both the Flexbox and CSS Grid algorithms.
-use crate::style::AlignContent;
+use crate::style::{AlignContent, AlignItems};
+
+/// An alignment style that has `safe` variants, which fall back to `Start` alignment when the aligned content overflows
+pub(crate) trait OverflowAlignment: Copy {
+    /// The alignment that `safe` alignments are aligned as when their content overflows
+    const START: Self;
+
+    /// The equivalent `unsafe` alignment if this is a `safe` alignment, and `None` otherwise
+    fn unsafe_alignment(self) -> Option<Self>;
+}
+
+impl OverflowAlignment for AlignItems {
+    const START: Self = Self::Start;
+
+    fn unsafe_alignment(self) -> Option<Self> {
+        match self {
+            Self::SafeEnd => Some(Self::End),
+            Self::SafeFlexStart => Some(Self::FlexStart),
+            Self::SafeFlexEnd => Some(Self::FlexEnd),
+            Self::SafeCenter => Some(Self::Center),
+            _ => None,
+        }
+    }
+}
+
+impl OverflowAlignment for AlignContent {
+    const START: Self = Self::Start;
+
+    fn unsafe_alignment(self) -> Option<Self> {
+        match self {
+            Self::SafeEnd => Some(Self::End),
+            Self::SafeFlexStart => Some(Self::FlexStart),
+            Self::SafeFlexEnd => Some(Self::FlexEnd),
+            Self::SafeCenter => Some(Self::Center),
+            _ => None,
+        }
+    }
+}
+
+/// Resolves the overflow alignment of a `safe` alignment given the free space in its alignment container: if it is
+/// negative then the content overflows and is aligned as `Start`, otherwise it is aligned with the equivalent `unsafe`
+/// alignment. Other alignments are returned unchanged.
+pub(crate) fn resolve_overflow_alignment<T: OverflowAlignment>(alignment: T, free_space: f32) -> T {
+    match alignment.unsafe_alignment() {
+        Some(_) if free_space < 0.0 => T::START,
+        Some(unsafe_alignment) => unsafe_alignment,
+        None => alignment,
+    }
+}
 
 /// Generic alignment function that is used:
 ///   - For both align-content and justify-content alignment
 ///   - For both the Flexbox and CSS Grid algorithms
 /// CSS Grid does not apply gaps as part of alignment, so the gap parameter should
 /// always be set to zero for CSS Grid.
+///
+/// Safe alignment modes are aligned as `Start` if the free space is negative.
 pub(crate) fn compute_alignment_offset(
     free_space: f32,
     num_items: usize,
@@ -14,25 +64,27 @@ pub(crate) fn compute_alignment_offset(
     layout_is_flex_reversed: bool,
     is_first: bool,
 ) -> f32 {
+    // Safe alignment modes that don't overflow are aligned like the equivalent unsafe mode
+    let alignment_mode = resolve_overflow_alignment(alignment_mode, free_space);
     if is_first {
         match alignment_mode {
             AlignContent::Start => 0.0,
-            AlignContent::FlexStart => {
+            AlignContent::FlexStart | AlignContent::SafeFlexStart => {
                 if layout_is_flex_reversed {
                     free_space
                 } else {
                     0.0
                 }
             }
-            AlignContent::End => free_space,
-            AlignContent::FlexEnd => {
+            AlignContent::End | AlignContent::SafeEnd => free_space,
+            AlignContent::FlexEnd | AlignContent::SafeFlexEnd => {
                 if layout_is_flex_reversed {
                     0.0
                 } else {
                     free_space
                 }
             }
-            AlignContent::Center => free_space / 2.0,
+            AlignContent::Center | AlignContent::SafeCenter => free_space / 2.0,
             AlignContent::Stretch => 0.0,
             AlignContent::SpaceBetween => 0.0,
             AlignContent::SpaceAround => (free_space / num_items as f32) / 2.0,
@@ -45,6 +97,10 @@ pub(crate) fn compute_alignment_offset(
             AlignContent::End => 0.0,
             AlignContent::FlexEnd => 0.0,
             AlignContent::Center => 0.0,
+            AlignContent::SafeEnd => 0.0,
+            AlignContent::SafeFlexStart => 0.0,
+            AlignContent::SafeFlexEnd => 0.0,
+            AlignContent::SafeCenter => 0.0,
             AlignContent::Stretch => 0.0,
             AlignContent::SpaceBetween => free_space / (num_items - 1) as f32,
             AlignContent::SpaceAround => free_space / num_items as f32,
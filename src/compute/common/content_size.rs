@@ -0,0 +1,78 @@
+//! Computation of the scrollable overflow of a node (see [`Layout::content_size`](crate::tree::Layout::content_size))
+use super::contents::layout_children;
+use crate::geometry::{Rect, Size};
+use crate::style::{Display, Position};
+use crate::tree::{LayoutTree, NodeId};
+use crate::util::sys::f32_max;
+use crate::util::ResolveOrZero;
+
+/// Compute the size of the scrollable overflow rectangle of `node` from the layouts of its children, once they have
+/// all been positioned. `size` is the border-box size of the node, and `padding` and `border` are its resolved padding
+/// and border.
+///
+/// The scrollable overflow extends from the inline-start edge of the node, so in a right-to-left node it extends
+/// leftwards: it is then measured from the right edge of the node, and content that overflows the left edge is included.
+///
+/// Fixed position children are skipped, as they are positioned against the viewport rather than the node.
+pub(crate) fn compute_content_size(
+    tree: &impl LayoutTree,
+    node: NodeId,
+    size: Size<f32>,
+    padding: Rect<f32>,
+    border: Rect<f32>,
+) -> Size<f32> {
+    let style = tree.style(node);
+    let is_rtl = style.direction.is_rtl();
+
+    // Horizontal positions and sides are relative to the inline-start edge (which the scrollbar gutter already is)
+    let padding = padding.swap_horizontal_sides_if(is_rtl);
+    let border = border.swap_horizontal_sides_if(is_rtl);
+    let scrollbar_gutter = style.scrollbar_gutter_inset();
+    let padding_box_end = Size {
+        width: size.width - border.right - scrollbar_gutter.right,
+        height: size.height - border.bottom - scrollbar_gutter.bottom,
+    };
+    let content_box_width = padding_box_end.width - border.left - scrollbar_gutter.left - padding.horizontal_axis_sum();
+
+    let mut content_end = Size::ZERO;
+    for child in layout_children(tree, node) {
+        let child_style = tree.style(child);
+        if child_style.display == Display::None || child_style.position == Position::Fixed {
+            continue;
+        }
+
+        // A child that doesn't clip its contents contributes its own scrollable overflow as well as its margin box
+        let layout = tree.layout(child);
+        let margin = child_style.margin.resolve_or_zero(Some(content_box_width)).swap_horizontal_sides_if(is_rtl);
+        let x = match is_rtl {
+            true => size.width - layout.location.x - layout.size.width,
+            false => layout.location.x,
+        };
+        let overflow_size = Size {
+            width: match child_style.overflow.x.clips_contents() {
+                true => 0.0,
+                false => layout.content_size.width,
+            },
+            height: match child_style.overflow.y.clips_contents() {
+                true => 0.0,
+                false => layout.content_size.height,
+            },
+        };
+        let margin_box_end = Size {
+            width: x + layout.size.width + margin.right,
+            height: layout.location.y + layout.size.height + margin.bottom,
+        };
+        content_end = Size {
+            width: f32_max(content_end.width, f32_max(margin_box_end.width, x + overflow_size.width)),
+            height: f32_max(
+                content_end.height,
+                f32_max(margin_box_end.height, layout.location.y + overflow_size.height),
+            ),
+        };
+    }
+
+    Size {
+        width: f32_max(padding_box_end.width, content_end.width + padding.right),
+        height: f32_max(padding_box_end.height, content_end.height + padding.bottom),
+    }
+}
@@ -0,0 +1,48 @@
+//! Support for nodes with `Display::Contents`, which generate no box of their own and are replaced by their children
+//! when laying out their parent
+use crate::style::Display;
+use crate::tree::{Layout, LayoutTree, NodeId};
+use crate::util::sys::{new_vec_with_capacity, Vec};
+
+#[cfg(any(feature = "flexbox", feature = "grid"))]
+use crate::util::sys::stable_sort_by_key;
+
+/// The children of `node` that take part in its layout, in order. Any `Display::Contents` children are replaced by
+/// their own children (recursively).
+pub(crate) fn layout_children(tree: &impl LayoutTree, node: NodeId) -> Vec<NodeId> {
+    /// Push the layout children of `node` onto `children`
+    fn push_layout_children(tree: &impl LayoutTree, node: NodeId, children: &mut Vec<NodeId>) {
+        for child in tree.children(node) {
+            if tree.style(child).display == Display::Contents {
+                push_layout_children(tree, child, children);
+            } else {
+                children.push(child);
+            }
+        }
+    }
+
+    let mut children = new_vec_with_capacity(tree.child_count(node));
+    push_layout_children(tree, node, &mut children);
+    children
+}
+
+/// The layout children of `node` (see [`layout_children`]) in "order-modified document order": stably sorted by their
+/// `order` style. Flexbox and grid containers lay out their children in this order, and paint them in it too.
+#[cfg(any(feature = "flexbox", feature = "grid"))]
+pub(crate) fn order_modified_layout_children(tree: &impl LayoutTree, node: NodeId) -> Vec<NodeId> {
+    let mut children = layout_children(tree, node);
+    stable_sort_by_key(&mut children, |&child| tree.style(child).order);
+    children
+}
+
+/// Give each `Display::Contents` node that was replaced by its children when laying out `node` a zero-sized layout at
+/// the origin of `node`, so that the positions of its children are relative to `node`
+pub(crate) fn perform_contents_layout(tree: &mut impl LayoutTree, node: NodeId) {
+    for order in 0..tree.child_count(node) {
+        let child = tree.child(node, order);
+        if tree.style(child).display == Display::Contents {
+            *tree.layout_mut(child) = Layout::with_order(order as u32);
+            perform_contents_layout(tree, child);
+        }
+    }
+}
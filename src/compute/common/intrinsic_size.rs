@@ -0,0 +1,99 @@
+//! Resolution of the intrinsic sizing keywords (`min-content`, `max-content`, `fit-content(…)` and `stretch`) that may
+//! be used in the `size`, `min_size` and `max_size` styles. These can't be resolved against the size of the parent, so
+//! the parent's layout algorithm resolves them by measuring the node before it sizes the node like any other.
+use crate::geometry::{AbsoluteAxis, Line, Size};
+use crate::style::{AvailableSpace, Dimension};
+use crate::tree::{LayoutTree, NodeId, SizingMode};
+use crate::util::sys::{f32_max, f32_min};
+use crate::util::{MaybeMath, MaybeResolve};
+
+/// Fills in the sizes of `node` that are set by an intrinsic sizing keyword in its `size`, `min_size` or `max_size`
+/// styles. The sizes passed in are the ones the caller resolved from the node's styles (in which the keywords resolve
+/// to `None`), and the resolved keywords are border-box sizes.
+///
+/// `available_space` is the definite space available to the node's border box (i.e. less its margins), which is what
+/// `stretch` resolves to. The horizontal axis is resolved first so that keywords in the vertical axis are measured at
+/// the resolved width.
+pub(crate) fn resolve_intrinsic_sizes(
+    tree: &mut impl LayoutTree,
+    node: NodeId,
+    parent_size: Size<Option<f32>>,
+    available_space: Size<Option<f32>>,
+    size: &mut Size<Option<f32>>,
+    min_size: &mut Size<Option<f32>>,
+    max_size: &mut Size<Option<f32>>,
+) {
+    let style = tree.style(node);
+    let styles = [style.size, style.min_size, style.max_size];
+    if !styles.iter().any(|style| style.width.is_intrinsic() || style.height.is_intrinsic()) {
+        return;
+    }
+    let mut sizes = [*size, *min_size, *max_size];
+
+    for axis in [AbsoluteAxis::Horizontal, AbsoluteAxis::Vertical] {
+        // The other axis is measured at the size the node will have in it, where that is already known
+        let [size, min_size, max_size] = sizes;
+        let known_dimensions = match axis {
+            AbsoluteAxis::Horizontal => {
+                Size { width: None, height: size.height.maybe_clamp(min_size.height, max_size.height) }
+            }
+            AbsoluteAxis::Vertical => {
+                Size { width: size.width.maybe_clamp(min_size.width, max_size.width), height: None }
+            }
+        };
+
+        // The node's min-content and max-content sizes in this axis are only measured if a keyword needs them
+        let mut content_sizes = None;
+        let mut content_sizes = |tree: &mut _| {
+            *content_sizes.get_or_insert_with(|| {
+                let mut measure =
+                    |space| measure_axis(tree, node, axis, known_dimensions, parent_size, available_space, space);
+                (measure(AvailableSpace::MinContent), measure(AvailableSpace::MaxContent))
+            })
+        };
+
+        for (style, resolved) in styles.iter().zip(sizes.iter_mut()) {
+            let dimension = style.get_abs(axis);
+            if resolved.get_abs(axis).is_some() || !dimension.is_intrinsic() {
+                continue;
+            }
+            let value = match dimension {
+                Dimension::Stretch => available_space.get_abs(axis),
+                Dimension::MinContent => Some(content_sizes(tree).0),
+                Dimension::MaxContent => Some(content_sizes(tree).1),
+                Dimension::FitContent(limit) => {
+                    let (min_content, max_content) = content_sizes(tree);
+                    let limit = limit.maybe_resolve(parent_size.get_abs(axis)).unwrap_or(f32::INFINITY);
+                    Some(f32_max(min_content, f32_min(max_content, limit)))
+                }
+                _ => None,
+            };
+            match axis {
+                AbsoluteAxis::Horizontal => resolved.width = value,
+                AbsoluteAxis::Vertical => resolved.height = value,
+            }
+        }
+    }
+
+    [*size, *min_size, *max_size] = sizes;
+}
+
+/// Measures the size of `node` in `axis` under a min-content or max-content constraint in that axis
+fn measure_axis(
+    tree: &mut impl LayoutTree,
+    node: NodeId,
+    axis: AbsoluteAxis,
+    known_dimensions: Size<Option<f32>>,
+    parent_size: Size<Option<f32>>,
+    available_space: Size<Option<f32>>,
+    space_in_axis: AvailableSpace,
+) -> f32 {
+    let available_space =
+        available_space.map(|space| space.map(AvailableSpace::Definite).unwrap_or(AvailableSpace::MaxContent));
+    let available_space = match axis {
+        AbsoluteAxis::Horizontal => Size { width: space_in_axis, ..available_space },
+        AbsoluteAxis::Vertical => Size { height: space_in_axis, ..available_space },
+    };
+    tree.measure_child_size(node, known_dimensions, parent_size, available_space, SizingMode::ContentSize, Line::FALSE)
+        .get_abs(axis)
+}
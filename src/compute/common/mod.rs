@@ -1,2 +1,22 @@
 //! Generic code that is shared between multiple layout algorithms
+#[cfg(any(feature = "block_layout", feature = "flexbox", feature = "taffy_tree"))]
+pub(crate) mod absolute;
+#[cfg(any(feature = "block_layout", feature = "flexbox", feature = "grid"))]
 pub(crate) mod alignment;
+#[cfg(any(feature = "block_layout", feature = "flexbox", feature = "grid", feature = "stack_layout"))]
+pub(crate) mod content_size;
+#[cfg(any(feature = "block_layout", feature = "flexbox", feature = "grid", feature = "stack_layout"))]
+pub(crate) mod contents;
+#[cfg(any(
+    feature = "block_layout",
+    feature = "flexbox",
+    feature = "grid",
+    feature = "stack_layout",
+    feature = "taffy_tree"
+))]
+pub(crate) mod intrinsic_size;
+#[cfg(all(
+    any(feature = "std", feature = "alloc"),
+    any(feature = "block_layout", feature = "flexbox", feature = "grid")
+))]
+pub(crate) mod writing_mode;
@@ -0,0 +1,295 @@
+//! Support for laying out the children of nodes with a vertical [`WritingMode`].
+//!
+//! The layout algorithms are written for horizontal writing modes, in which the inline axis is horizontal. A node with a
+//! vertical writing mode is laid out by running its layout algorithm on a transposed view of the node and its children
+//! (in which the inline axis is once again horizontal), and then transposing the resulting layouts back.
+use super::contents::layout_children;
+use crate::geometry::{Line, Point, Size};
+use crate::style::{AvailableSpace, Display, Style, WritingMode};
+use crate::tree::{Layout, LayoutTree, NodeId, RunMode, SizeBaselinesAndMargins, SizingMode};
+use crate::util::sys::Vec;
+
+#[cfg(feature = "grid")]
+use crate::geometry::AbsoluteAxis;
+
+#[cfg(feature = "block_layout")]
+use crate::compute::inline::is_inline_box_of;
+
+#[cfg(feature = "table_layout")]
+use crate::compute::table::is_table_part_of;
+
+/// Lay out a node that has a vertical writing mode. `compute` is the node's layout algorithm, which is run on a
+/// transposed view of the node using transposed constraints.
+pub(crate) fn compute_in_vertical_writing_mode<'t, Tree: LayoutTree>(
+    tree: &'t mut Tree,
+    node: NodeId,
+    known_dimensions: Size<Option<f32>>,
+    parent_size: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+    compute: impl FnOnce(
+        &mut WritingModeTree<'t, Tree>,
+        NodeId,
+        Size<Option<f32>>,
+        Size<Option<f32>>,
+        Size<AvailableSpace>,
+        RunMode,
+    ) -> SizeBaselinesAndMargins,
+) -> SizeBaselinesAndMargins {
+    let writing_mode = tree.style(node).writing_mode;
+    let mut transposed_tree = WritingModeTree::new(tree, node, writing_mode);
+    let output = compute(
+        &mut transposed_tree,
+        node,
+        known_dimensions.transpose(),
+        parent_size.transpose(),
+        available_space.transpose(),
+        run_mode,
+    );
+    if run_mode == RunMode::PerformLayout {
+        transposed_tree.restore_child_layouts(node, output.size);
+    }
+
+    // The node's margins are in a different axis to those of its children, so they never collapse together
+    let size = output.size.transpose();
+    SizeBaselinesAndMargins {
+        first_baselines: untranspose_point(output.first_baselines, size.width, writing_mode),
+        last_baselines: untranspose_point(output.last_baselines, size.width, writing_mode),
+        content_size: output.content_size.transpose(),
+        ..size.into()
+    }
+}
+
+/// A transposed view of a node with a vertical writing mode and its children: the width of each node is its height,
+/// and its left, right, top and bottom edges are its top, bottom and (depending on the writing mode) right and left
+/// edges respectively. All other nodes are passed through to the wrapped tree.
+pub(crate) struct WritingModeTree<'t, Tree: LayoutTree> {
+    /// The wrapped tree
+    tree: &'t mut Tree,
+    /// The writing mode of the node being laid out
+    writing_mode: WritingMode,
+    /// The node being laid out, its children and the children of any subgrids among them (which are laid out by the
+    /// node), sorted by node id, along with their transposed styles. The style is `None` if the wrapped tree has cached
+    /// it (see [`LayoutTree::transposed_style`]).
+    styles: Vec<(u64, Option<Style>)>,
+}
+
+impl<'t, Tree: LayoutTree> WritingModeTree<'t, Tree> {
+    /// Create a transposed view of `node` and its children
+    fn new(tree: &'t mut Tree, node: NodeId, writing_mode: WritingMode) -> Self {
+        /// Transposes the styles of the children of `node`, recursing into subgrids, and then the style of `node` itself
+        fn transpose_styles(
+            tree: &impl LayoutTree,
+            node: NodeId,
+            writing_mode: WritingMode,
+            styles: &mut Vec<(u64, Option<Style>)>,
+        ) {
+            let style = tree.style(node);
+            for child in layout_children(tree, node) {
+                if lays_out_children_of(style, tree.style(child)) {
+                    transpose_styles(tree, child, writing_mode, styles);
+                } else {
+                    styles.push((child.into(), transpose_style(tree, child, writing_mode)));
+                }
+            }
+            styles.push((node.into(), transpose_style(tree, node, writing_mode)));
+        }
+
+        /// Transposes the style of `node`, unless the tree has already cached its transposed style
+        fn transpose_style(tree: &impl LayoutTree, node: NodeId, writing_mode: WritingMode) -> Option<Style> {
+            match tree.transposed_style(node, writing_mode) {
+                Some(_) => None,
+                None => Some(tree.style(node).transposed(writing_mode)),
+            }
+        }
+
+        let mut styles = Vec::new();
+        transpose_styles(tree, node, writing_mode, &mut styles);
+        styles.sort_unstable_by_key(|(node, _)| *node);
+
+        Self { tree, writing_mode, styles }
+    }
+
+    /// Convert the layouts of the children of `node`, which have been computed in transposed coordinates, back into
+    /// physical coordinates. `size` is the transposed size of `node`.
+    fn restore_child_layouts(&mut self, node: NodeId, size: Size<f32>) {
+        for child in layout_children(&*self.tree, node) {
+            if self.tree.style(child).display == Display::None {
+                continue;
+            }
+
+            // The children of subgrids and inline boxes are laid out along with the node, so they are also in
+            // transposed coordinates
+            let layout = *self.tree.layout(child);
+            if lays_out_children_of(self.style(node), self.style(child)) {
+                self.restore_child_layouts(child, layout.size);
+            }
+
+            *self.tree.layout_mut(child) = self.restore_layout(layout, size);
+        }
+    }
+
+    /// Convert a layout that has been computed in transposed coordinates back into physical coordinates. `size` is the
+    /// transposed size of the box that the layout is positioned within.
+    fn restore_layout(&self, layout: Layout, size: Size<f32>) -> Layout {
+        let physical_size = layout.size.transpose();
+        let x = match self.writing_mode {
+            WritingMode::VerticalRl => size.height - layout.location.y - physical_size.width,
+            _ => layout.location.y,
+        };
+        Layout {
+            order: layout.order,
+            size: physical_size,
+            location: Point { x, y: layout.location.x },
+            is_visible: layout.is_visible,
+            content_size: layout.content_size.transpose(),
+        }
+    }
+}
+
+impl<'t, Tree: LayoutTree> LayoutTree for WritingModeTree<'t, Tree> {
+    type ChildIter<'a>
+        = Tree::ChildIter<'a>
+    where
+        Self: 'a;
+
+    fn children(&self, node: NodeId) -> Self::ChildIter<'_> {
+        self.tree.children(node)
+    }
+
+    fn child_count(&self, node: NodeId) -> usize {
+        self.tree.child_count(node)
+    }
+
+    fn child(&self, node: NodeId, index: usize) -> NodeId {
+        self.tree.child(node, index)
+    }
+
+    fn style(&self, node: NodeId) -> &Style {
+        match self.styles.binary_search_by_key(&u64::from(node), |(node, _)| *node) {
+            Ok(index) => match &self.styles[index].1 {
+                Some(style) => style,
+                // The wrapped tree had cached the transposed style when this view was created. Should it have been
+                // dropped since, the untransposed style is the best that can be returned by reference.
+                None => self.tree.transposed_style(node, self.writing_mode).unwrap_or_else(|| self.tree.style(node)),
+            },
+            Err(_) => self.tree.style(node),
+        }
+    }
+
+    fn layout(&self, node: NodeId) -> &Layout {
+        self.tree.layout(node)
+    }
+
+    fn layout_mut(&mut self, node: NodeId) -> &mut Layout {
+        self.tree.layout_mut(node)
+    }
+
+    fn measure_child_size(
+        &mut self,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        sizing_mode: SizingMode,
+        _vertical_margins_are_collapsible: Line<bool>,
+    ) -> Size<f32> {
+        self.tree
+            .measure_child_size(
+                node,
+                known_dimensions.transpose(),
+                parent_size.transpose(),
+                available_space.transpose(),
+                sizing_mode,
+                Line::FALSE,
+            )
+            .transpose()
+    }
+
+    fn perform_child_layout(
+        &mut self,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        sizing_mode: SizingMode,
+        _vertical_margins_are_collapsible: Line<bool>,
+    ) -> SizeBaselinesAndMargins {
+        let output = self.tree.perform_child_layout(
+            node,
+            known_dimensions.transpose(),
+            parent_size.transpose(),
+            available_space.transpose(),
+            sizing_mode,
+            Line::FALSE,
+        );
+
+        // The child's margins are collapsed in its physical vertical axis, which is not the transposed block axis
+        SizeBaselinesAndMargins {
+            first_baselines: transpose_point(output.first_baselines, output.size.width, self.writing_mode),
+            last_baselines: transpose_point(output.last_baselines, output.size.width, self.writing_mode),
+            content_size: output.content_size.transpose(),
+            ..output.size.transpose().into()
+        }
+    }
+
+    fn measure_child_fragments(
+        &mut self,
+        node: NodeId,
+        available_space: Size<AvailableSpace>,
+    ) -> Option<Vec<Size<f32>>> {
+        let fragments = self.tree.measure_child_fragments(node, available_space.transpose())?;
+        Some(fragments.into_iter().map(|size| size.transpose()).collect())
+    }
+
+    fn set_fragment_layouts(&mut self, node: NodeId, fragment_layouts: Vec<Layout>) {
+        // The node's own layout is still in transposed coordinates: it is restored after its parent has been laid out
+        let size = self.tree.layout(node).size;
+        let fragment_layouts = fragment_layouts.into_iter().map(|layout| self.restore_layout(layout, size)).collect();
+        self.tree.set_fragment_layouts(node, fragment_layouts)
+    }
+}
+
+/// Whether the children of `child` are laid out along with the other children of `parent`: either because `child` is
+/// a subgrid of the grid `parent`, because it is an inline box whose contents are placed on the lines of `parent`, or
+/// because it is a row (or row group) of the table `parent`
+pub(crate) fn lays_out_children_of(parent: &Style, child: &Style) -> bool {
+    #[cfg(feature = "block_layout")]
+    if is_inline_box_of(parent, child) {
+        return true;
+    }
+    #[cfg(feature = "table_layout")]
+    if is_table_part_of(parent, child) {
+        return true;
+    }
+    is_subgrid_of(parent, child)
+}
+
+/// Whether `child` is a subgrid that is laid out by its parent grid `parent`
+#[cfg(feature = "grid")]
+fn is_subgrid_of(parent: &Style, child: &Style) -> bool {
+    parent.display == Display::Grid
+        && (child.is_subgrid(AbsoluteAxis::Horizontal) || child.is_subgrid(AbsoluteAxis::Vertical))
+}
+
+/// Whether `child` is a subgrid that is laid out by its parent grid `parent`
+#[cfg(not(feature = "grid"))]
+fn is_subgrid_of(_parent: &Style, _child: &Style) -> bool {
+    false
+}
+
+/// Transpose a point (such as a baseline) within a box of the specified physical width
+fn transpose_point(point: Point<Option<f32>>, width: f32, writing_mode: WritingMode) -> Point<Option<f32>> {
+    match writing_mode {
+        WritingMode::VerticalRl => Point { x: point.y, y: point.x.map(|x| width - x) },
+        _ => Point { x: point.y, y: point.x },
+    }
+}
+
+/// Convert a transposed point (such as a baseline) within a box of the specified physical width back into a physical point
+fn untranspose_point(point: Point<Option<f32>>, width: f32, writing_mode: WritingMode) -> Point<Option<f32>> {
+    match writing_mode {
+        WritingMode::VerticalRl => Point { x: point.y.map(|y| width - y), y: point.x },
+        _ => Point { x: point.y, y: point.x },
+    }
+}
@@ -1,13 +1,17 @@
 //! Computes the [flexbox](https://css-tricks.com/snippets/css/a-guide-to-flexbox/) layout algorithm on [`Taffy`](crate::Taffy) according to the [spec](https://www.w3.org/TR/css-flexbox-1/)
 use core::f32;
 
-use crate::compute::common::alignment::compute_alignment_offset;
+use crate::compute::common::absolute::{resolve_absolute_box_sizes, AbsoluteBoxSizes};
+use crate::compute::common::alignment::{compute_alignment_offset, resolve_overflow_alignment};
+use crate::compute::common::content_size::compute_content_size;
+use crate::compute::common::contents::{order_modified_layout_children, perform_contents_layout};
+use crate::compute::common::intrinsic_size::resolve_intrinsic_sizes;
 use crate::compute::LayoutAlgorithm;
 use crate::geometry::{Line, Point, Rect, Size};
 use crate::prelude::{TaffyMaxContent, TaffyMinContent};
 use crate::style::{
     AlignContent, AlignItems, AlignSelf, AvailableSpace, Dimension, Display, FlexWrap, JustifyContent,
-    LengthPercentageAuto, Overflow, Position,
+    LengthPercentageAuto, Overflow, Position, Visibility,
 };
 use crate::style::{FlexDirection, Style};
 use crate::tree::{Layout, RunMode, SizeBaselinesAndMargins, SizingMode};
@@ -17,6 +21,9 @@ use crate::util::sys::{f32_max, new_vec_with_capacity};
 use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::compute::common::writing_mode::compute_in_vertical_writing_mode;
+
 #[cfg(feature = "debug")]
 use crate::util::debug::NODE_LOGGER;
 
@@ -54,6 +61,8 @@ impl LayoutAlgorithm for FlexboxAlgorithm {
 struct FlexItem {
     /// The identifier for the associated node
     node: NodeId,
+    /// The order of the node relative to it's siblings
+    order: u32,
 
     /// The base size of this item
     size: Size<Option<f32>>,
@@ -63,6 +72,8 @@ struct FlexItem {
     max_size: Size<Option<f32>>,
     /// The cross-alignment of this item
     align_self: AlignSelf,
+    /// The visibility style of the item
+    visibility: Visibility,
 
     /// The overflow style of the item
     overflow: Point<Overflow>,
@@ -110,6 +121,9 @@ struct FlexItem {
 
     /// The position of the bottom edge of this item
     baseline: f32,
+    /// The last baseline of this item. While the flex lines are being sized this is the distance from the item's last
+    /// baseline to its outer cross-end edge. After the final layout pass it is the position of the last baseline.
+    last_baseline: f32,
 
     /// A temporary value for the main offset
     ///
@@ -123,6 +137,18 @@ struct FlexItem {
     offset_cross: f32,
 }
 
+/// The space reserved in the cross axis for a flex item with `visibility: collapse`
+struct Strut {
+    /// The identifier for the collapsed item
+    node: NodeId,
+    /// The order of the collapsed item relative to its siblings
+    order: u32,
+    /// The number of (non-collapsed) flex items that precede the collapsed item
+    preceding_items: usize,
+    /// The cross size of the flex line that the collapsed item was placed in during the first layout round
+    cross_size: f32,
+}
+
 /// A line of [`FlexItem`] used for intermediate computation
 struct FlexLine<'a> {
     /// The slice of items to iterate over during computation of this line
@@ -134,6 +160,7 @@ struct FlexLine<'a> {
 }
 
 /// Values that can be cached during the flexbox algorithm
+#[derive(Clone)]
 struct AlgoConstants {
     /// The direction of the current segment being laid out
     dir: FlexDirection,
@@ -145,6 +172,9 @@ struct AlgoConstants {
     is_wrap: bool,
     /// Is the wrap direction inverted
     is_wrap_reverse: bool,
+    /// Is the container right-to-left. If so, the horizontal sides of the container and its items are swapped
+    /// and the resulting horizontal positions are mirrored once layout is complete.
+    is_rtl: bool,
 
     /// The item's min_size style
     min_size: Size<Option<f32>>,
@@ -187,15 +217,54 @@ pub fn compute(
     parent_size: Size<Option<f32>>,
     available_space: Size<AvailableSpace>,
     run_mode: RunMode,
+) -> SizeBaselinesAndMargins {
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    if tree.style(node).writing_mode.is_vertical() {
+        return compute_in_vertical_writing_mode(
+            tree,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            run_mode,
+            compute_horizontal,
+        );
+    }
+    compute_horizontal(tree, node, known_dimensions, parent_size, available_space, run_mode)
+}
+
+/// Computes the layout of a flexbox node whose inline axis is horizontal
+fn compute_horizontal(
+    tree: &mut impl LayoutTree,
+    node: NodeId,
+    known_dimensions: Size<Option<f32>>,
+    parent_size: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
 ) -> SizeBaselinesAndMargins {
     let style = tree.style(node);
 
     // Pull these out earlier to avoid borrowing issues
     let aspect_ratio = style.aspect_ratio;
-    let min_size = style.min_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
-    let max_size = style.max_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
-    let clamped_style_size =
-        style.size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+    let padding = style.padding.resolve_or_zero(parent_size.width);
+    let border = style.border.resolve_or_zero(parent_size.width);
+    let box_sizing_adjustment = style.box_sizing.adjustment((padding + border).sum_axes());
+    let min_size = style
+        .min_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let max_size = style
+        .max_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let clamped_style_size = style
+        .size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment)
+        .maybe_clamp(min_size, max_size);
 
     // If both min and max in a given axis are set and max <= min then this determines the size in that axis
     let min_max_definite_size = min_size.zip_map(max_size, |min, max| match (min, max) {
@@ -237,6 +306,17 @@ fn compute_preliminary(
     #[cfg(feature = "debug")]
     NODE_LOGGER.log("generate_anonymous_flex_items");
     let mut flex_items = generate_anonymous_flex_items(tree, node, &constants);
+    for item in flex_items.iter_mut() {
+        resolve_intrinsic_sizes(
+            tree,
+            item.node,
+            constants.node_inner_size,
+            constants.node_inner_size.maybe_sub(item.margin.sum_axes()),
+            &mut item.size,
+            &mut item.min_size,
+            &mut item.max_size,
+        );
+    }
 
     // 9.2. Line Length Determination
 
@@ -262,64 +342,7 @@ fn compute_preliminary(
     // 4. Determine the main size of the flex container
     // This has already been done as part of compute_constants. The inner size is exposed as constants.node_inner_size.
 
-    // 9.3. Main Size Determination
-
-    // 5. Collect flex items into flex lines.
-    #[cfg(feature = "debug")]
-    NODE_LOGGER.log("collect_flex_lines");
-    let mut flex_lines = collect_flex_lines(&constants, available_space, &mut flex_items);
-
-    // If container size is undefined, determine the container's main size
-    // and then re-resolve gaps based on newly determined size
-    let original_gap = constants.gap;
-    if let Some(inner_main_size) = constants.node_inner_size.main(constants.dir) {
-        let outer_main_size = inner_main_size + constants.content_box_inset.main_axis_sum(constants.dir);
-        constants.inner_container_size.set_main(constants.dir, inner_main_size);
-        constants.container_size.set_main(constants.dir, outer_main_size);
-    } else {
-        // Sets constants.container_size and constants.outer_container_size
-        determine_container_main_size(tree, available_space.main(constants.dir), &mut flex_lines, &mut constants);
-        constants.node_inner_size.set_main(constants.dir, Some(constants.inner_container_size.main(constants.dir)));
-        constants.node_outer_size.set_main(constants.dir, Some(constants.container_size.main(constants.dir)));
-
-        // Re-resolve percentage gaps
-        let style = tree.style(node);
-        let inner_container_size = constants.inner_container_size.main(constants.dir);
-        let new_gap = style.gap.main(constants.dir).maybe_resolve(inner_container_size).unwrap_or(0.0);
-        constants.gap.set_main(constants.dir, new_gap);
-    }
-
-    // 6. Resolve the flexible lengths of all the flex items to find their used main size.
-    #[cfg(feature = "debug")]
-    NODE_LOGGER.log("resolve_flexible_lengths");
-    for line in &mut flex_lines {
-        resolve_flexible_lengths(line, &constants, original_gap);
-    }
-
-    // 9.4. Cross Size Determination
-
-    // 7. Determine the hypothetical cross size of each item.
-    #[cfg(feature = "debug")]
-    NODE_LOGGER.log("determine_hypothetical_cross_size");
-    for line in &mut flex_lines {
-        determine_hypothetical_cross_size(tree, line, &constants, available_space);
-    }
-
-    // Calculate child baselines. This function is internally smart and only computes child baselines
-    // if they are necessary.
-    #[cfg(feature = "debug")]
-    NODE_LOGGER.log("calculate_children_base_lines");
-    calculate_children_base_lines(tree, known_dimensions, available_space, &mut flex_lines, &constants);
-
-    // 8. Calculate the cross size of each flex line.
-    #[cfg(feature = "debug")]
-    NODE_LOGGER.log("calculate_cross_size");
-    calculate_cross_size(&mut flex_lines, known_dimensions, &constants);
-
-    // 9. Handle 'align-content: stretch'.
-    #[cfg(feature = "debug")]
-    NODE_LOGGER.log("handle_align_content_stretch");
-    handle_align_content_stretch(&mut flex_lines, known_dimensions, &constants);
+    // Steps 5 to 9 are performed by determine_flex_lines so that they can be rerun by step 10
 
     // 10. Collapse visibility:collapse items. If any flex items have visibility: collapse,
     //     note the cross size of the line they’re in as the item’s strut size, and restart
@@ -333,8 +356,36 @@ fn compute_preliminary(
     //     that strut size.
     //
     //     Skip this step in the second layout round.
+    let mut struts = Vec::new();
+    let mut flex_lines = if flex_items.iter().any(|item| item.visibility == Visibility::Collapse) {
+        let original_constants = constants.clone();
+        let flex_lines =
+            determine_flex_lines(tree, node, known_dimensions, available_space, &mut constants, &mut flex_items, &[]);
+        let mut preceding_items = 0;
+        for line in flex_lines {
+            for item in line.items.iter() {
+                if item.visibility == Visibility::Collapse {
+                    struts.push(Strut {
+                        node: item.node,
+                        order: item.order,
+                        preceding_items,
+                        cross_size: line.cross_size,
+                    });
+                } else {
+                    preceding_items += 1;
+                }
+            }
+        }
 
-    // TODO implement once (if ever) we support visibility:collapse
+        constants = original_constants;
+        flex_items.retain(|item| item.visibility != Visibility::Collapse);
+        for item in flex_items.iter_mut() {
+            item.frozen = false;
+        }
+        determine_flex_lines(tree, node, known_dimensions, available_space, &mut constants, &mut flex_items, &struts)
+    } else {
+        determine_flex_lines(tree, node, known_dimensions, available_space, &mut constants, &mut flex_items, &[])
+    };
 
     // 11. Determine the used cross size of each flex item.
     #[cfg(feature = "debug")]
@@ -374,7 +425,7 @@ fn compute_preliminary(
     // Do a final layout pass and gather the resulting layouts
     #[cfg(feature = "debug")]
     NODE_LOGGER.log("final_layout_pass");
-    final_layout_pass(tree, node, &mut flex_lines, &constants);
+    final_layout_pass(tree, &mut flex_lines, &constants, &struts);
 
     // Before returning we perform absolute layout on all absolutely positioned children
     #[cfg(feature = "debug")]
@@ -383,9 +434,7 @@ fn compute_preliminary(
 
     #[cfg(feature = "debug")]
     NODE_LOGGER.log("hidden_layout");
-    let len = tree.child_count(node);
-    for order in 0..len {
-        let child = tree.child(node, order);
+    for (order, child) in order_modified_layout_children(tree, node).into_iter().enumerate() {
         if tree.style(child).display == Display::None {
             *tree.layout_mut(child) = Layout::with_order(order as u32);
             tree.perform_child_layout(
@@ -398,6 +447,13 @@ fn compute_preliminary(
             );
         }
     }
+    perform_contents_layout(tree, node);
+
+    // Compute the scrollable overflow from the final positions of the children
+    let style = tree.style(node);
+    let padding = style.padding.resolve_or_zero(parent_size.width);
+    let border = style.border.resolve_or_zero(parent_size.width);
+    let content_size = compute_content_size(tree, node, constants.container_size, padding, border);
 
     // 8.5. Flex Container Baselines: calculate the flex container's first baseline
     // See https://www.w3.org/TR/css-flexbox-1/#flex-baselines
@@ -415,10 +471,117 @@ fn compute_preliminary(
             })
     };
 
-    SizeBaselinesAndMargins::from_size_and_baselines(
-        constants.container_size,
-        Point { x: None, y: first_vertical_baseline },
-    )
+    // Calculate the flex container's last baseline from the last item on its last line that participates in last
+    // baseline alignment, or else from the last item on that line
+    let last_vertical_baseline = flex_lines.last().and_then(|line| {
+        line.items
+            .iter()
+            .rev()
+            .find(|item| constants.is_column || item.align_self == AlignSelf::LastBaseline)
+            .or_else(|| line.items.last())
+            .map(|child| child.last_baseline)
+    });
+
+    SizeBaselinesAndMargins {
+        content_size,
+        ..SizeBaselinesAndMargins::from_size_and_baselines(
+            constants.container_size,
+            Point { x: None, y: first_vertical_baseline },
+            Point { x: None, y: last_vertical_baseline },
+        )
+    }
+}
+
+/// Collect the flex items into flex lines, and resolve the main size of each item and the cross size of each line.
+///
+/// This covers steps 5 to 9 of the flexbox algorithm, which are run a second time (ignoring any collapsed items)
+/// if any of the flex items have `visibility: collapse`. The `struts` of the collapsed items are used as the
+/// minimum cross size of the line that each collapsed item is placed in.
+fn determine_flex_lines<'a>(
+    tree: &mut impl LayoutTree,
+    node: NodeId,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    constants: &mut AlgoConstants,
+    flex_items: &'a mut Vec<FlexItem>,
+    struts: &[Strut],
+) -> Vec<FlexLine<'a>> {
+    // 9.3. Main Size Determination
+
+    // 5. Collect flex items into flex lines.
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log("collect_flex_lines");
+    let mut flex_lines = collect_flex_lines(constants, available_space, flex_items);
+
+    // If container size is undefined, determine the container's main size
+    // and then re-resolve gaps based on newly determined size
+    let original_gap = constants.gap;
+    if let Some(inner_main_size) = constants.node_inner_size.main(constants.dir) {
+        let outer_main_size = inner_main_size + constants.content_box_inset.main_axis_sum(constants.dir);
+        constants.inner_container_size.set_main(constants.dir, inner_main_size);
+        constants.container_size.set_main(constants.dir, outer_main_size);
+    } else {
+        // Sets constants.container_size and constants.outer_container_size
+        determine_container_main_size(tree, available_space.main(constants.dir), &mut flex_lines, constants);
+        constants.node_inner_size.set_main(constants.dir, Some(constants.inner_container_size.main(constants.dir)));
+        constants.node_outer_size.set_main(constants.dir, Some(constants.container_size.main(constants.dir)));
+
+        // Re-resolve percentage gaps
+        let style = tree.style(node);
+        let inner_container_size = constants.inner_container_size.main(constants.dir);
+        let new_gap = style.gap.main(constants.dir).maybe_resolve(inner_container_size).unwrap_or(0.0);
+        constants.gap.set_main(constants.dir, new_gap);
+    }
+
+    // 6. Resolve the flexible lengths of all the flex items to find their used main size.
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log("resolve_flexible_lengths");
+    for line in &mut flex_lines {
+        resolve_flexible_lengths(line, constants, original_gap);
+    }
+
+    // 9.4. Cross Size Determination
+
+    // 7. Determine the hypothetical cross size of each item.
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log("determine_hypothetical_cross_size");
+    for line in &mut flex_lines {
+        determine_hypothetical_cross_size(tree, line, constants, available_space);
+    }
+
+    // Calculate child baselines. This function is internally smart and only computes child baselines
+    // if they are necessary.
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log("calculate_children_base_lines");
+    calculate_children_base_lines(tree, known_dimensions, available_space, &mut flex_lines, constants);
+
+    // 8. Calculate the cross size of each flex line.
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log("calculate_cross_size");
+    calculate_cross_size(&mut flex_lines, known_dimensions, constants);
+
+    // Lines must be at least as large in the cross axis as the struts of the collapsed items that they contain.
+    // A collapsed item is placed in the line of the item that precedes it (or in the first line).
+    if flex_lines.is_empty() && !struts.is_empty() {
+        flex_lines.push(FlexLine { items: &mut [], cross_size: 0.0, offset_cross: 0.0 });
+    }
+    for strut in struts {
+        let mut line_end = 0;
+        let line = flex_lines.iter_mut().find(|line| {
+            line_end += line.items.len();
+            strut.preceding_items <= line_end
+        });
+        if let Some(line) = line {
+            line.cross_size = line.cross_size.max(strut.cross_size);
+        }
+    }
+
+    // 9. Handle 'align-content: stretch'.
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log("handle_align_content_stretch");
+    handle_align_content_stretch(&mut flex_lines, known_dimensions, constants);
+
+    flex_lines
 }
 
 /// Compute constants that can be reused during the flexbox algorithm.
@@ -433,27 +596,22 @@ fn compute_constants(
     let is_column = dir.is_column();
     let is_wrap = matches!(style.flex_wrap, FlexWrap::Wrap | FlexWrap::WrapReverse);
     let is_wrap_reverse = style.flex_wrap == FlexWrap::WrapReverse;
+    let is_rtl = style.direction.is_rtl();
 
     let aspect_ratio = style.aspect_ratio;
     let margin = style.margin.resolve_or_zero(parent_size.width);
-    let padding = style.padding.resolve_or_zero(parent_size.width);
-    let border = style.border.resolve_or_zero(parent_size.width);
+    let padding = style.padding.resolve_or_zero(parent_size.width).swap_horizontal_sides_if(is_rtl);
+    let border = style.border.resolve_or_zero(parent_size.width).swap_horizontal_sides_if(is_rtl);
     let align_items = style.align_items.unwrap_or(AlignItems::Stretch);
     let align_content = style.align_content.unwrap_or(AlignContent::Stretch);
     let justify_content = style.justify_content;
 
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = style.overflow.transpose().map(|overflow| match overflow {
-        Overflow::Scroll => style.scrollbar_width,
-        _ => 0.0,
-    });
-    // TODO: make side configurable based on the `direction` property
-    let mut content_box_inset = padding + border;
-    content_box_inset.right += scrollbar_gutter.x;
-    content_box_inset.bottom += scrollbar_gutter.y;
+    let scrollbar_gutter_inset = style.scrollbar_gutter_inset();
+    let scrollbar_gutter =
+        Point { x: scrollbar_gutter_inset.horizontal_axis_sum(), y: scrollbar_gutter_inset.vertical_axis_sum() };
+    let content_box_inset = padding + border + scrollbar_gutter_inset;
 
+    let box_sizing_adjustment = style.box_sizing.adjustment((padding + border).sum_axes());
     let node_outer_size = known_dimensions;
     let node_inner_size = node_outer_size.maybe_sub(content_box_inset.sum_axes());
     let gap = style.gap.resolve_or_zero(node_inner_size.or(Size::zero()));
@@ -467,8 +625,17 @@ fn compute_constants(
         is_column,
         is_wrap,
         is_wrap_reverse,
-        min_size: style.min_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio),
-        max_size: style.max_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio),
+        is_rtl,
+        min_size: style
+            .min_size
+            .maybe_resolve(parent_size)
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment),
+        max_size: style
+            .max_size
+            .maybe_resolve(parent_size)
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment),
         margin,
         border,
         gap,
@@ -491,30 +658,56 @@ fn compute_constants(
 /// - [**Generate anonymous flex items**](https://www.w3.org/TR/css-flexbox-1/#algo-anon-box) as described in [§4 Flex Items](https://www.w3.org/TR/css-flexbox-1/#flex-items).
 #[inline]
 fn generate_anonymous_flex_items(tree: &impl LayoutTree, node: NodeId, constants: &AlgoConstants) -> Vec<FlexItem> {
-    tree.children(node)
-        .map(|child| (child, tree.style(child)))
-        .filter(|(_, style)| style.position != Position::Absolute)
-        .filter(|(_, style)| style.display != Display::None)
-        .map(|(child, child_style)| {
+    order_modified_layout_children(tree, node)
+        .into_iter()
+        .enumerate()
+        .map(|(order, child)| (order, child, tree.style(child)))
+        .filter(|(_, _, style)| !style.position.is_out_of_flow())
+        .filter(|(_, _, style)| style.display != Display::None)
+        .map(|(order, child, child_style)| {
             let aspect_ratio = child_style.aspect_ratio;
+            let padding = child_style.padding.resolve_or_zero(constants.node_inner_size.width);
+            let border = child_style.border.resolve_or_zero(constants.node_inner_size.width);
+            let box_sizing_adjustment = child_style.box_sizing.adjustment((padding + border).sum_axes());
             FlexItem {
                 node: child,
-                size: child_style.size.maybe_resolve(constants.node_inner_size).maybe_apply_aspect_ratio(aspect_ratio),
+                order: order as u32,
+                size: child_style
+                    .size
+                    .maybe_resolve(constants.node_inner_size)
+                    .maybe_apply_aspect_ratio(aspect_ratio)
+                    .maybe_add(box_sizing_adjustment),
                 min_size: child_style
                     .min_size
                     .maybe_resolve(constants.node_inner_size)
-                    .maybe_apply_aspect_ratio(aspect_ratio),
+                    .maybe_apply_aspect_ratio(aspect_ratio)
+                    .maybe_add(box_sizing_adjustment),
                 max_size: child_style
                     .max_size
                     .maybe_resolve(constants.node_inner_size)
-                    .maybe_apply_aspect_ratio(aspect_ratio),
-
-                inset: child_style.inset.zip_size(constants.node_inner_size, |p, s| p.maybe_resolve(s)),
-                margin: child_style.margin.resolve_or_zero(constants.node_inner_size.width),
-                margin_is_auto: child_style.margin.map(|m| m == LengthPercentageAuto::Auto),
-                padding: child_style.padding.resolve_or_zero(constants.node_inner_size.width),
-                border: child_style.border.resolve_or_zero(constants.node_inner_size.width),
+                    .maybe_apply_aspect_ratio(aspect_ratio)
+                    .maybe_add(box_sizing_adjustment),
+
+                // The inset of a sticky item doesn't offset it from its position in the flex line
+                inset: match child_style.position {
+                    Position::Sticky => Rect { left: None, right: None, top: None, bottom: None },
+                    _ => child_style
+                        .inset
+                        .zip_size(constants.node_inner_size, |p, s| p.maybe_resolve(s))
+                        .swap_horizontal_sides_if(constants.is_rtl),
+                },
+                margin: child_style
+                    .margin
+                    .resolve_or_zero(constants.node_inner_size.width)
+                    .swap_horizontal_sides_if(constants.is_rtl),
+                margin_is_auto: child_style
+                    .margin
+                    .map(|m| m == LengthPercentageAuto::Auto)
+                    .swap_horizontal_sides_if(constants.is_rtl),
+                padding,
+                border,
                 align_self: child_style.align_self.unwrap_or(constants.align_items),
+                visibility: child_style.visibility,
                 overflow: child_style.overflow,
                 flex_grow: child_style.flex_grow,
                 flex_shrink: child_style.flex_shrink,
@@ -531,6 +724,7 @@ fn generate_anonymous_flex_items(tree: &impl LayoutTree, node: NodeId, constants
                 content_flex_fraction: 0.0,
 
                 baseline: 0.0,
+                last_baseline: 0.0,
 
                 offset_main: 0.0,
                 offset_cross: 0.0,
@@ -637,7 +831,12 @@ fn determine_flex_base_size(
             // Note: `child.size` has already been resolved against aspect_ratio in generate_anonymous_flex_items
             // So B will just work here by using main_size without special handling for aspect_ratio
 
-            let flex_basis = child_style.flex_basis.maybe_resolve(constants.node_inner_size.main(dir));
+            // Like `size`, `flex_basis` refers to the box selected by `box_sizing`
+            let box_sizing_adjustment = child_style.box_sizing.adjustment((child.padding + child.border).sum_axes());
+            let flex_basis = child_style
+                .flex_basis
+                .maybe_resolve(constants.node_inner_size.main(dir))
+                .maybe_add(box_sizing_adjustment.main(dir));
             let main_size = child.size.main(dir);
             if let Some(flex_basis) = flex_basis.or(main_size) {
                 break 'flex_basis flex_basis;
@@ -658,7 +857,9 @@ fn determine_flex_base_size(
             //    for a box in an orthogonal flow [CSS3-WRITING-MODES]. The flex base size
             //    is the item’s max-content main size.
 
-            // TODO if/when vertical writing modes are supported
+            // Items are always measured in their own writing mode (a container with a vertical writing mode is laid out
+            // in transposed coordinates), and an infinite available main size is passed through as max-content in step E,
+            // so step E also covers this case.
 
             // E. Otherwise, size the item into the available space using its used flex basis
             //    in place of its main size, treating a value of content as max-content.
@@ -1297,23 +1498,31 @@ fn calculate_children_base_lines(
     constants: &AlgoConstants,
 ) {
     // Only compute baselines for flex rows because we only support baseline alignment in the cross axis
-    // where that axis is also the inline axis
-    // TODO: this may need revisiting if/when we support vertical writing modes
+    // where that axis is also the inline axis. Containers with a vertical writing mode are laid out in transposed
+    // coordinates, so a row's main axis is always its inline axis here.
     if !constants.is_row {
         return;
     }
 
     for line in flex_lines {
-        // If a flex line has one or zero items participating in baseline alignment then baseline alignment is a no-op so we skip
-        let line_baseline_child_count =
-            line.items.iter().filter(|child| child.align_self == AlignSelf::Baseline).count();
-        if line_baseline_child_count <= 1 {
+        // If a flex line has one or zero items participating in first (or last) baseline alignment then that baseline
+        // alignment is a no-op so we skip it
+        let baseline_child_count =
+            |alignment: AlignSelf| line.items.iter().filter(|child| child.align_self == alignment).count();
+        let has_first_baseline_group = baseline_child_count(AlignSelf::Baseline) > 1;
+        let has_last_baseline_group = baseline_child_count(AlignSelf::LastBaseline) > 1;
+        if !has_first_baseline_group && !has_last_baseline_group {
             continue;
         }
 
         for child in line.items.iter_mut() {
             // Only calculate baselines for children participating in baseline alignment
-            if child.align_self != AlignSelf::Baseline {
+            let participates_in_baseline_alignment = match child.align_self {
+                AlignSelf::Baseline => has_first_baseline_group,
+                AlignSelf::LastBaseline => has_last_baseline_group,
+                _ => false,
+            };
+            if !participates_in_baseline_alignment {
                 continue;
             }
 
@@ -1348,10 +1557,14 @@ fn calculate_children_base_lines(
                 Line::FALSE,
             );
 
-            let baseline = measured_size_and_baselines.first_baselines.y;
             let height = measured_size_and_baselines.size.height;
-
-            child.baseline = baseline.unwrap_or(height) + child.margin.top;
+            if child.align_self == AlignSelf::Baseline {
+                let baseline = measured_size_and_baselines.first_baselines.y;
+                child.baseline = baseline.unwrap_or(height) + child.margin.top;
+            } else {
+                let last_baseline = measured_size_and_baselines.last_baselines.y;
+                child.last_baseline = height - last_baseline.unwrap_or(height) + child.margin.bottom;
+            }
         }
     }
 }
@@ -1412,18 +1625,27 @@ fn calculate_cross_size(flex_lines: &mut [FlexLine], node_size: Size<Option<f32>
             //    3. The used cross-size of the flex line is the largest of the numbers found in the
             //       previous two steps and zero.
 
+            //    Items with align-self: last baseline are collected into a separate group, using the distances
+            //    between their last baselines and their hypothetical outer cross-end edges.
+
             let max_baseline: f32 = line.items.iter().map(|child| child.baseline).fold(0.0, |acc, x| acc.max(x));
+            let max_last_baseline: f32 =
+                line.items.iter().map(|child| child.last_baseline).fold(0.0, |acc, x| acc.max(x));
             line.cross_size = line
                 .items
                 .iter()
                 .map(|child| {
-                    if child.align_self == AlignSelf::Baseline
-                        && !child.margin_is_auto.cross_start(constants.dir)
-                        && !child.margin_is_auto.cross_end(constants.dir)
-                    {
-                        max_baseline - child.baseline + child.hypothetical_outer_size.cross(constants.dir)
-                    } else {
-                        child.hypothetical_outer_size.cross(constants.dir)
+                    let hypothetical_outer_cross_size = child.hypothetical_outer_size.cross(constants.dir);
+                    let has_auto_cross_margin = child.margin_is_auto.cross_start(constants.dir)
+                        || child.margin_is_auto.cross_end(constants.dir);
+                    match child.align_self {
+                        AlignSelf::Baseline if !has_auto_cross_margin => {
+                            max_baseline - child.baseline + hypothetical_outer_cross_size
+                        }
+                        AlignSelf::LastBaseline if !has_auto_cross_margin => {
+                            max_last_baseline - child.last_baseline + hypothetical_outer_cross_size
+                        }
+                        _ => hypothetical_outer_cross_size,
                     }
                 })
                 .fold(0.0, |acc, x| acc.max(x));
@@ -1486,12 +1708,15 @@ fn determine_used_cross_size(tree: &mut impl LayoutTree, flex_lines: &mut [FlexL
                 if child.align_self == AlignSelf::Stretch
                     && !child.margin_is_auto.cross_start(constants.dir)
                     && !child.margin_is_auto.cross_end(constants.dir)
-                    && child_style.size.cross(constants.dir) == Dimension::Auto
+                    && matches!(child_style.size.cross(constants.dir), Dimension::Auto | Dimension::Stretch)
                 {
                     // For some reason this particular usage of max_width is an exception to the rule that max_width's transfer
                     // using the aspect_ratio (if set). Both Chrome and Firefox agree on this. And reading the spec, it seems like
                     // a reasonable interpretation. Although it seems to me that the spec *should* apply aspect_ratio here.
-                    let max_size_ignoring_aspect_ratio = child_style.max_size.maybe_resolve(constants.node_inner_size);
+                    let box_sizing_adjustment =
+                        child_style.box_sizing.adjustment((child.padding + child.border).sum_axes());
+                    let max_size_ignoring_aspect_ratio =
+                        child_style.max_size.maybe_resolve(constants.node_inner_size).maybe_add(box_sizing_adjustment);
 
                     (line_cross_size - child.margin.cross_axis_sum(constants.dir)).maybe_clamp(
                         child.min_size.cross(constants.dir),
@@ -1594,6 +1819,8 @@ fn resolve_cross_axis_auto_margins(flex_lines: &mut [FlexLine], constants: &Algo
     for line in flex_lines {
         let line_cross_size = line.cross_size;
         let max_baseline: f32 = line.items.iter_mut().map(|child| child.baseline).fold(0.0, |acc, x| acc.max(x));
+        let max_last_baseline: f32 =
+            line.items.iter_mut().map(|child| child.last_baseline).fold(0.0, |acc, x| acc.max(x));
 
         for child in line.items.iter_mut() {
             let free_space = line_cross_size - child.outer_target_size.cross(constants.dir);
@@ -1620,7 +1847,8 @@ fn resolve_cross_axis_auto_margins(flex_lines: &mut [FlexLine], constants: &Algo
                 }
             } else {
                 // 14. Align all flex items along the cross-axis.
-                child.offset_cross = align_flex_items_along_cross_axis(child, free_space, max_baseline, constants);
+                child.offset_cross =
+                    align_flex_items_along_cross_axis(child, free_space, max_baseline, max_last_baseline, constants);
             }
         }
     }
@@ -1637,32 +1865,33 @@ fn align_flex_items_along_cross_axis(
     child: &mut FlexItem,
     free_space: f32,
     max_baseline: f32,
+    max_last_baseline: f32,
     constants: &AlgoConstants,
 ) -> f32 {
-    match child.align_self {
+    match resolve_overflow_alignment(child.align_self, free_space) {
         AlignSelf::Start => 0.0,
-        AlignSelf::FlexStart => {
+        AlignSelf::FlexStart | AlignSelf::SafeFlexStart => {
             if constants.is_wrap_reverse {
                 free_space
             } else {
                 0.0
             }
         }
-        AlignSelf::End => free_space,
-        AlignSelf::FlexEnd => {
+        AlignSelf::End | AlignSelf::SafeEnd => free_space,
+        AlignSelf::FlexEnd | AlignSelf::SafeFlexEnd => {
             if constants.is_wrap_reverse {
                 0.0
             } else {
                 free_space
             }
         }
-        AlignSelf::Center => free_space / 2.0,
+        AlignSelf::Center | AlignSelf::SafeCenter => free_space / 2.0,
         AlignSelf::Baseline => {
             if constants.is_row {
                 max_baseline - child.baseline
             } else {
-                // Until we support vertical writing modes, baseline alignment only makes sense if
-                // the constants.direction is row, so we treat it as flex-start alignment in columns.
+                // Baseline alignment is only supported when the cross axis is the block axis (i.e. the
+                // constants.direction is row), so we treat it as flex-start alignment in columns.
                 if constants.is_wrap_reverse {
                     free_space
                 } else {
@@ -1670,6 +1899,18 @@ fn align_flex_items_along_cross_axis(
                 }
             }
         }
+        AlignSelf::LastBaseline => {
+            if constants.is_row {
+                free_space - (max_last_baseline - child.last_baseline)
+            } else {
+                // Likewise, last baseline alignment is treated as flex-end alignment in columns
+                if constants.is_wrap_reverse {
+                    0.0
+                } else {
+                    free_space
+                }
+            }
+        }
         AlignSelf::Stretch => {
             if constants.is_wrap_reverse {
                 free_space
@@ -1745,7 +1986,6 @@ fn align_flex_lines_per_align_content(flex_lines: &mut [FlexLine], constants: &A
 #[allow(clippy::too_many_arguments)]
 fn calculate_flex_item(
     tree: &mut impl LayoutTree,
-    node: NodeId,
     item: &mut FlexItem,
     total_offset_main: &mut f32,
     total_offset_cross: f32,
@@ -1778,47 +2018,106 @@ fn calculate_flex_item(
     if direction.is_row() {
         let baseline_offset_cross = total_offset_cross + item.offset_cross + item.margin.cross_start(direction);
         let inner_baseline = preliminary_size_and_baselines.first_baselines.y.unwrap_or(preliminary_size.height);
+        let inner_last_baseline = preliminary_size_and_baselines.last_baselines.y.unwrap_or(preliminary_size.height);
         item.baseline = baseline_offset_cross + inner_baseline;
+        item.last_baseline = baseline_offset_cross + inner_last_baseline;
     } else {
         let baseline_offset_main = *total_offset_main + item.offset_main + item.margin.main_start(direction);
         let inner_baseline = preliminary_size_and_baselines.first_baselines.y.unwrap_or(preliminary_size.height);
+        let inner_last_baseline = preliminary_size_and_baselines.last_baselines.y.unwrap_or(preliminary_size.height);
         item.baseline = baseline_offset_main + inner_baseline;
+        item.last_baseline = baseline_offset_main + inner_last_baseline;
     }
 
-    let order = tree.children(node).position(|n| n == item.node).unwrap() as u32;
-
     *tree.layout_mut(item.node) = Layout {
-        order,
+        order: item.order,
         size: preliminary_size_and_baselines.size,
         location: Point {
             x: if direction.is_row() { offset_main } else { offset_cross },
             y: if direction.is_column() { offset_main } else { offset_cross },
         },
+        is_visible: item.visibility.is_visible(),
+        content_size: preliminary_size_and_baselines.content_size,
     };
 
     *total_offset_main += item.offset_main + item.margin.main_axis_sum(direction) + preliminary_size.main(direction);
 }
 
+/// Lays out the collapsed items (see [`Strut`]) that are placed in a line before its item at `index` (or after all of
+/// its items), at the main axis position `offset_main`. They are given a zero main size, and are sized to their strut
+/// in the cross axis.
+#[allow(clippy::too_many_arguments)]
+fn calculate_collapsed_items(
+    tree: &mut impl LayoutTree,
+    struts: &[Strut],
+    line_start: usize,
+    index: usize,
+    offset_main: f32,
+    offset_cross: f32,
+    node_inner_size: Size<Option<f32>>,
+    direction: FlexDirection,
+) {
+    // A collapsed item is placed in the line of the item that precedes it (or in the first line)
+    let is_placed_at_index =
+        |strut: &&Strut| strut.preceding_items == line_start + index && (index > 0 || line_start == 0);
+    for strut in struts.iter().filter(is_placed_at_index) {
+        let mut size = Size::ZERO;
+        size.set_cross(direction, strut.cross_size);
+        tree.perform_child_layout(
+            strut.node,
+            size.map(Some),
+            node_inner_size,
+            size.map(AvailableSpace::Definite),
+            SizingMode::InherentSize,
+            Line::FALSE,
+        );
+        let location = Point {
+            x: if direction.is_row() { offset_main } else { offset_cross },
+            y: if direction.is_column() { offset_main } else { offset_cross },
+        };
+        *tree.layout_mut(strut.node) =
+            Layout { order: strut.order, size, location, is_visible: false, content_size: Size::ZERO };
+    }
+}
+
 /// Calculates the layout line
+///
+/// The collapsed items in `struts` that are placed in the line are positioned where they would be in the line, at the
+/// start of the margin box of the item that follows them. `line_start` is the number of items in the preceding lines.
 #[allow(clippy::too_many_arguments)]
 fn calculate_layout_line(
     tree: &mut impl LayoutTree,
-    node: NodeId,
     line: &mut FlexLine,
     total_offset_cross: &mut f32,
     container_size: Size<f32>,
     node_inner_size: Size<Option<f32>>,
     padding_border: Rect<f32>,
     direction: FlexDirection,
+    struts: &[Strut],
+    line_start: usize,
 ) {
     let mut total_offset_main = padding_border.main_start(direction);
     let line_offset_cross = line.offset_cross;
+    let line_cross_start = *total_offset_cross + line_offset_cross;
+    let item_count = line.items.len();
 
+    // The items of reversed lines are laid out from last to first, so the collapsed items that precede an item (in
+    // the order of the line) follow it
     if direction.is_reverse() {
-        for item in line.items.iter_mut().rev() {
+        for (index, item) in line.items.iter_mut().enumerate().rev() {
+            let offset_main = total_offset_main + item.offset_main;
+            calculate_collapsed_items(
+                tree,
+                struts,
+                line_start,
+                index + 1,
+                offset_main,
+                line_cross_start,
+                node_inner_size,
+                direction,
+            );
             calculate_flex_item(
                 tree,
-                node,
                 item,
                 &mut total_offset_main,
                 *total_offset_cross,
@@ -1828,11 +2127,31 @@ fn calculate_layout_line(
                 direction,
             );
         }
+        calculate_collapsed_items(
+            tree,
+            struts,
+            line_start,
+            0,
+            total_offset_main,
+            line_cross_start,
+            node_inner_size,
+            direction,
+        );
     } else {
-        for item in line.items.iter_mut() {
+        for (index, item) in line.items.iter_mut().enumerate() {
+            let offset_main = total_offset_main + item.offset_main;
+            calculate_collapsed_items(
+                tree,
+                struts,
+                line_start,
+                index,
+                offset_main,
+                line_cross_start,
+                node_inner_size,
+                direction,
+            );
             calculate_flex_item(
                 tree,
-                node,
                 item,
                 &mut total_offset_main,
                 *total_offset_cross,
@@ -1842,6 +2161,16 @@ fn calculate_layout_line(
                 direction,
             );
         }
+        calculate_collapsed_items(
+            tree,
+            struts,
+            line_start,
+            item_count,
+            total_offset_main,
+            line_cross_start,
+            node_inner_size,
+            direction,
+        );
     }
 
     *total_offset_cross += line_offset_cross + line.cross_size;
@@ -1849,34 +2178,58 @@ fn calculate_layout_line(
 
 /// Do a final layout pass and collect the resulting layouts.
 #[inline]
-fn final_layout_pass(tree: &mut impl LayoutTree, node: NodeId, flex_lines: &mut [FlexLine], constants: &AlgoConstants) {
+fn final_layout_pass(
+    tree: &mut impl LayoutTree,
+    flex_lines: &mut [FlexLine],
+    constants: &AlgoConstants,
+    struts: &[Strut],
+) {
     let mut total_offset_cross = constants.content_box_inset.cross_start(constants.dir);
 
     if constants.is_wrap_reverse {
+        let mut line_end: usize = flex_lines.iter().map(|line| line.items.len()).sum();
         for line in flex_lines.iter_mut().rev() {
+            line_end -= line.items.len();
             calculate_layout_line(
                 tree,
-                node,
                 line,
                 &mut total_offset_cross,
                 constants.container_size,
                 constants.node_inner_size,
                 constants.content_box_inset,
                 constants.dir,
+                struts,
+                line_end,
             );
         }
     } else {
+        let mut line_start = 0;
         for line in flex_lines.iter_mut() {
             calculate_layout_line(
                 tree,
-                node,
                 line,
                 &mut total_offset_cross,
                 constants.container_size,
                 constants.node_inner_size,
                 constants.content_box_inset,
                 constants.dir,
+                struts,
+                line_start,
             );
+            line_start += line.items.len();
+        }
+    }
+
+    // Items of right-to-left containers were laid out against the swapped (mirrored) sides of the container,
+    // so mirror their horizontal positions back into place.
+    if constants.is_rtl {
+        for item in flex_lines.iter().flat_map(|line| line.items.iter()) {
+            let layout = tree.layout_mut(item.node);
+            layout.location.x = constants.container_size.width - layout.location.x - layout.size.width;
+        }
+        for strut in struts {
+            let layout = tree.layout_mut(strut.node);
+            layout.location.x = constants.container_size.width - layout.location.x - layout.size.width;
         }
     }
 }
@@ -1887,8 +2240,7 @@ fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node
     let container_width = constants.container_size.width;
     let container_height = constants.container_size.height;
 
-    for order in 0..tree.child_count(node) {
-        let child = tree.child(node, order);
+    for (order, child) in order_modified_layout_children(tree, node).into_iter().enumerate() {
         let child_style = tree.style(child);
 
         // Skip items that are display:none or are not position:absolute
@@ -1896,49 +2248,11 @@ fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node
             continue;
         }
 
-        let aspect_ratio = child_style.aspect_ratio;
+        let is_visible = child_style.visibility.is_visible();
         let align_self = child_style.align_self.unwrap_or(constants.align_items);
-        let margin = child_style.margin.map(|margin| margin.resolve_to_option(container_width));
-        let padding = child_style.padding.resolve_or_zero(Some(container_width));
-        let border = child_style.border.resolve_or_zero(Some(container_width));
-        let padding_border_sum = (padding + border).sum_axes();
-
-        // Resolve inset
-        let left = child_style.inset.left.maybe_resolve(container_width);
-        let right = child_style.inset.right.maybe_resolve(container_width);
-        let top = child_style.inset.top.maybe_resolve(container_height);
-        let bottom = child_style.inset.bottom.maybe_resolve(container_height);
-
-        // Compute known dimensions from min/max/inherent size styles
-        let style_size =
-            child_style.size.maybe_resolve(constants.container_size).maybe_apply_aspect_ratio(aspect_ratio);
-        let min_size = child_style
-            .min_size
-            .maybe_resolve(constants.container_size)
-            .maybe_apply_aspect_ratio(aspect_ratio)
-            .or(padding_border_sum.map(Some))
-            .maybe_max(padding_border_sum);
-        let max_size =
-            child_style.max_size.maybe_resolve(constants.container_size).maybe_apply_aspect_ratio(aspect_ratio);
-        let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
-
-        // Fill in width from left/right and reapply aspect ratio if:
-        //   - Width is not already known
-        //   - Item has both left and right inset properties set
-        if let (None, Some(left), Some(right)) = (known_dimensions.width, left, right) {
-            let new_width_raw = container_width.maybe_sub(margin.left).maybe_sub(margin.right) - left - right;
-            known_dimensions.width = Some(f32_max(new_width_raw, 0.0));
-            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
-        }
-
-        // Fill in height from top/bottom and reapply aspect ratio if:
-        //   - Height is not already known
-        //   - Item has both top and bottom inset properties set
-        if let (None, Some(top), Some(bottom)) = (known_dimensions.height, top, bottom) {
-            let new_height_raw = container_height.maybe_sub(margin.top).maybe_sub(margin.bottom) - top - bottom;
-            known_dimensions.height = Some(f32_max(new_height_raw, 0.0));
-            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
-        }
+        let AbsoluteBoxSizes { inset, margin, min_size, max_size, known_dimensions, .. } =
+            resolve_absolute_box_sizes(tree, child, constants.container_size, constants.is_rtl);
+        let Rect { left, right, top, bottom } = inset;
 
         let measured_size_and_baselines = tree.perform_child_layout(
             child,
@@ -1995,8 +2309,24 @@ fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node
         let (start_main, end_main) = if constants.is_row { (left, right) } else { (top, bottom) };
         let (start_cross, end_cross) = if constants.is_row { (top, bottom) } else { (left, right) };
 
+        // The space left over in the content box of the container once the item is placed in it, which may be negative
+        // if the item overflows the container. This determines the overflow alignment of safe alignment modes.
+        let overflowing_free_space = Size {
+            width: constants.container_size.width
+                - constants.content_box_inset.horizontal_axis_sum()
+                - final_size.width
+                - resolved_margin.horizontal_axis_sum(),
+            height: constants.container_size.height
+                - constants.content_box_inset.vertical_axis_sum()
+                - final_size.height
+                - resolved_margin.vertical_axis_sum(),
+        };
+
         // Apply main-axis alignment
-        // let free_main_space = free_space.main(constants.dir) - resolved_margin.main_axis_sum(constants.dir);
+        let justify_content = resolve_overflow_alignment(
+            constants.justify_content.unwrap_or(JustifyContent::Start),
+            overflowing_free_space.main(constants.dir),
+        );
         let offset_main = if let Some(start) = start_main {
             start + constants.border.main_start(constants.dir) + resolved_margin.main_start(constants.dir)
         } else if let Some(end) = end_main {
@@ -2008,24 +2338,26 @@ fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node
         } else {
             // Stretch is an invalid value for justify_content in the flexbox algorithm, so we
             // treat it as if it wasn't set (and thus we default to FlexStart behaviour)
-            match (constants.justify_content.unwrap_or(JustifyContent::Start), constants.is_wrap_reverse) {
+            match (justify_content, constants.is_wrap_reverse) {
                 (JustifyContent::SpaceBetween, _)
                 | (JustifyContent::Start, _)
                 | (JustifyContent::Stretch, false)
-                | (JustifyContent::FlexStart, false)
-                | (JustifyContent::FlexEnd, true) => {
+                | (JustifyContent::FlexStart | JustifyContent::SafeFlexStart, false)
+                | (JustifyContent::FlexEnd | JustifyContent::SafeFlexEnd, true) => {
                     constants.content_box_inset.main_start(constants.dir) + resolved_margin.main_start(constants.dir)
                 }
-                (JustifyContent::End, _)
-                | (JustifyContent::FlexEnd, false)
-                | (JustifyContent::FlexStart, true)
+                (JustifyContent::End | JustifyContent::SafeEnd, _)
+                | (JustifyContent::FlexEnd | JustifyContent::SafeFlexEnd, false)
+                | (JustifyContent::FlexStart | JustifyContent::SafeFlexStart, true)
                 | (JustifyContent::Stretch, true) => {
                     constants.container_size.main(constants.dir)
                         - constants.content_box_inset.main_end(constants.dir)
                         - final_size.main(constants.dir)
                         - resolved_margin.main_end(constants.dir)
                 }
-                (JustifyContent::SpaceEvenly, _) | (JustifyContent::SpaceAround, _) | (JustifyContent::Center, _) => {
+                (JustifyContent::SpaceEvenly, _)
+                | (JustifyContent::SpaceAround, _)
+                | (JustifyContent::Center | JustifyContent::SafeCenter, _) => {
                     (constants.container_size.main(constants.dir)
                         + constants.content_box_inset.main_start(constants.dir)
                         - constants.content_box_inset.main_end(constants.dir)
@@ -2038,7 +2370,7 @@ fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node
         };
 
         // Apply cross-axis alignment
-        // let free_cross_space = free_space.cross(constants.dir) - resolved_margin.cross_axis_sum(constants.dir);
+        let align_self = resolve_overflow_alignment(align_self, overflowing_free_space.cross(constants.dir));
         let offset_cross = if let Some(start) = start_cross {
             start + constants.border.cross_start(constants.dir) + resolved_margin.cross_start(constants.dir)
         } else if let Some(end) = end_cross {
@@ -2053,19 +2385,19 @@ fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node
                 // See "Example 3" at https://www.w3.org/TR/css-flexbox-1/#abspos-items
                 // Note: Stretch should be FlexStart not Start when we support both
                 (AlignSelf::Start, _)
-                | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart, false)
-                | (AlignSelf::FlexEnd, true) => {
+                | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart | AlignSelf::SafeFlexStart, false)
+                | (AlignSelf::LastBaseline | AlignSelf::FlexEnd | AlignSelf::SafeFlexEnd, true) => {
                     constants.content_box_inset.cross_start(constants.dir) + resolved_margin.cross_start(constants.dir)
                 }
-                (AlignSelf::End, _)
-                | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart, true)
-                | (AlignSelf::FlexEnd, false) => {
+                (AlignSelf::End | AlignSelf::SafeEnd, _)
+                | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart | AlignSelf::SafeFlexStart, true)
+                | (AlignSelf::LastBaseline | AlignSelf::FlexEnd | AlignSelf::SafeFlexEnd, false) => {
                     constants.container_size.cross(constants.dir)
                         - constants.content_box_inset.cross_end(constants.dir)
                         - final_size.cross(constants.dir)
                         - resolved_margin.cross_end(constants.dir)
                 }
-                (AlignSelf::Center, _) => {
+                (AlignSelf::Center | AlignSelf::SafeCenter, _) => {
                     (constants.container_size.cross(constants.dir)
                         + constants.content_box_inset.cross_start(constants.dir)
                         - constants.content_box_inset.cross_end(constants.dir)
@@ -2077,13 +2409,16 @@ fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node
             }
         };
 
+        let x = if constants.is_row { offset_main } else { offset_cross };
         *tree.layout_mut(child) = Layout {
             order: order as u32,
             size: final_size,
             location: Point {
-                x: if constants.is_row { offset_main } else { offset_cross },
+                x: if constants.is_rtl { container_width - x - final_size.width } else { x },
                 y: if constants.is_column { offset_main } else { offset_cross },
             },
+            is_visible,
+            content_size: measured_size_and_baselines.content_size,
         };
     }
 }
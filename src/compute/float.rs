@@ -0,0 +1,272 @@
+//! Places the floated children of a block container and tracks the space that they exclude from the content that
+//! flows around them
+//!
+//! The floats of a block container that establishes a new block formatting context are shared with its block-level
+//! descendants that don't establish one of their own: the floats shorten the lines of all of them, and the floats of
+//! those descendants are added to the shared floats, so that they in turn shorten the lines that follow them. Only the
+//! container that establishes the formatting context grows to enclose the floats. Block-level children that establish
+//! a new block formatting context are placed beside the floats that they would otherwise overlap (or below them).
+use crate::geometry::{Line, Point, Size};
+use crate::style::{Clear, Float, Style};
+use crate::util::sys::{f32_max, f32_min, Vec};
+
+/// Whether a child of a block container with the specified style is floated
+#[inline]
+pub(crate) fn is_floated(style: &Style) -> bool {
+    style.float != Float::None && !style.position.is_out_of_flow()
+}
+
+/// The side that a float is placed on once its container has been laid out as if it were left-to-right
+#[inline]
+pub(crate) fn resolve_float_side(float: Float, is_rtl: bool) -> Float {
+    match (float, is_rtl) {
+        (Float::Left, true) => Float::Right,
+        (Float::Right, true) => Float::Left,
+        (float, _) => float,
+    }
+}
+
+/// The floats that a node must be placed below once its container has been laid out as if it were left-to-right
+#[inline]
+pub(crate) fn resolve_clear_side(clear: Clear, is_rtl: bool) -> Clear {
+    match (clear, is_rtl) {
+        (Clear::Left, true) => Clear::Right,
+        (Clear::Right, true) => Clear::Left,
+        (clear, _) => clear,
+    }
+}
+
+/// A float that has been placed within its container
+#[derive(Debug, Clone, Copy)]
+struct PlacedFloat {
+    /// The side of the container that the float was placed against
+    side: Float,
+    /// The horizontal extent of the margin box of the float
+    x: Line<f32>,
+    /// The vertical extent of the margin box of the float
+    y: Line<f32>,
+}
+
+impl PlacedFloat {
+    /// The float as seen from a box at `offset` that is `width` wide, which is mirrored if `is_mirrored` is true
+    #[inline]
+    fn into_box(self, offset: Point<f32>, width: f32, is_mirrored: bool) -> Self {
+        let x = Line { start: self.x.start - offset.x, end: self.x.end - offset.x };
+        let y = Line { start: self.y.start - offset.y, end: self.y.end - offset.y };
+        match is_mirrored {
+            true => Self {
+                side: resolve_float_side(self.side, true),
+                x: Line { start: width - x.end, end: width - x.start },
+                y,
+            },
+            false => Self { side: self.side, x, y },
+        }
+    }
+
+    /// The float as seen from the box that contains a box at `offset` that is `width` wide (the inverse of
+    /// [`PlacedFloat::into_box`])
+    #[inline]
+    fn out_of_box(self, offset: Point<f32>, width: f32, is_mirrored: bool) -> Self {
+        let float = self.into_box(Point { x: 0.0, y: 0.0 }, width, is_mirrored);
+        let x = Line { start: float.x.start + offset.x, end: float.x.end + offset.x };
+        let y = Line { start: float.y.start + offset.y, end: float.y.end + offset.y };
+        Self { side: float.side, x, y }
+    }
+
+    /// Whether the float intersects the horizontal band between `y` and `y + height`. A band with a zero height
+    /// intersects the floats that span `y`.
+    #[inline]
+    fn intersects(&self, y: f32, height: f32) -> bool {
+        self.y.end > y && (self.y.start < y + height || self.y.start <= y)
+    }
+}
+
+/// The floats of a block container, in the coordinates of its border box
+#[derive(Debug, Clone)]
+pub(crate) struct FloatContext {
+    /// The horizontal extent of the content box of the container
+    content_box_x: Line<f32>,
+    /// The floats that have been placed so far
+    floats: Vec<PlacedFloat>,
+    /// The lowest position that the top of a float may be placed at: a float is never placed above an earlier float
+    min_float_top: f32,
+    /// The number of floats (at the start of `floats`) that were placed by the ancestors of the container
+    inherited_float_count: usize,
+}
+
+impl FloatContext {
+    /// Create a context for a container whose content box spans `content_box_x` horizontally
+    pub(crate) fn new(content_box_x: Line<f32>) -> Self {
+        Self { content_box_x, floats: Vec::new(), min_float_top: f32::NEG_INFINITY, inherited_float_count: 0 }
+    }
+
+    /// Create a context for a block-level child that shares the floats of this container, whose border box is at
+    /// `location` and is `width` wide. The floats are mirrored if `is_mirrored` is true, which is the case if the
+    /// direction of the child differs from that of the container. The content box of the child must be set with
+    /// [`FloatContext::set_content_box_x`] before it is used.
+    pub(crate) fn for_child(&self, location: Point<f32>, width: f32, is_mirrored: bool) -> Self {
+        Self {
+            content_box_x: Line { start: 0.0, end: width },
+            floats: self.floats.iter().map(|float| float.into_box(location, width, is_mirrored)).collect(),
+            min_float_top: self.min_float_top - location.y,
+            inherited_float_count: self.floats.len(),
+        }
+    }
+
+    /// Add the floats that were placed by a child created with [`FloatContext::for_child`] to this context
+    pub(crate) fn add_child_floats(&mut self, child: &Self, location: Point<f32>, width: f32, is_mirrored: bool) {
+        let child_floats = &child.floats[child.inherited_float_count..];
+        self.floats.extend(child_floats.iter().map(|float| float.out_of_box(location, width, is_mirrored)));
+        self.min_float_top = f32_max(self.min_float_top, child.min_float_top + location.y);
+    }
+
+    /// Set the horizontal extent of the content box of the container
+    pub(crate) fn set_content_box_x(&mut self, content_box_x: Line<f32>) {
+        self.content_box_x = content_box_x;
+    }
+
+    /// Whether any floats have been placed
+    pub(crate) fn is_empty(&self) -> bool {
+        self.floats.is_empty()
+    }
+
+    /// The horizontal extent of the space that isn't excluded by floats in the band between `y` and `y + height`
+    pub(crate) fn available_space(&self, y: f32, height: f32) -> Line<f32> {
+        self.floats.iter().filter(|float| float.intersects(y, height)).fold(self.content_box_x, |space, float| {
+            match float.side {
+                Float::Left => Line { start: f32_max(space.start, float.x.end), end: space.end },
+                _ => Line { start: space.start, end: f32_min(space.end, float.x.start) },
+            }
+        })
+    }
+
+    /// The position of the first band at or below `y` (of height `height`) in which there is room for content of
+    /// width `width` beside the floats, along with the horizontal extent of the available space in that band. Content
+    /// that is too wide to fit beside any float is placed below all of the floats.
+    pub(crate) fn find_space(&self, mut y: f32, width: f32, height: f32) -> (f32, Line<f32>) {
+        loop {
+            let space = self.available_space(y, height);
+            let fits = space.end - space.start >= width && space.end > space.start;
+            match self.next_float_bottom(y, height) {
+                Some(float_bottom) if !fits => y = float_bottom,
+                _ => return (y, space),
+            }
+        }
+    }
+
+    /// The highest bottom edge of the floats that intersect the band between `y` and `y + height`
+    fn next_float_bottom(&self, y: f32, height: f32) -> Option<f32> {
+        self.floats.iter().filter(|float| float.intersects(y, height)).map(|float| float.y.end).reduce(f32_min)
+    }
+
+    /// The position below the floats that a node with the specified `clear` style must be placed below, if any
+    pub(crate) fn clearance_position(&self, clear: Clear) -> Option<f32> {
+        self.floats
+            .iter()
+            .filter(|float| match clear {
+                Clear::None => false,
+                Clear::Left => float.side == Float::Left,
+                Clear::Right => float.side == Float::Right,
+                Clear::Both => true,
+            })
+            .map(|float| float.y.end)
+            .reduce(f32_max)
+    }
+
+    /// The bottom edge of the lowest float, if there are any floats
+    pub(crate) fn floats_bottom(&self) -> Option<f32> {
+        self.floats.iter().map(|float| float.y.end).reduce(f32_max)
+    }
+
+    /// Place a float with the specified margin box size against the `side` side of the container, as high as possible
+    /// but no higher than `y`, returning the location of its margin box
+    pub(crate) fn place_float(&mut self, side: Float, margin_box_size: Size<f32>, y: f32, clear: Clear) -> Point<f32> {
+        let min_y = f32_max(f32_max(y, self.min_float_top), self.clearance_position(clear).unwrap_or(y));
+        let (y, space) = self.find_space(min_y, margin_box_size.width, margin_box_size.height);
+        let x = match side {
+            Float::Left => space.start,
+            _ => space.end - margin_box_size.width,
+        };
+
+        self.min_float_top = y;
+        self.floats.push(PlacedFloat {
+            side,
+            x: Line { start: x, end: x + margin_box_size.width },
+            y: Line { start: y, end: y + margin_box_size.height },
+        });
+        Point { x, y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FloatContext;
+    use crate::geometry::{Line, Point, Size};
+    use crate::style::{Clear, Float};
+
+    fn context_with_floats() -> FloatContext {
+        let mut context = FloatContext::new(Line { start: 0.0, end: 100.0 });
+        context.place_float(Float::Left, Size { width: 30.0, height: 20.0 }, 0.0, Clear::None);
+        context.place_float(Float::Right, Size { width: 20.0, height: 40.0 }, 0.0, Clear::None);
+        context
+    }
+
+    #[test]
+    fn floats_are_placed_against_the_sides_of_the_container() {
+        let mut context = FloatContext::new(Line { start: 10.0, end: 110.0 });
+        let left = context.place_float(Float::Left, Size { width: 30.0, height: 20.0 }, 5.0, Clear::None);
+        let right = context.place_float(Float::Right, Size { width: 20.0, height: 20.0 }, 5.0, Clear::None);
+        let second_left = context.place_float(Float::Left, Size { width: 30.0, height: 20.0 }, 5.0, Clear::None);
+
+        assert_eq!(left, Point { x: 10.0, y: 5.0 });
+        assert_eq!(right, Point { x: 90.0, y: 5.0 });
+        assert_eq!(second_left, Point { x: 40.0, y: 5.0 });
+    }
+
+    #[test]
+    fn floats_that_dont_fit_are_placed_below() {
+        let mut context = context_with_floats();
+        let float = context.place_float(Float::Left, Size { width: 60.0, height: 10.0 }, 0.0, Clear::None);
+        assert_eq!(float, Point { x: 0.0, y: 20.0 });
+
+        // A float is never placed above an earlier float
+        let float = context.place_float(Float::Right, Size { width: 10.0, height: 10.0 }, 0.0, Clear::None);
+        assert_eq!(float, Point { x: 70.0, y: 20.0 });
+    }
+
+    #[test]
+    fn available_space_excludes_floats() {
+        let context = context_with_floats();
+        assert_eq!(context.available_space(0.0, 0.0), Line { start: 30.0, end: 80.0 });
+        assert_eq!(context.available_space(20.0, 0.0), Line { start: 0.0, end: 80.0 });
+        assert_eq!(context.available_space(15.0, 10.0), Line { start: 30.0, end: 80.0 });
+        assert_eq!(context.available_space(40.0, 0.0), Line { start: 0.0, end: 100.0 });
+        assert_eq!(context.find_space(0.0, 60.0, 0.0), (20.0, Line { start: 0.0, end: 80.0 }));
+        assert_eq!(context.find_space(0.0, 90.0, 0.0), (40.0, Line { start: 0.0, end: 100.0 }));
+    }
+
+    #[test]
+    fn clearance() {
+        let context = context_with_floats();
+        assert_eq!(context.clearance_position(Clear::None), None);
+        assert_eq!(context.clearance_position(Clear::Left), Some(20.0));
+        assert_eq!(context.clearance_position(Clear::Right), Some(40.0));
+        assert_eq!(context.clearance_position(Clear::Both), Some(40.0));
+        assert_eq!(context.floats_bottom(), Some(40.0));
+    }
+
+    #[test]
+    fn floats_are_shared_with_children() {
+        let mut context = context_with_floats();
+        let location = Point { x: 10.0, y: 10.0 };
+        let mut child = context.for_child(location, 80.0, true);
+        child.set_content_box_x(Line { start: 0.0, end: 80.0 });
+        assert_eq!(child.available_space(0.0, 0.0), Line { start: 10.0, end: 60.0 });
+
+        let float = child.place_float(Float::Left, Size { width: 10.0, height: 40.0 }, 0.0, Clear::None);
+        assert_eq!(float, Point { x: 10.0, y: 0.0 });
+        context.add_child_floats(&child, location, 80.0, true);
+        assert_eq!(context.available_space(30.0, 0.0), Line { start: 0.0, end: 70.0 });
+        assert_eq!(context.floats_bottom(), Some(50.0));
+    }
+}
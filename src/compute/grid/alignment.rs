@@ -1,6 +1,7 @@
 //! Alignment of tracks and final positioning of items
 use super::types::GridTrack;
-use crate::compute::common::alignment::compute_alignment_offset;
+use crate::compute::common::alignment::{compute_alignment_offset, resolve_overflow_alignment};
+use crate::compute::common::intrinsic_size::resolve_intrinsic_sizes;
 use crate::geometry::InBothAbsAxis;
 use crate::geometry::{Line, Point, Rect, Size};
 use crate::style::{AlignContent, AlignItems, AlignSelf, AvailableSpace, Position};
@@ -26,7 +27,8 @@ pub(super) fn align_tracks(
     let overflow = f32_min(size_diff, 0.0);
 
     // If the used_size > grid_container_size then the tracks must overflow their container
-    // The direction in which they do so is determined by the alignment style
+    // The direction in which they do so is determined by the alignment style (safe alignment styles always
+    // overflow at the end)
     let origin = padding.start
         + border.start
         + match track_alignment_style {
@@ -35,6 +37,10 @@ pub(super) fn align_tracks(
             AlignContent::End => overflow,
             AlignContent::FlexEnd => overflow,
             AlignContent::Center => overflow / 2.0,
+            AlignContent::SafeEnd => 0.0,
+            AlignContent::SafeFlexStart => 0.0,
+            AlignContent::SafeFlexEnd => 0.0,
+            AlignContent::SafeCenter => 0.0,
             AlignContent::Stretch => 0.0,
             AlignContent::SpaceBetween => 0.0,
             AlignContent::SpaceEvenly => 0.0,
@@ -70,13 +76,18 @@ pub(super) fn align_tracks(
 }
 
 /// Align and size a grid item into it's final position
+///
+/// If `is_rtl` is true, the grid area is in the coordinates of the horizontally mirrored container, so the item's
+/// horizontal margins and insets are swapped. The caller is responsible for mirroring the resulting position.
+#[allow(clippy::too_many_arguments)]
 pub(super) fn align_and_position_item(
     tree: &mut impl LayoutTree,
     node: NodeId,
     order: u32,
     grid_area: Rect<f32>,
     container_alignment_styles: InBothAbsAxis<Option<AlignItems>>,
-    baseline_shim: f32,
+    baseline_shim: Rect<f32>,
+    is_rtl: bool,
 ) {
     let grid_area_size = Size { width: grid_area.right - grid_area.left, height: grid_area.bottom - grid_area.top };
 
@@ -86,19 +97,53 @@ pub(super) fn align_and_position_item(
     let align_self = style.align_self;
 
     let position = style.position;
-    let inset_horizontal = style.inset.horizontal_components().map(|size| size.resolve_to_option(grid_area_size.width));
+    let is_visible = style.visibility.is_visible();
+    let inset_horizontal = style
+        .inset
+        .swap_horizontal_sides_if(is_rtl)
+        .horizontal_components()
+        .map(|size| size.resolve_to_option(grid_area_size.width));
     let inset_vertical = style.inset.vertical_components().map(|size| size.resolve_to_option(grid_area_size.height));
     let padding = style.padding.map(|p| p.resolve_or_zero(Some(grid_area_size.width)));
     let border = style.border.map(|p| p.resolve_or_zero(Some(grid_area_size.width)));
     let padding_border_size = (padding + border).sum_axes();
-    let inherent_size = style.size.maybe_resolve(grid_area_size).maybe_apply_aspect_ratio(aspect_ratio);
-    let min_size = style
-        .min_size
+    let box_sizing_adjustment = style.box_sizing.adjustment(padding_border_size);
+    // Note: This is not a bug. It is part of the CSS spec that both horizontal and vertical margins
+    // resolve against the WIDTH of the grid area.
+    let margin =
+        style.margin.map(|margin| margin.resolve_to_option(grid_area_size.width)).swap_horizontal_sides_if(is_rtl);
+
+    let grid_area_minus_item_margins_size = Size {
+        width: grid_area_size.width.maybe_sub(margin.left).maybe_sub(margin.right)
+            - baseline_shim.horizontal_axis_sum(),
+        height: grid_area_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom)
+            - baseline_shim.vertical_axis_sum(),
+    };
+
+    let mut inherent_size = style
+        .size
+        .maybe_resolve(grid_area_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let mut min_size = style.min_size.maybe_resolve(grid_area_size).maybe_add(box_sizing_adjustment);
+    let mut max_size = style
+        .max_size
         .maybe_resolve(grid_area_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    resolve_intrinsic_sizes(
+        tree,
+        node,
+        grid_area_size.map(Some),
+        grid_area_minus_item_margins_size.map(Some),
+        &mut inherent_size,
+        &mut min_size,
+        &mut max_size,
+    );
+    let min_size = min_size
         .or(padding_border_size.map(Some))
         .maybe_max(padding_border_size)
-        .maybe_apply_aspect_ratio(aspect_ratio);
-    let max_size = style.max_size.maybe_resolve(grid_area_size).maybe_apply_aspect_ratio(aspect_ratio);
+        .maybe_apply_aspect_ratio_with_adjustment(aspect_ratio, box_sizing_adjustment);
 
     // Resolve default alignment styles if they are set on neither the parent or the node itself
     // Note: if the child has a preferred aspect ratio but neither width or height are set, then the width is stretched
@@ -121,15 +166,6 @@ pub(super) fn align_and_position_item(
         }),
     };
 
-    // Note: This is not a bug. It is part of the CSS spec that both horizontal and vertical margins
-    // resolve against the WIDTH of the grid area.
-    let margin = style.margin.map(|margin| margin.resolve_to_option(grid_area_size.width));
-
-    let grid_area_minus_item_margins_size = Size {
-        width: grid_area_size.width.maybe_sub(margin.left).maybe_sub(margin.right),
-        height: grid_area_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom) - baseline_shim,
-    };
-
     // If node is absolutely positioned and width is not set explicitly, then deduce it
     // from left, right and container_content_box if both are set.
     let width = inherent_size.width.or_else(|| {
@@ -157,7 +193,8 @@ pub(super) fn align_and_position_item(
     });
 
     // Reapply aspect ratio after stretch and absolute position width adjustments
-    let Size { width, height } = Size { width, height: inherent_size.height }.maybe_apply_aspect_ratio(aspect_ratio);
+    let Size { width, height } = Size { width, height: inherent_size.height }
+        .maybe_apply_aspect_ratio_with_adjustment(aspect_ratio, box_sizing_adjustment);
 
     let height = height.or_else(|| {
         if position == Position::Absolute {
@@ -181,7 +218,8 @@ pub(super) fn align_and_position_item(
         None
     });
     // Reapply aspect ratio after stretch and absolute position height adjustments
-    let Size { width, height } = Size { width, height }.maybe_apply_aspect_ratio(aspect_ratio);
+    let Size { width, height } =
+        Size { width, height }.maybe_apply_aspect_ratio_with_adjustment(aspect_ratio, box_sizing_adjustment);
 
     // Clamp size by min and max width/height
     let Size { width, height } = Size { width, height }.maybe_clamp(min_size, max_size);
@@ -207,7 +245,7 @@ pub(super) fn align_and_position_item(
         position,
         inset_horizontal,
         margin.horizontal_components(),
-        0.0,
+        baseline_shim.horizontal_components(),
     );
     let y = align_item_within_area(
         Line { start: grid_area.top, end: grid_area.bottom },
@@ -216,10 +254,16 @@ pub(super) fn align_and_position_item(
         position,
         inset_vertical,
         margin.vertical_components(),
-        baseline_shim,
+        baseline_shim.vertical_components(),
     );
 
-    *tree.layout_mut(node) = Layout { order, size: Size { width, height }, location: Point { x, y } };
+    *tree.layout_mut(node) = Layout {
+        order,
+        size: Size { width, height },
+        location: Point { x, y },
+        is_visible,
+        content_size: measured_size_and_baselines.content_size,
+    };
 }
 
 /// Align and size a grid item along a single axis
@@ -230,10 +274,13 @@ pub(super) fn align_item_within_area(
     position: Position,
     inset: Line<Option<f32>>,
     margin: Line<Option<f32>>,
-    baseline_shim: f32,
+    baseline_shim: Line<f32>,
 ) -> f32 {
     // Calculate grid area dimension in the axis
-    let non_auto_margin = Line { start: margin.start.unwrap_or(0.0) + baseline_shim, end: margin.end.unwrap_or(0.0) };
+    let non_auto_margin = Line {
+        start: margin.start.unwrap_or(0.0) + baseline_shim.start,
+        end: margin.end.unwrap_or(0.0) + baseline_shim.end,
+    };
     let grid_area_size = f32_max(grid_area.end - grid_area.start, 0.0);
     let free_space = f32_max(grid_area_size - resolved_size - non_auto_margin.sum(), 0.0);
 
@@ -241,17 +288,24 @@ pub(super) fn align_item_within_area(
     let auto_margin_count = margin.start.is_none() as u8 + margin.end.is_none() as u8;
     let auto_margin_size = if auto_margin_count > 0 { free_space / auto_margin_count as f32 } else { 0.0 };
     let resolved_margin = Line {
-        start: margin.start.unwrap_or(auto_margin_size) + baseline_shim,
-        end: margin.end.unwrap_or(auto_margin_size),
+        start: margin.start.unwrap_or(auto_margin_size) + baseline_shim.start,
+        end: margin.end.unwrap_or(auto_margin_size) + baseline_shim.end,
     };
 
-    // Compute offset in the axis
-    let alignment_based_offset = match alignment_style {
-        AlignSelf::Start | AlignSelf::FlexStart => resolved_margin.start,
-        AlignSelf::End | AlignSelf::FlexEnd => grid_area_size - resolved_size - resolved_margin.end,
-        AlignSelf::Center => (grid_area_size - resolved_size + resolved_margin.start - resolved_margin.end) / 2.0,
-        // TODO: Add support for baseline alignment. For now we treat it as "start".
+    // Compute offset in the axis. Safe alignment styles align items that overflow their grid area to its start.
+    let overflowing_free_space = grid_area_size - resolved_size - resolved_margin.sum();
+    let alignment_based_offset = match resolve_overflow_alignment(alignment_style, overflowing_free_space) {
+        AlignSelf::Start | AlignSelf::FlexStart | AlignSelf::SafeFlexStart => resolved_margin.start,
+        AlignSelf::End | AlignSelf::FlexEnd | AlignSelf::SafeEnd | AlignSelf::SafeFlexEnd => {
+            grid_area_size - resolved_size - resolved_margin.end
+        }
+        AlignSelf::Center | AlignSelf::SafeCenter => {
+            (grid_area_size - resolved_size + resolved_margin.start - resolved_margin.end) / 2.0
+        }
+        // Baseline aligned items are placed at the start (or for last baseline alignment, the end) of their area,
+        // offset by their baseline shim (which is included in the resolved margins)
         AlignSelf::Baseline => resolved_margin.start,
+        AlignSelf::LastBaseline => grid_area_size - resolved_size - resolved_margin.end,
         AlignSelf::Stretch => resolved_margin.start,
     };
 
@@ -1,12 +1,15 @@
 //! Helper functions for intialising GridTrack's from styles
 //! This mainly consists of evaluating GridAutoTracks
-use super::types::{GridTrack, TrackCounts};
+use super::types::{GridTrack, OriginZeroLine, TrackCounts};
 use crate::geometry::AbsoluteAxis;
-use crate::style::{GridTrackRepetition, LengthPercentage, NonRepeatedTrackSizingFunction, Style, TrackSizingFunction};
+use crate::style::{
+    BoxSizing, GridTrackRepetition, LengthPercentage, MaxTrackSizingFunction, MinTrackSizingFunction,
+    NonRepeatedTrackSizingFunction, Style, TrackSizingFunction,
+};
 use crate::style_helpers::TaffyAuto;
 use crate::util::sys::{GridTrackVec, Vec};
 use crate::util::MaybeMath;
-use crate::util::ResolveOrZero;
+use crate::util::{MaybeResolve, ResolveOrZero};
 
 #[cfg(not(feature = "std"))]
 use num_traits::float::FloatCore;
@@ -21,35 +24,40 @@ pub(crate) fn compute_explicit_grid_size_in_axis(style: &Style, axis: AbsoluteAx
         return 0;
     }
 
-    // If there are any repetitions that contains no tracks, then the whole definition should be considered invalid
-    // and we default to no explicit tracks
-    let template_has_repetitions_with_zero_tracks = template.iter().any(|track_def| match track_def {
-        TrackSizingFunction::Single(_) => false,
-        TrackSizingFunction::Repeat(_, tracks) => tracks.is_empty(),
+    // A subgrid doesn't define any tracks of its own in the subgridded axis (they are inherited from the parent grid and
+    // are initialised separately), and neither does a masonry grid in its masonry axis. A `subgrid` or `masonry` value
+    // combined with any other track definitions is invalid.
+    if template.iter().any(|track_def| matches!(track_def, TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry))
+    {
+        return 0;
+    }
+
+    // If there are any repetitions that contain no tracks (or that contain anything other than tracks and line names),
+    // then the whole definition should be considered invalid and we default to no explicit tracks
+    let template_has_invalid_repetitions = template.iter().any(|track_def| match track_def {
+        TrackSizingFunction::Repeat(_, tracks) => {
+            repeated_tracks(tracks).next().is_none()
+                || tracks
+                    .iter()
+                    .any(|track| !matches!(track, TrackSizingFunction::Single(_) | TrackSizingFunction::LineNames(_)))
+        }
+        _ => false,
     });
-    if template_has_repetitions_with_zero_tracks {
+    if template_has_invalid_repetitions {
         return 0;
     }
 
     // Compute that number of track generated by single track definition and repetitions with a fixed repetition count
-    let non_auto_repeating_track_count = template
-        .iter()
-        .map(|track_def| {
-            use GridTrackRepetition::{AutoFill, AutoFit, Count};
-            match track_def {
-                TrackSizingFunction::Single(_) => 1,
-                TrackSizingFunction::Repeat(Count(count), tracks) => count * tracks.len() as u16,
-                TrackSizingFunction::Repeat(AutoFit | AutoFill, _) => 0,
-            }
-        })
-        .sum::<u16>();
+    let non_auto_repeating_track_count = compute_non_auto_repeating_track_count(template);
 
     let auto_repetition_count = template.iter().filter(|track_def| track_def.is_auto_repetition()).count() as u16;
     let all_track_defs_have_fixed_component = template.iter().all(|track_def| match track_def {
         TrackSizingFunction::Single(sizing_function) => sizing_function.has_fixed_component(),
         TrackSizingFunction::Repeat(_, tracks) => {
-            tracks.iter().all(|sizing_function| sizing_function.has_fixed_component())
+            repeated_tracks(tracks).all(|sizing_function| sizing_function.has_fixed_component())
         }
+        TrackSizingFunction::LineNames(_) => true,
+        TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry => false,
     });
 
     let template_is_valid =
@@ -67,18 +75,8 @@ pub(crate) fn compute_explicit_grid_size_in_axis(style: &Style, axis: AbsoluteAx
         return non_auto_repeating_track_count;
     }
 
-    let repetition_definition = template
-        .iter()
-        .find_map(|def| {
-            use GridTrackRepetition::{AutoFill, AutoFit, Count};
-            match def {
-                TrackSizingFunction::Single(_) => None,
-                TrackSizingFunction::Repeat(Count(_), _) => None,
-                TrackSizingFunction::Repeat(AutoFit | AutoFill, tracks) => Some(tracks),
-            }
-        })
-        .unwrap();
-    let repetition_track_count = repetition_definition.len() as u16;
+    let repetition_definition = auto_repeated_tracks(template).unwrap();
+    let repetition_track_count = repeated_tracks(repetition_definition).count() as u16;
 
     // Otherwise, run logic to resolve the auto-repeated track count:
     //
@@ -88,15 +86,18 @@ pub(crate) fn compute_explicit_grid_size_in_axis(style: &Style, axis: AbsoluteAx
     // Otherwise, if the grid container has a definite min size in the relevant axis:
     //   - then the number of repetitions is the smallest possible positive integer that fulfills that minimum requirement
     // Otherwise, the specified track list repeats only once.
-    let style_size = style.size.get_abs(axis).into_option();
-    let style_min_size = style.min_size.get_abs(axis).into_option();
-    let style_max_size = style.max_size.get_abs(axis).into_option();
+    let style_size = style.size.get_abs(axis).maybe_resolve(None);
+    let style_min_size = style.min_size.get_abs(axis).maybe_resolve(None);
+    let style_max_size = style.max_size.get_abs(axis).maybe_resolve(None);
 
     let outer_container_size = style_size.maybe_min(style_max_size).or(style_max_size).or(style_min_size);
-    let inner_container_size = outer_container_size.map(|size| {
-        let padding_sum = style.padding.resolve_or_zero(outer_container_size).grid_axis_sum(axis);
-        let border_sum = style.border.resolve_or_zero(outer_container_size).grid_axis_sum(axis);
-        size - padding_sum - border_sum
+    let inner_container_size = outer_container_size.map(|size| match style.box_sizing {
+        BoxSizing::BorderBox => {
+            let padding_sum = style.padding.resolve_or_zero(outer_container_size).grid_axis_sum(axis);
+            let border_sum = style.border.resolve_or_zero(outer_container_size).grid_axis_sum(axis);
+            size - padding_sum - border_sum
+        }
+        BoxSizing::ContentBox => size,
     });
     let size_is_maximum = style_size.is_some() || style_max_size.is_some();
 
@@ -122,22 +123,23 @@ pub(crate) fn compute_explicit_grid_size_in_axis(style: &Style, axis: AbsoluteAx
                         TrackSizingFunction::Single(sizing_function) => {
                             track_definite_value(sizing_function, parent_size)
                         }
-                        TrackSizingFunction::Repeat(Count(count), repeated_tracks) => {
-                            let sum = repeated_tracks
-                                .iter()
+                        TrackSizingFunction::Repeat(Count(count), tracks) => {
+                            let sum = repeated_tracks(tracks)
                                 .map(|sizing_function| track_definite_value(sizing_function, parent_size))
                                 .sum::<f32>();
                             sum * (*count as f32)
                         }
-                        TrackSizingFunction::Repeat(AutoFit | AutoFill, _) => 0.0,
+                        TrackSizingFunction::Repeat(AutoFit | AutoFill, _)
+                        | TrackSizingFunction::LineNames(_)
+                        | TrackSizingFunction::Subgrid
+                        | TrackSizingFunction::Masonry => 0.0,
                     }
                 })
                 .sum();
             let gap_size = style.gap.get_abs(axis).resolve_or_zero(Some(inner_container_size));
 
             // Compute the amount of space that a single repetition of the repeated track list takes
-            let per_repetition_track_used_space: f32 = repetition_definition
-                .iter()
+            let per_repetition_track_used_space: f32 = repeated_tracks(repetition_definition)
                 .map(|sizing_function| track_definite_value(sizing_function, parent_size))
                 .sum::<f32>();
 
@@ -152,7 +154,7 @@ pub(crate) fn compute_explicit_grid_size_in_axis(style: &Style, axis: AbsoluteAx
             if first_repetition_and_non_repeating_tracks_used_space > inner_container_size {
                 1u16
             } else {
-                let per_repetition_gap_used_space = (repetition_definition.len() as f32) * gap_size;
+                let per_repetition_gap_used_space = (repetition_track_count as f32) * gap_size;
                 let per_repetition_used_space = per_repetition_track_used_space + per_repetition_gap_used_space;
                 let num_repetition_that_fit = (inner_container_size
                     - first_repetition_and_non_repeating_tracks_used_space)
@@ -176,13 +178,97 @@ pub(crate) fn compute_explicit_grid_size_in_axis(style: &Style, axis: AbsoluteAx
     non_auto_repeating_track_count + (repetition_track_count * num_repetitions)
 }
 
+/// The track sizing functions of the tracks in the track list of a repetition, skipping its line names
+pub(super) fn repeated_tracks(
+    tracks: &[TrackSizingFunction],
+) -> impl Iterator<Item = &NonRepeatedTrackSizingFunction> + Clone {
+    tracks.iter().filter_map(|track_def| match track_def {
+        TrackSizingFunction::Single(sizing_function) => Some(sizing_function),
+        _ => None,
+    })
+}
+
+/// The track list of the auto-repetition in a track template, if it has one
+fn auto_repeated_tracks(template: &[TrackSizingFunction]) -> Option<&GridTrackVec<TrackSizingFunction>> {
+    template.iter().find_map(|track_def| match track_def {
+        TrackSizingFunction::Repeat(GridTrackRepetition::AutoFill | GridTrackRepetition::AutoFit, tracks) => {
+            Some(tracks)
+        }
+        _ => None,
+    })
+}
+
+/// The number of tracks generated by the single track definitions and the repetitions with a fixed repetition count of
+/// a track template
+fn compute_non_auto_repeating_track_count(template: &[TrackSizingFunction]) -> u16 {
+    template
+        .iter()
+        .map(|track_def| match track_def {
+            TrackSizingFunction::Single(_) => 1,
+            TrackSizingFunction::Repeat(GridTrackRepetition::Count(count), tracks) => {
+                count * repeated_tracks(tracks).count() as u16
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+/// The number of times that the auto-repeated tracks of a valid track template are repeated, given the number of tracks
+/// that the template generates. Zero if the template has no auto-repetition.
+pub(super) fn compute_auto_repetition_count(template: &[TrackSizingFunction], template_track_count: u16) -> u16 {
+    match auto_repeated_tracks(template) {
+        Some(tracks) => {
+            let auto_repeated_track_count = template_track_count - compute_non_auto_repeating_track_count(template);
+            auto_repeated_track_count / repeated_tracks(tracks).count() as u16
+        }
+        None => 0,
+    }
+}
+
+/// Compute the number of explicit rows or columns required to contain all of the named areas in grid-template-areas
+pub(crate) fn compute_template_areas_extent_in_axis(style: &Style, axis: AbsoluteAxis) -> u16 {
+    style
+        .grid_template_areas
+        .iter()
+        .map(|area| match axis {
+            AbsoluteAxis::Horizontal => area.column_end,
+            AbsoluteAxis::Vertical => area.row_end,
+        })
+        .map(|end_line| OriginZeroLine::from_area_line(end_line).0 as u16)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Compute the number of tracks generated by the track template and the total number of explicit tracks in the specified axis.
+///
+/// These differ when the explicit grid is expanded to contain all of the named areas in grid-template-areas. In axes in which
+/// a subgrid inherits the tracks of its parent grid, its explicit grid consists of exactly the inherited tracks.
+pub(super) fn compute_explicit_track_counts(
+    style: &Style,
+    axis: AbsoluteAxis,
+    inherited_track_count: Option<u16>,
+) -> (u16, u16) {
+    match inherited_track_count {
+        Some(count) => (count, count),
+        None => {
+            let template_track_count = compute_explicit_grid_size_in_axis(style, axis);
+            (template_track_count, template_track_count.max(compute_template_areas_extent_in_axis(style, axis)))
+        }
+    }
+}
+
 /// Resolve the track sizing functions of explicit tracks, automatically created tracks, and gutters
 /// given a set of track counts and all of the relevant styles
+///
+/// `template_track_count` is the number of explicit tracks generated by `track_template`. Any further explicit tracks
+/// (which are generated by grid-template-areas) are sized using `auto_tracks`.
+#[allow(clippy::too_many_arguments)]
 pub(super) fn initialize_grid_tracks(
     tracks: &mut Vec<GridTrack>,
     counts: TrackCounts,
+    template_track_count: u16,
     track_template: &GridTrackVec<TrackSizingFunction>,
-    auto_tracks: &Vec<NonRepeatedTrackSizingFunction>,
+    auto_tracks: &[NonRepeatedTrackSizingFunction],
     gap: LengthPercentage,
     track_has_items: impl Fn(usize) -> bool,
 ) {
@@ -209,7 +295,7 @@ pub(super) fn initialize_grid_tracks(
     // Create explicit tracks
     // An explicit check against the count (rather than just relying on track_template being empty) is required here
     // because a count of zero can result from the track_template being invalid, in which case it should be ignored.
-    if counts.explicit > 0 {
+    if template_track_count > 0 {
         track_template.iter().for_each(|track_sizing_function| {
             use GridTrackRepetition::{AutoFill, AutoFit, Count};
             match track_sizing_function {
@@ -221,8 +307,9 @@ pub(super) fn initialize_grid_tracks(
                     tracks.push(GridTrack::gutter(gap));
                     current_track_index += 1;
                 }
-                TrackSizingFunction::Repeat(Count(count), repeated_tracks) => {
-                    let track_iter = repeated_tracks.iter().cycle().take(repeated_tracks.len() * *count as usize);
+                TrackSizingFunction::Repeat(Count(count), track_list) => {
+                    let track_count = repeated_tracks(track_list).count();
+                    let track_iter = repeated_tracks(track_list).cycle().take(track_count * *count as usize);
                     track_iter.for_each(|sizing_function| {
                         tracks.push(GridTrack::new(
                             sizing_function.min_sizing_function(),
@@ -232,9 +319,10 @@ pub(super) fn initialize_grid_tracks(
                         current_track_index += 1;
                     });
                 }
-                TrackSizingFunction::Repeat(repetition_kind @ (AutoFit | AutoFill), repeated_tracks) => {
-                    let auto_repeated_track_count = (counts.explicit - (track_template.len() as u16 - 1)) as usize;
-                    let iter = repeated_tracks.iter().copied().cycle();
+                TrackSizingFunction::Repeat(repetition_kind @ (AutoFit | AutoFill), track_list) => {
+                    let repetition_count = compute_auto_repetition_count(track_template, template_track_count);
+                    let auto_repeated_track_count = repeated_tracks(track_list).count() * repetition_count as usize;
+                    let iter = repeated_tracks(track_list).cycle();
                     for track_def in iter.take(auto_repeated_track_count) {
                         let mut track =
                             GridTrack::new(track_def.min_sizing_function(), track_def.max_sizing_function());
@@ -252,18 +340,46 @@ pub(super) fn initialize_grid_tracks(
                         current_track_index += 1;
                     }
                 }
+                // Line names don't generate tracks, and templates containing `subgrid` or `masonry` always have a
+                // template track count of zero
+                TrackSizingFunction::LineNames(_) | TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry => {}
             }
         });
     }
 
-    // Create positive implicit tracks
+    // Create explicit tracks not covered by the template (generated by grid-template-areas) and positive implicit tracks.
+    // Both are sized by grid-auto-rows/grid-auto-columns, so they share a single iteration of the auto track pattern.
+    let auto_sized_track_count = (counts.explicit - template_track_count) + counts.positive_implicit;
     if auto_tracks.is_empty() {
         let iter = core::iter::repeat(NonRepeatedTrackSizingFunction::AUTO);
-        create_implicit_tracks(tracks, counts.positive_implicit, iter, gap)
+        create_implicit_tracks(tracks, auto_sized_track_count, iter, gap)
     } else {
         let iter = auto_tracks.iter().copied().cycle();
-        create_implicit_tracks(tracks, counts.positive_implicit, iter, gap)
+        create_implicit_tracks(tracks, auto_sized_track_count, iter, gap)
+    }
+
+    // Mark first and last grid lines as collapsed
+    tracks.first_mut().unwrap().collapse();
+    tracks.last_mut().unwrap().collapse();
+}
+
+/// Initialise the tracks and gutters of a subgrid in an axis in which it inherits the tracks of its parent grid.
+///
+/// `track_sizes` holds the sizes of the inherited tracks interleaved with the sizes of the gutters between them. These are
+/// used as fixed sizes so that the subgrid's tracks line up exactly with those of its parent.
+pub(super) fn initialize_subgrid_tracks(tracks: &mut Vec<GridTrack>, track_sizes: &[f32]) {
+    tracks.clear();
+    tracks.reserve(track_sizes.len() + 2);
+    tracks.push(GridTrack::gutter(LengthPercentage::Length(0.0)));
+    for (index, size) in track_sizes.iter().enumerate() {
+        let size = LengthPercentage::Length(*size);
+        if index % 2 == 0 {
+            tracks.push(GridTrack::new(MinTrackSizingFunction::Fixed(size), MaxTrackSizingFunction::Fixed(size)));
+        } else {
+            tracks.push(GridTrack::gutter(size));
+        }
     }
+    tracks.push(GridTrack::gutter(LengthPercentage::Length(0.0)));
 
     // Mark first and last grid lines as collapsed
     tracks.first_mut().unwrap().collapse();
@@ -287,10 +403,13 @@ fn create_implicit_tracks(
 #[cfg(test)]
 mod test {
     use super::compute_explicit_grid_size_in_axis;
+    use super::compute_template_areas_extent_in_axis;
     use super::initialize_grid_tracks;
+    use super::initialize_subgrid_tracks;
     use crate::compute::grid::types::GridTrackKind;
     use crate::compute::grid::types::TrackCounts;
     use crate::compute::grid::util::*;
+    use crate::compute::grid::OriginZeroLine;
     use crate::geometry::AbsoluteAxis;
     use crate::prelude::*;
 
@@ -450,6 +569,99 @@ mod test {
         assert_eq!(height, 4); // 20px vertical padding
     }
 
+    #[test]
+    fn explicit_grid_sizing_masonry() {
+        let grid_style = Style { grid_template_rows: masonry(), ..(600.0, 600.0, 2, 2).into_grid() };
+        let width = compute_explicit_grid_size_in_axis(&grid_style, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&grid_style, AbsoluteAxis::Vertical);
+        assert_eq!(width, 2);
+        assert_eq!(height, 0);
+    }
+
+    #[test]
+    fn explicit_grid_sizing_masonry_columns() {
+        let grid_style = Style { grid_template_columns: masonry(), ..(600.0, 600.0, 2, 2).into_grid() };
+        let width = compute_explicit_grid_size_in_axis(&grid_style, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&grid_style, AbsoluteAxis::Vertical);
+        assert_eq!(width, 0);
+        assert_eq!(height, 2);
+    }
+
+    #[test]
+    fn explicit_grid_sizing_masonry_with_other_tracks() {
+        let mut grid_style = (600.0, 600.0, 2, 2).into_grid();
+        grid_style.grid_template_rows.push(TrackSizingFunction::Masonry);
+        assert!(!grid_style.is_masonry());
+        let height = compute_explicit_grid_size_in_axis(&grid_style, AbsoluteAxis::Vertical);
+        assert_eq!(height, 0);
+    }
+
+    #[test]
+    fn explicit_grid_sizing_with_template_areas() {
+        let grid_style = Style {
+            grid_template_areas: template_areas(&["a a .", "b c c", "b . ."]),
+            ..(600.0, 600.0, 2, 2).into_grid()
+        };
+        let width = compute_template_areas_extent_in_axis(&grid_style, AbsoluteAxis::Horizontal);
+        let height = compute_template_areas_extent_in_axis(&grid_style, AbsoluteAxis::Vertical);
+        assert_eq!(width, 3);
+        assert_eq!(height, 3);
+    }
+
+    #[test]
+    fn explicit_grid_sizing_with_out_of_range_template_areas() {
+        let area = |name: &str, start: u16, end: u16| GridTemplateArea {
+            name: name.into(),
+            row_start: start,
+            row_end: end,
+            column_start: start,
+            column_end: end,
+        };
+        let grid_style = Style { grid_template_areas: vec![area("a", 0, 0)], ..Default::default() };
+        assert_eq!(compute_template_areas_extent_in_axis(&grid_style, AbsoluteAxis::Horizontal), 0);
+        assert_eq!(compute_template_areas_extent_in_axis(&grid_style, AbsoluteAxis::Vertical), 0);
+
+        let grid_style = Style { grid_template_areas: vec![area("a", u16::MAX, u16::MAX)], ..Default::default() };
+        let max_distance = OriginZeroLine::MAX_DISTANCE as u16;
+        assert_eq!(compute_template_areas_extent_in_axis(&grid_style, AbsoluteAxis::Horizontal), max_distance);
+        assert_eq!(compute_template_areas_extent_in_axis(&grid_style, AbsoluteAxis::Vertical), max_distance);
+    }
+
+    #[test]
+    fn explicit_grid_sizing_subgrid() {
+        let subgrid_style = Style {
+            display: Display::Grid,
+            grid_template_columns: subgrid(),
+            grid_template_rows: vec![subgrid()[0].clone(), length(20.0)],
+            ..Default::default()
+        };
+        let width = compute_explicit_grid_size_in_axis(&subgrid_style, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&subgrid_style, AbsoluteAxis::Vertical);
+        assert_eq!(width, 0);
+        assert_eq!(height, 0); // Invalid template
+    }
+
+    #[test]
+    fn test_initialize_subgrid_tracks() {
+        let mut tracks = Vec::new();
+        initialize_subgrid_tracks(&mut tracks, &[10.0, 5.0, 20.0]);
+
+        let sizes: Vec<_> = tracks.iter().map(|track| (track.kind, track.min_track_sizing_function)).collect();
+        let fixed = |size| MinTrackSizingFunction::Fixed(LengthPercentage::Length(size));
+        assert_eq!(
+            sizes,
+            vec![
+                (GridTrackKind::Gutter, fixed(0.0)),
+                (GridTrackKind::Track, fixed(10.0)),
+                (GridTrackKind::Gutter, fixed(5.0)),
+                (GridTrackKind::Track, fixed(20.0)),
+                (GridTrackKind::Gutter, fixed(0.0)),
+            ]
+        );
+        assert!(tracks.first().unwrap().is_collapsed);
+        assert!(tracks.last().unwrap().is_collapsed);
+    }
+
     #[test]
     fn test_initialize_grid_tracks() {
         let px0 = LengthPercentage::Length(0.0);
@@ -465,7 +677,15 @@ mod test {
 
         // Call function
         let mut tracks = Vec::new();
-        initialize_grid_tracks(&mut tracks, track_counts, &track_template, &auto_tracks, gap, |_| false);
+        initialize_grid_tracks(
+            &mut tracks,
+            track_counts,
+            track_counts.explicit,
+            &track_template,
+            &auto_tracks,
+            gap,
+            |_| false,
+        );
 
         // Assertions
         let expected = vec![
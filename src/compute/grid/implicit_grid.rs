@@ -1,9 +1,10 @@
 //! This module is not required for spec compliance, but is used as a performance optimisation
 //! to reduce the number of allocations required when creating a grid.
-use crate::geometry::Line;
-use crate::style::{GenericGridPlacement, GridPlacement, Style};
+use crate::geometry::{AbsoluteAxis, Line};
+use crate::style::{GenericGridPlacement, OriginZeroGridPlacement, Style};
 use core::cmp::{max, min};
 
+use super::named_lines::NamedLineResolver;
 use super::types::TrackCounts;
 use super::OriginZeroLine;
 
@@ -16,14 +17,16 @@ use super::OriginZeroLine;
 ///
 /// Note that this function internally mixes use of grid track numbers and grid line numbers
 pub(crate) fn compute_grid_size_estimate<'a>(
-    explicit_col_count: u16,
-    explicit_row_count: u16,
+    line_resolver: &NamedLineResolver,
     child_styles_iter: impl Iterator<Item = &'a Style>,
 ) -> (TrackCounts, TrackCounts) {
+    let explicit_col_count = line_resolver.explicit_track_count(AbsoluteAxis::Horizontal);
+    let explicit_row_count = line_resolver.explicit_track_count(AbsoluteAxis::Vertical);
+
     // Iterate over children, producing an estimate of the min and max grid lines (in origin-zero coordinates where)
     // along with the span of each item
     let (col_min, col_max, col_max_span, row_min, row_max, row_max_span) =
-        get_known_child_positions(child_styles_iter, explicit_col_count, explicit_row_count);
+        get_known_child_positions(child_styles_iter, line_resolver);
 
     // Compute *track* count estimates for each axis from:
     //   - The explicit track counts
@@ -62,18 +65,19 @@ pub(crate) fn compute_grid_size_estimate<'a>(
 /// The span is measured in tracks spanned
 fn get_known_child_positions<'a>(
     children_iter: impl Iterator<Item = &'a Style>,
-    explicit_col_count: u16,
-    explicit_row_count: u16,
+    line_resolver: &NamedLineResolver,
 ) -> (OriginZeroLine, OriginZeroLine, u16, OriginZeroLine, OriginZeroLine, u16) {
     let (mut col_min, mut col_max, mut col_max_span) = (OriginZeroLine(0), OriginZeroLine(0), 0);
     let (mut row_min, mut row_max, mut row_max_span) = (OriginZeroLine(0), OriginZeroLine(0), 0);
     children_iter.for_each(|child_style: &Style| {
         // Note: that the children reference the lines in between (and around) the tracks not tracks themselves,
         // and thus we must subtract 1 to get an accurate estimate of the number of tracks
-        let (child_col_min, child_col_max, child_col_span) =
-            child_min_line_max_line_span(child_style.grid_column, explicit_col_count);
-        let (child_row_min, child_row_max, child_row_span) =
-            child_min_line_max_line_span(child_style.grid_row, explicit_row_count);
+        let (child_col_min, child_col_max, child_col_span) = child_min_line_max_line_span(
+            line_resolver.resolve_placement(&child_style.grid_column, AbsoluteAxis::Horizontal),
+        );
+        let (child_row_min, child_row_max, child_row_span) = child_min_line_max_line_span(
+            line_resolver.resolve_placement(&child_style.grid_row, AbsoluteAxis::Vertical),
+        );
         col_min = min(col_min, child_col_min);
         col_max = max(col_max, child_col_max);
         col_max_span = max(col_max_span, child_col_span);
@@ -88,12 +92,9 @@ fn get_known_child_positions<'a>(
 /// Helper function for `compute_grid_size_estimate`
 /// Produces a conservative estimate of the greatest and smallest grid lines used by a single grid item
 ///
-/// Values are returned in origin-zero coordinates
+/// The placement must already have been resolved into origin-zero coordinates, and values are returned in origin-zero coordinates
 #[inline]
-fn child_min_line_max_line_span(
-    line: Line<GridPlacement>,
-    explicit_track_count: u16,
-) -> (OriginZeroLine, OriginZeroLine, u16) {
+fn child_min_line_max_line_span(oz_line: Line<OriginZeroGridPlacement>) -> (OriginZeroLine, OriginZeroLine, u16) {
     use GenericGridPlacement::*;
 
     // 8.3.1. Grid Placement Conflict Handling
@@ -101,9 +102,7 @@ fn child_min_line_max_line_span(
     // B. If the start line is equal to the end line, remove the end line.
     // C. If the placement contains two spans, remove the one contributed by the end grid-placement property.
     // D. If the placement contains only a span for a named line, replace it with a span of 1.
-
-    // Convert line into origin-zero coordinates before attempting to analyze
-    let oz_line = line.into_origin_zero(explicit_track_count);
+    //    (named spans have already been resolved to plain spans by the NamedLineResolver)
 
     let min = match (oz_line.start, oz_line.end) {
         // Both tracks specified
@@ -121,7 +120,7 @@ fn child_min_line_max_line_span(
         (Line(track), Span(_)) => track,
 
         // End track specified
-        (Auto, Line(track)) => track,
+        (Auto, Line(track)) => track - 1,
         (Span(span), Line(track)) => track - span,
 
         // Only spans or autos
@@ -155,8 +154,8 @@ fn child_min_line_max_line_span(
 
     // Calculate span only for indefinitely placed items as we don't need for other items (whose required space will
     // be taken into account by min and max)
-    let span = match (line.start, line.end) {
-        (Auto | Span(_), Auto | Span(_)) => line.indefinite_span(),
+    let span = match (oz_line.start, oz_line.end) {
+        (Auto | Span(_), Auto | Span(_)) => oz_line.indefinite_span(),
         _ => 1,
     };
 
@@ -169,12 +168,20 @@ mod tests {
     mod test_child_min_max_line {
         use super::super::child_min_line_max_line_span;
         use super::super::OriginZeroLine;
-        use crate::geometry::Line;
+        use crate::compute::grid::named_lines::NamedLineResolver;
+        use crate::geometry::{AbsoluteAxis, Line};
+        use crate::style::{GridPlacement, OriginZeroGridPlacement, Style};
         use crate::style_helpers::*;
 
+        fn resolve(line: Line<GridPlacement>, explicit_track_count: u16) -> Line<OriginZeroGridPlacement> {
+            NamedLineResolver::new(&Style::DEFAULT, explicit_track_count, 0)
+                .resolve_placement(&line, AbsoluteAxis::Horizontal)
+        }
+
         #[test]
         fn child_min_max_line_auto() {
-            let (min_col, max_col, span) = child_min_line_max_line_span(Line { start: line(5), end: span(6) }, 6);
+            let (min_col, max_col, span) =
+                child_min_line_max_line_span(resolve(Line { start: line(5), end: span(6) }, 6));
             assert_eq!(min_col, OriginZeroLine(4));
             assert_eq!(max_col, OriginZeroLine(10));
             assert_eq!(span, 1);
@@ -182,16 +189,28 @@ mod tests {
 
         #[test]
         fn child_min_max_line_negative_track() {
-            let (min_col, max_col, span) = child_min_line_max_line_span(Line { start: line(-5), end: span(3) }, 6);
+            let (min_col, max_col, span) =
+                child_min_line_max_line_span(resolve(Line { start: line(-5), end: span(3) }, 6));
             assert_eq!(min_col, OriginZeroLine(2));
             assert_eq!(max_col, OriginZeroLine(5));
             assert_eq!(span, 1);
         }
+
+        #[test]
+        fn child_min_max_line_auto_start() {
+            let (min_col, max_col, span) =
+                child_min_line_max_line_span(resolve(Line { start: auto(), end: line(1) }, 6));
+            assert_eq!(min_col, OriginZeroLine(-1));
+            assert_eq!(max_col, OriginZeroLine(0));
+            assert_eq!(span, 1);
+        }
     }
 
     mod test_intial_grid_sizing {
         use super::super::compute_grid_size_estimate;
+        use crate::compute::grid::named_lines::NamedLineResolver;
         use crate::compute::grid::util::test_helpers::*;
+        use crate::style::Style;
         use crate::style_helpers::*;
 
         #[test]
@@ -202,8 +221,9 @@ mod tests {
                 (line(1), span(2), line(2), auto()).into_grid_child(),
                 (line(-4), auto(), line(-2), auto()).into_grid_child(),
             ];
-            let (inline, block) =
-                compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles.iter());
+            let grid_style = Style::default();
+            let line_resolver = NamedLineResolver::new(&grid_style, explicit_col_count, explicit_row_count);
+            let (inline, block) = compute_grid_size_estimate(&line_resolver, child_styles.iter());
             assert_eq!(inline.negative_implicit, 0);
             assert_eq!(inline.explicit, explicit_col_count);
             assert_eq!(inline.positive_implicit, 0);
@@ -220,8 +240,9 @@ mod tests {
                 (line(-6), span(2), line(-8), auto()).into_grid_child(),
                 (line(4), auto(), line(3), auto()).into_grid_child(),
             ];
-            let (inline, block) =
-                compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles.iter());
+            let grid_style = Style::default();
+            let line_resolver = NamedLineResolver::new(&grid_style, explicit_col_count, explicit_row_count);
+            let (inline, block) = compute_grid_size_estimate(&line_resolver, child_styles.iter());
             assert_eq!(inline.negative_implicit, 1);
             assert_eq!(inline.explicit, explicit_col_count);
             assert_eq!(inline.positive_implicit, 0);
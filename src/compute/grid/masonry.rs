@@ -0,0 +1,141 @@
+//! Masonry layout of grids whose rows are `masonry` (<https://drafts.csswg.org/css-grid-3/#masonry-layout>)
+//!
+//! The columns of a masonry grid are sized by the regular track sizing algorithm, but its items are not placed into
+//! rows. Instead, each item is placed in the column(s) whose content currently ends earliest and is packed directly
+//! below the items that were placed there before it. The whole masonry layout is then treated as a single row of the
+//! grid, so that the rest of the grid algorithm (container sizing, content alignment and item alignment) applies as
+//! usual.
+use super::named_lines::NamedLineResolver;
+use super::placement::record_grid_placement;
+use super::types::{CellOccupancyMatrix, CellOccupancyState, GridItem, GridTrack, TrackCounts};
+use super::OriginZeroLine;
+use crate::geometry::{AbsoluteAxis, AbstractAxis, Line, Size};
+use crate::style::{AlignItems, Style};
+use crate::tree::{LayoutTree, NodeId};
+use crate::util::sys::{f32_max, Vec};
+
+/// Place the items of a masonry grid for the purpose of sizing its columns. All items are placed in the grid's single
+/// row. Items with a definite column placement are placed in those columns, while the columns of the other items
+/// depend on the heights of the items before them, and so aren't known until the columns have been sized. These are
+/// considered to be placed in every column they could end up in, and so contribute to the sizing of all of them.
+///
+/// Subgrids aren't supported in masonry grids: subgrid items are laid out as if their template in the subgridded axis
+/// was empty.
+pub(super) fn place_masonry_items<'a, ChildIter>(
+    cell_occupancy_matrix: &mut CellOccupancyMatrix,
+    items: &mut Vec<GridItem>,
+    children_iter: impl Fn() -> ChildIter,
+    line_resolver: &NamedLineResolver,
+    align_items: AlignItems,
+    justify_items: AlignItems,
+) where
+    ChildIter: Iterator<Item = (usize, NodeId, &'a Style)>,
+{
+    let row = Line { start: OriginZeroLine(0), end: OriginZeroLine(1) };
+    let mut record = |cell_occupancy_matrix: &mut CellOccupancyMatrix, index, node, style, column, state| {
+        record_grid_placement(
+            cell_occupancy_matrix,
+            items,
+            node,
+            index,
+            style,
+            align_items,
+            justify_items,
+            AbsoluteAxis::Horizontal,
+            column,
+            row,
+            state,
+        );
+    };
+
+    // 1. Place children with definite column positions. These may add implicit columns to the grid.
+    for (index, node, style) in children_iter() {
+        let placement = line_resolver.resolve_placement(&style.grid_column, AbsoluteAxis::Horizontal);
+        if placement.is_definite() {
+            let column = placement.resolve_definite_grid_lines();
+            record(cell_occupancy_matrix, index, node, style, column, CellOccupancyState::DefinitelyPlaced);
+        }
+    }
+
+    // 2. Place the remaining children at every position in which they fit within the grid's columns
+    for (index, node, style) in children_iter() {
+        let placement = line_resolver.resolve_placement(&style.grid_column, AbsoluteAxis::Horizontal);
+        if placement.is_definite() {
+            continue;
+        }
+        let track_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal);
+        let span = placement.indefinite_span() as i16;
+        let first_start = track_counts.implicit_start_line();
+        let last_start = OriginZeroLine(i16::max(first_start.0, track_counts.implicit_end_line().0 - span));
+        let mut start = first_start;
+        while start <= last_start {
+            let column = placement.resolve_indefinite_grid_tracks(start);
+            record(cell_occupancy_matrix, index, node, style, column, CellOccupancyState::AutoPlaced);
+            start += 1;
+        }
+    }
+
+    for item in items.iter_mut() {
+        item.is_subgrid = Size { width: false, height: false };
+    }
+}
+
+/// Lay out the items of a masonry grid in its sized columns, and return the height of the resulting masonry layout.
+///
+/// The copies of auto-placed items that were created by [`place_masonry_items`] are removed, and each item is given
+/// its final column and its position within the grid's single row (see [`GridItem::masonry_position`]). Items are
+/// placed in order: each is placed in the columns whose content ends earliest (preferring the first such columns),
+/// or in its definite columns if it has them, and is separated from the items above it by the row gap.
+pub(super) fn perform_masonry_layout(
+    tree: &mut impl LayoutTree,
+    items: &mut Vec<GridItem>,
+    columns: &[GridTrack],
+    column_counts: TrackCounts,
+    line_resolver: &NamedLineResolver,
+    row_gap: f32,
+    inner_node_size: Size<Option<f32>>,
+) -> f32 {
+    items.sort_by_key(|item| item.source_order);
+    items.dedup_by_key(|item| item.node);
+
+    // The position at which the content of each column currently ends
+    let mut column_ends: Vec<f32> = (0..column_counts.len()).map(|_| 0.0).collect();
+    let span_end = |column_ends: &[f32], start: usize, span: usize| {
+        column_ends[start..start + span].iter().copied().fold(0.0, f32_max)
+    };
+
+    for item in items.iter_mut() {
+        let span = item.span(AbstractAxis::Inline) as usize;
+        let placement = line_resolver.resolve_placement(&tree.style(item.node).grid_column, AbsoluteAxis::Horizontal);
+        if !placement.is_definite() {
+            let start = (0..=column_ends.len().saturating_sub(span))
+                .min_by(|&a, &b| span_end(&column_ends, a, span).total_cmp(&span_end(&column_ends, b, span)))
+                .unwrap_or(0);
+            let start_line = column_counts.track_to_prev_oz_line(start as u16);
+            item.column = Line { start: start_line, end: start_line + span as u16 };
+            item.column_indexes = item.column.map(|line| line.into_track_vec_index(column_counts) as u16);
+        }
+
+        // Measure the item at the width of its columns
+        let first_column = item.column_indexes.start as usize / 2;
+        let width = columns[item.track_range_excluding_lines(AbstractAxis::Inline)]
+            .iter()
+            .map(|track| track.base_size)
+            .sum::<f32>();
+        let height = item.min_content_contribution(
+            AbstractAxis::Block,
+            tree,
+            Size { width: Some(width), height: None },
+            inner_node_size,
+        ) + item.margins_axis_sums_with_baseline_shims(inner_node_size.width).height;
+
+        let top = span_end(&column_ends, first_column, span);
+        item.masonry_position = Some(Line { start: top, end: top + height });
+        column_ends[first_column..first_column + span].iter_mut().for_each(|end| *end = top + height + row_gap);
+    }
+
+    match items.is_empty() {
+        true => 0.0,
+        false => f32_max(0.0, column_ends.iter().copied().fold(0.0, f32_max) - row_gap),
+    }
+}
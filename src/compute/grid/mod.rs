@@ -1,8 +1,11 @@
 //! This module is a partial implementation of the CSS Grid Level 1 specification
 //! <https://www.w3.org/TR/css-grid-1>
+use crate::compute::common::content_size::compute_content_size;
+use crate::compute::common::contents::{order_modified_layout_children, perform_contents_layout};
+use crate::compute::common::writing_mode::compute_in_vertical_writing_mode;
 use crate::geometry::{AbsoluteAxis, AbstractAxis, InBothAbsAxis};
 use crate::geometry::{Line, Point, Rect, Size};
-use crate::style::{AlignContent, AlignItems, AlignSelf, AvailableSpace, Display, Overflow, Position};
+use crate::style::{AlignContent, AlignItems, AlignSelf, AvailableSpace, Display, Position};
 use crate::style_helpers::*;
 use crate::tree::{Layout, RunMode, SizeBaselinesAndMargins, SizingMode};
 use crate::tree::{LayoutTree, NodeId};
@@ -10,13 +13,16 @@ use crate::util::sys::{f32_max, GridTrackVec, Vec};
 use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 use alignment::{align_and_position_item, align_tracks};
-use explicit_grid::{compute_explicit_grid_size_in_axis, initialize_grid_tracks};
+use explicit_grid::{compute_explicit_track_counts, initialize_grid_tracks, initialize_subgrid_tracks};
 use implicit_grid::compute_grid_size_estimate;
+use masonry::{perform_masonry_layout, place_masonry_items};
+pub(crate) use named_lines::NamedLineResolver;
 use placement::place_grid_items;
+use subgrid::{clamp_to_inherited_tracks, place_subgrid_items, SubgridTree};
 use track_sizing::{
     determine_if_item_crosses_flexible_or_intrinsic_tracks, resolve_item_track_indexes, track_sizing_algorithm,
 };
-use types::{CellOccupancyMatrix, GridTrack};
+use types::{CellOccupancyMatrix, GridTrack, TrackCounts};
 
 pub(crate) use types::{GridCoordinate, GridLine, OriginZeroLine};
 
@@ -28,7 +34,10 @@ use super::LayoutAlgorithm;
 mod alignment;
 mod explicit_grid;
 mod implicit_grid;
+mod masonry;
+mod named_lines;
 mod placement;
+mod subgrid;
 mod track_sizing;
 mod types;
 mod util;
@@ -77,88 +86,197 @@ pub fn compute(
     available_space: Size<AvailableSpace>,
     run_mode: RunMode,
 ) -> SizeBaselinesAndMargins {
-    let get_child_styles_iter = |node| tree.children(node).map(|child_node: NodeId| tree.style(child_node));
+    let inherited_track_sizes = Size { width: None, height: None };
+    if tree.style(node).writing_mode.is_vertical() {
+        return compute_in_vertical_writing_mode(
+            tree,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            run_mode,
+            |tree, node, known_dimensions, parent_size, available_space, run_mode| {
+                compute_with_inherited_tracks(
+                    tree,
+                    node,
+                    known_dimensions,
+                    parent_size,
+                    available_space,
+                    run_mode,
+                    inherited_track_sizes,
+                )
+            },
+        );
+    }
+    compute_with_inherited_tracks(
+        tree,
+        node,
+        known_dimensions,
+        parent_size,
+        available_space,
+        run_mode,
+        inherited_track_sizes,
+    )
+}
+
+/// Grid layout algorithm for a grid which may be a subgrid.
+///
+/// `inherited_track_sizes` holds the sizes of the tracks (interleaved with the sizes of the gutters between them) that
+/// a subgrid inherits from its parent grid in each axis (width = columns, height = rows). In axes in which tracks are
+/// inherited, the grid uses exactly those tracks rather than the ones defined by its own styles. A subgrid that is laid
+/// out without inherited tracks (for example, because it is the root node) behaves as if it had no template in that axis.
+fn compute_with_inherited_tracks(
+    tree: &mut impl LayoutTree,
+    node: NodeId,
+    known_dimensions: Size<Option<f32>>,
+    parent_size: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+    inherited_track_sizes: Size<Option<&[f32]>>,
+) -> SizeBaselinesAndMargins {
+    // Right-to-left grids are laid out as if they were left-to-right with the horizontal sides of the container and its
+    // items swapped, and the horizontal positions of the items are then mirrored
+    let direction = tree.style(node).direction;
+    let is_rtl = direction.is_rtl();
+
+    // Subgrid items are measured and laid out using the tracks they inherit from this grid
+    let tree = &mut SubgridTree::new(tree, direction);
+
+    // The children of `display: contents` children are laid out as if they were children of this node. Children are
+    // placed (and painted) in order of their `order` style, with ties broken by their position in the tree.
+    let children = order_modified_layout_children(tree, node);
     let style = tree.style(node).clone();
-    let child_styles_iter = get_child_styles_iter(node);
+    let child_styles_iter = children.iter().map(|&child_node| tree.style(child_node));
 
     // 1. Resolve the explicit grid
     // Exactly compute the number of rows and columns in the explicit grid.
-    let explicit_col_count = compute_explicit_grid_size_in_axis(&style, AbsoluteAxis::Horizontal);
-    let explicit_row_count = compute_explicit_grid_size_in_axis(&style, AbsoluteAxis::Vertical);
+    // The explicit grid is sized by the track templates, but is expanded to contain any named template areas.
+    // In subgridded axes, the explicit grid consists of the inherited tracks.
+    let inherited_track_count = inherited_track_sizes.map(|sizes| sizes.map(|sizes| sizes.len().div_ceil(2) as u16));
+    let (template_col_count, explicit_col_count) =
+        compute_explicit_track_counts(&style, AbsoluteAxis::Horizontal, inherited_track_count.width);
+    let (template_row_count, explicit_row_count) =
+        compute_explicit_track_counts(&style, AbsoluteAxis::Vertical, inherited_track_count.height);
+    let line_resolver = NamedLineResolver::new(&style, explicit_col_count, explicit_row_count);
 
     // 2. Implicit Grid: Estimate Track Counts
     // Estimate the number of rows and columns in the implicit grid (= the entire grid)
     // This is necessary as part of placement. Doing it early here is a perf optimisation to reduce allocations.
-    let (est_col_counts, est_row_counts) =
-        compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles_iter);
+    let (est_col_counts, est_row_counts) = compute_grid_size_estimate(&line_resolver, child_styles_iter);
 
     // 2. Grid Item Placement
-    // Match items (children) to a definite grid position (row start/end and column start/end position)
-    let mut items = Vec::with_capacity(tree.child_count(node));
+    // Match items (children) to a definite grid position (row start/end and column start/end position). The items of
+    // a masonry grid are all placed in a single row, and only get their final columns once the columns have been sized.
+    let is_masonry = style.is_masonry() && inherited_track_sizes.height.is_none();
+    let mut items = Vec::with_capacity(children.len());
     let mut cell_occupancy_matrix = CellOccupancyMatrix::with_track_counts(est_col_counts, est_row_counts);
     let in_flow_children_iter = || {
-        tree.children(node)
+        children
+            .iter()
+            .copied()
             .enumerate()
             .map(|(index, child_node)| (index, child_node, tree.style(child_node)))
-            .filter(|(_, _, style)| style.display != Display::None && style.position != Position::Absolute)
+            .filter(|(_, _, style)| style.display != Display::None && !style.position.is_out_of_flow())
     };
-    place_grid_items(
-        &mut cell_occupancy_matrix,
-        &mut items,
-        in_flow_children_iter,
-        style.grid_auto_flow,
-        style.align_items.unwrap_or(AlignItems::Stretch),
-        style.justify_items.unwrap_or(AlignItems::Stretch),
-    );
+    match is_masonry {
+        true => place_masonry_items(
+            &mut cell_occupancy_matrix,
+            &mut items,
+            in_flow_children_iter,
+            &line_resolver,
+            style.align_items.unwrap_or(AlignItems::Stretch),
+            style.justify_items.unwrap_or(AlignItems::Stretch),
+        ),
+        false => place_grid_items(
+            &mut cell_occupancy_matrix,
+            &mut items,
+            in_flow_children_iter,
+            &line_resolver,
+            style.grid_auto_flow,
+            style.align_items.unwrap_or(AlignItems::Stretch),
+            style.justify_items.unwrap_or(AlignItems::Stretch),
+        ),
+    }
 
     // Extract track counts from previous step (auto-placement can expand the number of tracks)
-    let final_col_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal);
-    let final_row_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Vertical);
+    let mut final_col_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal);
+    let mut final_row_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Vertical);
+
+    // There are no implicit tracks in subgridded axes, so items are clamped to the inherited tracks
+    if let Some(count) = inherited_track_count.width {
+        items.iter_mut().for_each(|item| item.column = clamp_to_inherited_tracks(item.column, count));
+        final_col_counts = TrackCounts { negative_implicit: 0, explicit: count, positive_implicit: 0 };
+    }
+    if let Some(count) = inherited_track_count.height {
+        items.iter_mut().for_each(|item| item.row = clamp_to_inherited_tracks(item.row, count));
+        final_row_counts = TrackCounts { negative_implicit: 0, explicit: count, positive_implicit: 0 };
+    }
+
+    if is_masonry {
+        final_row_counts = TrackCounts::from_raw(0, 0, 1);
+    }
+
+    // Add the children of subgrid items, which take part in sizing the tracks they span in subgridded axes
+    place_subgrid_items(tree, &mut items, direction);
 
     // 3. Initialize Tracks
     // Initialize (explicit and implicit) grid tracks (and gutters)
     // This resolves the min and max track sizing functions for all tracks and gutters
     let mut columns = GridTrackVec::new();
     let mut rows = GridTrackVec::new();
-    initialize_grid_tracks(
-        &mut columns,
-        final_col_counts,
-        &style.grid_template_columns,
-        &style.grid_auto_columns,
-        style.gap.width,
-        |column_index| cell_occupancy_matrix.column_is_occupied(column_index),
-    );
-    initialize_grid_tracks(
-        &mut rows,
-        final_row_counts,
-        &style.grid_template_rows,
-        &style.grid_auto_rows,
-        style.gap.height,
-        |row_index| cell_occupancy_matrix.row_is_occupied(row_index),
-    );
+    match inherited_track_sizes.width {
+        Some(sizes) => initialize_subgrid_tracks(&mut columns, sizes),
+        None => initialize_grid_tracks(
+            &mut columns,
+            final_col_counts,
+            template_col_count,
+            &style.grid_template_columns,
+            &style.grid_auto_columns,
+            style.gap.width,
+            |column_index| cell_occupancy_matrix.column_is_occupied(column_index),
+        ),
+    }
+    match inherited_track_sizes.height {
+        Some(sizes) => initialize_subgrid_tracks(&mut rows, sizes),
+        // The single row of a masonry grid is sized by the masonry layout
+        None if is_masonry => {
+            initialize_grid_tracks(&mut rows, final_row_counts, 0, &GridTrackVec::new(), &[], style.gap.height, |_| {
+                true
+            })
+        }
+        None => initialize_grid_tracks(
+            &mut rows,
+            final_row_counts,
+            template_row_count,
+            &style.grid_template_rows,
+            &style.grid_auto_rows,
+            style.gap.height,
+            |row_index| cell_occupancy_matrix.row_is_occupied(row_index),
+        ),
+    }
 
     // 4. Compute "available grid space"
     // https://www.w3.org/TR/css-grid-1/#available-grid-space
-    let padding = style.padding.resolve_or_zero(parent_size.width);
-    let border = style.border.resolve_or_zero(parent_size.width);
+    let padding = style.padding.resolve_or_zero(parent_size.width).swap_horizontal_sides_if(is_rtl);
+    let border = style.border.resolve_or_zero(parent_size.width).swap_horizontal_sides_if(is_rtl);
     let padding_border = padding + border;
     let padding_border_size = padding_border.sum_axes();
     let aspect_ratio = style.aspect_ratio;
-    let min_size = style.min_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
-    let max_size = style.max_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
-    let size = style.size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
-
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = style.overflow.transpose().map(|overflow| match overflow {
-        Overflow::Scroll => style.scrollbar_width,
-        _ => 0.0,
-    });
-    // TODO: make side configurable based on the `direction` property
-    let mut content_box_inset = padding_border;
-    content_box_inset.right += scrollbar_gutter.x;
-    content_box_inset.bottom += scrollbar_gutter.y;
+    let box_sizing_adjustment = style.box_sizing.adjustment(padding_border_size);
+    let min_size = style
+        .min_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let max_size = style
+        .max_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let size =
+        style.size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio).maybe_add(box_sizing_adjustment);
+
+    let content_box_inset = padding_border + style.scrollbar_gutter_inset();
 
     let constrained_available_space = known_dimensions
         .or(size)
@@ -193,6 +311,12 @@ pub fn compute(
     #[cfg(feature = "debug")]
     NODE_LOGGER.labelled_debug_log("inner_node_size", inner_node_size);
 
+    // Intrinsic sizing keywords in the items' size styles are resolved up front by measuring the items, so that the
+    // track sizing algorithm sees the sizes they resolve to
+    for item in items.iter_mut() {
+        item.resolve_intrinsic_sizes(tree, inner_node_size);
+    }
+
     // 5. Track Sizing
 
     // Convert grid placements in origin-zero coordinates to indexes into the GridTrack (rows and columns) vectors
@@ -204,8 +328,11 @@ pub fn compute(
     // Record this as a boolean (per-axis) on each item for later use in the track-sizing algorithm
     determine_if_item_crosses_flexible_or_intrinsic_tracks(&mut items, &columns, &rows);
 
-    // Determine if the grid has any baseline aligned items
-    let has_baseline_aligned_item = items.iter().any(|item| item.align_self == AlignSelf::Baseline);
+    // Determine if the grid has any baseline aligned items in each axis
+    let has_baseline_aligned_item = Size {
+        width: items.iter().any(|item| matches!(item.justify_self, AlignSelf::Baseline | AlignSelf::LastBaseline)),
+        height: items.iter().any(|item| matches!(item.align_self, AlignSelf::Baseline | AlignSelf::LastBaseline)),
+    };
 
     // Run track sizing algorithm for Inline axis
     track_sizing_algorithm(
@@ -220,33 +347,51 @@ pub fn compute(
         &mut rows,
         &mut items,
         |track: &GridTrack, parent_size: Option<f32>| track.max_track_sizing_function.definite_value(parent_size),
-        has_baseline_aligned_item,
+        has_baseline_aligned_item.get(AbstractAxis::Inline),
+        is_rtl,
     );
     let initial_column_sum = columns.iter().map(|track| track.base_size).sum::<f32>();
     inner_node_size.width = inner_node_size.width.or_else(|| initial_column_sum.into());
+    tree.inherit_tracks(&items, AbstractAxis::Inline, &columns);
 
     items.iter_mut().for_each(|item| item.available_space_cache = None);
 
-    // Run track sizing algorithm for Block axis
-    track_sizing_algorithm(
-        tree,
-        AbstractAxis::Block,
-        min_size.get(AbstractAxis::Block),
-        max_size.get(AbstractAxis::Block),
-        style.grid_align_content(AbstractAxis::Inline),
-        available_grid_space,
-        inner_node_size,
-        &mut rows,
-        &mut columns,
-        &mut items,
-        |track: &GridTrack, _| Some(track.base_size),
-        false, // TODO: Support baseline alignment in the vertical axis
-    );
+    // Run track sizing algorithm for Block axis, or lay out the items of a masonry grid in its columns
+    if is_masonry {
+        let row_gap = style.gap.height.resolve_or_zero(inner_node_size.height);
+        rows[1].base_size = perform_masonry_layout(
+            tree,
+            &mut items,
+            &columns,
+            final_col_counts,
+            &line_resolver,
+            row_gap,
+            inner_node_size,
+        );
+    } else {
+        track_sizing_algorithm(
+            tree,
+            AbstractAxis::Block,
+            min_size.get(AbstractAxis::Block),
+            max_size.get(AbstractAxis::Block),
+            style.grid_align_content(AbstractAxis::Inline),
+            available_grid_space,
+            inner_node_size,
+            &mut rows,
+            &mut columns,
+            &mut items,
+            |track: &GridTrack, _| Some(track.base_size),
+            has_baseline_aligned_item.get(AbstractAxis::Block),
+            is_rtl,
+        );
+    }
     let initial_row_sum = rows.iter().map(|track| track.base_size).sum::<f32>();
     inner_node_size.height = inner_node_size.height.or_else(|| initial_row_sum.into());
+    tree.inherit_tracks(&items, AbstractAxis::Block, &rows);
 
     // 6. Compute container size
-    let resolved_style_size = known_dimensions.or(style.size.maybe_resolve(parent_size));
+    let resolved_style_size =
+        known_dimensions.or(style.size.maybe_resolve(parent_size).maybe_add(box_sizing_adjustment));
     let container_border_box = Size {
         width: resolved_style_size
             .get(AbstractAxis::Inline)
@@ -293,16 +438,17 @@ pub fn compute(
     //   - The grid container's width was initially indefinite and there are any columns with percentage track sizing functions
     //   - Any grid item crossing an intrinsically sized track's min content contribution width has changed
     // TODO: Only rerun sizing for tracks that actually require it rather than for all tracks if any need it.
+    // The columns of masonry grids are not re-sized, as their items have already been placed in them.
     let mut rerun_column_sizing;
 
     let has_percentage_column = columns.iter().any(|track| track.uses_percentage());
     let parent_width_indefinite = !available_space.width.is_definite();
-    rerun_column_sizing = parent_width_indefinite && has_percentage_column;
+    rerun_column_sizing = !is_masonry && parent_width_indefinite && has_percentage_column;
 
-    if !rerun_column_sizing {
+    if !rerun_column_sizing && !is_masonry {
         let min_content_contribution_changed = items
             .iter_mut()
-            .filter(|item| item.crosses_intrinsic_column)
+            .filter(|item| item.crosses_intrinsic_column && item.contributes_to_track_sizing(AbstractAxis::Inline))
             .map(|item| {
                 let available_space = item.available_space(
                     AbstractAxis::Inline,
@@ -348,8 +494,10 @@ pub fn compute(
             &mut rows,
             &mut items,
             |track: &GridTrack, _| Some(track.base_size),
-            has_baseline_aligned_item,
+            has_baseline_aligned_item.get(AbstractAxis::Inline),
+            is_rtl,
         );
+        tree.inherit_tracks(&items, AbstractAxis::Inline, &columns);
 
         // Row sizing must be re-run (once) if:
         //   - The grid container's height was initially indefinite and there are any rows with percentage track sizing functions
@@ -364,7 +512,7 @@ pub fn compute(
         if !rerun_row_sizing {
             let min_content_contribution_changed = items
                 .iter_mut()
-                .filter(|item| item.crosses_intrinsic_column)
+                .filter(|item| item.crosses_intrinsic_column && item.contributes_to_track_sizing(AbstractAxis::Block))
                 .map(|item| {
                     let available_space = item.available_space(
                         AbstractAxis::Block,
@@ -410,20 +558,29 @@ pub fn compute(
                 &mut columns,
                 &mut items,
                 |track: &GridTrack, _| Some(track.base_size),
-                false, // TODO: Support baseline alignment in the vertical axis
+                has_baseline_aligned_item.get(AbstractAxis::Block),
+                is_rtl,
             );
+            tree.inherit_tracks(&items, AbstractAxis::Block, &rows);
         }
     }
 
     // 8. Track Alignment
 
+    // Inherited tracks are always placed at the start of a subgrid, as their sizes already account for any free space
+    // in the parent grid
+    let track_alignment = |inherited_sizes: Option<&[f32]>, alignment: Option<AlignContent>| match inherited_sizes {
+        Some(_) => AlignContent::Start,
+        None => alignment.unwrap_or(AlignContent::Stretch),
+    };
+
     // Align columns
     align_tracks(
         container_content_box.get(AbstractAxis::Inline),
         Line { start: padding.left, end: padding.right },
         Line { start: border.left, end: border.right },
         &mut columns,
-        style.justify_content.unwrap_or(AlignContent::Stretch),
+        track_alignment(inherited_track_sizes.width, style.justify_content),
     );
     // Align rows
     align_tracks(
@@ -431,11 +588,16 @@ pub fn compute(
         Line { start: padding.top, end: padding.bottom },
         Line { start: border.top, end: border.bottom },
         &mut rows,
-        style.align_content.unwrap_or(AlignContent::Stretch),
+        track_alignment(inherited_track_sizes.height, style.align_content),
     );
+    tree.inherit_tracks(&items, AbstractAxis::Inline, &columns);
+    tree.inherit_tracks(&items, AbstractAxis::Block, &rows);
 
     // 9. Size, Align, and Position Grid Items
 
+    // The children of subgrid items are laid out by their subgrid
+    items.retain(|item| !item.is_subgrid_child);
+
     // Sort items back into original order to allow them to be matched up with styles
     items.sort_by_key(|item| item.source_order);
 
@@ -443,9 +605,12 @@ pub fn compute(
 
     // Position in-flow children (stored in items vector)
     for (index, item) in items.iter().enumerate() {
+        let row_start = rows[item.row_indexes.start as usize + 1].offset;
         let grid_area = Rect {
-            top: rows[item.row_indexes.start as usize + 1].offset,
-            bottom: rows[item.row_indexes.end as usize].offset,
+            top: item.masonry_position.map_or(row_start, |position| row_start + position.start),
+            bottom: item
+                .masonry_position
+                .map_or(rows[item.row_indexes.end as usize].offset, |position| row_start + position.end),
             left: columns[item.column_indexes.start as usize + 1].offset,
             right: columns[item.column_indexes.end as usize].offset,
         };
@@ -456,13 +621,16 @@ pub fn compute(
             grid_area,
             container_alignment_styles,
             item.baseline_shim,
+            is_rtl,
         );
+        if is_rtl {
+            mirror_horizontal_position(tree, item.node, container_border_box.width);
+        }
     }
 
     // Position hidden and absolutely positioned children
     let mut order = items.len() as u32;
-    (0..tree.child_count(node)).for_each(|index| {
-        let child = tree.child(node, index);
+    children.iter().copied().for_each(|child| {
         let child_style = tree.style(child);
 
         // Position hidden child
@@ -483,22 +651,20 @@ pub fn compute(
         // Position absolutely positioned child
         if child_style.position == Position::Absolute {
             // Convert grid-col-{start/end} into Option's of indexes into the columns vector
-            // The Option is None if the style property is Auto and an unresolvable Span
-            let maybe_col_indexes = child_style
-                .grid_column
-                .into_origin_zero(final_col_counts.explicit)
+            // The Option is None if the style property is Auto, an unresolvable Span, or a line outside of the grid
+            let maybe_col_indexes = line_resolver
+                .resolve_placement(&child_style.grid_column, AbsoluteAxis::Horizontal)
                 .resolve_absolutely_positioned_grid_tracks()
                 .map(|maybe_grid_line| {
-                    maybe_grid_line.map(|line: OriginZeroLine| line.into_track_vec_index(final_col_counts))
+                    maybe_grid_line.and_then(|line: OriginZeroLine| line.try_into_track_vec_index(final_col_counts))
                 });
             // Convert grid-row-{start/end} into Option's of indexes into the row vector
-            // The Option is None if the style property is Auto and an unresolvable Span
-            let maybe_row_indexes = child_style
-                .grid_row
-                .into_origin_zero(final_row_counts.explicit)
+            // The Option is None if the style property is Auto, an unresolvable Span, or a line outside of the grid
+            let maybe_row_indexes = line_resolver
+                .resolve_placement(&child_style.grid_row, AbsoluteAxis::Vertical)
                 .resolve_absolutely_positioned_grid_tracks()
                 .map(|maybe_grid_line| {
-                    maybe_grid_line.map(|line: OriginZeroLine| line.into_track_vec_index(final_row_counts))
+                    maybe_grid_line.and_then(|line: OriginZeroLine| line.try_into_track_vec_index(final_row_counts))
                 });
 
             let grid_area = Rect {
@@ -514,17 +680,30 @@ pub fn compute(
                     .unwrap_or(container_border_box.width - border.right),
             };
             // TODO: Baseline alignment support for absolutely positioned items (should check if is actuallty specified)
-            align_and_position_item(tree, child, order, grid_area, container_alignment_styles, 0.0);
+            align_and_position_item(tree, child, order, grid_area, container_alignment_styles, Rect::ZERO, is_rtl);
+            if is_rtl {
+                mirror_horizontal_position(tree, child, container_border_box.width);
+            }
             order += 1;
         }
     });
+    perform_contents_layout(tree, node);
+
+    // Compute the scrollable overflow from the final positions of the children
+    let content_size = compute_content_size(
+        tree,
+        node,
+        container_border_box,
+        padding.swap_horizontal_sides_if(is_rtl),
+        border.swap_horizontal_sides_if(is_rtl),
+    );
 
     // If there are not items then return just the container size (no baseline)
     if items.is_empty() {
-        return container_border_box.into();
+        return SizeBaselinesAndMargins { content_size, ..container_border_box.into() };
     }
 
-    // Determine the grid container baseline(s) (currently we only compute the first baseline)
+    // Determine the grid container's first baseline
     let grid_container_baseline: f32 = {
         // Sort items by row start position so that we can iterate items in groups which are in the same row
         items.sort_by_key(|item| item.row_indexes.start);
@@ -545,11 +724,48 @@ pub fn compute(
         };
 
         let layout = tree.layout_mut(item.node);
-        layout.location.y + item.baseline.unwrap_or(layout.size.height)
+        layout.location.y + item.baseline.y.unwrap_or(layout.size.height)
     };
 
-    SizeBaselinesAndMargins::from_size_and_baselines(
-        container_border_box,
-        Point { x: None, y: Some(grid_container_baseline) },
-    )
+    // Determine the grid container's last baseline
+    let grid_container_last_baseline: f32 = {
+        // Sort items by row end position so that the items which end in the last row containing items come last
+        items.sort_by_key(|item| item.row_indexes.end);
+
+        // Get the row index of the last row containing items
+        let last_row = items[items.len() - 1].row_indexes.end;
+
+        // Create a slice of all of the items which end in this row
+        let last_row_items = &items[0..].rsplit(|item| item.row_indexes.end != last_row).next().unwrap();
+
+        // Prefer an item in *this row* which is last baseline aligned
+        let item = last_row_items
+            .iter()
+            .find(|item| item.align_self == AlignSelf::LastBaseline)
+            .unwrap_or(&last_row_items[last_row_items.len() - 1]);
+
+        let margin_bottom = item.margin.bottom.resolve_or_zero(Some(container_border_box.width));
+        let layout = tree.layout_mut(item.node);
+        let bottom_edge = layout.location.y + layout.size.height;
+        match (item.align_self, item.baseline.y) {
+            (AlignSelf::LastBaseline, Some(baseline)) => bottom_edge + margin_bottom - baseline,
+            _ => bottom_edge,
+        }
+    };
+
+    SizeBaselinesAndMargins {
+        content_size,
+        ..SizeBaselinesAndMargins::from_size_and_baselines(
+            container_border_box,
+            Point { x: None, y: Some(grid_container_baseline) },
+            Point { x: None, y: Some(grid_container_last_baseline) },
+        )
+    }
+}
+
+/// Mirror the horizontal position of a child of a right-to-left grid container that was positioned as if the container
+/// were left-to-right
+fn mirror_horizontal_position(tree: &mut impl LayoutTree, child: NodeId, container_width: f32) {
+    let layout = tree.layout_mut(child);
+    layout.location.x = container_width - layout.location.x - layout.size.width;
 }
@@ -0,0 +1,401 @@
+//! Implements resolving named grid lines and named grid areas into OriginZero line coordinates
+//! <https://www.w3.org/TR/css-grid-1/#line-placement>
+use super::explicit_grid::{compute_auto_repetition_count, compute_explicit_grid_size_in_axis};
+use super::OriginZeroLine;
+use crate::geometry::{AbsoluteAxis, Line};
+use crate::style::{GridPlacement, GridTrackRepetition, OriginZeroGridPlacement, Style, TrackSizingFunction};
+use crate::util::sys::{String, Vec};
+use core::cmp::{max, min};
+
+/// Resolves a grid item's (possibly named) [`GridPlacement`]s into [`OriginZeroGridPlacement`]s using the line names
+/// and template areas defined on the grid container
+pub(crate) struct NamedLineResolver<'a> {
+    /// The style of the grid container
+    style: &'a Style,
+    /// The number of columns in the explicit grid
+    explicit_column_count: u16,
+    /// The number of rows in the explicit grid
+    explicit_row_count: u16,
+    /// The lines named in the grid container's `grid_template_columns`, along with their names
+    column_line_names: Vec<(OriginZeroLine, &'a str)>,
+    /// The lines named in the grid container's `grid_template_rows`, along with their names
+    row_line_names: Vec<(OriginZeroLine, &'a str)>,
+}
+
+impl<'a> NamedLineResolver<'a> {
+    /// Create a new resolver for a grid container with the specified explicit grid size
+    pub(crate) fn new(style: &'a Style, explicit_column_count: u16, explicit_row_count: u16) -> Self {
+        Self {
+            style,
+            explicit_column_count,
+            explicit_row_count,
+            column_line_names: template_line_names(style, AbsoluteAxis::Horizontal),
+            row_line_names: template_line_names(style, AbsoluteAxis::Vertical),
+        }
+    }
+
+    /// The number of tracks in the explicit grid in the specified axis
+    pub(super) fn explicit_track_count(&self, axis: AbsoluteAxis) -> u16 {
+        match axis {
+            AbsoluteAxis::Horizontal => self.explicit_column_count,
+            AbsoluteAxis::Vertical => self.explicit_row_count,
+        }
+    }
+
+    /// Resolve a grid item's placement in the specified axis into OriginZero coordinates.
+    ///
+    /// Named lines and areas always resolve to a definite line (falling back to implicit lines where necessary).
+    /// Named spans resolve to a plain span relative to the opposite edge.
+    pub(crate) fn resolve_placement(
+        &self,
+        placement: &Line<GridPlacement>,
+        axis: AbsoluteAxis,
+    ) -> Line<OriginZeroGridPlacement> {
+        Line {
+            start: self.resolve_edge(&placement.start, &placement.end, axis, true),
+            end: self.resolve_edge(&placement.end, &placement.start, axis, false),
+        }
+    }
+
+    /// Resolve a single edge of a placement. `opposite` is the placement of the other edge in the same axis.
+    fn resolve_edge(
+        &self,
+        placement: &GridPlacement,
+        opposite: &GridPlacement,
+        axis: AbsoluteAxis,
+        is_start_edge: bool,
+    ) -> OriginZeroGridPlacement {
+        if let Some(line) = self.resolve_line(placement, axis, is_start_edge) {
+            return OriginZeroGridPlacement::Line(line);
+        }
+
+        match placement {
+            GridPlacement::Span(span) => OriginZeroGridPlacement::Span(*span),
+            // A named span can only be resolved relative to a definite line on the opposite edge.
+            // Otherwise it is treated as a span of 1.
+            GridPlacement::NamedSpan(name, span) => match self.resolve_line(opposite, axis, !is_start_edge) {
+                Some(opposite_line) => {
+                    let target = self.find_named_line_beyond(name, max(*span, 1), opposite_line, !is_start_edge, axis);
+                    // The target is only the opposite line itself if both have been clamped to the edge of the
+                    // supported range of lines
+                    OriginZeroGridPlacement::Span(max((target.0 - opposite_line.0).unsigned_abs(), 1))
+                }
+                None => OriginZeroGridPlacement::Span(1),
+            },
+            _ => OriginZeroGridPlacement::Auto,
+        }
+    }
+
+    /// Resolve a placement that specifies a (possibly named) line. Returns None for placements that
+    /// are not lines (auto, spans and the invalid line 0).
+    fn resolve_line(
+        &self,
+        placement: &GridPlacement,
+        axis: AbsoluteAxis,
+        is_start_edge: bool,
+    ) -> Option<OriginZeroLine> {
+        match placement {
+            GridPlacement::Line(line) if line.as_i16() != 0 => {
+                Some(line.into_origin_zero_line(self.explicit_track_count(axis)))
+            }
+            GridPlacement::NamedLine(name, index) if *index != 0 => Some(self.find_nth_named_line(name, *index, axis)),
+            GridPlacement::NamedArea(name) => {
+                let mut area_line_name = String::from(&**name);
+                area_line_name.push_str(if is_start_edge { "-start" } else { "-end" });
+                match self.lines_with_name(&area_line_name, axis).first() {
+                    Some(line) => Some(*line),
+                    None => Some(self.find_nth_named_line(name, 1, axis)),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Find the nth line with the specified name, counting from the start of the explicit grid if n is positive
+    /// and backwards from the end of the explicit grid if n is negative. If there are not enough lines with
+    /// that name then all implicit lines on the relevant side of the explicit grid are assumed to have that name.
+    fn find_nth_named_line(&self, name: &str, n: i16, axis: AbsoluteAxis) -> OriginZeroLine {
+        let lines = self.lines_with_name(name, axis);
+        let line_count = lines.len() as i32;
+        let explicit_track_count = i32::from(self.explicit_track_count(axis));
+        let n = i32::from(n);
+        if n > 0 {
+            match lines.get(n as usize - 1) {
+                Some(line) => *line,
+                None => OriginZeroLine::clamped(explicit_track_count.saturating_add(n - line_count)),
+            }
+        } else {
+            match usize::try_from(line_count + n) {
+                Ok(idx) => lines[idx],
+                Err(_) => OriginZeroLine::clamped(line_count + n),
+            }
+        }
+    }
+
+    /// Find the nth line with the specified name strictly after (or before if `search_forwards` is false) the line `from`.
+    /// If there are not enough lines with that name then all implicit lines in the search direction are assumed to have that name.
+    fn find_named_line_beyond(
+        &self,
+        name: &str,
+        n: u16,
+        from: OriginZeroLine,
+        search_forwards: bool,
+        axis: AbsoluteAxis,
+    ) -> OriginZeroLine {
+        let lines = self.lines_with_name(name, axis);
+        let matching: Vec<OriginZeroLine> = match search_forwards {
+            true => lines.into_iter().filter(|line| *line > from).collect(),
+            false => lines.into_iter().rev().filter(|line| *line < from).collect(),
+        };
+        let n = n as usize;
+        if let Some(line) = matching.get(n - 1) {
+            return *line;
+        }
+
+        let remaining = (n - matching.len()) as i32;
+        match search_forwards {
+            true => {
+                let last_line = max(i32::from(from.0), i32::from(self.explicit_track_count(axis)));
+                OriginZeroLine::clamped(last_line.saturating_add(remaining))
+            }
+            false => OriginZeroLine::clamped(min(i32::from(from.0), 0).saturating_sub(remaining)),
+        }
+    }
+
+    /// All of the explicit grid lines with the specified name in ascending order. This includes both lines named in
+    /// the grid container's template line names and the implicit `<area>-start`/`<area>-end` lines of its template areas.
+    fn lines_with_name(&self, name: &str, axis: AbsoluteAxis) -> Vec<OriginZeroLine> {
+        let line_names = match axis {
+            AbsoluteAxis::Horizontal => &self.column_line_names,
+            AbsoluteAxis::Vertical => &self.row_line_names,
+        };
+        let mut lines: Vec<OriginZeroLine> =
+            line_names.iter().filter(|(_, line_name)| *line_name == name).map(|(line, _)| *line).collect();
+
+        let area_lines = self.style.grid_template_areas.iter().filter_map(|area| {
+            let (start, end) = match axis {
+                AbsoluteAxis::Horizontal => (area.column_start, area.column_end),
+                AbsoluteAxis::Vertical => (area.row_start, area.row_end),
+            };
+            let area_name = area.name.as_str();
+            if name.strip_suffix("-start") == Some(area_name) {
+                Some(OriginZeroLine::from_area_line(start))
+            } else if name.strip_suffix("-end") == Some(area_name) {
+                Some(OriginZeroLine::from_area_line(end))
+            } else {
+                None
+            }
+        });
+        lines.extend(area_lines);
+
+        lines.sort();
+        lines.dedup();
+        lines
+    }
+}
+
+/// The lines named by the [`TrackSizingFunction::LineNames`] entries of a grid container's track template in the
+/// specified axis, along with their names, in ascending order. Repetitions are expanded (along with the names inside
+/// them), so this depends on the number of tracks that the template generates. Invalid templates don't name any lines.
+fn template_line_names(style: &Style, axis: AbsoluteAxis) -> Vec<(OriginZeroLine, &str)> {
+    let mut line_names = Vec::new();
+    let template = style.grid_template_tracks(axis);
+    let template_track_count = compute_explicit_grid_size_in_axis(style, axis);
+    if template_track_count == 0 {
+        return line_names;
+    }
+
+    let auto_repetition_count = compute_auto_repetition_count(template, template_track_count);
+    let mut line = OriginZeroLine(0);
+    for track_def in template.iter() {
+        let (track_list, repetition_count) = match track_def {
+            TrackSizingFunction::Repeat(GridTrackRepetition::Count(count), track_list) => {
+                (track_list.as_slice(), *count)
+            }
+            TrackSizingFunction::Repeat(_, track_list) => (track_list.as_slice(), auto_repetition_count),
+            _ => (core::slice::from_ref(track_def), 1),
+        };
+        for _ in 0..repetition_count {
+            for track_def in track_list {
+                match track_def {
+                    TrackSizingFunction::Single(_) => line += 1,
+                    TrackSizingFunction::LineNames(names) => {
+                        line_names.extend(names.iter().map(|name| (line, name.as_str())))
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    line_names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NamedLineResolver;
+    use crate::compute::grid::OriginZeroLine;
+    use crate::geometry::{AbsoluteAxis, Line};
+    use crate::prelude::*;
+    use crate::style::OriginZeroGridPlacement;
+
+    fn oz_line(index: i16) -> OriginZeroGridPlacement {
+        OriginZeroGridPlacement::Line(OriginZeroLine(index))
+    }
+
+    #[test]
+    fn named_lines_resolve_to_nth_match() {
+        let style = Style {
+            grid_template_columns: vec![
+                line_names(&["a"]),
+                length(10.0),
+                length(10.0),
+                line_names(&["a", "b"]),
+                length(10.0),
+                line_names(&["a"]),
+            ],
+            ..Default::default()
+        };
+        let resolver = NamedLineResolver::new(&style, 3, 0);
+        let resolve = |start: GridPlacement, end: GridPlacement| {
+            resolver.resolve_placement(&Line { start, end }, AbsoluteAxis::Horizontal)
+        };
+
+        assert_eq!(resolve(named_line("a", 2), auto()).start, oz_line(2));
+        assert_eq!(resolve(named_line("a", -1), auto()).start, oz_line(3));
+        assert_eq!(resolve(named_line("b", 1), auto()).start, oz_line(2));
+        assert_eq!(resolve(named_line("a", 0), auto()).start, OriginZeroGridPlacement::Auto);
+    }
+
+    #[test]
+    fn missing_named_lines_resolve_to_implicit_lines() {
+        let style =
+            Style { grid_template_columns: vec![line_names(&["a"]), length(10.0), length(10.0)], ..Default::default() };
+        let resolver = NamedLineResolver::new(&style, 2, 0);
+        let resolve = |start: GridPlacement| {
+            resolver.resolve_placement(&Line { start, end: auto() }, AbsoluteAxis::Horizontal).start
+        };
+
+        assert_eq!(resolve(named_line("a", 3)), oz_line(4));
+        assert_eq!(resolve(named_line("a", -3)), oz_line(-2));
+        assert_eq!(resolve(named_line("missing", 1)), oz_line(3));
+    }
+
+    #[test]
+    fn extreme_named_lines_are_clamped_to_the_supported_range() {
+        let style = Style { grid_template_columns: vec![line_names(&["a"]), length(10.0)], ..Default::default() };
+        let resolver = NamedLineResolver::new(&style, 1, 0);
+        let resolve = |start: GridPlacement, end: GridPlacement| {
+            resolver.resolve_placement(&Line { start, end }, AbsoluteAxis::Horizontal)
+        };
+        let max_distance = OriginZeroLine::MAX_DISTANCE as i16;
+
+        assert_eq!(resolve(named_line("a", i16::MAX), auto()).start, oz_line(max_distance));
+        assert_eq!(resolve(named_line("a", i16::MIN), auto()).start, oz_line(-max_distance));
+        assert_eq!(resolve(named_line("missing", i16::MIN), auto()).start, oz_line(-max_distance));
+        assert_eq!(resolve(line(1), named_span("a", u16::MAX)).end, OriginZeroGridPlacement::Span(max_distance as u16));
+        assert_eq!(
+            resolve(named_span("a", u16::MAX), line(1)).start,
+            OriginZeroGridPlacement::Span(max_distance as u16)
+        );
+        // A named span from a line at the edge of the supported range still spans a track
+        assert_eq!(resolve(named_line("a", i16::MAX), named_span("a", 1)).end, OriginZeroGridPlacement::Span(1));
+    }
+
+    #[test]
+    fn named_areas_resolve_to_area_edges() {
+        let style = Style {
+            grid_template_areas: template_areas(&["header header", "sidebar main"]),
+            grid_template_rows: vec![length(10.0), line_names(&["main"]), length(10.0)],
+            ..Default::default()
+        };
+        let resolver = NamedLineResolver::new(&style, 2, 2);
+
+        let columns = resolver.resolve_placement(&area("main"), AbsoluteAxis::Horizontal);
+        assert_eq!(columns, Line { start: oz_line(1), end: oz_line(2) });
+        let rows = resolver.resolve_placement(&area("header"), AbsoluteAxis::Vertical);
+        assert_eq!(rows, Line { start: oz_line(0), end: oz_line(1) });
+
+        // Falls back to the lines named `main` in the vertical axis (as main-start/main-end are area lines)
+        let rows = resolver.resolve_placement(&area("main"), AbsoluteAxis::Vertical);
+        assert_eq!(rows, Line { start: oz_line(1), end: oz_line(2) });
+        let explicit_named_line = resolver.resolve_placement(&area("unknown"), AbsoluteAxis::Vertical);
+        assert_eq!(explicit_named_line, Line { start: oz_line(3), end: oz_line(3) });
+    }
+
+    #[test]
+    fn out_of_range_area_lines_are_clamped_to_the_supported_range() {
+        let area_lines = |name: &str, start: u16, end: u16| GridTemplateArea {
+            name: name.into(),
+            row_start: start,
+            row_end: end,
+            column_start: start,
+            column_end: end,
+        };
+        let style = Style {
+            grid_template_areas: vec![area_lines("zero", 0, 0), area_lines("max", u16::MAX, u16::MAX)],
+            ..Default::default()
+        };
+        let max_distance = OriginZeroLine::MAX_DISTANCE as i16;
+        let resolver = NamedLineResolver::new(&style, max_distance as u16, max_distance as u16);
+
+        for axis in [AbsoluteAxis::Horizontal, AbsoluteAxis::Vertical] {
+            let zero = resolver.resolve_placement(&area("zero"), axis);
+            assert_eq!(zero, Line { start: oz_line(0), end: oz_line(0) });
+            let max = resolver.resolve_placement(&area("max"), axis);
+            assert_eq!(max, Line { start: oz_line(max_distance), end: oz_line(max_distance) });
+        }
+    }
+
+    #[test]
+    fn named_spans_resolve_relative_to_opposite_edge() {
+        let style = Style {
+            grid_template_columns: vec![
+                line_names(&["a"]),
+                length(10.0),
+                length(10.0),
+                line_names(&["a"]),
+                length(10.0),
+            ],
+            ..Default::default()
+        };
+        let resolver = NamedLineResolver::new(&style, 3, 0);
+        let resolve = |start: GridPlacement, end: GridPlacement| {
+            resolver.resolve_placement(&Line { start, end }, AbsoluteAxis::Horizontal)
+        };
+
+        assert_eq!(resolve(line(1), named_span("a", 1)).end, OriginZeroGridPlacement::Span(2));
+        assert_eq!(resolve(line(1), named_span("a", 2)).end, OriginZeroGridPlacement::Span(4));
+        assert_eq!(resolve(named_span("a", 1), line(-1)).start, OriginZeroGridPlacement::Span(1));
+        assert_eq!(resolve(named_span("a", 1), auto()).start, OriginZeroGridPlacement::Span(1));
+    }
+
+    #[test]
+    fn line_names_inside_repetitions_are_repeated() {
+        let style = Style {
+            grid_template_columns: vec![
+                line_names(&["start"]),
+                repeat(2, vec![line_names(&["a"]), length(10.0), line_names(&["b"])]),
+                line_names(&["middle"]),
+                repeat(GridTrackRepetition::AutoFill, vec![line_names(&["col"]), length(40.0)]),
+                line_names(&["end"]),
+            ],
+            size: Size { width: length(140.0), height: auto() },
+            ..Default::default()
+        };
+        // The auto-fill repetition is repeated three times, so the explicit grid has 5 columns
+        let resolver = NamedLineResolver::new(&style, 5, 0);
+        let resolve = |start: GridPlacement| {
+            resolver.resolve_placement(&Line { start, end: auto() }, AbsoluteAxis::Horizontal).start
+        };
+
+        assert_eq!(resolve(named_line("start", 1)), oz_line(0));
+        assert_eq!(resolve(named_line("a", 2)), oz_line(1));
+        assert_eq!(resolve(named_line("b", 1)), oz_line(1));
+        assert_eq!(resolve(named_line("b", -1)), oz_line(2));
+        assert_eq!(resolve(named_line("middle", 1)), oz_line(2));
+        assert_eq!(resolve(named_line("col", 1)), oz_line(2));
+        assert_eq!(resolve(named_line("col", -1)), oz_line(4));
+        assert_eq!(resolve(named_line("col", 4)), oz_line(6));
+        assert_eq!(resolve(named_line("end", 1)), oz_line(5));
+    }
+}
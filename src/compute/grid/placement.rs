@@ -1,5 +1,6 @@
 //! Implements placing items in the grid and resolving the implicit grid.
 //! <https://www.w3.org/TR/css-grid-1/#placement>
+use super::named_lines::NamedLineResolver;
 use super::types::{CellOccupancyMatrix, CellOccupancyState, GridItem};
 use super::OriginZeroLine;
 use crate::geometry::Line;
@@ -16,6 +17,7 @@ pub(super) fn place_grid_items<'a, ChildIter>(
     cell_occupancy_matrix: &mut CellOccupancyMatrix,
     items: &mut Vec<GridItem>,
     children_iter: impl Fn() -> ChildIter,
+    line_resolver: &NamedLineResolver,
     grid_auto_flow: GridAutoFlow,
     align_items: AlignItems,
     justify_items: AlignItems,
@@ -25,23 +27,22 @@ pub(super) fn place_grid_items<'a, ChildIter>(
     let primary_axis = grid_auto_flow.primary_axis();
     let secondary_axis = primary_axis.other_axis();
 
-    let map_child_style_to_origin_zero_placement = {
-        let explicit_col_count = cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal).explicit;
-        let explicit_row_count = cell_occupancy_matrix.track_counts(AbsoluteAxis::Vertical).explicit;
-        move |(index, node, style): (usize, NodeId, &'a Style)| -> (_, _, _, &'a Style) {
+    // Named lines and areas are resolved up front so that the remainder of the algorithm can operate
+    // purely in terms of OriginZero coordinates
+    let children_with_origin_zero_placement = || {
+        children_iter().map(|(index, node, style): (usize, NodeId, &'a Style)| -> (_, _, _, &'a Style) {
             let origin_zero_placement = InBothAbsAxis {
-                horizontal: style.grid_column.map(|placement| placement.into_origin_zero_placement(explicit_col_count)),
-                vertical: style.grid_row.map(|placement| placement.into_origin_zero_placement(explicit_row_count)),
+                horizontal: line_resolver.resolve_placement(&style.grid_column, AbsoluteAxis::Horizontal),
+                vertical: line_resolver.resolve_placement(&style.grid_row, AbsoluteAxis::Vertical),
             };
             (index, node, origin_zero_placement, style)
-        }
+        })
     };
 
     // 1. Place children with definite positions
     let mut idx = 0;
-    children_iter()
-        .filter(|(_, _, child_style)| child_style.grid_row.is_definite() && child_style.grid_column.is_definite())
-        .map(map_child_style_to_origin_zero_placement)
+    children_with_origin_zero_placement()
+        .filter(|(_, _, placement, _)| placement.vertical.is_definite() && placement.horizontal.is_definite())
         .for_each(|(index, child_node, child_placement, style)| {
             idx += 1;
             #[cfg(test)]
@@ -65,12 +66,10 @@ pub(super) fn place_grid_items<'a, ChildIter>(
 
     // 2. Place remaining children with definite secondary axis positions
     let mut idx = 0;
-    children_iter()
-        .filter(|(_, _, child_style)| {
-            child_style.grid_placement(secondary_axis).is_definite()
-                && !child_style.grid_placement(primary_axis).is_definite()
+    children_with_origin_zero_placement()
+        .filter(|(_, _, placement, _)| {
+            placement.get(secondary_axis).is_definite() && !placement.get(primary_axis).is_definite()
         })
-        .map(map_child_style_to_origin_zero_placement)
         .for_each(|(index, child_node, child_placement, style)| {
             idx += 1;
             #[cfg(test)]
@@ -120,9 +119,8 @@ pub(super) fn place_grid_items<'a, ChildIter>(
     let grid_start_position = (OriginZeroLine(-primary_neg_tracks), OriginZeroLine(-secondary_neg_tracks));
     let mut grid_position = grid_start_position;
     let mut idx = 0;
-    children_iter()
-        .filter(|(_, _, child_style)| !child_style.grid_placement(secondary_axis).is_definite())
-        .map(map_child_style_to_origin_zero_placement)
+    children_with_origin_zero_placement()
+        .filter(|(_, _, placement, _)| !placement.get(secondary_axis).is_definite())
         .for_each(|(index, child_node, child_placement, style)| {
             idx += 1;
             #[cfg(test)]
@@ -223,9 +221,12 @@ fn place_indefinitely_positioned_item(
     let primary_placement_style = placement.get(primary_axis);
     let secondary_placement_style = placement.get(primary_axis.other_axis());
 
-    let primary_span = primary_placement_style.indefinite_span();
-    let secondary_span = secondary_placement_style.indefinite_span();
     let has_definite_primary_axis_position = primary_placement_style.is_definite();
+    let primary_span = match has_definite_primary_axis_position {
+        true => primary_placement_style.resolve_definite_grid_lines().span(),
+        false => primary_placement_style.indefinite_span(),
+    };
+    let secondary_span = secondary_placement_style.indefinite_span();
     let primary_axis_grid_start_line = cell_occupancy_matrix.track_counts(primary_axis).implicit_start_line();
     let primary_axis_grid_end_line = cell_occupancy_matrix.track_counts(primary_axis).implicit_end_line();
     let secondary_axis_grid_start_line =
@@ -296,7 +297,7 @@ fn place_indefinitely_positioned_item(
 /// Record the grid item in both CellOccupancyMatric and the GridItems list
 /// once a definite placement has been determined
 #[allow(clippy::too_many_arguments)]
-fn record_grid_placement(
+pub(super) fn record_grid_placement(
     cell_occupancy_matrix: &mut CellOccupancyMatrix,
     items: &mut Vec<GridItem>,
     node: NodeId,
@@ -348,6 +349,7 @@ mod tests {
 
     mod test_placement_algorithm {
         use crate::compute::grid::implicit_grid::compute_grid_size_estimate;
+        use crate::compute::grid::named_lines::NamedLineResolver;
         use crate::compute::grid::types::TrackCounts;
         use crate::compute::grid::util::*;
         use crate::compute::grid::CellOccupancyMatrix;
@@ -369,7 +371,9 @@ mod tests {
             // Setup test
             let children_iter = || children.iter().map(|(index, style, _)| (*index, NodeId::from(*index), style));
             let child_styles_iter = children.iter().map(|(_, style, _)| style);
-            let estimated_sizes = compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles_iter);
+            let grid_style = Style::default();
+            let line_resolver = NamedLineResolver::new(&grid_style, explicit_col_count, explicit_row_count);
+            let estimated_sizes = compute_grid_size_estimate(&line_resolver, child_styles_iter);
             let mut items = Vec::new();
             let mut cell_occupancy_matrix =
                 CellOccupancyMatrix::with_track_counts(estimated_sizes.0, estimated_sizes.1);
@@ -379,6 +383,7 @@ mod tests {
                 &mut cell_occupancy_matrix,
                 &mut items,
                 children_iter,
+                &line_resolver,
                 flow,
                 AlignSelf::Start,
                 AlignSelf::Start,
@@ -0,0 +1,365 @@
+//! Implements subgrids: grid items which are themselves grid containers and which adopt the tracks of their parent grid
+//! in one or both axes instead of defining their own.
+//! <https://www.w3.org/TR/css-grid-2/#subgrids>
+use super::explicit_grid::compute_explicit_track_counts;
+use super::implicit_grid::compute_grid_size_estimate;
+use super::named_lines::NamedLineResolver;
+use super::placement::place_grid_items;
+use super::types::{CellOccupancyMatrix, GridItem, GridTrack};
+use super::OriginZeroLine;
+use crate::compute::common::contents::order_modified_layout_children;
+use crate::geometry::{AbsoluteAxis, AbstractAxis, Line, Size};
+use crate::style::{AlignItems, AlignSelf, AvailableSpace, Direction, Display, Style, WritingMode};
+use crate::tree::{Layout, LayoutTree, NodeId, RunMode, SizeBaselinesAndMargins, SizingMode};
+use crate::util::sys::{f32_max, Vec};
+use crate::util::ResolveOrZero;
+
+/// Clamp a placement within a subgrid to the tracks that it inherits. Subgrids have no implicit tracks in the axes in
+/// which they inherit tracks, so items that would be placed (partly) outside of those tracks are moved inside them.
+pub(super) fn clamp_to_inherited_tracks(lines: Line<OriginZeroLine>, track_count: u16) -> Line<OriginZeroLine> {
+    let track_count = track_count as i16;
+    let start = lines.start.0.clamp(0, track_count - 1);
+    let end = lines.end.0.clamp(start + 1, track_count);
+    Line { start: OriginZeroLine(start), end: OriginZeroLine(end) }
+}
+
+/// Place the in-flow children of each subgrid item and append them to `items`, so that they contribute to the sizing of
+/// the tracks of this grid that they span. The appended items are processed in turn, which handles nested subgrids.
+///
+/// The children are placed exactly as their subgrid will place them when it is laid out, and their placements are then
+/// translated into this grid's coordinates. In axes in which the tracks are not inherited, they are instead given the
+/// placement of their subgrid (and don't contribute to track sizing in that axis).
+///
+/// `direction` is the direction of this grid. The columns of a subgrid with the opposite direction run in the opposite
+/// order to this grid's columns.
+pub(super) fn place_subgrid_items(tree: &impl LayoutTree, items: &mut Vec<GridItem>, direction: Direction) {
+    let mut index = 0;
+    while index < items.len() {
+        let subgrid = &items[index];
+        index += 1;
+
+        let inherits_tracks = Size {
+            width: subgrid.is_subgrid.width && subgrid.is_placed_in_grid.width,
+            height: subgrid.is_subgrid.height && subgrid.is_placed_in_grid.height,
+        };
+        if !inherits_tracks.width && !inherits_tracks.height {
+            continue;
+        }
+
+        let node = subgrid.node;
+        let style = tree.style(node);
+        let subgrid_column = subgrid.column;
+        let subgrid_row = subgrid.row;
+        let column_span = subgrid.span(AbstractAxis::Inline);
+        let row_span = subgrid.span(AbstractAxis::Block);
+        let parent_edge_offsets = subgrid.subgrid_edge_offsets;
+
+        let (_, explicit_col_count) = compute_explicit_track_counts(
+            style,
+            AbsoluteAxis::Horizontal,
+            subgrid.is_subgrid.width.then_some(column_span),
+        );
+        let (_, explicit_row_count) =
+            compute_explicit_track_counts(style, AbsoluteAxis::Vertical, subgrid.is_subgrid.height.then_some(row_span));
+        let line_resolver = NamedLineResolver::new(style, explicit_col_count, explicit_row_count);
+
+        let children = order_modified_layout_children(tree, node);
+        let child_styles_iter = children.iter().map(|&child_node| tree.style(child_node));
+        let (est_col_counts, est_row_counts) = compute_grid_size_estimate(&line_resolver, child_styles_iter);
+        let mut cell_occupancy_matrix = CellOccupancyMatrix::with_track_counts(est_col_counts, est_row_counts);
+        let mut subgrid_items = Vec::with_capacity(children.len());
+        let in_flow_children_iter = || {
+            children
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(index, child_node)| (index, child_node, tree.style(child_node)))
+                .filter(|(_, _, style)| style.display != Display::None && !style.position.is_out_of_flow())
+        };
+        place_grid_items(
+            &mut cell_occupancy_matrix,
+            &mut subgrid_items,
+            in_flow_children_iter,
+            &line_resolver,
+            style.grid_auto_flow,
+            style.align_items.unwrap_or(AlignItems::Stretch),
+            style.justify_items.unwrap_or(AlignItems::Stretch),
+        );
+
+        // The space between the subgrid's edges and its content box acts as an extra margin on items adjacent to those edges.
+        // The edges are expressed relative to this grid's inline-start, which is on the right for right-to-left grids.
+        let edges = (style.margin.resolve_or_zero(None)
+            + style.border.resolve_or_zero(None)
+            + style.padding.resolve_or_zero(None))
+        .swap_horizontal_sides_if(direction.is_rtl());
+        let is_column_order_reversed = style.direction.is_rtl() != direction.is_rtl();
+
+        for mut item in subgrid_items {
+            if inherits_tracks.width {
+                let mut column = clamp_to_inherited_tracks(item.column, column_span);
+                if is_column_order_reversed {
+                    let span = column_span as i16;
+                    column =
+                        Line { start: OriginZeroLine(span - column.end.0), end: OriginZeroLine(span - column.start.0) };
+                }
+                item.subgrid_edge_offsets.left =
+                    if column.start.0 == 0 { parent_edge_offsets.left + edges.left } else { 0.0 };
+                item.subgrid_edge_offsets.right =
+                    if column.end.0 == column_span as i16 { parent_edge_offsets.right + edges.right } else { 0.0 };
+                item.column = column.map(|line| subgrid_column.start + line.0 as u16);
+            } else {
+                item.column = subgrid_column;
+            }
+            if inherits_tracks.height {
+                let row = clamp_to_inherited_tracks(item.row, row_span);
+                item.subgrid_edge_offsets.top =
+                    if row.start.0 == 0 { parent_edge_offsets.top + edges.top } else { 0.0 };
+                item.subgrid_edge_offsets.bottom =
+                    if row.end.0 == row_span as i16 { parent_edge_offsets.bottom + edges.bottom } else { 0.0 };
+                item.row = row.map(|line| subgrid_row.start + line.0 as u16);
+            } else {
+                item.row = subgrid_row;
+            }
+
+            item.is_placed_in_grid = inherits_tracks;
+            item.is_subgrid_child = true;
+            // Baselines are shared within the subgrid's own rows, which it aligns when it is laid out
+            let without_baseline = |alignment: AlignSelf| match alignment {
+                AlignSelf::Baseline => AlignSelf::Start,
+                AlignSelf::LastBaseline => AlignSelf::End,
+                alignment => alignment,
+            };
+            item.align_self = without_baseline(item.align_self);
+            item.justify_self = without_baseline(item.justify_self);
+            items.push(item);
+        }
+    }
+}
+
+/// Wraps the [`LayoutTree`] passed to the grid algorithm so that the grid's subgrid items are sized and laid out using
+/// the tracks that they inherit from the grid. All other nodes are passed through to the wrapped tree.
+pub(super) struct SubgridTree<'t, Tree: LayoutTree> {
+    /// The wrapped tree
+    tree: &'t mut Tree,
+    /// The direction of the grid that is being laid out
+    direction: Direction,
+    /// The subgrid items of the grid that is being laid out
+    subgrids: Vec<InheritingSubgrid>,
+}
+
+/// A subgrid item, along with the sizes of the tracks that it inherits from its parent grid
+struct InheritingSubgrid {
+    /// The id of the subgrid node
+    node: NodeId,
+    /// The sizes of the inherited columns interleaved with the gutters between them. None until the columns are sized.
+    column_sizes: Option<Vec<f32>>,
+    /// The sizes of the inherited rows interleaved with the gutters between them. None until the rows are sized.
+    row_sizes: Option<Vec<f32>>,
+}
+
+impl InheritingSubgrid {
+    /// The sizes of the inherited tracks as seen from inside the subgrid. The space taken up by the subgrid's
+    /// margin, border and padding is taken out of the first and last tracks.
+    fn track_sizes_within(&self, style: &Style, parent_width: Option<f32>) -> (Option<Vec<f32>>, Option<Vec<f32>>) {
+        // The first column is on the right of right-to-left subgrids
+        let edges = (style.margin.resolve_or_zero(parent_width)
+            + style.border.resolve_or_zero(parent_width)
+            + style.padding.resolve_or_zero(parent_width))
+        .swap_horizontal_sides_if(style.direction.is_rtl());
+        let shrink_edge_tracks = |sizes: &Vec<f32>, start: f32, end: f32| {
+            let mut sizes = sizes.clone();
+            let last = sizes.len() - 1;
+            sizes[0] = f32_max(0.0, sizes[0] - start);
+            sizes[last] = f32_max(0.0, sizes[last] - end);
+            sizes
+        };
+        (
+            self.column_sizes.as_ref().map(|sizes| shrink_edge_tracks(sizes, edges.left, edges.right)),
+            self.row_sizes.as_ref().map(|sizes| shrink_edge_tracks(sizes, edges.top, edges.bottom)),
+        )
+    }
+}
+
+impl<'t, Tree: LayoutTree> SubgridTree<'t, Tree> {
+    /// Wrap a tree for laying out a grid with the specified direction
+    pub(super) fn new(tree: &'t mut Tree, direction: Direction) -> Self {
+        Self { tree, direction, subgrids: Vec::new() }
+    }
+
+    /// Record the current sizes of the tracks in the specified axis that are inherited by the grid's subgrid items.
+    /// Subgrids are measured and laid out using the most recently recorded sizes.
+    pub(super) fn inherit_tracks(&mut self, items: &[GridItem], axis: AbstractAxis, tracks: &[GridTrack]) {
+        for item in items.iter().filter(|item| !item.is_subgrid_child && item.is_subgrid.get(axis)) {
+            let spanned_tracks = &tracks[item.track_range_excluding_lines(axis)];
+            let mut sizes: Vec<f32> = spanned_tracks
+                .iter()
+                .enumerate()
+                .map(|(index, track)| match index % 2 {
+                    0 => track.base_size,
+                    // Once the tracks have been aligned, any free space distributed between them widens the gutters
+                    _ => {
+                        let (previous, next) = (&spanned_tracks[index - 1], &spanned_tracks[index + 1]);
+                        f32_max(track.base_size, next.offset - previous.offset - previous.base_size)
+                    }
+                })
+                .collect();
+            // Subgrids list their columns starting from their own inline-start side
+            if axis == AbstractAxis::Inline && self.tree.style(item.node).direction.is_rtl() != self.direction.is_rtl()
+            {
+                sizes.reverse();
+            }
+
+            let position = self.subgrids.iter().position(|subgrid| subgrid.node == item.node);
+            let subgrid = match position {
+                Some(position) => &mut self.subgrids[position],
+                None => {
+                    self.subgrids.push(InheritingSubgrid { node: item.node, column_sizes: None, row_sizes: None });
+                    self.subgrids.last_mut().unwrap()
+                }
+            };
+            match axis {
+                AbstractAxis::Inline => subgrid.column_sizes = Some(sizes),
+                AbstractAxis::Block => subgrid.row_sizes = Some(sizes),
+            }
+        }
+    }
+
+    /// Run the grid algorithm on a subgrid using its inherited tracks. Returns None if the node is not one of
+    /// the grid's subgrid items (or if none of its inherited tracks have been sized yet).
+    fn compute_subgrid(
+        &mut self,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        run_mode: RunMode,
+    ) -> Option<SizeBaselinesAndMargins> {
+        let subgrid = self.subgrids.iter().find(|subgrid| subgrid.node == node)?;
+        let (column_sizes, row_sizes) = subgrid.track_sizes_within(self.tree.style(node), parent_size.width);
+        let inherited_track_sizes = Size { width: column_sizes.as_deref(), height: row_sizes.as_deref() };
+        Some(super::compute_with_inherited_tracks(
+            self.tree,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            run_mode,
+            inherited_track_sizes,
+        ))
+    }
+}
+
+impl<'t, Tree: LayoutTree> LayoutTree for SubgridTree<'t, Tree> {
+    type ChildIter<'a>
+        = Tree::ChildIter<'a>
+    where
+        Self: 'a;
+
+    fn children(&self, node: NodeId) -> Self::ChildIter<'_> {
+        self.tree.children(node)
+    }
+
+    fn child_count(&self, node: NodeId) -> usize {
+        self.tree.child_count(node)
+    }
+
+    fn child(&self, node: NodeId, index: usize) -> NodeId {
+        self.tree.child(node, index)
+    }
+
+    fn style(&self, node: NodeId) -> &Style {
+        self.tree.style(node)
+    }
+
+    fn transposed_style(&self, node: NodeId, writing_mode: WritingMode) -> Option<&Style> {
+        self.tree.transposed_style(node, writing_mode)
+    }
+
+    fn layout(&self, node: NodeId) -> &Layout {
+        self.tree.layout(node)
+    }
+
+    fn layout_mut(&mut self, node: NodeId) -> &mut Layout {
+        self.tree.layout_mut(node)
+    }
+
+    fn measure_child_size(
+        &mut self,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        sizing_mode: SizingMode,
+        vertical_margins_are_collapsible: Line<bool>,
+    ) -> Size<f32> {
+        match self.compute_subgrid(node, known_dimensions, parent_size, available_space, RunMode::ComputeSize) {
+            Some(output) => output.size,
+            None => self.tree.measure_child_size(
+                node,
+                known_dimensions,
+                parent_size,
+                available_space,
+                sizing_mode,
+                vertical_margins_are_collapsible,
+            ),
+        }
+    }
+
+    fn perform_child_layout(
+        &mut self,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        sizing_mode: SizingMode,
+        vertical_margins_are_collapsible: Line<bool>,
+    ) -> SizeBaselinesAndMargins {
+        match self.compute_subgrid(node, known_dimensions, parent_size, available_space, RunMode::PerformLayout) {
+            Some(output) => output,
+            None => self.tree.perform_child_layout(
+                node,
+                known_dimensions,
+                parent_size,
+                available_space,
+                sizing_mode,
+                vertical_margins_are_collapsible,
+            ),
+        }
+    }
+
+    fn measure_child_fragments(
+        &mut self,
+        node: NodeId,
+        available_space: Size<AvailableSpace>,
+    ) -> Option<Vec<Size<f32>>> {
+        self.tree.measure_child_fragments(node, available_space)
+    }
+
+    fn set_fragment_layouts(&mut self, node: NodeId, fragment_layouts: Vec<Layout>) {
+        self.tree.set_fragment_layouts(node, fragment_layouts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clamp_to_inherited_tracks;
+    use crate::compute::grid::OriginZeroLine;
+    use crate::geometry::Line;
+
+    fn lines(start: i16, end: i16) -> Line<OriginZeroLine> {
+        Line { start: OriginZeroLine(start), end: OriginZeroLine(end) }
+    }
+
+    #[test]
+    fn placements_within_inherited_tracks_are_unchanged() {
+        assert_eq!(clamp_to_inherited_tracks(lines(0, 2), 3), lines(0, 2));
+        assert_eq!(clamp_to_inherited_tracks(lines(1, 3), 3), lines(1, 3));
+    }
+
+    #[test]
+    fn placements_outside_inherited_tracks_are_clamped() {
+        assert_eq!(clamp_to_inherited_tracks(lines(-2, 1), 3), lines(0, 1));
+        assert_eq!(clamp_to_inherited_tracks(lines(2, 5), 3), lines(2, 3));
+        assert_eq!(clamp_to_inherited_tracks(lines(4, 5), 3), lines(2, 3));
+    }
+}
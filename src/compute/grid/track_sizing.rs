@@ -3,10 +3,8 @@
 use super::types::{GridItem, GridTrack, TrackCounts};
 use crate::geometry::AbstractAxis;
 use crate::geometry::{Line, Size};
-use crate::prelude::{LayoutTree, TaffyMinContent};
-use crate::style::{
-    AlignContent, AlignSelf, AvailableSpace, LengthPercentage, MaxTrackSizingFunction, MinTrackSizingFunction,
-};
+use crate::prelude::LayoutTree;
+use crate::style::{AlignContent, AlignSelf, AvailableSpace, MaxTrackSizingFunction, MinTrackSizingFunction};
 use crate::tree::SizingMode;
 use crate::util::sys::{f32_max, f32_min, Vec};
 use crate::util::MaybeMath;
@@ -114,6 +112,9 @@ where
     /// Retrieve the item's min content contribution from the cache or compute it using the provided parameters
     #[inline(always)]
     fn min_content_contribution(&mut self, item: &mut GridItem) -> f32 {
+        if !item.contributes_to_track_sizing(self.axis) {
+            return 0.0;
+        }
         let available_space = self.available_space(item);
         let margin_axis_sums = self.margins_axis_sums_with_baseline_shims(item);
         let contribution =
@@ -124,6 +125,9 @@ where
     /// Retrieve the item's max content contribution from the cache or compute it using the provided parameters
     #[inline(always)]
     fn max_content_contribution(&mut self, item: &mut GridItem) -> f32 {
+        if !item.contributes_to_track_sizing(self.axis) {
+            return 0.0;
+        }
         let available_space = self.available_space(item);
         let margin_axis_sums = self.margins_axis_sums_with_baseline_shims(item);
         let contribution =
@@ -139,6 +143,9 @@ where
     /// Because the minimum contribution often depends on the size of the item’s content, it is considered a type of intrinsic size contribution.
     #[inline(always)]
     fn minimum_contribution(&mut self, item: &mut GridItem, axis_tracks: &[GridTrack]) -> f32 {
+        if !item.contributes_to_track_sizing(self.axis) {
+            return 0.0;
+        }
         let available_space = self.available_space(item);
         let margin_axis_sums = self.margins_axis_sums_with_baseline_shims(item);
         let contribution =
@@ -192,6 +199,10 @@ pub(super) fn compute_alignment_gutter_adjustment(
         AlignContent::End => 1,
         AlignContent::FlexEnd => 1,
         AlignContent::Center => 1,
+        AlignContent::SafeEnd => 1,
+        AlignContent::SafeFlexStart => 1,
+        AlignContent::SafeFlexEnd => 1,
+        AlignContent::SafeCenter => 1,
         AlignContent::Stretch => 0,
         AlignContent::SpaceBetween => 0,
         AlignContent::SpaceAround => 1,
@@ -204,6 +215,10 @@ pub(super) fn compute_alignment_gutter_adjustment(
         AlignContent::FlexEnd => 0,
         AlignContent::End => 0,
         AlignContent::Center => 0,
+        AlignContent::SafeEnd => 0,
+        AlignContent::SafeFlexStart => 0,
+        AlignContent::SafeFlexEnd => 0,
+        AlignContent::SafeCenter => 0,
         AlignContent::Stretch => 0,
         AlignContent::SpaceBetween => 1,
         AlignContent::SpaceAround => 2,
@@ -276,14 +291,21 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutTree>(
     items: &mut [GridItem],
     get_track_size_estimate: impl Fn(&GridTrack, Option<f32>) -> Option<f32>,
     has_baseline_aligned_item: bool,
+    is_rtl: bool,
 ) {
+    // A grid can only be without tracks in an axis if it has no items at all (for example, a subgrid without children
+    // that only inherits tracks in the other axis), in which case there is nothing to size
+    if axis_tracks.len() < 3 {
+        return;
+    }
+
     // 11.4 Initialise Track sizes
     // Initialize each track’s base size and growth limit.
     initialize_track_sizes(axis_tracks, inner_node_size.get(axis));
 
     // 11.5.1 Shim item baselines
     if has_baseline_aligned_item {
-        resolve_item_baselines(tree, axis, items, inner_node_size);
+        resolve_item_baselines(tree, axis, other_axis_tracks, items, inner_node_size, &get_track_size_estimate, is_rtl);
     }
 
     // If all tracks have base_size = growth_limit, then skip the rest of this function.
@@ -424,73 +446,137 @@ fn initialize_track_sizes(axis_tracks: &mut [GridTrack], axis_inner_node_size: O
 }
 
 /// 11.5.1 Shim baseline-aligned items so their intrinsic size contributions reflect their baseline alignment.
+///
+/// The items that are baseline-aligned in `axis` (`justify_self` in the inline axis and `align_self` in the block axis)
+/// form a baseline-sharing group with the other baseline-aligned items that start in the same track. Each item in a
+/// group is given a shim on its start side so that the baselines of all of the items in the group line up.
+#[allow(clippy::too_many_arguments)]
 fn resolve_item_baselines(
     tree: &mut impl LayoutTree,
     axis: AbstractAxis,
+    other_axis_tracks: &[GridTrack],
     items: &mut [GridItem],
     inner_node_size: Size<Option<f32>>,
+    get_track_size_estimate: impl Fn(&GridTrack, Option<f32>) -> Option<f32>,
+    is_rtl: bool,
 ) {
-    // Sort items by track in the other axis (row) start position so that we can iterate items in groups which
-    // are in the same track in the other axis (row)
-    let other_axis = axis.other();
-    items.sort_by_key(|item| item.placement(other_axis).start);
-
-    // Iterate over grid rows
-    let mut remaining_items = &mut items[0..];
-    while !remaining_items.is_empty() {
-        // Get the row index of the current row
-        let current_row = remaining_items[0].placement(other_axis).start;
-
-        // Find the item index of the first item that is in a different row (or None if we've reached the end of the list)
-        let next_row_first_item =
-            remaining_items.iter().position(|item| item.placement(other_axis).start != current_row);
-
-        // Use this index to split the `remaining_items` slice in two slices:
-        //    - A `row_items` slice containing the items (that start) in the current row
-        //    - A new `remaining_items` consisting of the remainder of the `remaining_items` slice
-        //      that hasn't been split off into `row_items
-        let row_items = if let Some(index) = next_row_first_item {
-            let (row_items, tail) = remaining_items.split_at_mut(index);
-            remaining_items = tail;
-            row_items
-        } else {
-            let row_items = remaining_items;
-            remaining_items = &mut [];
-            row_items
-        };
-
-        // Count how many items in *this row* are baseline aligned
-        // If a row has one or zero items participating in baseline alignment then baseline alignment is a no-op
-        // for those items and we skip further computations for that row
-        let row_baseline_item_count = row_items.iter().filter(|item| item.align_self == AlignSelf::Baseline).count();
-        if row_baseline_item_count <= 1 {
-            continue;
-        }
-
-        // Compute the baselines of all items in the row
-        for item in row_items.iter_mut() {
-            let measured_size_and_baselines = tree.perform_child_layout(
-                item.node,
-                Size::NONE,
-                inner_node_size,
-                Size::MIN_CONTENT,
-                SizingMode::InherentSize,
-                Line::FALSE,
-            );
+    // First baseline aligned items share a baseline with the other items that start in the same track, and last
+    // baseline aligned items with the other items that end in the same track
+    for alignment in [AlignSelf::Baseline, AlignSelf::LastBaseline] {
+        let is_last_baseline = alignment == AlignSelf::LastBaseline;
+        let group_track =
+            |item: &GridItem| if is_last_baseline { item.placement(axis).end } else { item.placement(axis).start };
+
+        // Sort items by their start (or end) track in the axis so that we can iterate items in groups which share a track
+        items.sort_by_key(group_track);
+
+        // Iterate over grid tracks
+        let mut remaining_items = &mut items[0..];
+        while !remaining_items.is_empty() {
+            // Get the index of the current track
+            let current_track = group_track(&remaining_items[0]);
+
+            // Find the item index of the first item that is in a different track (or None if we've reached the end of the list)
+            let next_track_first_item = remaining_items.iter().position(|item| group_track(item) != current_track);
+
+            // Use this index to split the `remaining_items` slice in two slices:
+            //    - A `track_items` slice containing the items (that start or end) in the current track
+            //    - A new `remaining_items` consisting of the remainder of the `remaining_items` slice
+            //      that hasn't been split off into `track_items`
+            let track_items = if let Some(index) = next_track_first_item {
+                let (track_items, tail) = remaining_items.split_at_mut(index);
+                remaining_items = tail;
+                track_items
+            } else {
+                let track_items = remaining_items;
+                remaining_items = &mut [];
+                track_items
+            };
 
-            let baseline = measured_size_and_baselines.first_baselines.y;
-            let height = measured_size_and_baselines.size.height;
+            // Count how many items in *this track* are baseline aligned
+            // If a track has one or zero items participating in baseline alignment then baseline alignment is a no-op
+            // for those items and we skip further computations for that track
+            let is_baseline_aligned = |item: &GridItem| item.self_alignment(axis) == alignment;
+            let track_baseline_item_count = track_items.iter().filter(|item| is_baseline_aligned(item)).count();
+            if track_baseline_item_count <= 1 {
+                continue;
+            }
 
-            item.baseline = Some(baseline.unwrap_or(height) + item.margin.top.resolve_or_zero(inner_node_size.width));
-        }
+            // Compute the baselines of the baseline aligned items in the track. The items are sized against the
+            // (estimated) size of the tracks they span in the other axis.
+            for item in track_items.iter_mut().filter(|item| is_baseline_aligned(item)) {
+                let available_space = item.available_space(
+                    axis,
+                    other_axis_tracks,
+                    inner_node_size.get(axis.other()),
+                    &get_track_size_estimate,
+                );
+                let known_dimensions = item.known_dimensions(inner_node_size, available_space);
+                let measured_size_and_baselines = tree.perform_child_layout(
+                    item.node,
+                    known_dimensions,
+                    inner_node_size,
+                    available_space.map(|size| match size {
+                        Some(size) => AvailableSpace::Definite(size),
+                        None => AvailableSpace::MinContent,
+                    }),
+                    SizingMode::InherentSize,
+                    Line::FALSE,
+                );
 
-        // Compute the max baseline of all items in the row
-        let row_max_baseline =
-            row_items.iter().map(|item| item.baseline.unwrap_or(0.0)).max_by(|a, b| a.total_cmp(b)).unwrap();
+                let size = measured_size_and_baselines.size;
+                let margin = item.margin.map(|margin| margin.resolve_or_zero(inner_node_size.width));
+                let baselines = match is_last_baseline {
+                    true => measured_size_and_baselines.last_baselines,
+                    false => measured_size_and_baselines.first_baselines,
+                };
+                // First baselines are measured from the start margin edge of the item, and last baselines from its
+                // end margin edge
+                match axis {
+                    // Items without a vertical baseline (such as items with a horizontal writing mode) synthesize one
+                    // from their left (line-under) edge. Right-to-left grids are laid out mirrored, so the start margin
+                    // edge of the item is its right margin edge.
+                    AbstractAxis::Inline => {
+                        let baseline = baselines.x.unwrap_or(0.0);
+                        item.baseline.x = Some(match is_last_baseline != is_rtl {
+                            true => size.width - baseline + margin.right,
+                            false => baseline + margin.left,
+                        });
+                    }
+                    // Items without a horizontal baseline synthesize one from their bottom edge
+                    AbstractAxis::Block => {
+                        let baseline = baselines.y.unwrap_or(size.height);
+                        item.baseline.y = Some(match is_last_baseline {
+                            true => size.height - baseline + margin.bottom,
+                            false => baseline + margin.top,
+                        });
+                    }
+                }
+            }
 
-        // Compute the baseline shim for each item in the row
-        for item in row_items.iter_mut() {
-            item.baseline_shim = row_max_baseline - item.baseline.unwrap_or(0.0);
+            // Compute the max baseline of the baseline aligned items in the track
+            let item_baseline = |item: &GridItem| match axis {
+                AbstractAxis::Inline => item.baseline.x.unwrap_or(0.0),
+                AbstractAxis::Block => item.baseline.y.unwrap_or(0.0),
+            };
+            let track_max_baseline = track_items
+                .iter()
+                .filter(|item| is_baseline_aligned(item))
+                .map(item_baseline)
+                .max_by(|a, b| a.total_cmp(b))
+                .unwrap();
+
+            // Compute the baseline shim for each baseline aligned item in the track. First baseline shims act like an
+            // extra start margin and last baseline shims like an extra end margin.
+            for item in track_items.iter_mut().filter(|item| is_baseline_aligned(item)) {
+                let shim = track_max_baseline - item_baseline(item);
+                match (axis, is_last_baseline) {
+                    (AbstractAxis::Inline, false) => item.baseline_shim.left = shim,
+                    (AbstractAxis::Inline, true) => item.baseline_shim.right = shim,
+                    (AbstractAxis::Block, false) => item.baseline_shim.top = shim,
+                    (AbstractAxis::Block, true) => item.baseline_shim.bottom = shim,
+                }
+            }
         }
     }
 }
@@ -551,7 +637,7 @@ fn resolve_intrinsic_track_sizes(
                     }
                     // If the container size is indefinite and has not yet been resolved then percentage sized
                     // tracks should be treated as min-content (this matches Chrome's behaviour and seems sensible)
-                    MinTrackSizingFunction::Fixed(LengthPercentage::Percent(_)) => {
+                    MinTrackSizingFunction::Fixed(size) if size.uses_percentage() => {
                         if axis_inner_node_size.is_none() {
                             f32_max(track.base_size, item_sizer.min_content_contribution(item))
                         } else {
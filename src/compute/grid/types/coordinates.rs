@@ -85,6 +85,23 @@ impl Sub<u16> for OriginZeroLine {
 }
 
 impl OriginZeroLine {
+    /// The furthest that a line computed from a placement may be from the start of the explicit grid. Lines further
+    /// away are clamped to this distance, which is the limit that the spec suggests for overlarge grids.
+    /// <https://www.w3.org/TR/css-grid-1/#overlarge-grids>
+    pub(crate) const MAX_DISTANCE: i32 = 10_000;
+
+    /// Creates a line from an index computed in a wider type, clamping it to the supported range of lines
+    pub(crate) fn clamped(index: i32) -> Self {
+        OriginZeroLine(index.clamp(-Self::MAX_DISTANCE, Self::MAX_DISTANCE) as i16)
+    }
+
+    /// Creates a line from one of the lines of a [`GridTemplateArea`](crate::style::GridTemplateArea), which are CSS
+    /// Grid Line coordinates that can't be negative. The invalid line 0 is treated as line 1, and lines are clamped to
+    /// the supported range of lines.
+    pub(crate) fn from_area_line(line: u16) -> Self {
+        Self::clamped(i32::from(line.saturating_sub(1)))
+    }
+
     /// Converts a grid line in OriginZero coordinates into the index of that same grid line in the GridTrackVec.
     pub(crate) fn into_track_vec_index(self, track_counts: TrackCounts) -> usize {
         assert!(
@@ -98,6 +115,14 @@ impl OriginZeroLine {
         2 * ((self.0 + track_counts.negative_implicit as i16) as usize)
     }
 
+    /// Converts a grid line in OriginZero coordinates into the index of that same grid line in the GridTrackVec,
+    /// or None if the line lies outside of the grid (which absolutely positioned items may reference).
+    pub(crate) fn try_into_track_vec_index(self, track_counts: TrackCounts) -> Option<usize> {
+        let is_in_grid = self.0 >= -(track_counts.negative_implicit as i16)
+            && self.0 <= (track_counts.explicit + track_counts.positive_implicit) as i16;
+        is_in_grid.then(|| self.into_track_vec_index(track_counts))
+    }
+
     /// The minimum number of negative implicit track there must be if a grid item starts at this line.
     pub(crate) fn implied_negative_implicit_tracks(self) -> u16 {
         if self.0 < 0 {
@@ -1,12 +1,13 @@
 //! Contains GridItem used to represent a single grid item during layout
 use super::GridTrack;
+use crate::compute::common::intrinsic_size::resolve_intrinsic_sizes;
 use crate::compute::grid::OriginZeroLine;
-use crate::geometry::AbstractAxis;
+use crate::geometry::{AbsoluteAxis, AbstractAxis};
 use crate::geometry::{Line, Point, Rect, Size};
 use crate::prelude::LayoutTree;
 use crate::style::{
-    AlignItems, AlignSelf, AvailableSpace, Dimension, LengthPercentageAuto, MaxTrackSizingFunction,
-    MinTrackSizingFunction, Overflow, Style,
+    AlignItems, AlignSelf, AvailableSpace, BoxSizing, Dimension, LengthPercentage, LengthPercentageAuto,
+    MaxTrackSizingFunction, MinTrackSizingFunction, Overflow, Style,
 };
 use crate::tree::NodeId;
 use crate::tree::SizingMode;
@@ -41,19 +42,27 @@ pub(in super::super) struct GridItem {
     pub min_size: Size<Dimension>,
     /// The item's max_size style
     pub max_size: Size<Dimension>,
+    /// The item's box_sizing style
+    pub box_sizing: BoxSizing,
     /// The item's aspect_ratio style
     pub aspect_ratio: Option<f32>,
     /// The item's margin style
     pub margin: Rect<LengthPercentageAuto>,
+    /// The item's padding style
+    pub padding: Rect<LengthPercentage>,
+    /// The item's border style
+    pub border: Rect<LengthPercentage>,
     /// The item's align_self property, or the parent's align_items property is not set
     pub align_self: AlignSelf,
     /// The item's justify_self property, or the parent's justify_items property is not set
     pub justify_self: AlignSelf,
-    /// The items first baseline (horizontal)
-    pub baseline: Option<f32>,
-    /// Shim for baseline alignment that acts like an extra top margin
-    /// TODO: Support last baseline and vertical text baselines
-    pub baseline_shim: f32,
+    /// The distance from the start margin edge of the item to its first baseline (or, for items aligned by their
+    /// last baseline, from the end margin edge of the item to its last baseline) in each axis. This is only set in
+    /// the axes in which the item takes part in baseline alignment with other items.
+    pub baseline: Point<Option<f32>>,
+    /// Shims for baseline alignment that act like extra margins. First baseline alignment shims the start edges
+    /// (left and top) of the item and last baseline alignment shims its end edges (right and bottom).
+    pub baseline_shim: Rect<f32>,
 
     /// The item's definite row-start and row-end (same as `row` field, except in a different coordinate system)
     /// (as indexes into the Vec<GridTrack> stored in a grid's AbstractAxisTracks)
@@ -71,6 +80,21 @@ pub(in super::super) struct GridItem {
     /// Whether the item crosses a intrinsic column
     pub crosses_intrinsic_column: bool,
 
+    /// Whether the item is a subgrid in each axis (width = columns, height = rows)
+    pub is_subgrid: Size<bool>,
+    /// Whether the item's placement in each axis refers to the tracks of this grid. This is always true for the grid's
+    /// own children. For the children of subgrids it is only true in the axes in which the tracks are inherited.
+    pub is_placed_in_grid: Size<bool>,
+    /// Whether the item is a child of a subgrid rather than of the grid itself. Such items contribute to the sizing of
+    /// the grid's tracks, but are laid out by their subgrid.
+    pub is_subgrid_child: bool,
+    /// The sum of the margin, border and padding of any subgrids that the item sits at the edge of. These act as an
+    /// additional margin on that edge of the item when computing its contributions to track sizing.
+    pub subgrid_edge_offsets: Rect<f32>,
+    /// The start and end of the item's margin box in the rows of a masonry grid, relative to the start of the grid's
+    /// single row. Only set once the item has been placed by the masonry layout.
+    pub masonry_position: Option<Line<f32>>,
+
     // Caches for intrinsic size computation. These caches are only valid for a single run of the track-sizing algorithm.
     /// Cache for the known_dimensions input to intrinsic sizing computation
     pub available_space_cache: Option<Size<Option<f32>>>,
@@ -102,18 +126,29 @@ impl GridItem {
             size: style.size,
             min_size: style.min_size,
             max_size: style.max_size,
+            box_sizing: style.box_sizing,
             aspect_ratio: style.aspect_ratio,
             margin: style.margin,
+            padding: style.padding,
+            border: style.border,
             align_self: style.align_self.unwrap_or(parent_align_items),
             justify_self: style.justify_self.unwrap_or(parent_justify_items),
-            baseline: None,
-            baseline_shim: 0.0,
+            baseline: Point::NONE,
+            baseline_shim: Rect::ZERO,
             row_indexes: Line { start: 0, end: 0 }, // Properly initialised later
             column_indexes: Line { start: 0, end: 0 }, // Properly initialised later
             crosses_flexible_row: false,            // Properly initialised later
             crosses_flexible_column: false,         // Properly initialised later
             crosses_intrinsic_row: false,           // Properly initialised later
             crosses_intrinsic_column: false,        // Properly initialised later
+            is_subgrid: Size {
+                width: style.is_subgrid(AbsoluteAxis::Horizontal),
+                height: style.is_subgrid(AbsoluteAxis::Vertical),
+            },
+            is_placed_in_grid: Size { width: true, height: true },
+            is_subgrid_child: false,
+            subgrid_edge_offsets: Rect::ZERO,
+            masonry_position: None,
             available_space_cache: None,
             min_content_contribution_cache: Size::NONE,
             max_content_contribution_cache: Size::NONE,
@@ -129,6 +164,21 @@ impl GridItem {
         }
     }
 
+    /// The item's self-alignment in the specified axis (justify_self in the inline axis and align_self in the block axis)
+    pub fn self_alignment(&self, axis: AbstractAxis) -> AlignSelf {
+        match axis {
+            AbstractAxis::Inline => self.justify_self,
+            AbstractAxis::Block => self.align_self,
+        }
+    }
+
+    /// Whether the item's content contributes to the sizing of the grid's tracks in the specified axis. Subgrids don't
+    /// contribute in the axes they inherit tracks in (their children do instead), and the children of subgrids only
+    /// contribute in those axes.
+    pub fn contributes_to_track_sizing(&self, axis: AbstractAxis) -> bool {
+        self.is_placed_in_grid.get(axis) && !self.is_subgrid.get(axis)
+    }
+
     /// This item's placement in the specified axis as GridTrackVec indices
     pub fn placement_indexes(&self, axis: AbstractAxis) -> Line<u16> {
         match axis {
@@ -220,7 +270,7 @@ impl GridItem {
     /// Compute the known_dimensions to be passed to the child sizing functions
     /// The key thing that is being done here is applying stretch alignment, which is necessary to
     /// allow percentage sizes further down the tree to resolve properly in some cases
-    fn known_dimensions(
+    pub fn known_dimensions(
         &self,
         inner_node_size: Size<Option<f32>>,
         grid_area_size: Size<Option<f32>>,
@@ -228,9 +278,22 @@ impl GridItem {
         let margins = self.margins_axis_sums_with_baseline_shims(inner_node_size.width);
 
         let aspect_ratio = self.aspect_ratio;
-        let inherent_size = self.size.maybe_resolve(grid_area_size).maybe_apply_aspect_ratio(aspect_ratio);
-        let min_size = self.min_size.maybe_resolve(grid_area_size).maybe_apply_aspect_ratio(aspect_ratio);
-        let max_size = self.max_size.maybe_resolve(grid_area_size).maybe_apply_aspect_ratio(aspect_ratio);
+        let box_sizing_adjustment = self.box_sizing_adjustment(grid_area_size.width);
+        let inherent_size = self
+            .size
+            .maybe_resolve(grid_area_size)
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment);
+        let min_size = self
+            .min_size
+            .maybe_resolve(grid_area_size)
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment);
+        let max_size = self
+            .max_size
+            .maybe_resolve(grid_area_size)
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment);
 
         let grid_area_minus_item_margins_size = grid_area_size.maybe_sub(margins);
 
@@ -251,8 +314,8 @@ impl GridItem {
             None
         });
         // Reapply aspect ratio after stretch and absolute position width adjustments
-        let Size { width, height } =
-            Size { width, height: inherent_size.height }.maybe_apply_aspect_ratio(aspect_ratio);
+        let Size { width, height } = Size { width, height: inherent_size.height }
+            .maybe_apply_aspect_ratio_with_adjustment(aspect_ratio, box_sizing_adjustment);
 
         let height = height.or_else(|| {
             // Apply height based on stretch alignment if:
@@ -269,7 +332,8 @@ impl GridItem {
             None
         });
         // Reapply aspect ratio after stretch and absolute position height adjustments
-        let Size { width, height } = Size { width, height }.maybe_apply_aspect_ratio(aspect_ratio);
+        let Size { width, height } =
+            Size { width, height }.maybe_apply_aspect_ratio_with_adjustment(aspect_ratio, box_sizing_adjustment);
 
         // Clamp size by min and max width/height
         let Size { width, height } = Size { width, height }.maybe_clamp(min_size, max_size);
@@ -323,13 +387,65 @@ impl GridItem {
     /// to zero if the container size is indefinite as otherwise this would introduce a cyclic dependency.
     #[inline(always)]
     pub fn margins_axis_sums_with_baseline_shims(&self, inner_node_width: Option<f32>) -> Size<f32> {
-        Rect {
+        let margins = Rect {
             left: self.margin.left.resolve_or_zero(Some(0.0)),
             right: self.margin.right.resolve_or_zero(Some(0.0)),
-            top: self.margin.top.resolve_or_zero(inner_node_width) + self.baseline_shim,
+            top: self.margin.top.resolve_or_zero(inner_node_width),
             bottom: self.margin.bottom.resolve_or_zero(inner_node_width),
+        } + self.baseline_shim;
+        (margins + self.subgrid_edge_offsets).sum_axes()
+    }
+
+    /// Replaces the intrinsic sizing keywords in the item's size styles with the lengths that they resolve to when the
+    /// item is measured. This is done against the size of the grid container, as the item's grid area isn't known
+    /// until the tracks have been sized.
+    pub fn resolve_intrinsic_sizes(&mut self, tree: &mut impl LayoutTree, inner_node_size: Size<Option<f32>>) {
+        let styles = [&self.size, &self.min_size, &self.max_size];
+        if !styles.iter().any(|style| style.width.is_intrinsic() || style.height.is_intrinsic()) {
+            return;
         }
-        .sum_axes()
+
+        let box_sizing_adjustment = self.box_sizing_adjustment(inner_node_size.width);
+        let [mut size, mut min_size, mut max_size] = styles.map(|style| {
+            style
+                .maybe_resolve(inner_node_size)
+                .maybe_apply_aspect_ratio(self.aspect_ratio)
+                .maybe_add(box_sizing_adjustment)
+        });
+        let margin_sum = self.margin.resolve_or_zero(inner_node_size.width).sum_axes();
+        resolve_intrinsic_sizes(
+            tree,
+            self.node,
+            inner_node_size,
+            inner_node_size.maybe_sub(margin_sum),
+            &mut size,
+            &mut min_size,
+            &mut max_size,
+        );
+
+        // The resolved sizes are border-box sizes, so the box-sizing adjustment that is added to the size styles
+        // when they're resolved is taken back off
+        let to_dimension = |value: Option<f32>, adjustment: f32| match value {
+            Some(value) => Dimension::Length(value - adjustment),
+            None => Dimension::Auto,
+        };
+        for (style, resolved) in
+            [&mut self.size, &mut self.min_size, &mut self.max_size].into_iter().zip([size, min_size, max_size])
+        {
+            if style.width.is_intrinsic() {
+                style.width = to_dimension(resolved.width, box_sizing_adjustment.width);
+            }
+            if style.height.is_intrinsic() {
+                style.height = to_dimension(resolved.height, box_sizing_adjustment.height);
+            }
+        }
+    }
+
+    /// The amount that must be added to the item's resolved size styles to get border-box sizes
+    pub fn box_sizing_adjustment(&self, inner_node_width: Option<f32>) -> Size<f32> {
+        let padding_border =
+            self.padding.resolve_or_zero(inner_node_width) + self.border.resolve_or_zero(inner_node_width);
+        self.box_sizing.adjustment(padding_border.sum_axes())
     }
 
     /// Compute the item's min content contribution from the provided parameters
@@ -340,6 +456,9 @@ impl GridItem {
         available_space: Size<Option<f32>>,
         inner_node_size: Size<Option<f32>>,
     ) -> f32 {
+        if !self.contributes_to_track_sizing(axis) {
+            return 0.0;
+        }
         let known_dimensions = self.known_dimensions(inner_node_size, available_space);
         tree.measure_child_size(
             self.node,
@@ -379,6 +498,9 @@ impl GridItem {
         available_space: Size<Option<f32>>,
         inner_node_size: Size<Option<f32>>,
     ) -> f32 {
+        if !self.contributes_to_track_sizing(axis) {
+            return 0.0;
+        }
         let known_dimensions = self.known_dimensions(inner_node_size, available_space);
         tree.measure_child_size(
             self.node,
@@ -425,13 +547,22 @@ impl GridItem {
         known_dimensions: Size<Option<f32>>,
         inner_node_size: Size<Option<f32>>,
     ) -> f32 {
+        if !self.contributes_to_track_sizing(axis) {
+            return 0.0;
+        }
+        let box_sizing_adjustment = self.box_sizing_adjustment(inner_node_size.width);
         let size = self
             .size
             .maybe_resolve(inner_node_size)
             .maybe_apply_aspect_ratio(self.aspect_ratio)
+            .maybe_add(box_sizing_adjustment)
             .get(axis)
             .or_else(|| {
-                self.min_size.maybe_resolve(inner_node_size).maybe_apply_aspect_ratio(self.aspect_ratio).get(axis)
+                self.min_size
+                    .maybe_resolve(inner_node_size)
+                    .maybe_apply_aspect_ratio(self.aspect_ratio)
+                    .maybe_add(box_sizing_adjustment)
+                    .get(axis)
             })
             .or_else(|| self.overflow.get(axis).maybe_into_automatic_min_size())
             .unwrap_or_else(|| {
@@ -2,6 +2,7 @@
 use crate::{
     style::{LengthPercentage, MaxTrackSizingFunction, MinTrackSizingFunction},
     util::sys::f32_min,
+    util::MaybeResolve,
 };
 
 /// Whether a GridTrack represents an actual track or a gutter.
@@ -127,12 +128,8 @@ impl GridTrack {
     /// Returns true if the track is flexible (has a Flex MaxTrackSizingFunction), else false.
     pub fn fit_content_limit(&self, axis_available_grid_space: Option<f32>) -> f32 {
         match self.max_track_sizing_function {
-            MaxTrackSizingFunction::FitContent(LengthPercentage::Length(limit)) => limit,
-            MaxTrackSizingFunction::FitContent(LengthPercentage::Percent(fraction)) => {
-                match axis_available_grid_space {
-                    Some(space) => space * fraction,
-                    None => f32::INFINITY,
-                }
+            MaxTrackSizingFunction::FitContent(limit) => {
+                limit.maybe_resolve(axis_available_grid_space).unwrap_or(f32::INFINITY)
             }
             _ => f32::INFINITY,
         }
@@ -0,0 +1,600 @@
+//! Computes the layout of the inline-level children of a block container, which are placed side by side on lines
+//! (an [inline formatting context](https://www.w3.org/TR/CSS22/visuren.html#inline-formatting))
+//!
+//! Each run of consecutive inline-level children of a block container is broken into atoms, which are placed on lines
+//! that are stacked vertically in place of the run. An atom is either an atomic inline-level box (such as an
+//! inline-block), a fragment of a leaf node that its measure function has split into fragments (such as a word of a
+//! run of text), or an edge of an inline box. The contents of inline boxes (inline nodes that aren't atomic) are placed
+//! on the lines as if they were siblings of the inline box, which is then sized to fit them.
+use crate::compute::common::contents::{layout_children, perform_contents_layout};
+use crate::compute::common::intrinsic_size::resolve_intrinsic_sizes;
+use crate::compute::float::{is_floated, FloatContext};
+use crate::geometry::{Line, Point, Rect, Size};
+use crate::style::{AvailableSpace, Display, Position, Style, TextAlign};
+use crate::style_helpers::TaffyMaxContent;
+use crate::tree::{Layout, LayoutTree, NodeId, SizeBaselinesAndMargins, SizingMode};
+use crate::util::sys::{f32_max, f32_min, Vec};
+use crate::util::MaybeMath;
+use crate::util::{MaybeResolve, ResolveOrZero};
+
+/// Whether a child of a block container with the specified style is placed on lines rather than stacked vertically
+#[inline]
+pub(crate) fn is_inline_level(style: &Style) -> bool {
+    matches!(style.display, Display::Inline | Display::InlineBlock)
+        && !style.position.is_out_of_flow()
+        && !is_floated(style)
+}
+
+/// Whether `child` is an inline box whose children are placed on the lines of its parent `parent` (if `parent` is a
+/// block container) or on the lines that `parent` is itself placed on (if `parent` is an inline box)
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[inline]
+pub(crate) fn is_inline_box_of(parent: &Style, child: &Style) -> bool {
+    matches!(parent.display, Display::Block | Display::Inline | Display::InlineBlock)
+        && child.display == Display::Inline
+        && !child.position.is_out_of_flow()
+        && !is_floated(child)
+}
+
+/// The result of laying out a run of inline-level children
+pub(crate) struct InlineLayoutOutput {
+    /// The total height of the lines
+    pub height: f32,
+    /// The distance from the top of the first line to its baseline
+    pub first_baseline: f32,
+    /// The distance from the top of the first line to the baseline of the last line
+    pub last_baseline: f32,
+    /// Whether the lines contain nothing other than the edges of inline boxes that have no margin, border or padding.
+    /// Such lines are treated as if they didn't exist.
+    pub is_empty: bool,
+}
+
+/// An inline-level node whose content is placed on lines
+struct InlineItem {
+    /// The id of the node
+    node_id: NodeId,
+    /// The order of the node within its parent
+    order: u32,
+    /// The index of the inline box that contains this item, or `None` if the item is a child of the block container
+    parent: Option<usize>,
+    /// Whether the item should be painted
+    is_visible: bool,
+    /// Whether the item is an inline box (whose content is placed on the lines as separate atoms) rather than an atomic box
+    is_inline_box: bool,
+    /// Whether the item is a leaf node that has been split into fragments
+    is_fragmented: bool,
+    /// The resolved margin of the item. Auto margins resolve to zero.
+    margin: Rect<f32>,
+    /// The resolved padding and border of the item
+    padding_border: Rect<f32>,
+    /// The bounding box of the atoms that belong to the item (or to the items it contains), in the coordinates of the
+    /// content box of the block container. Set once the atoms have been placed on lines.
+    bounds: Option<Rect<f32>>,
+    /// The border box size of the item
+    size: Size<f32>,
+    /// The scrollable overflow of an atomic item, as computed by its layout algorithm. This is zero for inline boxes,
+    /// whose size already covers their content.
+    content_size: Size<f32>,
+    /// The location of the border box of the item, in the coordinates of the content box of the block container
+    location: Point<f32>,
+}
+
+/// The kind of content that an [`InlineAtom`] represents
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum AtomKind {
+    /// An atomic inline-level box, such as an inline-block
+    Box,
+    /// A fragment of a leaf node that has been split by its measure function, such as a word
+    Fragment,
+    /// The inline-start margin, border and padding of an inline box
+    StartEdge,
+    /// The inline-end margin, border and padding of an inline box
+    EndEdge,
+}
+
+/// A piece of content that is placed on a single line
+struct InlineAtom {
+    /// The index of the item that the atom belongs to
+    item: usize,
+    /// What the atom represents
+    kind: AtomKind,
+    /// The size of the atom. This includes the margins of atomic boxes.
+    size: Size<f32>,
+    /// The distance from the top of the atom to its baseline
+    baseline: f32,
+    /// Whether the line may be broken before this atom
+    can_break_before: bool,
+    /// The location of the atom in the coordinates of the content box of the block container. Set once the atom has
+    /// been placed on a line.
+    location: Point<f32>,
+}
+
+/// Compute the width of the widest line that the inline-level nodes `nodes` would be placed on, given the available
+/// width of the content box of their block container
+pub(crate) fn compute_inline_content_width(
+    tree: &mut impl LayoutTree,
+    nodes: &[(NodeId, u32)],
+    available_width: AvailableSpace,
+) -> f32 {
+    let (_, atoms) = generate_atoms(tree, nodes, available_width, None, false);
+    let line_starts = break_lines(&atoms, available_width);
+    lines(&atoms, &line_starts).map(|line| line.iter().map(|atom| atom.size.width).sum()).fold(0.0, f32_max)
+}
+
+/// Place the inline-level nodes `nodes` on lines that are as wide as the content box of their block container, and set
+/// the layouts of the nodes and of their descendants. `content_box_location` is the location of the top of the lines
+/// within the block container. Lines are shortened (or moved down) to avoid the floats of the container in `floats`.
+///
+/// The lines are always laid out from left to right. If the block container is right-to-left then it is expected to
+/// mirror the horizontal positions of `nodes` within itself afterwards: the contents of inline boxes and the fragments
+/// of leaf nodes are mirrored within their parents here.
+pub(crate) fn perform_inline_layout(
+    tree: &mut impl LayoutTree,
+    nodes: &[(NodeId, u32)],
+    container_inner_width: f32,
+    content_box_location: Point<f32>,
+    floats: &FloatContext,
+    text_align: TextAlign,
+    is_rtl: bool,
+) -> InlineLayoutOutput {
+    let available_width = AvailableSpace::Definite(container_inner_width);
+    let (mut items, mut atoms) = generate_atoms(tree, nodes, available_width, Some(container_inner_width), true);
+
+    // Resolve the text alignment as if the container were left-to-right, as it will be mirrored if it isn't
+    let text_align = match text_align {
+        TextAlign::Left if is_rtl => TextAlign::End,
+        TextAlign::Right if !is_rtl => TextAlign::End,
+        TextAlign::Left | TextAlign::Right => TextAlign::Start,
+        text_align => text_align,
+    };
+
+    // Break the atoms into lines and place them on the lines
+    let mut line_top = 0.0;
+    let mut line_start = 0;
+    let mut first_baseline = None;
+    let mut last_baseline = 0.0;
+    while line_start < atoms.len() {
+        // Each line fills the space beside the floats at its top, or is moved down below the floats if there isn't
+        // room beside them for its first unbreakable sequence of atoms
+        let first_segment_width: f32 = atoms[line_start..]
+            .iter()
+            .enumerate()
+            .take_while(|(index, atom)| *index == 0 || !atom.can_break_before)
+            .map(|(_, atom)| atom.size.width)
+            .sum();
+        let (top, space) = floats.find_space(content_box_location.y + line_top, first_segment_width, 0.0);
+        line_top = top - content_box_location.y;
+        let line_offset = space.start - content_box_location.x;
+        let line_max_width = space.end - space.start;
+
+        let line_end = find_line_end(&atoms, line_start, line_max_width);
+        let is_last_line = line_end == atoms.len();
+        let line = &mut atoms[line_start..line_end];
+        line_start = line_end;
+
+        // The atoms in each line are aligned by their baselines
+        let ascent = line.iter().map(|atom| atom.baseline).fold(0.0, f32_max);
+        let descent = line.iter().map(|atom| atom.size.height - atom.baseline).fold(0.0, f32_max);
+        first_baseline.get_or_insert(line_top + ascent);
+        last_baseline = line_top + ascent;
+
+        let line_width: f32 = line.iter().map(|atom| atom.size.width).sum();
+        let free_space = f32_max(0.0, line_max_width - line_width);
+        let break_opportunity_count = line.iter().skip(1).filter(|atom| atom.can_break_before).count();
+        let (start_offset, justification_gap) = match text_align {
+            TextAlign::Justify if !is_last_line && break_opportunity_count > 0 => {
+                (0.0, free_space / break_opportunity_count as f32)
+            }
+            TextAlign::End => (free_space, 0.0),
+            TextAlign::Center => (free_space / 2.0, 0.0),
+            _ => (0.0, 0.0),
+        };
+
+        let mut x = line_offset + start_offset;
+        for (index, atom) in line.iter_mut().enumerate() {
+            if index > 0 && atom.can_break_before {
+                x += justification_gap;
+            }
+            atom.location = Point { x, y: line_top + ascent - atom.baseline };
+            x += atom.size.width;
+        }
+
+        line_top += ascent + descent;
+    }
+
+    let is_empty = atoms.iter().all(|atom| {
+        matches!(atom.kind, AtomKind::StartEdge | AtomKind::EndEdge)
+            && atom.size.width == 0.0
+            && atom.size.height == 0.0
+    });
+
+    // Determine the final position and size of each item from the atoms that belong to it
+    for atom in atoms.iter() {
+        let item = &mut items[atom.item];
+        let atom_rect = match atom.kind {
+            AtomKind::Box => {
+                item.location = Point { x: atom.location.x + item.margin.left, y: atom.location.y + item.margin.top };
+                margin_box_rect(atom)
+            }
+            AtomKind::Fragment => margin_box_rect(atom),
+            // The margins of inline boxes are not part of their border box
+            AtomKind::StartEdge => Rect {
+                left: atom.location.x + item.margin.left,
+                right: atom.location.x + atom.size.width,
+                top: atom.location.y,
+                bottom: atom.location.y,
+            },
+            AtomKind::EndEdge => Rect {
+                left: atom.location.x,
+                right: atom.location.x + atom.size.width - item.margin.right,
+                top: atom.location.y,
+                bottom: atom.location.y,
+            },
+        };
+
+        // Inline boxes contain the atoms of their descendants
+        let mut ancestor = Some(atom.item);
+        while let Some(index) = ancestor {
+            let item = &mut items[index];
+            item.bounds = Some(match item.bounds {
+                Some(bounds) => Rect {
+                    left: f32_min(bounds.left, atom_rect.left),
+                    right: f32_max(bounds.right, atom_rect.right),
+                    top: f32_min(bounds.top, atom_rect.top),
+                    bottom: f32_max(bounds.bottom, atom_rect.bottom),
+                },
+                None => atom_rect,
+            });
+            ancestor = item.parent;
+        }
+    }
+    for item in items.iter_mut().filter(|item| item.is_inline_box) {
+        // Only the content of an inline box affects the height of the lines. Its vertical padding and border overflow them.
+        let bounds = item.bounds.unwrap_or(Rect::zero());
+        item.location = Point { x: bounds.left, y: bounds.top - item.padding_border.top };
+        item.size = Size {
+            width: bounds.right - bounds.left,
+            height: bounds.bottom - bounds.top + item.padding_border.vertical_axis_sum(),
+        };
+    }
+
+    // Set the layouts of the items, relative to their parents
+    for index in 0..items.len() {
+        let item = &items[index];
+        let location = match item.parent {
+            Some(parent_index) => {
+                let parent = &items[parent_index];
+                let x = item.location.x - parent.location.x;
+                Point {
+                    x: if is_rtl { parent.size.width - x - item.size.width } else { x },
+                    y: item.location.y - parent.location.y,
+                }
+            }
+            None => Point { x: content_box_location.x + item.location.x, y: content_box_location.y + item.location.y },
+        };
+        *tree.layout_mut(item.node_id) = Layout {
+            order: item.order,
+            size: item.size,
+            location,
+            is_visible: item.is_visible,
+            content_size: item.content_size,
+        };
+
+        if item.is_fragmented {
+            let fragment_layouts = atoms
+                .iter()
+                .filter(|atom| atom.item == index && atom.kind == AtomKind::Fragment)
+                .enumerate()
+                .map(|(order, atom)| {
+                    let x = atom.location.x - item.location.x;
+                    Layout {
+                        order: order as u32,
+                        size: atom.size,
+                        is_visible: item.is_visible,
+                        content_size: Size::ZERO,
+                        location: Point {
+                            x: if is_rtl { item.size.width - x - atom.size.width } else { x },
+                            y: atom.location.y - item.location.y,
+                        },
+                    }
+                })
+                .collect();
+            tree.set_fragment_layouts(item.node_id, fragment_layouts);
+        }
+    }
+
+    // Hide the hidden children of inline boxes
+    for item in items.iter().filter(|item| item.is_inline_box && !item.is_fragmented) {
+        for (order, child) in layout_children(tree, item.node_id).into_iter().enumerate() {
+            if tree.style(child).display == Display::None {
+                *tree.layout_mut(child) = Layout::with_order(order as u32);
+                tree.perform_child_layout(
+                    child,
+                    Size::NONE,
+                    Size::NONE,
+                    Size::MAX_CONTENT,
+                    SizingMode::InherentSize,
+                    Line::FALSE,
+                );
+            }
+        }
+        perform_contents_layout(tree, item.node_id);
+    }
+
+    InlineLayoutOutput { height: line_top, first_baseline: first_baseline.unwrap_or(0.0), last_baseline, is_empty }
+}
+
+/// The rectangle covered by an atom
+#[inline]
+fn margin_box_rect(atom: &InlineAtom) -> Rect<f32> {
+    Rect {
+        left: atom.location.x,
+        right: atom.location.x + atom.size.width,
+        top: atom.location.y,
+        bottom: atom.location.y + atom.size.height,
+    }
+}
+
+/// Iterate over the atoms of each line, given the index of the first atom of each line
+#[inline]
+fn lines<'a>(atoms: &'a [InlineAtom], line_starts: &'a [usize]) -> impl Iterator<Item = &'a [InlineAtom]> {
+    line_starts.iter().enumerate().map(move |(index, &start)| {
+        let end = line_starts.get(index + 1).copied().unwrap_or(atoms.len());
+        &atoms[start..end]
+    })
+}
+
+/// Break the atoms into lines that fit within the available width where possible, returning the index of the first atom
+/// of each line
+fn break_lines(atoms: &[InlineAtom], available_width: AvailableSpace) -> Vec<usize> {
+    let max_line_width = match available_width {
+        AvailableSpace::Definite(width) => width,
+        AvailableSpace::MinContent => 0.0,
+        AvailableSpace::MaxContent => f32::INFINITY,
+    };
+
+    let mut line_starts = Vec::new();
+    let mut line_start = 0;
+    while line_start < atoms.len() {
+        line_starts.push(line_start);
+        line_start = find_line_end(atoms, line_start, max_line_width);
+    }
+    line_starts
+}
+
+/// Find the index of the atom after the last atom of the line that starts with the atom at index `line_start`. A line
+/// is broken before an atom if it doesn't fit on the line and a break is allowed before it.
+fn find_line_end(atoms: &[InlineAtom], line_start: usize, max_line_width: f32) -> usize {
+    let mut line_width = 0.0;
+    for (index, atom) in atoms.iter().enumerate().skip(line_start) {
+        if index > line_start && atom.can_break_before && line_width + atom.size.width > max_line_width {
+            return index;
+        }
+        line_width += atom.size.width;
+    }
+    atoms.len()
+}
+
+/// Generate the items and atoms for the inline-level nodes `nodes` (along with their order within their block
+/// container), sizing each atomic inline-level box. If `perform_layout` is true then atomic boxes are fully laid out.
+fn generate_atoms(
+    tree: &mut impl LayoutTree,
+    nodes: &[(NodeId, u32)],
+    available_width: AvailableSpace,
+    container_inner_width: Option<f32>,
+    perform_layout: bool,
+) -> (Vec<InlineItem>, Vec<InlineAtom>) {
+    let mut generator = AtomGenerator {
+        items: Vec::new(),
+        atoms: Vec::new(),
+        available_width,
+        container_inner_width,
+        perform_layout,
+        is_break_allowed: true,
+    };
+    for &(node_id, order) in nodes {
+        generator.push_item(tree, node_id, order, None);
+    }
+    (generator.items, generator.atoms)
+}
+
+/// Accumulates the items and atoms of a run of inline-level nodes
+struct AtomGenerator {
+    /// The items generated so far
+    items: Vec<InlineItem>,
+    /// The atoms generated so far, in the order in which they are placed on lines
+    atoms: Vec<InlineAtom>,
+    /// The width available to the content box of the block container
+    available_width: AvailableSpace,
+    /// The width of the content box of the block container, if known. Percentages are resolved against it.
+    container_inner_width: Option<f32>,
+    /// Whether to fully lay out atomic boxes rather than just measuring them
+    perform_layout: bool,
+    /// Whether a line may be broken before the next atom. Lines are never broken between the inline-start edge of
+    /// an inline box and its first atom.
+    is_break_allowed: bool,
+}
+
+impl AtomGenerator {
+    /// Generate the item for `node_id`, along with the atoms for its content
+    fn push_item(&mut self, tree: &mut impl LayoutTree, node_id: NodeId, order: u32, parent: Option<usize>) {
+        let style = tree.style(node_id);
+        let margin = style.margin.resolve_or_zero(self.container_inner_width);
+        let padding_border = style.padding.resolve_or_zero(self.container_inner_width)
+            + style.border.resolve_or_zero(self.container_inner_width);
+        let is_inline_box = style.display == Display::Inline && !style.position.is_out_of_flow() && !is_floated(style);
+        let is_visible = style.visibility.is_visible();
+
+        let index = self.items.len();
+        self.items.push(InlineItem {
+            node_id,
+            order,
+            parent,
+            is_visible,
+            is_inline_box,
+            is_fragmented: false,
+            margin,
+            padding_border,
+            bounds: None,
+            size: Size::ZERO,
+            content_size: Size::ZERO,
+            location: Point::ZERO,
+        });
+
+        // An inline leaf node is split into fragments if its measure function supports it. Otherwise it is atomic.
+        let fragments = match is_inline_box && tree.child_count(node_id) == 0 {
+            true => {
+                let available_space = Size { width: self.available_width, height: AvailableSpace::MaxContent };
+                tree.measure_child_fragments(node_id, available_space)
+            }
+            false => None,
+        };
+
+        if is_inline_box && (fragments.is_some() || tree.child_count(node_id) > 0) {
+            self.push_atom(
+                index,
+                AtomKind::StartEdge,
+                Size { width: margin.left + padding_border.left, height: 0.0 },
+                0.0,
+            );
+            match fragments {
+                Some(fragments) => {
+                    self.items[index].is_fragmented = true;
+                    for size in fragments {
+                        self.push_atom(index, AtomKind::Fragment, size, size.height);
+                    }
+                }
+                None => {
+                    // Absolutely positioned and floated children of inline boxes are not supported: they are placed
+                    // on the lines as atomic boxes. Fixed children are laid out separately.
+                    let children = layout_children(tree, node_id);
+                    for (order, child) in children.into_iter().enumerate() {
+                        let child_style = tree.style(child);
+                        if child_style.display != Display::None && child_style.position != Position::Fixed {
+                            self.push_item(tree, child, order as u32, Some(index));
+                        }
+                    }
+                }
+            }
+            self.push_atom(
+                index,
+                AtomKind::EndEdge,
+                Size { width: padding_border.right + margin.right, height: 0.0 },
+                0.0,
+            );
+        } else {
+            self.items[index].is_inline_box = false;
+            let size_and_baselines = compute_shrink_to_fit_size(
+                tree,
+                node_id,
+                self.container_inner_width,
+                self.available_width,
+                margin,
+                self.perform_layout,
+            );
+            let size = size_and_baselines.size;
+            self.items[index].size = size;
+            self.items[index].content_size = size_and_baselines.content_size;
+
+            // An atomic box without a baseline is aligned by the bottom of its margin box
+            let baseline = margin.top + size_and_baselines.first_baselines.y.unwrap_or(size.height + margin.bottom);
+            let margin_box_size = Size {
+                width: size.width + margin.horizontal_axis_sum(),
+                height: size.height + margin.vertical_axis_sum(),
+            };
+            self.push_atom(index, AtomKind::Box, margin_box_size, baseline);
+        }
+    }
+
+    /// Append an atom
+    fn push_atom(&mut self, item: usize, kind: AtomKind, size: Size<f32>, baseline: f32) {
+        let can_break_before = self.is_break_allowed && kind != AtomKind::EndEdge;
+        self.is_break_allowed = kind != AtomKind::StartEdge;
+        self.atoms.push(InlineAtom { item, kind, size, baseline, can_break_before, location: Point::ZERO });
+    }
+}
+
+/// Size a node that shrinks to fit its content within the available width unless its width is specified, such as an
+/// atomic inline-level box or a float. `available_width` is the width available to the content box of its block container
+/// (of width `container_inner_width`, if known). If `perform_layout` is true then the node is fully laid out.
+pub(crate) fn compute_shrink_to_fit_size(
+    tree: &mut impl LayoutTree,
+    node_id: NodeId,
+    container_inner_width: Option<f32>,
+    available_width: AvailableSpace,
+    margin: Rect<f32>,
+    perform_layout: bool,
+) -> SizeBaselinesAndMargins {
+    let style = tree.style(node_id);
+    let parent_size = Size { width: container_inner_width, height: None };
+    let aspect_ratio = style.aspect_ratio;
+    let padding = style.padding.resolve_or_zero(container_inner_width);
+    let border = style.border.resolve_or_zero(container_inner_width);
+    let box_sizing_adjustment = style.box_sizing.adjustment((padding + border).sum_axes());
+    let mut size =
+        style.size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio).maybe_add(box_sizing_adjustment);
+    let mut min_size = style
+        .min_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let mut max_size = style
+        .max_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let available_size = Size { width: available_width.into_option(), height: None };
+    let available_size = available_size.maybe_sub(margin.sum_axes());
+    resolve_intrinsic_sizes(tree, node_id, parent_size, available_size, &mut size, &mut min_size, &mut max_size);
+
+    let mut known_dimensions = size.maybe_clamp(min_size, max_size);
+    let available_space =
+        Size { width: available_width.maybe_sub(margin.horizontal_axis_sum()), height: AvailableSpace::MinContent };
+
+    if known_dimensions.width.is_none() {
+        let mut measure_width = |width: AvailableSpace| {
+            tree.measure_child_size(
+                node_id,
+                known_dimensions,
+                parent_size,
+                Size { width, ..available_space },
+                SizingMode::InherentSize,
+                Line::FALSE,
+            )
+            .width
+        };
+        let width = match available_space.width {
+            AvailableSpace::Definite(available_width) => {
+                let max_content_width = measure_width(AvailableSpace::MaxContent);
+                if max_content_width <= available_width {
+                    max_content_width
+                } else {
+                    f32_max(measure_width(AvailableSpace::MinContent), available_width)
+                }
+            }
+            available_width => measure_width(available_width),
+        };
+        known_dimensions.width = Some(width.maybe_clamp(min_size.width, max_size.width));
+    }
+
+    match perform_layout {
+        true => tree.perform_child_layout(
+            node_id,
+            known_dimensions,
+            parent_size,
+            available_space,
+            SizingMode::InherentSize,
+            Line::FALSE,
+        ),
+        false => tree
+            .measure_child_size(
+                node_id,
+                known_dimensions,
+                parent_size,
+                available_space,
+                SizingMode::InherentSize,
+                Line::FALSE,
+            )
+            .into(),
+    }
+}
@@ -1,7 +1,7 @@
 //! Computes size using styles and measure functions
 
 use crate::geometry::{Line, Point, Size};
-use crate::style::{AvailableSpace, Display, Overflow, Position, Style};
+use crate::style::{AvailableSpace, Display, Style};
 use crate::tree::{CollapsibleMarginSet, Measurable};
 use crate::tree::{SizeBaselinesAndMargins, SizingMode};
 use crate::util::sys::f32_max;
@@ -46,6 +46,13 @@ pub fn compute(
     available_space: Size<AvailableSpace>,
     sizing_mode: SizingMode,
 ) -> SizeBaselinesAndMargins {
+    // Note: both horizontal and vertical percentage padding/borders are resolved against the container's inline size (i.e. width).
+    // This is not a bug, but is how CSS is specified (see: https://developer.mozilla.org/en-US/docs/Web/CSS/padding#values)
+    let padding = style.padding.resolve_or_zero(parent_size.width);
+    let border = style.border.resolve_or_zero(parent_size.width);
+    let padding_border = padding + border;
+    let box_sizing_adjustment = style.box_sizing.adjustment(padding_border.sum_axes());
+
     // Resolve node's preferred/min/max sizes (width/heights) against the available space (percentages resolve to pixel values)
     // For ContentSize mode, we pretend that the node has no size styles as these should be ignored.
     let (node_size, node_min_size, node_max_size, aspect_ratio) = match sizing_mode {
@@ -57,32 +64,25 @@ pub fn compute(
         }
         SizingMode::InherentSize => {
             let aspect_ratio = style.aspect_ratio;
-            let style_size = style.size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
-            let style_min_size = style.min_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
-            let style_max_size = style.max_size.maybe_resolve(parent_size);
+            let style_size = style
+                .size
+                .maybe_resolve(parent_size)
+                .maybe_apply_aspect_ratio(aspect_ratio)
+                .maybe_add(box_sizing_adjustment);
+            let style_min_size = style
+                .min_size
+                .maybe_resolve(parent_size)
+                .maybe_apply_aspect_ratio(aspect_ratio)
+                .maybe_add(box_sizing_adjustment);
+            let style_max_size = style.max_size.maybe_resolve(parent_size).maybe_add(box_sizing_adjustment);
 
             let node_size = known_dimensions.or(style_size);
             (node_size, style_min_size, style_max_size, aspect_ratio)
         }
     };
 
-    // Note: both horizontal and vertical percentage padding/borders are resolved against the container's inline size (i.e. width).
-    // This is not a bug, but is how CSS is specified (see: https://developer.mozilla.org/en-US/docs/Web/CSS/padding#values)
-    let padding = style.padding.resolve_or_zero(parent_size.width);
-    let border = style.border.resolve_or_zero(parent_size.width);
-    let padding_border = padding + border;
-
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = style.overflow.transpose().map(|overflow| match overflow {
-        Overflow::Scroll => style.scrollbar_width,
-        _ => 0.0,
-    });
-    // TODO: make side configurable based on the `direction` property
-    let mut content_box_inset = padding_border;
-    content_box_inset.right += scrollbar_gutter.x;
-    content_box_inset.bottom += scrollbar_gutter.y;
+    let scrollbar_gutter = style.scrollbar_gutter_inset().swap_horizontal_sides_if(style.direction.is_rtl());
+    let content_box_inset = padding_border + scrollbar_gutter;
 
     #[cfg(feature = "block_layout")]
     let is_block = style.display == Display::Block;
@@ -92,7 +92,7 @@ pub fn compute(
     let has_styles_preventing_being_collapsed_through = !is_block
         || style.overflow.x.is_scroll_container()
         || style.overflow.y.is_scroll_container()
-        || style.position == Position::Absolute
+        || style.position.is_out_of_flow()
         || padding.top > 0.0
         || padding.bottom > 0.0
         || border.top > 0.0
@@ -115,11 +115,13 @@ pub fn compute(
         return SizeBaselinesAndMargins {
             size,
             first_baselines: Point::NONE,
+            last_baselines: Point::NONE,
             top_margin: CollapsibleMarginSet::ZERO,
             bottom_margin: CollapsibleMarginSet::ZERO,
             margins_can_collapse_through: !has_styles_preventing_being_collapsed_through
                 && size.height == 0.0
                 && measurable.is_none(),
+            content_size: Size::ZERO,
         };
     };
 
@@ -146,46 +148,63 @@ pub fn compute(
             node_size.unwrap_or(measured_size + content_box_inset.sum_axes()).maybe_clamp(node_min_size, node_max_size);
         let size = Size {
             width: clamped_size.width,
-            height: f32_max(clamped_size.height, aspect_ratio.map(|ratio| clamped_size.width / ratio).unwrap_or(0.0)),
+            height: f32_max(
+                clamped_size.height,
+                aspect_ratio
+                    .map(|ratio| {
+                        (clamped_size.width - box_sizing_adjustment.width) / ratio + box_sizing_adjustment.height
+                    })
+                    .unwrap_or(0.0),
+            ),
         };
         let size = size.maybe_max(padding_border.sum_axes().map(Some));
 
         return SizeBaselinesAndMargins {
             size,
             first_baselines: Point::NONE,
+            last_baselines: Point::NONE,
             top_margin: CollapsibleMarginSet::ZERO,
             bottom_margin: CollapsibleMarginSet::ZERO,
             margins_can_collapse_through: !has_styles_preventing_being_collapsed_through
                 && size.height == 0.0
                 && measured_size.height == 0.0,
+            content_size: Size::ZERO,
         };
     }
 
     let size = Size {
         width: node_size
             .width
-            // .unwrap_or(0.0) + padding.horizontal_axis_sum() + border.horizontal_axis_sum(), // content-box
-            .unwrap_or(content_box_inset.horizontal_axis_sum()) // border-box
+            .unwrap_or(content_box_inset.horizontal_axis_sum())
             .maybe_clamp(node_min_size.width, node_max_size.width)
             .maybe_max(padding_border.horizontal_axis_sum().into()),
         height: node_size
             .height
-            // .unwrap_or(0.0) + padding.vertical_axis_sum() + border.vertical_axis_sum(), // content-box
-            .unwrap_or(content_box_inset.vertical_axis_sum()) // border-box
+            .unwrap_or(content_box_inset.vertical_axis_sum())
             .maybe_clamp(node_min_size.height, node_max_size.height)
             .maybe_max(padding_border.vertical_axis_sum().into()),
     };
 
+    // The aspect ratio applies to the box selected by `box_sizing`
+    let adjustment = box_sizing_adjustment;
     let size = Size {
-        width: f32_max(size.width, aspect_ratio.map(|ratio| size.height * ratio).unwrap_or(0.0)),
-        height: f32_max(size.height, aspect_ratio.map(|ratio| size.width / ratio).unwrap_or(0.0)),
+        width: f32_max(
+            size.width,
+            aspect_ratio.map(|ratio| (size.height - adjustment.height) * ratio + adjustment.width).unwrap_or(0.0),
+        ),
+        height: f32_max(
+            size.height,
+            aspect_ratio.map(|ratio| (size.width - adjustment.width) / ratio + adjustment.height).unwrap_or(0.0),
+        ),
     };
 
     SizeBaselinesAndMargins {
         size,
         first_baselines: Point::NONE,
+        last_baselines: Point::NONE,
         top_margin: CollapsibleMarginSet::ZERO,
         bottom_margin: CollapsibleMarginSet::ZERO,
         margins_can_collapse_through: !has_styles_preventing_being_collapsed_through && size.height == 0.0,
+        content_size: Size::ZERO,
     }
 }
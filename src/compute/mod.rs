@@ -7,6 +7,12 @@ pub use leaf::compute;
 
 #[cfg(feature = "block_layout")]
 pub(crate) mod block;
+#[cfg(feature = "block_layout")]
+pub(crate) mod float;
+#[cfg(feature = "block_layout")]
+pub(crate) mod inline;
+#[cfg(feature = "block_layout")]
+pub(crate) mod multicol;
 
 #[cfg(feature = "flexbox")]
 pub(crate) mod flexbox;
@@ -14,10 +20,19 @@ pub(crate) mod flexbox;
 #[cfg(feature = "grid")]
 pub(crate) mod grid;
 
+#[cfg(feature = "table_layout")]
+pub(crate) mod table;
+
+#[cfg(feature = "stack_layout")]
+pub(crate) mod stack;
+
 use crate::geometry::{Line, Size};
 use crate::style::AvailableSpace;
 use crate::tree::{Layout, LayoutTree, NodeId, SizeBaselinesAndMargins, SizingMode};
 
+#[cfg(all(feature = "taffy_tree", any(feature = "std", feature = "alloc")))]
+use crate::tree::Taffy;
+
 #[cfg(feature = "block_layout")]
 pub use self::block::BlockAlgorithm;
 
@@ -27,6 +42,12 @@ pub use self::flexbox::FlexboxAlgorithm;
 #[cfg(feature = "grid")]
 pub use self::grid::CssGridAlgorithm;
 
+#[cfg(feature = "table_layout")]
+pub use self::table::TableAlgorithm;
+
+#[cfg(feature = "stack_layout")]
+pub use self::stack::StackAlgorithm;
+
 #[cfg(feature = "taffy_tree")]
 pub(crate) mod taffy_tree;
 
@@ -58,6 +79,85 @@ pub trait LayoutAlgorithm {
     ) -> SizeBaselinesAndMargins;
 }
 
+/// A layout algorithm for the nodes with a [`Display::Custom`](crate::style::Display::Custom) display mode in the
+/// [`Taffy`] tree, as registered with [`Taffy::register_layout_algorithm`]
+///
+/// Unlike a [`LayoutAlgorithm`], it is registered as an object whose methods take `&self`, so it can hold state (such
+/// as configuration). Every [`LayoutAlgorithm`] that is `Send` and `Sync` is also a `CustomLayoutAlgorithm`.
+#[cfg(all(feature = "taffy_tree", any(feature = "std", feature = "alloc")))]
+pub trait CustomLayoutAlgorithm: Send + Sync {
+    /// Compute the size of the node given the specified constraints
+    #[allow(clippy::too_many_arguments)]
+    fn measure_size(
+        &self,
+        tree: &mut Taffy,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        sizing_mode: SizingMode,
+        vertical_margins_are_collapsible: Line<bool>,
+    ) -> Size<f32>;
+
+    /// Perform a full layout on the node given the specified constraints
+    #[allow(clippy::too_many_arguments)]
+    fn perform_layout(
+        &self,
+        tree: &mut Taffy,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        sizing_mode: SizingMode,
+        vertical_margins_are_collapsible: Line<bool>,
+    ) -> SizeBaselinesAndMargins;
+}
+
+#[cfg(all(feature = "taffy_tree", any(feature = "std", feature = "alloc")))]
+impl<Algorithm: LayoutAlgorithm + Send + Sync> CustomLayoutAlgorithm for Algorithm {
+    fn measure_size(
+        &self,
+        tree: &mut Taffy,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        sizing_mode: SizingMode,
+        vertical_margins_are_collapsible: Line<bool>,
+    ) -> Size<f32> {
+        Algorithm::measure_size(
+            tree,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            sizing_mode,
+            vertical_margins_are_collapsible,
+        )
+    }
+
+    fn perform_layout(
+        &self,
+        tree: &mut Taffy,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        sizing_mode: SizingMode,
+        vertical_margins_are_collapsible: Line<bool>,
+    ) -> SizeBaselinesAndMargins {
+        Algorithm::perform_layout(
+            tree,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            sizing_mode,
+            vertical_margins_are_collapsible,
+        )
+    }
+}
+
 /// The public interface to Taffy's hidden node algorithm implementation
 pub struct HiddenAlgorithm;
 impl LayoutAlgorithm for HiddenAlgorithm {
@@ -0,0 +1,235 @@
+//! Multi-column layout of block containers (<https://www.w3.org/TR/css-multicol-1>)
+//!
+//! The children of a multi-column container are laid out by the block algorithm as if they were in a single column as
+//! wide as the container's columns. That column is then divided into columns at the boundaries between its children:
+//! children themselves are not broken across columns, and the margins between two children that are placed in
+//! different columns are truncated.
+use crate::style::{ColumnFill, LengthPercentage, LengthPercentageAuto, Style};
+use crate::tree::{LayoutTree, NodeId};
+use crate::util::sys::{f32_max, f32_min, floor, stable_sort_by, Vec};
+use crate::util::{MaybeResolve, ResolveOrZero};
+
+/// The column styles of a multi-column container
+pub(crate) struct ColumnStyles {
+    /// The number of columns, or `None` if it is determined by `width`
+    count: Option<u16>,
+    /// The ideal width of the columns
+    width: LengthPercentageAuto,
+    /// The gap between adjacent columns: the container's `gap.width`, which is the CSS `column-gap` property that
+    /// applies to multi-column containers as well as to grid and flex containers
+    gap: LengthPercentage,
+    /// How the content is distributed between the columns
+    pub fill: ColumnFill,
+}
+
+impl ColumnStyles {
+    /// The column styles of a block container, or `None` if it is not a multi-column container (because both its
+    /// `column_count` and its `column_width` are auto)
+    pub(crate) fn of(style: &Style) -> Option<ColumnStyles> {
+        if style.column_count.is_none() && style.column_width.is_auto() {
+            return None;
+        }
+        Some(ColumnStyles {
+            count: style.column_count.map(|count| count.max(1)),
+            width: style.column_width,
+            gap: style.gap.width,
+            fill: style.column_fill,
+        })
+    }
+
+    /// Resolve the number and width of the columns of a container whose content box is `inner_width` wide. As many
+    /// columns of at least the ideal width as fit in the container are used (but no more than `count`), and they are
+    /// then stretched to fill it.
+    pub(crate) fn resolve(&self, inner_width: f32) -> Columns {
+        let gap = self.gap.resolve_or_zero(Some(inner_width));
+        let count = match self.width.maybe_resolve(inner_width) {
+            Some(width) if width + gap > 0.0 => {
+                let fitting_count = f32_max(1.0, floor((inner_width + gap) / (width + gap))) as usize;
+                self.count.map_or(fitting_count, |count| fitting_count.min(usize::from(count)))
+            }
+            _ => self.count.map_or(1, usize::from),
+        };
+        let width = f32_max(0.0, (inner_width - gap * (count - 1) as f32) / count as f32);
+        Columns { count, width, gap }
+    }
+
+    /// The width of the content box of a container whose width depends on its content, given the width that its
+    /// content needs when it is laid out in a single column. Each column is the ideal column width wide if there is one,
+    /// or as wide as the content otherwise.
+    pub(crate) fn content_based_inner_width(&self, content_width: f32) -> f32 {
+        let width = self.width.maybe_resolve(None).unwrap_or(content_width);
+        let count = f32::from(self.count.unwrap_or(1));
+        let gap = self.gap.resolve_or_zero(None);
+        width * count + gap * (count - 1.0)
+    }
+}
+
+/// The resolved columns of a multi-column container
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Columns {
+    /// The number of columns that the content is balanced across. More columns are created if the content overflows
+    /// the height of the container.
+    pub count: usize,
+    /// The width of each column
+    pub width: f32,
+    /// The gap between adjacent columns
+    pub gap: f32,
+}
+
+/// The result of dividing the content of a multi-column container into columns
+pub(crate) struct ColumnFragmentation {
+    /// The height of the tallest column
+    pub height: f32,
+    /// How far the content of the last column was moved up from its position in the single column
+    pub last_column_offset: f32,
+}
+
+/// A child of a multi-column container that is placed in a column as a whole
+struct ColumnUnit {
+    /// The node of the child
+    node: NodeId,
+    /// The top of the child's border box in the single column
+    top: f32,
+    /// The bottom of the border box of this child or of any child above it in the single column (whichever is lowest)
+    bottom: f32,
+    /// Whether a column may start with this child: one can't start with a child that is beside a preceding child (such
+    /// as a float, or an inline-level child on the same line as another)
+    is_break_allowed: bool,
+}
+
+/// Divide the children of a multi-column container (which have been laid out in a single column) into columns, and
+/// move them into place. `children` are the container's in-flow children, including floats.
+///
+/// `content_top` and `content_end` are the top of the container's content box and the bottom of its content in the
+/// single column. `height_limit` is the definite height of the content box, if any: columns are no taller than this,
+/// and whatever doesn't fit in `columns.count` columns overflows into extra columns.
+pub(crate) fn fragment_into_columns(
+    tree: &mut impl LayoutTree,
+    children: &[NodeId],
+    columns: Columns,
+    fill: ColumnFill,
+    content_top: f32,
+    content_end: f32,
+    height_limit: Option<f32>,
+) -> ColumnFragmentation {
+    let mut units: Vec<ColumnUnit> = children
+        .iter()
+        .map(|&node| {
+            let layout = tree.layout(node);
+            ColumnUnit {
+                node,
+                top: layout.location.y,
+                bottom: layout.location.y + layout.size.height,
+                is_break_allowed: false,
+            }
+        })
+        .collect();
+    stable_sort_by(&mut units, |a, b| a.top.total_cmp(&b.top));
+    let mut bottom = content_top;
+    for unit in units.iter_mut() {
+        unit.is_break_allowed = unit.top >= bottom;
+        bottom = f32_max(bottom, unit.bottom);
+        unit.bottom = bottom;
+    }
+    if units.is_empty() {
+        return ColumnFragmentation { height: f32_max(0.0, content_end - content_top), last_column_offset: 0.0 };
+    }
+
+    // The height of a column that holds the units `start..end`
+    let column_height = |start: usize, end: usize| {
+        let top = if start == 0 { content_top } else { units[start].top };
+        let bottom =
+            if end == units.len() { f32_max(units[end - 1].bottom, content_end) } else { units[end - 1].bottom };
+        bottom - top
+    };
+    let is_column_end = |end: usize| end == units.len() || units[end].is_break_allowed;
+
+    // Fill columns one after another with as many units as fit in `limit`. A column always holds at least one unit.
+    let fill_columns = |limit: f32| {
+        let mut column_starts = Vec::new();
+        let mut start = 0;
+        while start < units.len() {
+            column_starts.push(start);
+            let mut end = None;
+            for candidate in (start + 1..=units.len()).filter(|&end| is_column_end(end)) {
+                if end.is_some() && column_height(start, candidate) > limit {
+                    break;
+                }
+                end = Some(candidate);
+            }
+            start = end.unwrap_or(units.len());
+        }
+        column_starts
+    };
+
+    // Balanced columns are as short as possible while still fitting the content into the requested number of columns.
+    // Their height is that of one of the possible columns, and the taller the columns the fewer of them are needed, so
+    // the shortest possible column that is tall enough is found by a binary search.
+    let balanced_height = || {
+        let mut candidate_heights: Vec<f32> = Vec::new();
+        for start in (0..units.len()).filter(|&start| start == 0 || units[start].is_break_allowed) {
+            for end in (start + 1..=units.len()).filter(|&end| is_column_end(end)) {
+                candidate_heights.push(column_height(start, end));
+            }
+        }
+        candidate_heights.sort_unstable_by(|a, b| a.total_cmp(b));
+        let index = candidate_heights.partition_point(|&height| fill_columns(height).len() > columns.count);
+        candidate_heights.get(index).copied().unwrap_or_else(|| column_height(0, units.len()))
+    };
+    let limit = match (fill, height_limit) {
+        (ColumnFill::Auto, Some(height_limit)) => height_limit,
+        (_, height_limit) => {
+            let balanced_height = balanced_height();
+            height_limit.map_or(balanced_height, |height_limit| f32_min(balanced_height, height_limit))
+        }
+    };
+    let column_starts = fill_columns(limit);
+
+    // Move each column's units into place
+    let mut height: f32 = 0.0;
+    let mut last_column_offset = 0.0;
+    for (column, &start) in column_starts.iter().enumerate() {
+        let end = column_starts.get(column + 1).copied().unwrap_or(units.len());
+        let offset_x = column as f32 * (columns.width + columns.gap);
+        let offset_y = if start == 0 { 0.0 } else { units[start].top - content_top };
+        for unit in units[start..end].iter() {
+            let layout = tree.layout_mut(unit.node);
+            layout.location.x += offset_x;
+            layout.location.y -= offset_y;
+        }
+        height = f32_max(height, column_height(start, end));
+        last_column_offset = offset_y;
+    }
+    ColumnFragmentation { height, last_column_offset }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ColumnStyles;
+    use crate::style::{ColumnFill, LengthPercentage, LengthPercentageAuto};
+
+    fn column_styles(count: Option<u16>, width: LengthPercentageAuto, gap: f32) -> ColumnStyles {
+        ColumnStyles { count, width, gap: LengthPercentage::Length(gap), fill: ColumnFill::Balance }
+    }
+
+    #[test]
+    fn column_width_determines_count() {
+        let columns = column_styles(None, LengthPercentageAuto::Length(30.0), 5.0).resolve(100.0);
+        assert_eq!(columns.count, 3);
+        assert_eq!(columns.width, 30.0);
+    }
+
+    #[test]
+    fn column_count_caps_columns_fitting_column_width() {
+        let columns = column_styles(Some(2), LengthPercentageAuto::Length(20.0), 0.0).resolve(100.0);
+        assert_eq!(columns.count, 2);
+        assert_eq!(columns.width, 50.0);
+    }
+
+    #[test]
+    fn narrow_container_has_one_column() {
+        let columns = column_styles(Some(3), LengthPercentageAuto::Length(60.0), 10.0).resolve(50.0);
+        assert_eq!(columns.count, 1);
+        assert_eq!(columns.width, 50.0);
+    }
+}
@@ -0,0 +1,546 @@
+//! Computes the stack layout algorithm in the case that the layout mode of the container is Stack
+//!
+//! This follows the row/column layout model of [Morphorm](https://github.com/vizia/morphorm). The in-flow children of
+//! the container are placed one after another along its stacking axis (horizontally for [`StackDirection::Row`],
+//! vertically for [`StackDirection::Column`]), and every child is surrounded by a space on each of its four sides. In
+//! each axis, the free space in the container is shared between the stretched sizes ([`StackSize::Stretch`], or
+//! `Dimension::Stretch` with a weight of `1.0`) and spaces ([`StackSpace::Stretch`]) in proportion to their weights:
+//! between those of all the children in the stacking axis, and between those of each child on its own in the other
+//! axis. Absolutely positioned children (Morphorm's "self-directed" children) are placed within the container by their
+//! spaces in both axes, independently of their siblings.
+//!
+//! Stretched sizes and spaces don't contribute to the size of an auto-sized container. As in Morphorm, an auto-sized
+//! container is sized to its content even when the available space is definite (including at the root of the tree), so
+//! only the children of containers with a known size have any free space to stretch into. The axes are always physical:
+//! the `direction` and `writing_mode` of the container are ignored, as are the margins and insets of its children.
+//!
+//! [`StackDirection::Row`]: crate::style::StackDirection::Row
+//! [`StackDirection::Column`]: crate::style::StackDirection::Column
+//! [`StackSize::Stretch`]: crate::style::StackSize::Stretch
+use crate::compute::common::content_size::compute_content_size;
+use crate::compute::common::contents::{layout_children, perform_contents_layout};
+use crate::compute::common::intrinsic_size::resolve_intrinsic_sizes;
+use crate::compute::LayoutAlgorithm;
+use crate::geometry::{AbsoluteAxis, Line, Point, Rect, Size};
+use crate::style::{AvailableSpace, Display, Position, StackSpace};
+use crate::style_helpers::TaffyMaxContent;
+use crate::tree::{Layout, RunMode, SizeBaselinesAndMargins, SizingMode};
+use crate::tree::{LayoutTree, NodeId};
+use crate::util::sys::{f32_max, f32_min, new_vec_with_capacity, Vec};
+use crate::util::MaybeMath;
+use crate::util::{MaybeResolve, ResolveOrZero};
+
+#[cfg(feature = "debug")]
+use crate::util::debug::NODE_LOGGER;
+
+/// The public interface to Taffy's stack algorithm implementation
+pub struct StackAlgorithm;
+impl LayoutAlgorithm for StackAlgorithm {
+    const NAME: &'static str = "STACK";
+
+    fn perform_layout(
+        tree: &mut impl LayoutTree,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        _sizing_mode: SizingMode,
+        _vertical_margins_are_collapsible: Line<bool>,
+    ) -> SizeBaselinesAndMargins {
+        compute(tree, node, known_dimensions, parent_size, available_space, RunMode::PerformLayout)
+    }
+
+    fn measure_size(
+        tree: &mut impl LayoutTree,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        _sizing_mode: SizingMode,
+        _vertical_margins_are_collapsible: Line<bool>,
+    ) -> Size<f32> {
+        compute(tree, node, known_dimensions, parent_size, available_space, RunMode::ComputeSize).size
+    }
+}
+
+/// One of the lengths that make up the extent of a child of the stack container in one axis: the space before the
+/// child, its size, or the space after it
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct StackLength {
+    /// The length. For a stretched length, this is its minimum until the free space has been distributed.
+    value: f32,
+    /// The weight with which the length stretches into the free space, or zero if it doesn't stretch
+    stretch: f32,
+    /// The smallest the length may be stretched to
+    min: f32,
+    /// The largest the length may be stretched to
+    max: f32,
+}
+
+impl StackLength {
+    /// A length that doesn't stretch
+    fn fixed(value: f32) -> Self {
+        Self { value, stretch: 0.0, min: value, max: value }
+    }
+
+    /// A length that stretches with the weight `stretch`, between `min` and `max`
+    fn stretched(stretch: f32, min: f32, max: f32) -> Self {
+        Self { value: min, stretch: f32_max(stretch, 0.0), min, max: f32_max(min, max) }
+    }
+
+    /// The length of a space, given the size of the container's content box in the same axis
+    fn space(space: StackSpace, inner_size: Option<f32>) -> Self {
+        match space {
+            StackSpace::Length(length) => Self::fixed(length),
+            StackSpace::Percent(percent) => Self::fixed(inner_size.map(|size| size * percent).unwrap_or(0.0)),
+            StackSpace::Stretch(stretch) => Self::stretched(stretch, 0.0, f32::INFINITY),
+            StackSpace::Auto => Self::fixed(0.0),
+        }
+    }
+}
+
+/// An in-flow or absolutely positioned child of the stack container
+struct StackItem {
+    /// The node of the child
+    node: NodeId,
+    /// The index of the child among the layout children of the container
+    order: u32,
+    /// Whether the child should be painted
+    is_visible: bool,
+    /// Whether the child is absolutely positioned, and so placed within the container independently of its siblings
+    is_self_directed: bool,
+    /// The border-box size of the child from its `size` styles, in the axes in which it is neither auto nor stretched
+    size: Size<Option<f32>>,
+    /// The weight with which the size of the child stretches in each axis, or `None` in axes in which it doesn't stretch
+    stretch: Size<Option<f32>>,
+    /// The minimum border-box size of the child
+    min_size: Size<f32>,
+    /// The maximum border-box size of the child
+    max_size: Size<f32>,
+    /// The lengths that make up the extent of the child in each axis: the space before it, its size and the space
+    /// after it
+    lengths: Size<[StackLength; 3]>,
+}
+
+impl StackItem {
+    /// Whether the size of the child in `axis` depends on its content
+    fn is_auto(&self, axis: AbsoluteAxis) -> bool {
+        self.size.get_abs(axis).is_none() && self.stretch.get_abs(axis).is_none()
+    }
+
+    /// The extent of the child and its spaces in `axis`
+    fn extent(&self, axis: AbsoluteAxis) -> f32 {
+        self.lengths.get_abs(axis).iter().map(|length| length.value).sum()
+    }
+}
+
+/// Computes the layout of [`LayoutTree`] according to the stack layout algorithm
+pub fn compute(
+    tree: &mut impl LayoutTree,
+    node: NodeId,
+    known_dimensions: Size<Option<f32>>,
+    parent_size: Size<Option<f32>>,
+    _available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+) -> SizeBaselinesAndMargins {
+    let style = tree.style(node);
+    let main_axis = if style.stack_direction.is_row() { AbsoluteAxis::Horizontal } else { AbsoluteAxis::Vertical };
+    let cross_axis = main_axis.other_axis();
+    let aspect_ratio = style.aspect_ratio;
+    let padding = style.padding.resolve_or_zero(parent_size.width);
+    let border = style.border.resolve_or_zero(parent_size.width);
+    let padding_border = padding + border;
+    let padding_border_sum = padding_border.sum_axes();
+    let box_sizing_adjustment = style.box_sizing.adjustment(padding_border_sum);
+    let size =
+        style.size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio).maybe_add(box_sizing_adjustment);
+    let min_size = style
+        .min_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let max_size = style
+        .max_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+
+    // Short-circuit layout if the container's size is all that is needed and it is already known
+    if run_mode == RunMode::ComputeSize {
+        if let Size { width: Some(width), height: Some(height) } = known_dimensions {
+            return Size { width, height }.into();
+        }
+    }
+
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log("STACK");
+
+    let known_size = known_dimensions.or(size.maybe_clamp(min_size, max_size)).maybe_max(padding_border_sum);
+    let known_inner_size = known_size.maybe_sub(padding_border_sum);
+
+    // 1. Collect the in-flow and self-directed children of the container, and resolve their sizes and spaces
+    let mut items = generate_stack_items(tree, node, known_inner_size, run_mode);
+
+    // The size of the container in an axis is its own size if that is known, or else the size of its content
+    let container_size = |axis: AbsoluteAxis, content_size: f32| {
+        let padding_border_sum = padding_border_sum.get_abs(axis);
+        known_size.get_abs(axis).unwrap_or_else(|| {
+            f32_max(
+                (content_size + padding_border_sum).maybe_clamp(min_size.get_abs(axis), max_size.get_abs(axis)),
+                padding_border_sum,
+            )
+        })
+    };
+
+    // 2. Determine the cross size of the container, and of each in-flow child and the spaces around it
+    for item in items.iter_mut().filter(|item| !item.is_self_directed && item.is_auto(cross_axis)) {
+        let main_size = item.size.get_abs(main_axis);
+        measure_item(tree, item, cross_axis, main_size, known_inner_size);
+    }
+    let content_cross_size =
+        items.iter().filter(|item| !item.is_self_directed).map(|item| item.extent(cross_axis)).fold(0.0, f32_max);
+    let cross_size = container_size(cross_axis, content_cross_size);
+    let inner_cross_size = cross_size - padding_border_sum.get_abs(cross_axis);
+    for item in items.iter_mut().filter(|item| !item.is_self_directed) {
+        let mut lengths = item.lengths.get_abs(cross_axis);
+        distribute_stretch(&mut lengths, inner_cross_size);
+        item.lengths.set_abs(cross_axis, lengths);
+    }
+
+    // 3. Determine the main size of the container, and of each in-flow child and the spaces around it
+    for item in items.iter_mut().filter(|item| !item.is_self_directed && item.is_auto(main_axis)) {
+        let cross_size = item.lengths.get_abs(cross_axis)[1].value;
+        measure_item(tree, item, main_axis, Some(cross_size), known_inner_size);
+    }
+    let content_main_size =
+        items.iter().filter(|item| !item.is_self_directed).map(|item| item.extent(main_axis)).sum::<f32>();
+    let main_size = container_size(main_axis, content_main_size);
+    let inner_main_size = main_size - padding_border_sum.get_abs(main_axis);
+
+    let mut container_size = Size::ZERO;
+    container_size.set_abs(main_axis, main_size);
+    container_size.set_abs(cross_axis, cross_size);
+    if run_mode == RunMode::ComputeSize {
+        return container_size.into();
+    }
+    let mut inner_size = Size::ZERO;
+    inner_size.set_abs(main_axis, inner_main_size);
+    inner_size.set_abs(cross_axis, inner_cross_size);
+
+    // The free space in the main axis is shared between all of the in-flow children
+    let mut main_lengths: Vec<StackLength> =
+        items.iter().filter(|item| !item.is_self_directed).flat_map(|item| item.lengths.get_abs(main_axis)).collect();
+    distribute_stretch(&mut main_lengths, inner_main_size);
+    for (item, lengths) in items.iter_mut().filter(|item| !item.is_self_directed).zip(main_lengths.chunks_exact(3)) {
+        item.lengths.set_abs(main_axis, [lengths[0], lengths[1], lengths[2]]);
+    }
+
+    // 4. Size the self-directed children within the container in both axes. The sizes that don't depend on the content
+    // of the child are determined first, so that the child can be measured at them.
+    for item in items.iter_mut().filter(|item| item.is_self_directed) {
+        let mut item_size = Size::NONE;
+        let axes = match item.is_auto(AbsoluteAxis::Horizontal) && !item.is_auto(AbsoluteAxis::Vertical) {
+            true => [AbsoluteAxis::Vertical, AbsoluteAxis::Horizontal],
+            false => [AbsoluteAxis::Horizontal, AbsoluteAxis::Vertical],
+        };
+        for axis in axes {
+            if item.is_auto(axis) {
+                measure_item(tree, item, axis, item_size.get_abs(axis.other_axis()), inner_size.map(Some));
+            }
+            let mut lengths = item.lengths.get_abs(axis);
+            distribute_stretch(&mut lengths, inner_size.get_abs(axis));
+            item.lengths.set_abs(axis, lengths);
+            item_size.set_abs(axis, Some(lengths[1].value));
+        }
+    }
+
+    // 5. Position the children and lay them out at their final sizes
+    let mut main_offset = padding_border.get_abs_sides(main_axis).start;
+    for item in items.iter() {
+        let mut offset = Size::ZERO;
+        for axis in [AbsoluteAxis::Horizontal, AbsoluteAxis::Vertical] {
+            let [start, size, end] = item.lengths.get_abs(axis);
+            let inset_start = padding_border.get_abs_sides(axis).start;
+            if axis == main_axis && !item.is_self_directed {
+                offset.set_abs(axis, main_offset + start.value);
+                main_offset += start.value + size.value + end.value;
+            } else {
+                offset.set_abs(axis, inset_start + start.value);
+            }
+        }
+        let item_size = Size { width: item.lengths.width[1].value, height: item.lengths.height[1].value };
+        let output = tree.perform_child_layout(
+            item.node,
+            item_size.map(Some),
+            inner_size.map(Some),
+            inner_size.map(AvailableSpace::Definite),
+            SizingMode::InherentSize,
+            Line::FALSE,
+        );
+        *tree.layout_mut(item.node) = Layout {
+            order: item.order,
+            size: item_size,
+            location: Point { x: offset.width, y: offset.height },
+            is_visible: item.is_visible,
+            content_size: output.content_size,
+        };
+    }
+    perform_contents_layout(tree, node);
+
+    let content_size = compute_content_size(tree, node, container_size, padding, border);
+    SizeBaselinesAndMargins { content_size, ..container_size.into() }
+}
+
+/// Collect the in-flow and self-directed children of the stack container `node`, with their sizes and spaces resolved
+/// against the size of the container's content box where it is known. Hidden children are given an empty layout (when
+/// performing layout), and fixed position children are skipped, as they are positioned against the viewport.
+fn generate_stack_items(
+    tree: &mut impl LayoutTree,
+    node: NodeId,
+    inner_size: Size<Option<f32>>,
+    run_mode: RunMode,
+) -> Vec<StackItem> {
+    let style = tree.style(node);
+    let main_axis = if style.stack_direction.is_row() { AbsoluteAxis::Horizontal } else { AbsoluteAxis::Vertical };
+    let child_space = style.child_space;
+    let child_between = style.child_between;
+
+    let children = layout_children(tree, node);
+    let in_flow_count = children
+        .iter()
+        .filter(|&&child| {
+            let style = tree.style(child);
+            style.display != Display::None && !style.position.is_out_of_flow()
+        })
+        .count();
+
+    let mut items = new_vec_with_capacity(children.len());
+    let mut in_flow_index = 0;
+    for (order, child) in children.into_iter().enumerate() {
+        let order = order as u32;
+        let style = tree.style(child);
+        if style.display == Display::None {
+            if run_mode == RunMode::PerformLayout {
+                *tree.layout_mut(child) = Layout::with_order(order);
+                tree.perform_child_layout(
+                    child,
+                    Size::NONE,
+                    Size::NONE,
+                    Size::MAX_CONTENT,
+                    SizingMode::InherentSize,
+                    Line::FALSE,
+                );
+            }
+            continue;
+        }
+        if style.position == Position::Fixed {
+            continue;
+        }
+
+        // The spaces of in-flow children in the stacking axis default to the container's `child_space` at its edges,
+        // and to its `child_between` between adjacent children
+        let is_self_directed = style.position == Position::Absolute;
+        let mut space = Rect {
+            left: style.space.left.or(child_space.left),
+            right: style.space.right.or(child_space.right),
+            top: style.space.top.or(child_space.top),
+            bottom: style.space.bottom.or(child_space.bottom),
+        };
+        if !is_self_directed {
+            let style_space = style.space.get_abs_sides(main_axis);
+            let start = match in_flow_index {
+                0 => style_space.start.or(child_space.get_abs_sides(main_axis).start),
+                _ => style_space.start.or(child_between),
+            };
+            let end = match in_flow_index + 1 == in_flow_count {
+                true => style_space.end.or(child_space.get_abs_sides(main_axis).end),
+                false => style_space.end,
+            };
+            match main_axis {
+                AbsoluteAxis::Horizontal => (space.left, space.right) = (start, end),
+                AbsoluteAxis::Vertical => (space.top, space.bottom) = (start, end),
+            }
+            in_flow_index += 1;
+        }
+
+        let aspect_ratio = style.aspect_ratio;
+        let is_visible = style.visibility.is_visible();
+        let stretch = style.stack_size.zip_map(style.size, |stack_size, size| stack_size.stretch_weight(size));
+        let padding = style.padding.resolve_or_zero(inner_size.width);
+        let border = style.border.resolve_or_zero(inner_size.width);
+        let padding_border_sum = (padding + border).sum_axes();
+        let box_sizing_adjustment = style.box_sizing.adjustment(padding_border_sum);
+        let mut size = style
+            .size
+            .maybe_resolve(inner_size)
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment);
+        let mut min_size = style
+            .min_size
+            .maybe_resolve(inner_size)
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment);
+        let mut max_size = style
+            .max_size
+            .maybe_resolve(inner_size)
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment);
+        resolve_intrinsic_sizes(tree, child, inner_size, Size::NONE, &mut size, &mut min_size, &mut max_size);
+        let min_size = min_size.maybe_max(padding_border_sum).unwrap_or(padding_border_sum);
+        let max_size = max_size.unwrap_or(Size { width: f32::INFINITY, height: f32::INFINITY });
+        let size = size.zip_map(stretch, |size, stretch| size.filter(|_| stretch.is_none()));
+        let size = size.maybe_clamp(min_size.map(Some), max_size.map(Some));
+
+        let length = |axis: AbsoluteAxis| {
+            let spaces = space.get_abs_sides(axis);
+            let inner_size = inner_size.get_abs(axis);
+            let size = match (size.get_abs(axis), stretch.get_abs(axis)) {
+                (_, Some(stretch)) => StackLength::stretched(stretch, min_size.get_abs(axis), max_size.get_abs(axis)),
+                (Some(size), None) => StackLength::fixed(size),
+                // Measured once the size of the child in the other axis is known
+                (None, None) => StackLength::fixed(0.0),
+            };
+            [StackLength::space(spaces.start, inner_size), size, StackLength::space(spaces.end, inner_size)]
+        };
+        let lengths = Size { width: length(AbsoluteAxis::Horizontal), height: length(AbsoluteAxis::Vertical) };
+
+        items.push(StackItem {
+            node: child,
+            order,
+            is_visible,
+            is_self_directed,
+            size,
+            stretch,
+            min_size,
+            max_size,
+            lengths,
+        });
+    }
+    items
+}
+
+/// Set the size of `item` in `axis` to the size of its content, given its size in the other axis if that is known. The
+/// child is measured in the space left by the fixed spaces on either side of it in the container's content box.
+fn measure_item(
+    tree: &mut impl LayoutTree,
+    item: &mut StackItem,
+    axis: AbsoluteAxis,
+    other_size: Option<f32>,
+    inner_size: Size<Option<f32>>,
+) {
+    let mut known_dimensions = Size::NONE;
+    known_dimensions.set_abs(axis.other_axis(), other_size);
+    let available_space = |axis: AbsoluteAxis| {
+        let [start, _, end] = item.lengths.get_abs(axis);
+        inner_size
+            .get_abs(axis)
+            .map(|size| AvailableSpace::Definite(f32_max(size - start.value - end.value, 0.0)))
+            .unwrap_or(AvailableSpace::MaxContent)
+    };
+    let available_space =
+        Size { width: available_space(AbsoluteAxis::Horizontal), height: available_space(AbsoluteAxis::Vertical) };
+    let content_size = tree
+        .measure_child_size(
+            item.node,
+            known_dimensions,
+            inner_size,
+            available_space,
+            SizingMode::InherentSize,
+            Line::FALSE,
+        )
+        .get_abs(axis);
+
+    let mut lengths = item.lengths.get_abs(axis);
+    lengths[1] =
+        StackLength::fixed(f32_max(item.min_size.get_abs(axis), f32_min(content_size, item.max_size.get_abs(axis))));
+    item.lengths.set_abs(axis, lengths);
+}
+
+/// Share the free space in `space` (what the fixed lengths leave of it) between the stretched lengths in proportion to
+/// their weights. Lengths that would be stretched beyond their limits are clamped to them, and the rest of the free
+/// space is shared between the others, as when resolving flexible lengths in flexbox.
+fn distribute_stretch(lengths: &mut [StackLength], space: f32) {
+    let fixed_space: f32 = lengths.iter().filter(|length| length.stretch == 0.0).map(|length| length.value).sum();
+    let mut is_frozen: Vec<bool> = lengths.iter().map(|length| length.stretch == 0.0).collect();
+    let mut targets: Vec<f32> = lengths.iter().map(|length| length.value).collect();
+
+    loop {
+        let unfrozen = || lengths.iter().zip(is_frozen.iter()).filter(|(_, &is_frozen)| !is_frozen);
+        let total_stretch: f32 = unfrozen().map(|(length, _)| length.stretch).sum();
+        if total_stretch <= 0.0 {
+            break;
+        }
+        let frozen_space: f32 = lengths
+            .iter()
+            .zip(is_frozen.iter())
+            .filter(|(length, &is_frozen)| is_frozen && length.stretch > 0.0)
+            .map(|(length, _)| length.value)
+            .sum();
+        let free_space = space - fixed_space - frozen_space;
+
+        // Stretch the unfrozen lengths, clamping them to their limits
+        let mut total_violation = 0.0;
+        for ((length, target), _) in
+            lengths.iter_mut().zip(targets.iter_mut()).zip(is_frozen.iter()).filter(|(_, &is_frozen)| !is_frozen)
+        {
+            *target = free_space * length.stretch / total_stretch;
+            length.value = f32_max(length.min, f32_min(*target, length.max));
+            total_violation += length.value - *target;
+        }
+
+        // Freeze the lengths that were clamped in the direction of the total violation, and repeat for the rest
+        let mut has_frozen = false;
+        for ((length, target), is_frozen) in lengths.iter().zip(targets.iter()).zip(is_frozen.iter_mut()) {
+            let is_violation = match total_violation {
+                violation if violation > 0.0 => length.value > *target,
+                violation if violation < 0.0 => length.value < *target,
+                _ => length.value != *target,
+            };
+            if !*is_frozen && is_violation {
+                *is_frozen = true;
+                has_frozen = true;
+            }
+        }
+        if !has_frozen {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{distribute_stretch, StackLength};
+
+    fn values(lengths: &[StackLength]) -> Vec<f32> {
+        lengths.iter().map(|length| length.value).collect()
+    }
+
+    #[test]
+    fn distribute_stretch_in_proportion_to_weights() {
+        let mut lengths = [
+            StackLength::fixed(10.0),
+            StackLength::stretched(1.0, 0.0, f32::INFINITY),
+            StackLength::stretched(3.0, 0.0, f32::INFINITY),
+        ];
+        distribute_stretch(&mut lengths, 90.0);
+        assert_eq!(values(&lengths), [10.0, 20.0, 60.0]);
+    }
+
+    #[test]
+    fn distribute_stretch_respects_limits() {
+        let mut lengths = [
+            StackLength::stretched(1.0, 0.0, 10.0),
+            StackLength::stretched(1.0, 0.0, f32::INFINITY),
+            StackLength::stretched(1.0, 50.0, f32::INFINITY),
+        ];
+        distribute_stretch(&mut lengths, 90.0);
+        assert_eq!(values(&lengths), [10.0, 30.0, 50.0]);
+    }
+
+    #[test]
+    fn distribute_stretch_without_free_space() {
+        let mut lengths = [StackLength::fixed(60.0), StackLength::stretched(1.0, 5.0, f32::INFINITY)];
+        distribute_stretch(&mut lengths, 50.0);
+        assert_eq!(values(&lengths), [60.0, 5.0]);
+    }
+}
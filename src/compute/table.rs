@@ -0,0 +1,877 @@
+//! Computes the CSS table layout algorithm in the case that the layout mode of the container is Table
+//!
+//! This is a partial implementation of the automatic table layout algorithm described in
+//! <https://www.w3.org/TR/css-tables-3>. Children of the table that are not rows or row groups are wrapped in anonymous
+//! rows, and the children of rows are always treated as cells (whatever their `display`). Column widths are determined
+//! from the min-content and max-content widths of the cells, and the cells themselves are laid out as block containers.
+use crate::compute::common::absolute::{resolve_absolute_box_sizes, AbsoluteBoxSizes};
+use crate::compute::common::content_size::compute_content_size;
+use crate::compute::common::contents::{layout_children, perform_contents_layout};
+use crate::compute::LayoutAlgorithm;
+use crate::geometry::{Line, Point, Rect, Size};
+use crate::style::{AvailableSpace, BorderCollapse, Display, Position, Style};
+use crate::style_helpers::TaffyMaxContent;
+use crate::tree::{Layout, RunMode, SizeBaselinesAndMargins, SizingMode};
+use crate::tree::{LayoutTree, NodeId};
+use crate::util::sys::{f32_max, f32_min, stable_sort_by_key, Vec};
+use crate::util::MaybeMath;
+use crate::util::{MaybeResolve, ResolveOrZero};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::compute::common::writing_mode::compute_in_vertical_writing_mode;
+
+#[cfg(feature = "debug")]
+use crate::util::debug::NODE_LOGGER;
+
+/// The public interface to Taffy's table algorithm implementation
+pub struct TableAlgorithm;
+impl LayoutAlgorithm for TableAlgorithm {
+    const NAME: &'static str = "TABLE";
+
+    fn perform_layout(
+        tree: &mut impl LayoutTree,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        _sizing_mode: SizingMode,
+        _vertical_margins_are_collapsible: Line<bool>,
+    ) -> SizeBaselinesAndMargins {
+        compute(tree, node, known_dimensions, parent_size, available_space, RunMode::PerformLayout)
+    }
+
+    fn measure_size(
+        tree: &mut impl LayoutTree,
+        node: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        _sizing_mode: SizingMode,
+        _vertical_margins_are_collapsible: Line<bool>,
+    ) -> Size<f32> {
+        compute(tree, node, known_dimensions, parent_size, available_space, RunMode::ComputeSize).size
+    }
+}
+
+/// Whether the children of `child` are laid out by the table layout algorithm along with the other children of
+/// `parent`, because `child` is a row or row group of the table (or row group) `parent`
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub(crate) fn is_table_part_of(parent: &Style, child: &Style) -> bool {
+    match parent.display {
+        Display::Table => matches!(child.display, Display::TableRowGroup | Display::TableRow),
+        Display::TableRowGroup => child.display == Display::TableRow,
+        _ => false,
+    }
+}
+
+/// The parent of a child of the table structure: the table itself, or one of its row groups or rows
+#[derive(Copy, Clone)]
+enum TablePart {
+    /// The table node
+    Table,
+    /// The row group with this index
+    Group(usize),
+    /// The row with this index
+    Row(usize),
+}
+
+/// A row group of the table
+struct TableRowGroup {
+    /// The node of the row group
+    node: NodeId,
+    /// The index of the row group among the layout children of the table
+    order: u32,
+    /// The rows in the group
+    rows: core::ops::Range<usize>,
+    /// Whether the row group should be painted
+    is_visible: bool,
+}
+
+/// A row of the table
+struct TableRow {
+    /// The node of the row, or `None` for an anonymous row generated for consecutive children that are not rows
+    node: Option<NodeId>,
+    /// The index of the row among the layout children of its parent
+    order: u32,
+    /// The row group that contains the row, if any
+    group: Option<usize>,
+    /// Whether the row should be painted
+    is_visible: bool,
+    /// The height of the row from its `size` and `min_size` styles, which its cells may make it taller than
+    min_height: f32,
+}
+
+/// A cell of the table
+struct TableCell {
+    /// The node of the cell
+    node: NodeId,
+    /// The index of the cell among the layout children of its parent
+    order: u32,
+    /// Whether the cell should be painted
+    is_visible: bool,
+    /// The first row spanned by the cell (which is the row that contains it)
+    row: usize,
+    /// The first column spanned by the cell
+    column: usize,
+    /// The number of rows spanned by the cell. Before the cells are placed, `0` spans the rest of the row group.
+    row_span: usize,
+    /// The number of columns spanned by the cell
+    column_span: usize,
+    /// How far the border box of the cell extends beyond the columns and rows that it spans. In the collapsing border
+    /// model this is the cell's own border, which overlaps the collapsed borders around it. Zero in the separated model.
+    outset: Rect<f32>,
+    /// The width that the cell needs from the columns it spans (and the borders between them) to fit its min-content
+    min_content_width: f32,
+    /// The width that the cell needs from the columns it spans (and the borders between them) to fit its max-content
+    max_content_width: f32,
+}
+
+/// A child of the table (or of one of its rows or row groups) that does not take part in the table's grid: one that is
+/// hidden or positioned out of flow
+struct OutOfFlowChild {
+    /// The node of the child
+    node: NodeId,
+    /// The index of the child among the layout children of its parent
+    order: u32,
+    /// The parent of the child
+    parent: TablePart,
+}
+
+/// The rows, row groups and cells of a table, and its children that are not part of its grid
+#[derive(Default)]
+struct TableStructure {
+    /// The row groups of the table, in order
+    groups: Vec<TableRowGroup>,
+    /// The rows of the table, in order
+    rows: Vec<TableRow>,
+    /// The cells of the table, in order (so the rows that contain them are non-decreasing)
+    cells: Vec<TableCell>,
+    /// The hidden and out-of-flow children of the table, its rows and its row groups
+    out_of_flow: Vec<OutOfFlowChild>,
+}
+
+/// The border box of a row or row group, relative to the table (as if the table were left-to-right)
+#[derive(Copy, Clone)]
+struct PartBox {
+    /// The location of the box
+    location: Point<f32>,
+    /// The size of the box
+    size: Size<f32>,
+}
+
+/// Computes the layout of [`LayoutTree`] according to the table layout algorithm
+pub fn compute(
+    tree: &mut impl LayoutTree,
+    node: NodeId,
+    known_dimensions: Size<Option<f32>>,
+    parent_size: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+) -> SizeBaselinesAndMargins {
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    if tree.style(node).writing_mode.is_vertical() {
+        return compute_in_vertical_writing_mode(
+            tree,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            run_mode,
+            compute_horizontal,
+        );
+    }
+    compute_horizontal(tree, node, known_dimensions, parent_size, available_space, run_mode)
+}
+
+/// Computes the layout of a table whose inline axis is horizontal
+fn compute_horizontal(
+    tree: &mut impl LayoutTree,
+    node: NodeId,
+    known_dimensions: Size<Option<f32>>,
+    parent_size: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+) -> SizeBaselinesAndMargins {
+    let style = tree.style(node);
+
+    // Right-to-left tables are laid out as if they were left-to-right with the horizontal sides of the table and its
+    // cells swapped, and the horizontal positions of the rows and cells are then mirrored
+    let is_rtl = style.direction.is_rtl();
+    let aspect_ratio = style.aspect_ratio;
+    let border_collapse = style.border_collapse;
+    let margin = style.margin.resolve_or_zero(parent_size.width);
+    let padding = style.padding.swap_horizontal_sides_if(is_rtl).resolve_or_zero(parent_size.width);
+    let border = style.border.swap_horizontal_sides_if(is_rtl).resolve_or_zero(parent_size.width);
+    let border_spacing = style.border_spacing.resolve_or_zero(parent_size);
+    let box_sizing_adjustment = style.box_sizing.adjustment((padding + border).sum_axes());
+    let size =
+        style.size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio).maybe_add(box_sizing_adjustment);
+    let min_size = style
+        .min_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let max_size = style
+        .max_size
+        .maybe_resolve(parent_size)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+
+    // Short-circuit layout if the table's size is all that is needed and it is already known
+    if run_mode == RunMode::ComputeSize {
+        if let Size { width: Some(width), height: Some(height) } = known_dimensions {
+            return Size { width, height }.into();
+        }
+    }
+
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log("TABLE");
+
+    // 1. Build the table's structure and place its cells in its grid of rows and columns
+    let mut structure = generate_table_structure(tree, node);
+    let column_count = place_cells(&mut structure);
+    let row_count = structure.rows.len();
+    let has_cells = column_count > 0;
+
+    // 2. Determine the borders (or spacing) between the columns and rows, and how the cells overlap them
+    let (column_gutters, row_gutters) = match border_collapse {
+        BorderCollapse::Separate if has_cells => (
+            (0..=column_count).map(|_| border_spacing.width).collect(),
+            (0..=row_count).map(|_| border_spacing.height).collect(),
+        ),
+        // A table without cells has no columns to space, but its (empty) rows still need the gutters around them
+        BorderCollapse::Separate => (Vec::new(), (0..=row_count).map(|_| 0.0).collect()),
+        BorderCollapse::Collapse => {
+            compute_collapsed_borders(tree, &mut structure.cells, border, column_count, row_count, is_rtl)
+        }
+    };
+
+    // The grid of rows and columns is inset from the table's border box by its padding and border, except that the
+    // collapsed borders around the edges of the grid replace the table's own border (and its padding is ignored)
+    let grid_inset = match (border_collapse, has_cells) {
+        (BorderCollapse::Separate, _) => padding + border,
+        (BorderCollapse::Collapse, true) => Rect::zero(),
+        (BorderCollapse::Collapse, false) => border,
+    };
+    // The parts of the table's border box that act as its border (for positioning its out-of-flow children) and padding
+    let (used_border, used_padding) = match (border_collapse, has_cells) {
+        (BorderCollapse::Separate, _) => (border, padding),
+        (BorderCollapse::Collapse, true) => (
+            Rect {
+                left: column_gutters[0] / 2.0,
+                right: column_gutters[column_count] / 2.0,
+                top: row_gutters[0] / 2.0,
+                bottom: row_gutters[row_count] / 2.0,
+            },
+            Rect::zero(),
+        ),
+        (BorderCollapse::Collapse, false) => (border, Rect::zero()),
+    };
+
+    // 3. Determine the width of the table and of its columns
+    measure_cell_widths(tree, &mut structure.cells);
+    let (column_min_widths, column_max_widths) =
+        compute_column_width_limits(&structure.cells, &column_gutters, column_count);
+    let fixed_width = grid_inset.horizontal_axis_sum() + column_gutters.iter().sum::<f32>();
+    let min_width = fixed_width + column_min_widths.iter().sum::<f32>();
+    let max_width = fixed_width + column_max_widths.iter().sum::<f32>();
+    let width = known_dimensions.width.unwrap_or_else(|| {
+        let width = size.width.unwrap_or_else(|| match available_space.width {
+            AvailableSpace::Definite(available_width) => {
+                f32_max(min_width, f32_min(max_width, available_width - margin.horizontal_axis_sum()))
+            }
+            AvailableSpace::MinContent => min_width,
+            AvailableSpace::MaxContent => max_width,
+        });
+        f32_max(width.maybe_clamp(min_size.width, max_size.width), min_width)
+    });
+    let column_widths = distribute_space(&column_min_widths, &column_max_widths, width - fixed_width);
+    let column_starts = compute_track_starts(&column_widths, &column_gutters, grid_inset.left);
+
+    // 4. Determine the height of the table and of its rows
+    let mut row_heights = compute_row_heights(tree, &structure, &column_starts, &column_widths, &row_gutters);
+    let fixed_height = grid_inset.vertical_axis_sum() + row_gutters.iter().sum::<f32>();
+    let content_height = fixed_height + row_heights.iter().sum::<f32>();
+    let height = known_dimensions.height.unwrap_or_else(|| {
+        f32_max(size.height.unwrap_or(content_height).maybe_clamp(min_size.height, max_size.height), content_height)
+    });
+    if height > content_height && row_count > 0 {
+        row_heights = distribute_space(&row_heights, &row_heights, height - fixed_height);
+    }
+    let row_starts = compute_track_starts(&row_heights, &row_gutters, grid_inset.top);
+    let table_size = Size { width, height };
+
+    if run_mode == RunMode::ComputeSize {
+        return table_size.into();
+    }
+
+    // 5. Compute the boxes of the rows and row groups
+    let grid_columns = match column_count {
+        0 => grid_inset.left..grid_inset.left,
+        _ => column_starts[0]..column_starts[column_count - 1] + column_widths[column_count - 1],
+    };
+    let row_boxes: Vec<PartBox> = (0..row_count)
+        .map(|row| PartBox {
+            location: Point { x: grid_columns.start, y: row_starts[row] },
+            size: Size { width: grid_columns.end - grid_columns.start, height: row_heights[row] },
+        })
+        .collect();
+    let group_boxes: Vec<PartBox> = structure
+        .groups
+        .iter()
+        .map(|group| match group.rows.is_empty() {
+            true => PartBox {
+                location: Point { x: grid_columns.start, y: grid_inset.top },
+                size: Size { width: grid_columns.end - grid_columns.start, height: 0.0 },
+            },
+            false => {
+                let first = row_boxes[group.rows.start];
+                let last = row_boxes[group.rows.end - 1];
+                PartBox {
+                    location: first.location,
+                    size: Size {
+                        width: first.size.width,
+                        height: last.location.y + last.size.height - first.location.y,
+                    },
+                }
+            }
+        })
+        .collect();
+
+    // Locations are computed relative to the table as if it were left-to-right, then mirrored if it is right-to-left
+    // and made relative to the parent of the child
+    let to_parent_location = |parent: TablePart, location: Point<f32>, size: Size<f32>| {
+        let mirror = |location: Point<f32>, size: Size<f32>| Point {
+            x: if is_rtl { width - location.x - size.width } else { location.x },
+            y: location.y,
+        };
+        let parent_origin = match parent {
+            TablePart::Table => Point::zero(),
+            TablePart::Group(group) => mirror(group_boxes[group].location, group_boxes[group].size),
+            TablePart::Row(row) => mirror(row_boxes[row].location, row_boxes[row].size),
+        };
+        let location = mirror(location, size);
+        Point { x: location.x - parent_origin.x, y: location.y - parent_origin.y }
+    };
+
+    // 6. Lay out the cells in the boxes formed by the columns and rows they span
+    let mut first_baseline = None;
+    let mut last_baseline = None;
+    for cell in structure.cells.iter() {
+        let last_column = cell.column + cell.column_span - 1;
+        let last_row = cell.row + cell.row_span - 1;
+        let location =
+            Point { x: column_starts[cell.column] - cell.outset.left, y: row_starts[cell.row] - cell.outset.top };
+        let cell_size = Size {
+            width: column_starts[last_column] + column_widths[last_column] + cell.outset.right - location.x,
+            height: row_starts[last_row] + row_heights[last_row] + cell.outset.bottom - location.y,
+        };
+        let output = tree.perform_child_layout(
+            cell.node,
+            cell_size.map(Some),
+            Size::NONE,
+            cell_size.map(AvailableSpace::Definite),
+            SizingMode::InherentSize,
+            Line::FALSE,
+        );
+
+        // The baselines of the table are those of its first and last rows that have a baseline
+        if first_baseline.is_none() {
+            first_baseline = output.first_baselines.y.map(|baseline| location.y + baseline);
+        }
+        if let Some(baseline) = output.last_baselines.y {
+            last_baseline = Some(location.y + baseline);
+        }
+
+        let parent = parent_of_cell(&structure, cell);
+        *tree.layout_mut(cell.node) = Layout {
+            order: cell.order,
+            size: cell_size,
+            location: to_parent_location(parent, location, cell_size),
+            is_visible: cell.is_visible,
+            content_size: output.content_size,
+        };
+    }
+
+    // 7. Lay out the hidden and absolutely positioned children of the table, its row groups and its rows. Fixed
+    // position children are positioned against the viewport, once the whole tree has been laid out.
+    for child in structure.out_of_flow.iter() {
+        let child_style = tree.style(child.node);
+        if child_style.display == Display::None {
+            *tree.layout_mut(child.node) = Layout::with_order(child.order);
+            tree.perform_child_layout(
+                child.node,
+                Size::NONE,
+                Size::NONE,
+                Size::MAX_CONTENT,
+                SizingMode::InherentSize,
+                Line::FALSE,
+            );
+            continue;
+        }
+        if child_style.position != Position::Absolute {
+            continue;
+        }
+
+        // The children of the table are positioned against its padding box, and those of rows and row groups against
+        // their border box
+        let (area, static_position) = match child.parent {
+            TablePart::Table => (
+                PartBox {
+                    location: Point { x: used_border.left, y: used_border.top },
+                    size: table_size - used_border.sum_axes(),
+                },
+                Point { x: used_padding.left, y: used_padding.top },
+            ),
+            TablePart::Group(group) => (group_boxes[group], Point::zero()),
+            TablePart::Row(row) => (row_boxes[row], Point::zero()),
+        };
+        let mut layout = perform_absolute_layout(tree, child.node, area.size, static_position, is_rtl);
+        let location = Point { x: area.location.x + layout.location.x, y: area.location.y + layout.location.y };
+        layout.order = child.order;
+        layout.location = to_parent_location(child.parent, location, layout.size);
+        *tree.layout_mut(child.node) = layout;
+    }
+
+    // 8. Set the layouts of the rows and row groups, whose scrollable overflow depends on their children
+    for (index, row) in structure.rows.iter().enumerate() {
+        if let Some(row_node) = row.node {
+            let parent = row.group.map(TablePart::Group).unwrap_or(TablePart::Table);
+            let row_box = row_boxes[index];
+            perform_contents_layout(tree, row_node);
+            *tree.layout_mut(row_node) = Layout {
+                order: row.order,
+                size: row_box.size,
+                location: to_parent_location(parent, row_box.location, row_box.size),
+                is_visible: row.is_visible,
+                content_size: Size::zero(),
+            };
+            let content_size = compute_content_size(tree, row_node, row_box.size, Rect::zero(), Rect::zero());
+            tree.layout_mut(row_node).content_size = content_size;
+        }
+    }
+    for (group, group_box) in structure.groups.iter().zip(group_boxes.iter()) {
+        perform_contents_layout(tree, group.node);
+        *tree.layout_mut(group.node) = Layout {
+            order: group.order,
+            size: group_box.size,
+            location: to_parent_location(TablePart::Table, group_box.location, group_box.size),
+            is_visible: group.is_visible,
+            content_size: Size::zero(),
+        };
+        let content_size = compute_content_size(tree, group.node, group_box.size, Rect::zero(), Rect::zero());
+        tree.layout_mut(group.node).content_size = content_size;
+    }
+    perform_contents_layout(tree, node);
+
+    // 9. Compute the scrollable overflow of the table from the final positions of its children
+    let content_size = compute_content_size(
+        tree,
+        node,
+        table_size,
+        used_padding.swap_horizontal_sides_if(is_rtl),
+        used_border.swap_horizontal_sides_if(is_rtl),
+    );
+
+    SizeBaselinesAndMargins {
+        size: table_size,
+        first_baselines: Point { x: None, y: first_baseline },
+        last_baselines: Point { x: None, y: last_baseline },
+        content_size,
+        ..table_size.into()
+    }
+}
+
+/// Build the structure of the table `node` from its layout children. A row group or row that is laid out on its own
+/// (rather than by its parent table) is treated as the only row group or row of an anonymous table.
+fn generate_table_structure(tree: &impl LayoutTree, node: NodeId) -> TableStructure {
+    let mut structure = TableStructure::default();
+    match tree.style(node).display {
+        Display::TableRow => {
+            structure.rows.push(TableRow { node: None, order: 0, group: None, is_visible: true, min_height: 0.0 });
+            push_cells(tree, node, 0, TablePart::Table, &mut structure);
+        }
+        display => push_rows(tree, node, display == Display::Table, None, TablePart::Table, &mut structure),
+    }
+    structure
+}
+
+/// Push the rows of the table (or row group) `node`. Consecutive children that are not rows (or row groups, if
+/// `allows_groups` is true) are cells of an anonymous row.
+fn push_rows(
+    tree: &impl LayoutTree,
+    node: NodeId,
+    allows_groups: bool,
+    group: Option<usize>,
+    parent: TablePart,
+    structure: &mut TableStructure,
+) {
+    let mut anonymous_row = None;
+    for (order, child) in layout_children(tree, node).into_iter().enumerate() {
+        let order = order as u32;
+        let child_style = tree.style(child);
+        if child_style.display == Display::None || child_style.position.is_out_of_flow() {
+            structure.out_of_flow.push(OutOfFlowChild { node: child, order, parent });
+            continue;
+        }
+
+        match child_style.display {
+            Display::TableRowGroup if allows_groups => {
+                anonymous_row = None;
+                let group = structure.groups.len();
+                let first_row = structure.rows.len();
+                structure.groups.push(TableRowGroup {
+                    node: child,
+                    order,
+                    rows: first_row..first_row,
+                    is_visible: child_style.visibility.is_visible(),
+                });
+                push_rows(tree, child, false, Some(group), TablePart::Group(group), structure);
+                structure.groups[group].rows.end = structure.rows.len();
+            }
+            Display::TableRow => {
+                anonymous_row = None;
+                let row = structure.rows.len();
+                let min_height = f32_max(
+                    child_style.size.height.maybe_resolve(None).unwrap_or(0.0),
+                    child_style.min_size.height.maybe_resolve(None).unwrap_or(0.0),
+                );
+                structure.rows.push(TableRow {
+                    node: Some(child),
+                    order,
+                    group,
+                    is_visible: child_style.visibility.is_visible(),
+                    min_height,
+                });
+                push_cells(tree, child, row, TablePart::Row(row), structure);
+            }
+            _ => {
+                let row = *anonymous_row.get_or_insert_with(|| {
+                    structure.rows.push(TableRow { node: None, order: 0, group, is_visible: true, min_height: 0.0 });
+                    structure.rows.len() - 1
+                });
+                structure.cells.push(new_cell(child_style, child, order, row));
+            }
+        }
+    }
+}
+
+/// Push the children of the row `node` as its cells
+fn push_cells(tree: &impl LayoutTree, node: NodeId, row: usize, parent: TablePart, structure: &mut TableStructure) {
+    for (order, child) in layout_children(tree, node).into_iter().enumerate() {
+        let order = order as u32;
+        let child_style = tree.style(child);
+        if child_style.display == Display::None || child_style.position.is_out_of_flow() {
+            structure.out_of_flow.push(OutOfFlowChild { node: child, order, parent });
+            continue;
+        }
+        structure.cells.push(new_cell(child_style, child, order, row));
+    }
+}
+
+/// Create a cell in `row` from the node `node` with style `style`
+fn new_cell(style: &Style, node: NodeId, order: u32, row: usize) -> TableCell {
+    TableCell {
+        node,
+        order,
+        is_visible: style.visibility.is_visible(),
+        row,
+        column: 0,
+        row_span: style.row_span as usize,
+        column_span: (style.col_span as usize).max(1),
+        outset: Rect::zero(),
+        min_content_width: 0.0,
+        max_content_width: 0.0,
+    }
+}
+
+/// The node that a cell is a child of. The cells of anonymous rows are children of the row group or table that
+/// contains the row.
+fn parent_of_cell(structure: &TableStructure, cell: &TableCell) -> TablePart {
+    let row = &structure.rows[cell.row];
+    match (row.node, row.group) {
+        (Some(_), _) => TablePart::Row(cell.row),
+        (None, Some(group)) => TablePart::Group(group),
+        (None, None) => TablePart::Table,
+    }
+}
+
+/// Place each cell in the first column of its row that is not occupied by a cell from a previous row that spans into
+/// it, and clamp the row span of each cell to the end of its row group. Returns the number of columns in the table.
+///
+/// Rows that aren't in a row group are grouped with the adjacent rows that aren't in a row group either.
+fn place_cells(structure: &mut TableStructure) -> usize {
+    let row_count = structure.rows.len();
+    let mut group_ends = Vec::new();
+    let mut next_group_start = row_count;
+    for (index, row) in structure.rows.iter().enumerate().rev() {
+        match row.group {
+            Some(group) => {
+                group_ends.push(structure.groups[group].rows.end);
+                next_group_start = index;
+            }
+            None => group_ends.push(next_group_start),
+        }
+    }
+    group_ends.reverse();
+
+    // The row that each column is occupied until (exclusive)
+    let mut occupied_until: Vec<usize> = Vec::new();
+    let mut current_row = None;
+    let mut column = 0;
+    for cell in structure.cells.iter_mut() {
+        if current_row != Some(cell.row) {
+            current_row = Some(cell.row);
+            column = 0;
+        }
+        while column < occupied_until.len() && occupied_until[column] > cell.row {
+            column += 1;
+        }
+
+        let group_end = group_ends[cell.row];
+        let end_row = match cell.row_span {
+            0 => group_end,
+            span => (cell.row + span).min(group_end),
+        };
+        cell.row_span = end_row - cell.row;
+        cell.column = column;
+
+        let end_column = column + cell.column_span;
+        while occupied_until.len() < end_column {
+            occupied_until.push(0);
+        }
+        for occupied_until in occupied_until[column..end_column].iter_mut() {
+            *occupied_until = end_row;
+        }
+        column = end_column;
+    }
+    occupied_until.len()
+}
+
+/// Compute the widths of the collapsed borders of the table: one on each side of each column (horizontally) and row
+/// (vertically). Each is as wide as the widest of the borders of the cells on either side of it, or of the table's
+/// border at the edges of the table. Also sets the outset of each cell to its own border.
+fn compute_collapsed_borders(
+    tree: &impl LayoutTree,
+    cells: &mut [TableCell],
+    table_border: Rect<f32>,
+    column_count: usize,
+    row_count: usize,
+    is_rtl: bool,
+) -> (Vec<f32>, Vec<f32>) {
+    if column_count == 0 {
+        return (Vec::new(), (0..=row_count).map(|_| 0.0).collect());
+    }
+
+    let mut columns: Vec<f32> = (0..=column_count).map(|_| 0.0).collect();
+    let mut rows: Vec<f32> = (0..=row_count).map(|_| 0.0).collect();
+    columns[0] = table_border.left;
+    columns[column_count] = table_border.right;
+    rows[0] = table_border.top;
+    rows[row_count] = table_border.bottom;
+    for cell in cells.iter_mut() {
+        let border = tree.style(cell.node).border.swap_horizontal_sides_if(is_rtl).resolve_or_zero(None);
+        let end_column = cell.column + cell.column_span;
+        let end_row = cell.row + cell.row_span;
+        columns[cell.column] = f32_max(columns[cell.column], border.left);
+        columns[end_column] = f32_max(columns[end_column], border.right);
+        rows[cell.row] = f32_max(rows[cell.row], border.top);
+        rows[end_row] = f32_max(rows[end_row], border.bottom);
+        cell.outset = border;
+    }
+    (columns, rows)
+}
+
+/// Measure the min-content and max-content widths that each cell needs from the columns it spans
+fn measure_cell_widths(tree: &mut impl LayoutTree, cells: &mut [TableCell]) {
+    for cell in cells.iter_mut() {
+        let mut measure_width = |width| {
+            tree.measure_child_size(
+                cell.node,
+                Size::NONE,
+                Size::NONE,
+                Size { width, height: AvailableSpace::MinContent },
+                SizingMode::InherentSize,
+                Line::FALSE,
+            )
+            .width
+        };
+        let min_content_width = measure_width(AvailableSpace::MinContent);
+        let max_content_width = measure_width(AvailableSpace::MaxContent);
+        cell.min_content_width = min_content_width - cell.outset.horizontal_axis_sum();
+        cell.max_content_width = f32_max(max_content_width, min_content_width) - cell.outset.horizontal_axis_sum();
+    }
+}
+
+/// Compute the minimum and maximum widths of the columns of the table from the widths that the cells need. Cells that
+/// span several columns are handled after those that span one, in order of increasing span: whatever they need beyond
+/// the widths of the columns (and the borders between them) that they span is distributed across those columns.
+fn compute_column_width_limits(
+    cells: &[TableCell],
+    column_gutters: &[f32],
+    column_count: usize,
+) -> (Vec<f32>, Vec<f32>) {
+    let mut min_widths: Vec<f32> = (0..column_count).map(|_| 0.0).collect();
+    let mut max_widths: Vec<f32> = (0..column_count).map(|_| 0.0).collect();
+
+    let mut cells_by_span: Vec<&TableCell> = cells.iter().collect();
+    stable_sort_by_key(&mut cells_by_span, |cell| cell.column_span);
+    for cell in cells_by_span {
+        let columns = cell.column..cell.column + cell.column_span;
+        let inner_gutters: f32 = column_gutters[columns.start + 1..columns.end].iter().sum();
+
+        let min_target = cell.min_content_width - inner_gutters;
+        if min_target > min_widths[columns.clone()].iter().sum() {
+            let widths = distribute_space(&min_widths[columns.clone()], &max_widths[columns.clone()], min_target);
+            min_widths[columns.clone()].copy_from_slice(&widths);
+        }
+        let max_target = cell.max_content_width - inner_gutters;
+        if max_target > max_widths[columns.clone()].iter().sum() {
+            let widths = distribute_space(&max_widths[columns.clone()], &max_widths[columns.clone()], max_target);
+            max_widths[columns.clone()].copy_from_slice(&widths);
+        }
+        for column in columns {
+            max_widths[column] = f32_max(max_widths[column], min_widths[column]);
+        }
+    }
+    (min_widths, max_widths)
+}
+
+/// Compute the height of each row of the table from the height of its cells when they are laid out at the width of the
+/// columns they span. Cells that span several rows are handled after those that span one, in order of increasing span,
+/// and distribute whatever height they need beyond that of the rows (and the borders between them) that they span.
+fn compute_row_heights(
+    tree: &mut impl LayoutTree,
+    structure: &TableStructure,
+    column_starts: &[f32],
+    column_widths: &[f32],
+    row_gutters: &[f32],
+) -> Vec<f32> {
+    let mut row_heights: Vec<f32> = structure.rows.iter().map(|row| row.min_height).collect();
+
+    let mut cells_by_span: Vec<&TableCell> = structure.cells.iter().collect();
+    stable_sort_by_key(&mut cells_by_span, |cell| cell.row_span);
+    for cell in cells_by_span {
+        let last_column = cell.column + cell.column_span - 1;
+        let width = column_starts[last_column] + column_widths[last_column] - column_starts[cell.column]
+            + cell.outset.horizontal_axis_sum();
+        let height = tree
+            .measure_child_size(
+                cell.node,
+                Size { width: Some(width), height: None },
+                Size::NONE,
+                Size { width: AvailableSpace::Definite(width), height: AvailableSpace::MinContent },
+                SizingMode::InherentSize,
+                Line::FALSE,
+            )
+            .height
+            - cell.outset.vertical_axis_sum();
+
+        let rows = cell.row..cell.row + cell.row_span;
+        let target = height - row_gutters.get(rows.start + 1..rows.end).map_or(0.0, |gutters| gutters.iter().sum());
+        if target > row_heights[rows.clone()].iter().sum() {
+            let heights = distribute_space(&row_heights[rows.clone()], &row_heights[rows.clone()], target);
+            row_heights[rows].copy_from_slice(&heights);
+        }
+    }
+    row_heights
+}
+
+/// Distribute `target` across a set of columns (or rows) that have the given minimum and maximum sizes. Each gets at
+/// least its minimum size, and the space beyond the sum of the minimums is shared in proportion to how far each is from
+/// its maximum size. Once every column has reached its maximum size, the rest of the space is shared in proportion to
+/// the maximum sizes (or equally, if they are all zero).
+#[allow(clippy::iter_cloned_collect)] // due to no-std support, we need to use `iter_cloned` instead of `to_vec`
+fn distribute_space(min_sizes: &[f32], max_sizes: &[f32], target: f32) -> Vec<f32> {
+    let min_sum: f32 = min_sizes.iter().sum();
+    let max_sum: f32 = max_sizes.iter().sum();
+    if target <= min_sum {
+        return min_sizes.iter().copied().collect();
+    }
+    if target <= max_sum {
+        let ratio = (target - min_sum) / (max_sum - min_sum);
+        return min_sizes.iter().zip(max_sizes.iter()).map(|(&min, &max)| min + (max - min) * ratio).collect();
+    }
+    let extra_space = target - max_sum;
+    let count = max_sizes.len() as f32;
+    max_sizes
+        .iter()
+        .map(|&max| match max_sum > 0.0 {
+            true => max + extra_space * max / max_sum,
+            false => max + extra_space / count,
+        })
+        .collect()
+}
+
+/// Compute the start position of each track (column or row) from the sizes of the tracks and of the gutters around them
+fn compute_track_starts(sizes: &[f32], gutters: &[f32], offset: f32) -> Vec<f32> {
+    let mut position = offset;
+    sizes
+        .iter()
+        .zip(gutters.iter())
+        .map(|(size, gutter)| {
+            let start = position + gutter;
+            position = start + size;
+            start
+        })
+        .collect()
+}
+
+/// Lay out an absolutely positioned child of the table (or of one of its rows or row groups) in an area of size
+/// `area_size`. The location of the returned layout is relative to the area, as if the table were left-to-right.
+/// `auto` margins are treated as zero.
+fn perform_absolute_layout(
+    tree: &mut impl LayoutTree,
+    node: NodeId,
+    area_size: Size<f32>,
+    static_position: Point<f32>,
+    is_rtl: bool,
+) -> Layout {
+    let is_visible = tree.style(node).visibility.is_visible();
+    let AbsoluteBoxSizes { inset, margin, min_size, max_size, known_dimensions, .. } =
+        resolve_absolute_box_sizes(tree, node, area_size, is_rtl);
+    let Rect { left, right, top, bottom } = inset;
+    let margin = margin.map(|margin| margin.unwrap_or(0.0));
+
+    let output = tree.perform_child_layout(
+        node,
+        known_dimensions,
+        area_size.map(Some),
+        Size {
+            width: AvailableSpace::Definite(area_size.width.maybe_clamp(min_size.width, max_size.width)),
+            height: AvailableSpace::Definite(area_size.height.maybe_clamp(min_size.height, max_size.height)),
+        },
+        SizingMode::ContentSize,
+        Line::FALSE,
+    );
+    let size = known_dimensions.unwrap_or(output.size).maybe_clamp(min_size, max_size);
+    let location = Point {
+        x: left
+            .map(|left| left + margin.left)
+            .or(right.map(|right| area_size.width - size.width - right - margin.right))
+            .unwrap_or(static_position.x + margin.left),
+        y: top
+            .map(|top| top + margin.top)
+            .or(bottom.map(|bottom| area_size.height - size.height - bottom - margin.bottom))
+            .unwrap_or(static_position.y + margin.top),
+    };
+
+    Layout { order: 0, size, location, is_visible, content_size: output.content_size }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::distribute_space;
+
+    #[test]
+    fn distribute_space_interpolates_between_min_and_max() {
+        assert_eq!(distribute_space(&[10.0, 20.0], &[30.0, 20.0], 25.0), [10.0, 20.0]);
+        assert_eq!(distribute_space(&[10.0, 20.0], &[30.0, 20.0], 40.0), [20.0, 20.0]);
+    }
+
+    #[test]
+    fn distribute_space_beyond_max_is_proportional_to_max() {
+        assert_eq!(distribute_space(&[10.0, 10.0], &[20.0, 60.0], 160.0), [40.0, 120.0]);
+        assert_eq!(distribute_space(&[0.0, 0.0], &[0.0, 0.0], 10.0), [5.0, 5.0]);
+    }
+}
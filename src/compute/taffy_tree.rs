@@ -1,10 +1,31 @@
 //! Computation specific for the default `Taffy` tree implementation
 
+use crate::compute::common::absolute::{resolve_absolute_box_sizes, AbsoluteBoxSizes};
+use crate::compute::common::intrinsic_size::resolve_intrinsic_sizes;
 use crate::compute::{leaf, LayoutAlgorithm};
-use crate::geometry::{Line, Point, Size};
-use crate::style::{AvailableSpace, Display};
-use crate::tree::{Layout, LayoutTree, NodeId, RunMode, SizeBaselinesAndMargins, SizingMode, Taffy, TaffyError};
+use crate::geometry::{Line, Point, Rect, Size};
+use crate::style::{AvailableSpace, Display, Position};
+use crate::tree::{
+    Layout, LayoutTree, NodeId, RunMode, SizeBaselinesAndMargins, SizingMode, StickyConstraint, Taffy, TaffyError,
+};
 use crate::util::sys::round;
+use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::style::{Direction, RelativeLengthContext};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::util::sys::Box;
+
+#[cfg(all(
+    any(feature = "std", feature = "alloc"),
+    any(feature = "block_layout", feature = "flexbox", feature = "grid")
+))]
+use crate::compute::common::writing_mode::lays_out_children_of;
+#[cfg(all(
+    any(feature = "std", feature = "alloc"),
+    any(feature = "block_layout", feature = "flexbox", feature = "grid")
+))]
+use crate::style::WritingMode;
 
 #[cfg(feature = "block_layout")]
 use crate::compute::BlockAlgorithm;
@@ -15,6 +36,12 @@ use crate::compute::FlexboxAlgorithm;
 #[cfg(feature = "grid")]
 use crate::compute::CssGridAlgorithm;
 
+#[cfg(feature = "table_layout")]
+use crate::compute::TableAlgorithm;
+
+#[cfg(feature = "stack_layout")]
+use crate::compute::StackAlgorithm;
+
 #[cfg(any(feature = "debug", feature = "profile"))]
 use crate::util::debug::NODE_LOGGER;
 
@@ -39,20 +66,52 @@ pub(crate) fn compute_layout(
     root: NodeId,
     available_space: Size<AvailableSpace>,
 ) -> Result<(), TaffyError> {
+    // Font-relative and viewport-relative lengths and inherited directions are resolved up front so that the layout
+    // algorithms only ever see absolute lengths and definite directions. Without an allocator there is nowhere to store
+    // the resolved styles, so they are left as is.
+    let viewport_size = viewport_size(taffy, root, available_space);
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    {
+        let root_context = RelativeLengthContext::new(&taffy.nodes[root.into()].style, viewport_size);
+        resolve_computed_styles(taffy, root, root_context, Direction::Ltr)?;
+        #[cfg(any(feature = "block_layout", feature = "flexbox", feature = "grid"))]
+        if taffy.vertical_writing_mode_count > 0 {
+            transpose_styles(taffy, root, [false, false], None);
+        }
+    }
+
+    // The root has no parent to resolve the intrinsic sizing keywords in its size styles, so they are resolved here
+    let (mut size, mut min_size, mut max_size) = (Size::NONE, Size::NONE, Size::NONE);
+    let available_size = available_space.into_options();
+    resolve_intrinsic_sizes(taffy, root, available_size, available_size, &mut size, &mut min_size, &mut max_size);
+
     // Recursively compute node layout
     let size_and_baselines = perform_node_layout(
         taffy,
         root,
-        Size::NONE,
+        size.maybe_clamp(min_size, max_size),
         available_space.into_options(),
         available_space,
         SizingMode::InherentSize,
         Line::FALSE,
     );
 
-    let layout = Layout { order: 0, size: size_and_baselines.size, location: Point::ZERO };
+    let is_visible = taffy.nodes[root.into()].computed_style().visibility.is_visible();
+    let layout = Layout {
+        order: 0,
+        size: size_and_baselines.size,
+        location: Point::ZERO,
+        is_visible,
+        content_size: size_and_baselines.content_size,
+    };
     *taffy.layout_mut(root) = layout;
 
+    // Fixed position nodes are skipped by the layout algorithms and are instead laid out against the viewport once the
+    // rest of the tree has been laid out
+    if taffy.fixed_position_count > 0 {
+        perform_fixed_layout(taffy, root, Point::ZERO, available_size.width, viewport_size);
+    }
+
     // If rounding is enabled, recursively round the layout's of this node and all children
     if taffy.config.use_rounding {
         round_layout(taffy, root, 0.0, 0.0);
@@ -61,6 +120,112 @@ pub(crate) fn compute_layout(
     Ok(())
 }
 
+/// The size of the viewport that viewport-relative lengths resolve against and that fixed position nodes are laid out
+/// against. In each axis, this is the available space if it is definite, or else the size of the root node if that is
+/// set by an absolute length in its styles, or else zero.
+fn viewport_size(taffy: &Taffy, root: NodeId, available_space: Size<AvailableSpace>) -> Size<f32> {
+    let style = &taffy.nodes[root.into()].style;
+    let padding_border_sum = (style.padding.resolve_or_zero(None) + style.border.resolve_or_zero(None)).sum_axes();
+    let box_sizing_adjustment = style.box_sizing.adjustment(padding_border_sum);
+    let root_size = style.size.maybe_resolve(Size::NONE).maybe_add(box_sizing_adjustment).maybe_clamp(
+        style.min_size.maybe_resolve(Size::NONE).maybe_add(box_sizing_adjustment),
+        style.max_size.maybe_resolve(Size::NONE).maybe_add(box_sizing_adjustment),
+    );
+    available_space.into_options().or(root_size).unwrap_or(Size::ZERO)
+}
+
+/// Resolves the font-relative and viewport-relative lengths and the inherited direction in the styles of `node` and its
+/// descendants into their computed styles, given the direction of the parent of `node`. Only the nodes whose computed
+/// style has changed are marked as dirty, so the cached layouts of the rest of the tree survive a change to the
+/// viewport size or the root font size.
+///
+/// A style is only resolved again once it or the context it was resolved in changes, and the descendants of a node are
+/// skipped if its context is unchanged and it isn't dirty (as changing the style of a node marks its ancestors dirty).
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn resolve_computed_styles(
+    taffy: &mut Taffy,
+    node: NodeId,
+    context: RelativeLengthContext,
+    parent_direction: Direction,
+) -> Result<(), TaffyError> {
+    let node_data = &taffy.nodes[node.into()];
+    let direction = node_data.style.direction.resolve(parent_direction);
+    if node_data.relative_length_context == Some(context) && node_data.parent_direction == parent_direction {
+        if !node_data.cache.is_empty() {
+            return Ok(());
+        }
+    } else {
+        // The layout algorithms treat an unresolved `Direction::Inherit` as `Direction::Ltr`, so the style only needs
+        // resolving if it inherits a right-to-left direction
+        let inherits_rtl = node_data.style.direction == Direction::Inherit && direction.is_rtl();
+        let computed_style = (node_data.style.uses_relative_lengths() || inherits_rtl).then(|| {
+            let mut style = node_data.style.clone();
+            style.resolve_relative_lengths(&context);
+            style.direction = direction;
+            Box::new(style)
+        });
+        let node_data = &mut taffy.nodes[node.into()];
+        node_data.relative_length_context = Some(context);
+        node_data.parent_direction = parent_direction;
+        if computed_style != node_data.computed_style {
+            node_data.computed_style = computed_style;
+            node_data.transposed_styles = [None, None];
+            taffy.mark_dirty(node)?;
+        }
+    }
+
+    for index in 0..taffy.children[node.into()].len() {
+        let child = taffy.child(node, index);
+        let child_context = context.for_child(&taffy.nodes[child.into()].style);
+        resolve_computed_styles(taffy, child, child_context, direction)?;
+    }
+
+    Ok(())
+}
+
+/// Caches the transposed styles of the nodes that are laid out in a vertical writing mode (see
+/// [`LayoutTree::transposed_style`]), so that they aren't transposed each time such a node is laid out. Styles are only
+/// transposed again once they change. `writing_modes` are the vertical writing modes (in the order of
+/// [`WritingMode::VERTICAL`]) of the nodes that lay out `node`, and `layout_parent` is its nearest ancestor that isn't a
+/// `display: contents` node.
+#[cfg(all(
+    any(feature = "std", feature = "alloc"),
+    any(feature = "block_layout", feature = "flexbox", feature = "grid")
+))]
+fn transpose_styles(taffy: &mut Taffy, node: NodeId, writing_modes: [bool; 2], layout_parent: Option<NodeId>) {
+    // The children of a `display: contents` node are laid out by its parent
+    let style = taffy.nodes[node.into()].computed_style();
+    if style.display == Display::Contents {
+        for index in 0..taffy.children[node.into()].len() {
+            let child = taffy.child(node, index);
+            transpose_styles(taffy, child, writing_modes, layout_parent);
+        }
+        return;
+    }
+
+    // Nodes are laid out in their own writing mode as well as in those of the nodes that lay them out. The children of
+    // subgrids, inline boxes and table parts are laid out by the node that lays out their parent.
+    let own_writing_modes = WritingMode::VERTICAL.map(|writing_mode| style.writing_mode == writing_mode);
+    let lays_out_children =
+        layout_parent.is_some_and(|parent| lays_out_children_of(taffy.nodes[parent.into()].computed_style(), style));
+    let child_writing_modes =
+        [0, 1].map(|index| own_writing_modes[index] || (lays_out_children && writing_modes[index]));
+
+    let node_data = &mut taffy.nodes[node.into()];
+    for (index, writing_mode) in WritingMode::VERTICAL.into_iter().enumerate() {
+        if !writing_modes[index] && !own_writing_modes[index] {
+            node_data.transposed_styles[index] = None;
+        } else if node_data.transposed_styles[index].is_none() {
+            node_data.transposed_styles[index] = Some(Box::new(node_data.computed_style().transposed(writing_mode)));
+        }
+    }
+
+    for index in 0..taffy.children[node.into()].len() {
+        let child = taffy.child(node, index);
+        transpose_styles(taffy, child, child_writing_modes, Some(node));
+    }
+}
+
 /// Perform full layout on a node. Chooses which algorithm to use based on the `display` property.
 pub(crate) fn perform_node_layout(
     tree: &mut Taffy,
@@ -126,6 +291,12 @@ fn compute_node_layout(
     let node_key = node.into();
     let has_children = !tree.children[node_key].is_empty();
 
+    // A node that is laid out on its own isn't split into fragments: these are only set when it is placed on lines
+    // (without being laid out) by its inline formatting context, so clear any left over from a previous layout
+    if run_mode == RunMode::PerformLayout {
+        tree.fragment_layouts.remove(node_key);
+    }
+
     // First we check if we have a cached result for the given input
     let cache_run_mode = if !has_children { RunMode::PerformLayout } else { run_mode };
     if let Some(cached_size_and_baselines) =
@@ -143,52 +314,17 @@ fn compute_node_layout(
     #[cfg(feature = "debug")]
     debug_log_node(known_dimensions, parent_size, available_space, run_mode, sizing_mode);
 
-    /// Inlined function generic over the LayoutAlgorithm to reduce code duplication
-    #[inline(always)]
-    fn perform_computations<Algorithm: LayoutAlgorithm>(
-        tree: &mut impl LayoutTree,
-        node: NodeId,
-        known_dimensions: Size<Option<f32>>,
-        parent_size: Size<Option<f32>>,
-        available_space: Size<AvailableSpace>,
-        run_mode: RunMode,
-        sizing_mode: SizingMode,
-        vertical_margins_are_collapsible: Line<bool>,
-    ) -> SizeBaselinesAndMargins {
-        #[cfg(feature = "debug")]
-        NODE_LOGGER.log(Algorithm::NAME);
-
-        match run_mode {
-            RunMode::PerformLayout => Algorithm::perform_layout(
-                tree,
-                node,
-                known_dimensions,
-                parent_size,
-                available_space,
-                sizing_mode,
-                vertical_margins_are_collapsible,
-            ),
-            RunMode::ComputeSize => Algorithm::measure_size(
-                tree,
-                node,
-                known_dimensions,
-                parent_size,
-                available_space,
-                sizing_mode,
-                vertical_margins_are_collapsible,
-            )
-            .into(),
-        }
-    }
-
-    let display_mode = tree.nodes[node_key].style.display;
+    let display_mode = tree.nodes[node_key].computed_style().display;
     let computed_size_and_baselines = match (display_mode, has_children) {
-        (Display::None, _) => {
+        // A `Display::Contents` node is only laid out directly if it is the root node, as its parent lays out its
+        // children in its place. It has no box of its own to lay them out in, so it is treated as hidden.
+        (Display::None | Display::Contents, _) => {
             perform_taffy_tree_hidden_layout(tree, node);
             SizeBaselinesAndMargins::HIDDEN
         }
+        // Inline-level nodes that are laid out directly (rather than as part of their parent's lines) behave like blocks
         #[cfg(feature = "block_layout")]
-        (Display::Block, true) => perform_computations::<BlockAlgorithm>(
+        (Display::Block | Display::Inline | Display::InlineBlock, true) => perform_computations::<BlockAlgorithm>(
             tree,
             node,
             known_dimensions,
@@ -220,27 +356,61 @@ fn compute_node_layout(
             sizing_mode,
             vertical_margins_are_collapsible,
         ),
-        (_, false) => match run_mode {
-            RunMode::PerformLayout => leaf::perform_layout(
-                &tree.nodes[node_key].style,
-                tree.nodes[node_key].needs_measure.then(|| &tree.measure_funcs[node_key]),
-                known_dimensions,
-                parent_size,
-                available_space,
-                sizing_mode,
-                vertical_margins_are_collapsible,
-            ),
-            RunMode::ComputeSize => leaf::measure_size(
-                &tree.nodes[node_key].style,
-                tree.nodes[node_key].needs_measure.then(|| &tree.measure_funcs[node_key]),
-                known_dimensions,
-                parent_size,
-                available_space,
-                sizing_mode,
-                vertical_margins_are_collapsible,
-            )
-            .into(),
-        },
+        // A row group or row that is laid out directly (rather than by its table) is laid out as an anonymous table
+        #[cfg(feature = "table_layout")]
+        (Display::Table | Display::TableRowGroup | Display::TableRow, true) => perform_computations::<TableAlgorithm>(
+            tree,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            run_mode,
+            sizing_mode,
+            vertical_margins_are_collapsible,
+        ),
+        #[cfg(feature = "table_layout")]
+        (Display::TableCell, true) => perform_computations::<BlockAlgorithm>(
+            tree,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            run_mode,
+            sizing_mode,
+            vertical_margins_are_collapsible,
+        ),
+        #[cfg(feature = "stack_layout")]
+        (Display::Stack, true) => perform_computations::<StackAlgorithm>(
+            tree,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            run_mode,
+            sizing_mode,
+            vertical_margins_are_collapsible,
+        ),
+        (Display::Custom(id), true) => perform_custom_computations(
+            tree,
+            id,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            run_mode,
+            sizing_mode,
+            vertical_margins_are_collapsible,
+        ),
+        (_, false) => compute_leaf_layout(
+            tree,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            run_mode,
+            sizing_mode,
+            vertical_margins_are_collapsible,
+        ),
     };
 
     // Cache result
@@ -254,6 +424,155 @@ fn compute_node_layout(
     computed_size_and_baselines
 }
 
+/// Lays out (or measures) `node` as a leaf node, using its measure function if it has one
+#[allow(clippy::too_many_arguments)]
+fn compute_leaf_layout(
+    tree: &mut Taffy,
+    node: NodeId,
+    known_dimensions: Size<Option<f32>>,
+    parent_size: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+    sizing_mode: SizingMode,
+    vertical_margins_are_collapsible: Line<bool>,
+) -> SizeBaselinesAndMargins {
+    let node_key = node.into();
+    match run_mode {
+        RunMode::PerformLayout => leaf::perform_layout(
+            tree.nodes[node_key].computed_style(),
+            tree.nodes[node_key].needs_measure.then(|| &tree.measure_funcs[node_key]),
+            known_dimensions,
+            parent_size,
+            available_space,
+            sizing_mode,
+            vertical_margins_are_collapsible,
+        ),
+        RunMode::ComputeSize => leaf::measure_size(
+            tree.nodes[node_key].computed_style(),
+            tree.nodes[node_key].needs_measure.then(|| &tree.measure_funcs[node_key]),
+            known_dimensions,
+            parent_size,
+            available_space,
+            sizing_mode,
+            vertical_margins_are_collapsible,
+        )
+        .into(),
+    }
+}
+
+/// Perform layout on (or measure) a node using a given LayoutAlgorithm. Generic over the algorithm to reduce code
+/// duplication.
+#[inline(always)]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn perform_computations<Algorithm: LayoutAlgorithm>(
+    tree: &mut impl LayoutTree,
+    node: NodeId,
+    known_dimensions: Size<Option<f32>>,
+    parent_size: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+    sizing_mode: SizingMode,
+    vertical_margins_are_collapsible: Line<bool>,
+) -> SizeBaselinesAndMargins {
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log(Algorithm::NAME);
+
+    match run_mode {
+        RunMode::PerformLayout => Algorithm::perform_layout(
+            tree,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            sizing_mode,
+            vertical_margins_are_collapsible,
+        ),
+        RunMode::ComputeSize => Algorithm::measure_size(
+            tree,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            sizing_mode,
+            vertical_margins_are_collapsible,
+        )
+        .into(),
+    }
+}
+
+/// Perform layout on (or measure) a node with a [`Display::Custom`] display mode using the algorithm registered for its
+/// `id`. A node whose id has no registered algorithm is laid out as a block container (or, without the `block_layout`
+/// feature, as if it had no children, which are hidden).
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(not(any(feature = "std", feature = "alloc")), allow(unused_variables))]
+fn perform_custom_computations(
+    tree: &mut Taffy,
+    id: u16,
+    node: NodeId,
+    known_dimensions: Size<Option<f32>>,
+    parent_size: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+    sizing_mode: SizingMode,
+    vertical_margins_are_collapsible: Line<bool>,
+) -> SizeBaselinesAndMargins {
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    if let Some(algorithm) = tree.custom_layout_algorithm(id) {
+        #[cfg(feature = "debug")]
+        NODE_LOGGER.log("CUSTOM");
+
+        return match run_mode {
+            RunMode::PerformLayout => algorithm.perform_layout(
+                tree,
+                node,
+                known_dimensions,
+                parent_size,
+                available_space,
+                sizing_mode,
+                vertical_margins_are_collapsible,
+            ),
+            RunMode::ComputeSize => algorithm
+                .measure_size(
+                    tree,
+                    node,
+                    known_dimensions,
+                    parent_size,
+                    available_space,
+                    sizing_mode,
+                    vertical_margins_are_collapsible,
+                )
+                .into(),
+        };
+    }
+
+    #[cfg(feature = "block_layout")]
+    return perform_computations::<BlockAlgorithm>(
+        tree,
+        node,
+        known_dimensions,
+        parent_size,
+        available_space,
+        run_mode,
+        sizing_mode,
+        vertical_margins_are_collapsible,
+    );
+
+    #[cfg(not(feature = "block_layout"))]
+    {
+        perform_taffy_tree_hidden_layout(tree, node);
+        compute_leaf_layout(
+            tree,
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            run_mode,
+            sizing_mode,
+            vertical_margins_are_collapsible,
+        )
+    }
+}
+
 /// Creates a layout for this node and its children, recursively.
 /// Each hidden node has zero size and is placed at the origin
 fn perform_taffy_tree_hidden_layout(tree: &mut Taffy, node: NodeId) {
@@ -272,6 +591,190 @@ fn perform_taffy_tree_hidden_layout(tree: &mut Taffy, node: NodeId) {
     }
 }
 
+/// Lays out the [`Position::Fixed`] descendants of `node` (which is at `node_location` relative to the root) against a
+/// viewport of size `viewport_size` that is positioned at the root's origin. `containing_block_width` is the width that
+/// the percentages in the padding and border of `node` resolve against.
+fn perform_fixed_layout(
+    tree: &mut Taffy,
+    node: NodeId,
+    node_location: Point<f32>,
+    containing_block_width: Option<f32>,
+    viewport_size: Size<f32>,
+) {
+    // The children of a node are laid out against its content box
+    let style = tree.nodes[node.into()].computed_style();
+    let padding_border =
+        style.padding.resolve_or_zero(containing_block_width) + style.border.resolve_or_zero(containing_block_width);
+    let content_box_width = tree.nodes[node.into()].layout.size.width - padding_border.horizontal_axis_sum();
+
+    for index in 0..tree.children[node.into()].len() {
+        let child = tree.child(node, index);
+        let child_style = tree.nodes[child.into()].computed_style();
+        if child_style.display == Display::None {
+            continue;
+        }
+        let child_containing_block_width = if child_style.position == Position::Fixed {
+            let layout = compute_fixed_layout(tree, node, child, node_location, padding_border, viewport_size);
+            *tree.layout_mut(child) = Layout { order: index as u32, ..layout };
+            viewport_size.width
+        } else {
+            content_box_width
+        };
+
+        let child_location = node_location + tree.nodes[child.into()].layout.location;
+        perform_fixed_layout(tree, child, child_location, Some(child_containing_block_width), viewport_size);
+    }
+}
+
+/// Computes the layout of a [`Position::Fixed`] `node` (relative to its `parent`, which is at `parent_location` relative
+/// to the root and has the resolved `parent_padding_border`), laying out its children in the process
+fn compute_fixed_layout(
+    tree: &mut Taffy,
+    parent: NodeId,
+    node: NodeId,
+    parent_location: Point<f32>,
+    parent_padding_border: Rect<f32>,
+    viewport_size: Size<f32>,
+) -> Layout {
+    let viewport = viewport_size.map(Some);
+    let is_visible = tree.nodes[node.into()].computed_style().visibility.is_visible();
+
+    // Auto margins are treated as zero
+    let AbsoluteBoxSizes { inset, margin, min_size, max_size, known_dimensions, .. } =
+        resolve_absolute_box_sizes(tree, node, viewport_size, false);
+    let Rect { left, right, top, bottom } = inset;
+    let margin = margin.map(|margin| margin.unwrap_or(0.0));
+    let available_space = Size {
+        width: AvailableSpace::Definite(
+            (viewport_size.width - margin.horizontal_axis_sum()).maybe_clamp(min_size.width, max_size.width),
+        ),
+        height: AvailableSpace::Definite(
+            (viewport_size.height - margin.vertical_axis_sum()).maybe_clamp(min_size.height, max_size.height),
+        ),
+    };
+    let measured_size_and_baselines = perform_node_layout(
+        tree,
+        node,
+        known_dimensions,
+        viewport,
+        available_space,
+        SizingMode::ContentSize,
+        Line::FALSE,
+    );
+    let size = known_dimensions.unwrap_or(measured_size_and_baselines.size).maybe_clamp(min_size, max_size);
+
+    // A node without insets in an axis is placed at the start of its parent's content box in that axis
+    let parent_style = tree.nodes[parent.into()].computed_style();
+    let parent_size = tree.nodes[parent.into()].layout.size;
+    let static_x = if parent_style.direction.is_rtl() {
+        parent_size.width - parent_padding_border.right - margin.right - size.width
+    } else {
+        parent_padding_border.left + margin.left
+    };
+    let static_y = parent_padding_border.top + margin.top;
+
+    let x = left
+        .map(|left| left + margin.left)
+        .or(right.map(|right| viewport_size.width - right - margin.right - size.width))
+        .map_or(static_x, |x| x - parent_location.x);
+    let y = top
+        .map(|top| top + margin.top)
+        .or(bottom.map(|bottom| viewport_size.height - bottom - margin.bottom - size.height))
+        .map_or(static_y, |y| y - parent_location.y);
+
+    Layout {
+        order: 0,
+        size,
+        location: Point { x, y },
+        is_visible,
+        content_size: measured_size_and_baselines.content_size,
+    }
+}
+
+/// Computes the constraint on the position of the [`Position::Sticky`] `node` from the current layout of the tree
+pub(crate) fn compute_sticky_constraint(tree: &Taffy, node: NodeId) -> Option<StickyConstraint> {
+    if tree.nodes[node.into()].computed_style().position != Position::Sticky {
+        return None;
+    }
+    let parent = tree.parents[node.into()]?;
+    let layout = tree.nodes[node.into()].layout;
+
+    /// The resolved padding + border of a node, whose percentages resolve against the width of its parent
+    fn padding_border(tree: &Taffy, node: NodeId) -> Rect<f32> {
+        let style = tree.nodes[node.into()].computed_style();
+        let parent_width = tree.parents[node.into()]
+            .map_or(tree.nodes[node.into()].layout.size.width, |parent| tree.nodes[parent.into()].layout.size.width);
+        style.padding.resolve_or_zero(Some(parent_width)) + style.border.resolve_or_zero(Some(parent_width))
+    }
+
+    // Find the nearest scroll container (or the root), tracking the locations of the node and of its parent relative to
+    // the border box of that container
+    let mut scroll_container = parent;
+    let mut node_location = layout.location;
+    let mut parent_location = Point::ZERO;
+    loop {
+        let style = tree.nodes[scroll_container.into()].computed_style();
+        let is_scroll_container = style.overflow.x.is_scroll_container() || style.overflow.y.is_scroll_container();
+        match tree.parents[scroll_container.into()] {
+            Some(next) if !is_scroll_container => {
+                let location = tree.nodes[scroll_container.into()].layout.location;
+                node_location = node_location + location;
+                parent_location = parent_location + location;
+                scroll_container = next;
+            }
+            _ => break,
+        }
+    }
+
+    // Everything is expressed relative to the padding box of the scroll container
+    let scroll_container_style = tree.nodes[scroll_container.into()].computed_style();
+    let scroll_container_size = tree.nodes[scroll_container.into()].layout.size;
+    let scroll_container_border = scroll_container_style.border.resolve_or_zero(Some(scroll_container_size.width));
+    let scrollbar_gutter = scroll_container_style
+        .scrollbar_gutter_inset()
+        .swap_horizontal_sides_if(scroll_container_style.direction.is_rtl());
+    let scrollport_size = Size {
+        width: scroll_container_size.width
+            - scroll_container_border.horizontal_axis_sum()
+            - scrollbar_gutter.horizontal_axis_sum(),
+        height: scroll_container_size.height
+            - scroll_container_border.vertical_axis_sum()
+            - scrollbar_gutter.vertical_axis_sum(),
+    };
+    let origin = Point { x: scroll_container_border.left + scrollbar_gutter.left, y: scroll_container_border.top };
+
+    let inset = tree.nodes[node.into()].computed_style().inset;
+    let sticky_view_rect = Rect {
+        left: inset.left.maybe_resolve(scrollport_size.width),
+        right: inset.right.maybe_resolve(scrollport_size.width).map(|right| scrollport_size.width - right),
+        top: inset.top.maybe_resolve(scrollport_size.height),
+        bottom: inset.bottom.maybe_resolve(scrollport_size.height).map(|bottom| scrollport_size.height - bottom),
+    };
+
+    let parent_size = tree.nodes[parent.into()].layout.size;
+    let parent_padding_border = padding_border(tree, parent);
+    let containing_block = Rect {
+        left: parent_location.x - origin.x + parent_padding_border.left,
+        right: parent_location.x - origin.x + parent_size.width - parent_padding_border.right,
+        top: parent_location.y - origin.y + parent_padding_border.top,
+        bottom: parent_location.y - origin.y + parent_size.height - parent_padding_border.bottom,
+    };
+    let border_box = Rect {
+        left: node_location.x - origin.x,
+        right: node_location.x - origin.x + layout.size.width,
+        top: node_location.y - origin.y,
+        bottom: node_location.y - origin.y + layout.size.height,
+    };
+
+    Some(StickyConstraint {
+        scroll_container,
+        sticky_view_rect,
+        containing_block,
+        border_box,
+        location: layout.location,
+    })
+}
+
 /// Rounds the calculated [`Layout`] to exact pixel values
 /// In order to ensure that no gaps in the layout are introduced we:
 ///   - Always round based on the absolute coordinates rather than parent-relative coordinates
@@ -279,17 +782,27 @@ fn perform_taffy_tree_hidden_layout(tree: &mut Taffy, node: NodeId) {
 ///     rather than rounding the width/height directly
 ///
 /// See <https://github.com/facebook/yoga/commit/aa5b296ac78f7a22e1aeaf4891243c6bb76488e2> for more context
-fn round_layout(tree: &mut impl LayoutTree, node: NodeId, abs_x: f32, abs_y: f32) {
-    let layout = tree.layout_mut(node);
-    let abs_x = abs_x + layout.location.x;
-    let abs_y = abs_y + layout.location.y;
+fn round_layout(tree: &mut Taffy, node: NodeId, abs_x: f32, abs_y: f32) {
+    /// Round a single layout whose parent is at the absolute position (`abs_x`, `abs_y`), returning its own absolute position
+    fn round_single_layout(layout: &mut Layout, abs_x: f32, abs_y: f32) -> (f32, f32) {
+        let abs_x = abs_x + layout.location.x;
+        let abs_y = abs_y + layout.location.y;
 
-    layout.location.x = round(layout.location.x);
-    layout.location.y = round(layout.location.y);
-    layout.size.width = round(abs_x + layout.size.width) - round(abs_x);
-    layout.size.height = round(abs_y + layout.size.height) - round(abs_y);
+        layout.location.x = round(layout.location.x);
+        layout.location.y = round(layout.location.y);
+        layout.size.width = round(abs_x + layout.size.width) - round(abs_x);
+        layout.size.height = round(abs_y + layout.size.height) - round(abs_y);
+        (abs_x, abs_y)
+    }
+
+    let (abs_x, abs_y) = round_single_layout(tree.layout_mut(node), abs_x, abs_y);
+    if let Some(fragment_layouts) = tree.fragment_layouts.get_mut(node.into()) {
+        for fragment_layout in fragment_layouts.iter_mut() {
+            round_single_layout(fragment_layout, abs_x, abs_y);
+        }
+    }
 
-    let child_count = tree.child_count(node);
+    let child_count = tree.children[node.into()].len();
     for index in 0..child_count {
         let child = tree.child(node, index);
         round_layout(tree, child, abs_x, abs_y);
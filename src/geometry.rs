@@ -36,6 +36,28 @@ impl<T> Size<T> {
             AbsoluteAxis::Vertical => self.height,
         }
     }
+
+    #[inline(always)]
+    #[cfg(feature = "stack_layout")]
+    /// Set either the width or height depending on the AbsoluteAxis passed in
+    pub(crate) fn set_abs(&mut self, axis: AbsoluteAxis, value: T) {
+        match axis {
+            AbsoluteAxis::Horizontal => self.width = value,
+            AbsoluteAxis::Vertical => self.height = value,
+        }
+    }
+}
+
+#[cfg(feature = "stack_layout")]
+impl<T> Rect<T> {
+    #[inline(always)]
+    /// Get the sides of the rect in the AbsoluteAxis passed in (left and right, or top and bottom)
+    pub(crate) fn get_abs_sides(self, axis: AbsoluteAxis) -> Line<T> {
+        match axis {
+            AbsoluteAxis::Horizontal => self.horizontal_components(),
+            AbsoluteAxis::Vertical => self.vertical_components(),
+        }
+    }
 }
 
 impl<T: Add> Rect<T> {
@@ -157,6 +179,17 @@ impl<T> Rect<T> {
         Rect { left: f(self.left), right: f(self.right), top: f(self.top), bottom: f(self.bottom) }
     }
 
+    /// Returns a new `Rect` with the left and right sides exchanged if `should_swap` is true
+    ///
+    /// Right-to-left layout is computed by laying out a horizontally mirrored box as if it were left-to-right, so the
+    /// horizontal sides of margins, insets, padding and borders are swapped going in.
+    pub(crate) fn swap_horizontal_sides_if(self, should_swap: bool) -> Rect<T> {
+        match should_swap {
+            true => Rect { left: self.right, right: self.left, top: self.top, bottom: self.bottom },
+            false => self,
+        }
+    }
+
     /// Returns a `Line<T>` representing the left and right properties of the Rect
     pub fn horizontal_components(self) -> Line<T> {
         Line { start: self.left, end: self.right }
@@ -390,6 +423,11 @@ impl<T> Size<T> {
         Size { width: f(self.width, other.width), height: f(self.height, other.height) }
     }
 
+    /// Swaps the width and the height
+    pub(crate) fn transpose(self) -> Size<T> {
+        Size { width: self.height, height: self.width }
+    }
+
     /// Sets the extent of the main layout axis
     ///
     /// Whether this is the width or height depends on the `direction` provided
@@ -550,6 +588,25 @@ impl Size<Option<f32>> {
             None => self,
         }
     }
+
+    /// Applies aspect_ratio (if one is supplied) to a border-box Size when the aspect ratio applies to a box that is
+    /// smaller than the border box by `box_sizing_adjustment` (as is the case for nodes with `BoxSizing::ContentBox`)
+    #[cfg(any(feature = "block_layout", feature = "flexbox", feature = "grid", feature = "taffy_tree"))]
+    pub(crate) fn maybe_apply_aspect_ratio_with_adjustment(
+        self,
+        aspect_ratio: Option<f32>,
+        box_sizing_adjustment: Size<f32>,
+    ) -> Size<Option<f32>> {
+        let inner = Size {
+            width: self.width.map(|width| width - box_sizing_adjustment.width),
+            height: self.height.map(|height| height - box_sizing_adjustment.height),
+        };
+        let inner = inner.maybe_apply_aspect_ratio(aspect_ratio);
+        Size {
+            width: inner.width.map(|width| width + box_sizing_adjustment.width),
+            height: inner.height.map(|height| height + box_sizing_adjustment.height),
+        }
+    }
 }
 
 impl<T> Size<Option<T>> {
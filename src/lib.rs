@@ -30,6 +30,12 @@ pub mod util;
 pub use crate::compute::flexbox::FlexboxAlgorithm;
 #[cfg(feature = "grid")]
 pub use crate::compute::grid::CssGridAlgorithm;
+#[cfg(feature = "stack_layout")]
+pub use crate::compute::stack::StackAlgorithm;
+#[cfg(feature = "table_layout")]
+pub use crate::compute::table::TableAlgorithm;
+#[cfg(all(feature = "taffy_tree", any(feature = "std", feature = "alloc")))]
+pub use crate::compute::CustomLayoutAlgorithm;
 pub use crate::compute::LayoutAlgorithm;
 pub use crate::tree::LayoutTree;
 #[cfg(feature = "taffy_tree")]
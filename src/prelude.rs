@@ -3,28 +3,42 @@
 pub use crate::{
     geometry::{Line, Rect, Size},
     style::{
-        AlignContent, AlignItems, AlignSelf, AvailableSpace, Dimension, Display, JustifyContent, JustifyItems,
-        JustifySelf, LengthPercentage, LengthPercentageAuto, Position, Style,
+        AlignContent, AlignItems, AlignSelf, AvailableSpace, BoxSizing, Clear, Dimension, Direction, Display, Float,
+        JustifyContent, JustifyItems, JustifySelf, LengthPercentage, LengthPercentageAuto, Position, Style, TextAlign,
+        Visibility,
     },
     style_helpers::{
-        auto, fit_content, length, max_content, min_content, percent, zero, FromFlex, FromLength, FromPercent,
-        TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
+        auto, em, fit_content, length, max_content, min_content, percent, rem, vh, vmax, vmin, vw, zero, FromFlex,
+        FromLength, FromPercent, TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
     },
     tree::{Layout, LayoutTree, NodeId},
 };
 
+#[cfg(feature = "std")]
+pub use crate::style::CalcExpression;
+
 #[cfg(feature = "flexbox")]
 pub use crate::style::{FlexDirection, FlexWrap};
 
 #[cfg(feature = "grid")]
 pub use crate::style::{
-    GridAutoFlow, GridPlacement, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
+    GridAutoFlow, GridPlacement, GridTemplateArea, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
     NonRepeatedTrackSizingFunction, TrackSizingFunction,
 };
 #[cfg(feature = "grid")]
 pub use crate::style_helpers::{
-    evenly_sized_tracks, flex, fr, line, minmax, repeat, span, TaffyGridLine, TaffyGridSpan,
+    area, evenly_sized_tracks, flex, fr, line, line_names, masonry, minmax, named_line, named_span, repeat, span,
+    subgrid, template_areas, TaffyGridLine, TaffyGridSpan,
 };
 
+#[cfg(feature = "block_layout")]
+pub use crate::style::ColumnFill;
+
+#[cfg(feature = "table_layout")]
+pub use crate::style::BorderCollapse;
+
+#[cfg(feature = "stack_layout")]
+pub use crate::style::{StackDirection, StackSize, StackSpace};
+
 #[cfg(feature = "taffy_tree")]
 pub use crate::Taffy;
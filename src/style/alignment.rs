@@ -26,9 +26,24 @@ pub enum AlignItems {
     Center,
     /// Items are aligned such as their baselines align
     Baseline,
+    /// Items are aligned such as their last baselines align. Items that are not aligned with any other
+    /// items in this way are packed toward the end of the axis.
+    LastBaseline,
     /// Stretch to fill the container
     Stretch,
+    /// Like `End`, but items that overflow their alignment container are packed toward the start of the axis instead
+    SafeEnd,
+    /// Like `FlexStart`, but items that overflow their alignment container are packed toward the start of the axis
+    /// instead
+    SafeFlexStart,
+    /// Like `FlexEnd`, but items that overflow their alignment container are packed toward the start of the axis
+    /// instead
+    SafeFlexEnd,
+    /// Like `Center`, but items that overflow their alignment container are packed toward the start of the axis
+    /// instead (so that they overflow at the end, where they can be scrolled to)
+    SafeCenter,
 }
+
 /// Used to control how child nodes are aligned.
 /// Does not apply to Flexbox, and will be ignored if specified on a flex container
 /// For Grid it controls alignment in the inline axis
@@ -85,6 +100,15 @@ pub enum AlignContent {
     /// The gap between the first and last items is exactly HALF the gap between items.
     /// The gaps are distributed evenly in proportion to these ratios.
     SpaceAround,
+    /// Like `End`, but content that overflows the container is packed toward the start of the axis instead
+    SafeEnd,
+    /// Like `FlexStart`, but content that overflows the container is packed toward the start of the axis instead
+    SafeFlexStart,
+    /// Like `FlexEnd`, but content that overflows the container is packed toward the start of the axis instead
+    SafeFlexEnd,
+    /// Like `Center`, but content that overflows the container is packed toward the start of the axis instead
+    /// (so that it overflows at the end, where it can be scrolled to)
+    SafeCenter,
 }
 
 /// Sets the distribution of space between and around content items
@@ -93,3 +117,25 @@ pub enum AlignContent {
 ///
 /// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/justify-content)
 pub type JustifyContent = AlignContent;
+
+/// Sets how the inline-level content of a block container is aligned within each line
+///
+/// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/text-align)
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextAlign {
+    /// Content is aligned with the inline-start edge of the line (the left edge unless the container is right-to-left)
+    #[default]
+    Start,
+    /// Content is aligned with the inline-end edge of the line
+    End,
+    /// Content is aligned with the left edge of the line
+    Left,
+    /// Content is aligned with the right edge of the line
+    Right,
+    /// Content is centered within the line
+    Center,
+    /// The free space in each line is distributed evenly between the opportunities to break it (so that the content
+    /// is flush with both edges). The last line is aligned like `Start`.
+    Justify,
+}
@@ -0,0 +1,333 @@
+//! Style types for representing CSS math expressions (`calc()`, `min()`, `max()` and `clamp()`) of lengths and percentages
+use core::fmt::{self, Debug};
+use core::ops::{Add, Deref, Div, Mul, Neg, Sub};
+use std::sync::{PoisonError, RwLock};
+
+use crate::style_helpers::{FromLength, FromPercent, TaffyZero};
+use crate::util::sys::{f32_max, f32_min, Box, Vec};
+
+/// A CSS math expression that combines lengths and percentages, such as `calc(100% - 48px)` or
+/// `clamp(200px, 50%, 600px)`
+///
+/// Expressions are usually built using the arithmetic operators along with [`CalcExpression::min`],
+/// [`CalcExpression::max`] and [`CalcExpression::clamp`], and then converted into a
+/// [`Dimension`](super::Dimension), [`LengthPercentage`](super::LengthPercentage) or
+/// [`LengthPercentageAuto`](super::LengthPercentageAuto) using `.into()`:
+///
+/// ```
+/// # use taffy::prelude::*;
+/// # use taffy::style::CalcExpression;
+/// let width: Dimension = (CalcExpression::from_percent(1.0) - CalcExpression::from_length(48.0)).into();
+/// ```
+///
+/// The length types store a [`CalcId`] rather than the expression itself (so that they remain `Copy`), and the
+/// expression is interned when it is converted into one of them (see [`CalcExpression::intern`]).
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CalcExpression {
+    /// An absolute length
+    Length(f32),
+    /// A percentage of the length that the property resolves percentages against
+    Percent(f32),
+    /// A multiple of the node's font size (`em`)
+    Em(f32),
+    /// A multiple of the root node's font size (`rem`)
+    Rem(f32),
+    /// A fraction of the viewport's width (`vw`)
+    Vw(f32),
+    /// A fraction of the viewport's height (`vh`)
+    Vh(f32),
+    /// A fraction of the smaller of the viewport's width and height (`vmin`)
+    Vmin(f32),
+    /// A fraction of the larger of the viewport's width and height (`vmax`)
+    Vmax(f32),
+    /// The sum of the expressions. Subtraction is represented as the sum with a negated expression. The sum of no
+    /// expressions is zero.
+    Sum(Vec<CalcExpression>),
+    /// The expression multiplied by a number. Division is represented as multiplication by the reciprocal.
+    Product(Box<CalcExpression>, f32),
+    /// The smallest of the expressions: `min()`. Like in CSS, there must be at least one expression: the smallest of no
+    /// expressions can't be resolved.
+    Min(Vec<CalcExpression>),
+    /// The largest of the expressions: `max()`. Like in CSS, there must be at least one expression: the largest of no
+    /// expressions can't be resolved.
+    Max(Vec<CalcExpression>),
+    /// `clamp(min, value, max)`: the value clamped between the bounds. The lower bound wins if the bounds conflict.
+    Clamp {
+        /// The lower bound
+        min: Box<CalcExpression>,
+        /// The preferred value
+        value: Box<CalcExpression>,
+        /// The upper bound
+        max: Box<CalcExpression>,
+    },
+}
+
+/// The expressions that have been interned by [`CalcExpression::intern`], indexed by [`CalcId`]
+static INTERNED_EXPRESSIONS: RwLock<Vec<&'static CalcExpression>> = RwLock::new(Vec::new());
+
+/// A handle to a [`CalcExpression`] that has been interned (see [`CalcExpression::intern`])
+///
+/// This is what the length types store, so that they remain `Copy` and no larger than a length. Equal expressions
+/// share an id, and the id dereferences to the expression.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CalcId(u32);
+
+impl CalcId {
+    /// The expression that the id refers to
+    pub fn expression(self) -> &'static CalcExpression {
+        INTERNED_EXPRESSIONS.read().unwrap_or_else(PoisonError::into_inner)[self.0 as usize]
+    }
+}
+
+impl Deref for CalcId {
+    type Target = CalcExpression;
+
+    fn deref(&self) -> &CalcExpression {
+        self.expression()
+    }
+}
+
+impl Debug for CalcId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.expression().fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CalcId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(self.expression(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CalcId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <CalcExpression as serde::Deserialize>::deserialize(deserializer).map(CalcExpression::intern)
+    }
+}
+
+impl TaffyZero for CalcExpression {
+    const ZERO: Self = Self::Length(0.0);
+}
+impl FromLength for CalcExpression {
+    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
+        Self::Length(value.into())
+    }
+}
+impl FromPercent for CalcExpression {
+    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
+        Self::Percent(percent.into())
+    }
+}
+
+impl CalcExpression {
+    /// The smallest of the expressions (`min()`)
+    pub fn min(expressions: impl IntoIterator<Item = CalcExpression>) -> Self {
+        Self::Min(expressions.into_iter().collect())
+    }
+
+    /// The largest of the expressions (`max()`)
+    pub fn max(expressions: impl IntoIterator<Item = CalcExpression>) -> Self {
+        Self::Max(expressions.into_iter().collect())
+    }
+
+    /// The `value` clamped between `min` and `max` (`clamp()`)
+    pub fn clamp(min: CalcExpression, value: CalcExpression, max: CalcExpression) -> Self {
+        Self::Clamp { min: Box::new(min), value: Box::new(value), max: Box::new(max) }
+    }
+
+    /// Stores the expression for the rest of the program and returns its id, so that it can be referred to by the
+    /// `Copy` length types
+    ///
+    /// Equal expressions share an id, so styles can be rebuilt from the same expressions (for example on every frame)
+    /// without using more memory.
+    pub fn intern(self) -> CalcId {
+        let mut interned = INTERNED_EXPRESSIONS.write().unwrap_or_else(PoisonError::into_inner);
+        let index = match interned.iter().position(|expression| **expression == self) {
+            Some(index) => index,
+            None => {
+                interned.push(Box::leak(Box::new(self)));
+                interned.len() - 1
+            }
+        };
+        CalcId(u32::try_from(index).expect("too many calc() expressions have been interned"))
+    }
+
+    /// Evaluates the expression, resolving percentages against `percentage_basis`
+    ///
+    /// Returns `None` if the expression contains a percentage and the basis is unknown, if it contains a `min()` or
+    /// `max()` of no expressions, or if it contains a font-relative or viewport-relative length that hasn't been resolved (see [`Style::resolve_relative_lengths`](super::Style::resolve_relative_lengths)).
+    pub fn resolve(&self, percentage_basis: Option<f32>) -> Option<f32> {
+        /// Resolve each of the expressions and combine the results
+        fn fold(
+            expressions: &[CalcExpression],
+            percentage_basis: Option<f32>,
+            combine: fn(f32, f32) -> f32,
+        ) -> Option<f32> {
+            let mut values = expressions.iter().map(|expression| expression.resolve(percentage_basis));
+            let first = values.next()??;
+            values.try_fold(first, |result, value| Some(combine(result, value?)))
+        }
+
+        match self {
+            Self::Length(length) => Some(*length),
+            Self::Percent(percent) => percentage_basis.map(|basis| basis * percent),
+            Self::Em(_) | Self::Rem(_) | Self::Vw(_) | Self::Vh(_) | Self::Vmin(_) | Self::Vmax(_) => None,
+            Self::Sum(terms) if terms.is_empty() => Some(0.0),
+            Self::Sum(terms) => fold(terms, percentage_basis, |a, b| a + b),
+            Self::Product(expression, factor) => expression.resolve(percentage_basis).map(|value| value * factor),
+            Self::Min(expressions) => fold(expressions, percentage_basis, f32_min),
+            Self::Max(expressions) => fold(expressions, percentage_basis, f32_max),
+            Self::Clamp { min, value, max } => {
+                let min = min.resolve(percentage_basis)?;
+                let value = value.resolve(percentage_basis)?;
+                let max = max.resolve(percentage_basis)?;
+                Some(f32_max(min, f32_min(value, max)))
+            }
+        }
+    }
+
+    /// Whether the expression contains a percentage (and so depends on the size of the node's container)
+    pub fn uses_percentage(&self) -> bool {
+        match self {
+            Self::Length(_)
+            | Self::Em(_)
+            | Self::Rem(_)
+            | Self::Vw(_)
+            | Self::Vh(_)
+            | Self::Vmin(_)
+            | Self::Vmax(_) => false,
+            Self::Percent(_) => true,
+            Self::Sum(expressions) | Self::Min(expressions) | Self::Max(expressions) => {
+                expressions.iter().any(Self::uses_percentage)
+            }
+            Self::Product(expression, _) => expression.uses_percentage(),
+            Self::Clamp { min, value, max } => {
+                min.uses_percentage() || value.uses_percentage() || max.uses_percentage()
+            }
+        }
+    }
+}
+
+impl Add for CalcExpression {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        match self {
+            Self::Sum(mut terms) => {
+                terms.push(rhs);
+                Self::Sum(terms)
+            }
+            _ => Self::Sum(Vec::from([self, rhs])),
+        }
+    }
+}
+
+impl Sub for CalcExpression {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Neg for CalcExpression {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        match self {
+            Self::Length(length) => Self::Length(-length),
+            Self::Percent(percent) => Self::Percent(-percent),
+            _ => self * -1.0,
+        }
+    }
+}
+
+impl Mul<f32> for CalcExpression {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        match self {
+            Self::Product(expression, factor) => Self::Product(expression, factor * rhs),
+            _ => Self::Product(Box::new(self), rhs),
+        }
+    }
+}
+
+impl Div<f32> for CalcExpression {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        self * (1.0 / rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CalcExpression;
+    use crate::style_helpers::{FromLength, FromPercent};
+
+    fn length(value: f32) -> CalcExpression {
+        CalcExpression::from_length(value)
+    }
+
+    fn percent(value: f32) -> CalcExpression {
+        CalcExpression::from_percent(value)
+    }
+
+    #[test]
+    fn arithmetic() {
+        let expression = percent(1.0) - length(48.0);
+        assert_eq!(expression.resolve(Some(200.0)), Some(152.0));
+        assert_eq!(expression.resolve(None), None);
+
+        let expression = (percent(0.5) + length(10.0)) * 2.0 / 4.0;
+        assert_eq!(expression.resolve(Some(100.0)), Some(30.0));
+        assert_eq!((length(10.0) * 3.0).resolve(None), Some(30.0));
+    }
+
+    #[test]
+    fn comparison_functions() {
+        let clamp = CalcExpression::clamp(length(200.0), percent(0.5), length(600.0));
+        assert_eq!(clamp.resolve(Some(100.0)), Some(200.0));
+        assert_eq!(clamp.resolve(Some(800.0)), Some(400.0));
+        assert_eq!(clamp.resolve(Some(2000.0)), Some(600.0));
+        assert_eq!(clamp.resolve(None), None);
+
+        // The lower bound wins if the bounds conflict
+        let clamp = CalcExpression::clamp(length(50.0), length(10.0), length(20.0));
+        assert_eq!(clamp.resolve(None), Some(50.0));
+
+        let min = CalcExpression::min([length(100.0), percent(0.5)]);
+        let max = CalcExpression::max([length(100.0), percent(0.5)]);
+        assert_eq!(min.resolve(Some(100.0)), Some(50.0));
+        assert_eq!(max.resolve(Some(100.0)), Some(100.0));
+        assert_eq!(min.resolve(None), None);
+    }
+
+    #[test]
+    fn empty_expressions() {
+        assert_eq!(CalcExpression::Sum(Vec::new()).resolve(None), Some(0.0));
+        assert_eq!(CalcExpression::min([]).resolve(Some(100.0)), None);
+        assert_eq!(CalcExpression::max([]).resolve(Some(100.0)), None);
+        assert_eq!((CalcExpression::max([]) + length(10.0)).resolve(Some(100.0)), None);
+    }
+
+    #[test]
+    fn equal_expressions_are_interned_once() {
+        let first = (percent(1.0) - length(48.0)).intern();
+        let second = (percent(1.0) - length(48.0)).intern();
+        assert_eq!(first, second);
+        assert_ne!(first, (percent(1.0) - length(40.0)).intern());
+        assert_eq!(*first, percent(1.0) - length(48.0));
+    }
+
+    #[test]
+    fn uses_percentage() {
+        assert!(!(length(10.0) + length(5.0)).uses_percentage());
+        assert!((length(10.0) + percent(0.5)).uses_percentage());
+        assert!(CalcExpression::clamp(length(1.0), length(2.0), percent(1.0) * 2.0).uses_percentage());
+    }
+}
@@ -1,9 +1,14 @@
 //! Style types for representing lengths / sizes
 
 use crate::geometry::{Rect, Size};
-use crate::style_helpers::{FromLength, FromPercent, TaffyAuto, TaffyMaxContent, TaffyMinContent, TaffyZero};
+use crate::style_helpers::{
+    FromLength, FromPercent, TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
+};
 use crate::util::sys::abs;
 
+#[cfg(feature = "std")]
+use super::{CalcExpression, CalcId};
+
 /// A unit of linear measurement
 ///
 /// This is commonly combined with [`Rect`], [`Point`](crate::geometry::Point) and [`Size<T>`].
@@ -15,6 +20,21 @@ pub enum LengthPercentage {
     Length(f32),
     /// The dimension is stored in percentage relative to the parent item.
     Percent(f32),
+    /// A multiple of the node's font size (`em`)
+    Em(f32),
+    /// A multiple of the root node's font size (`rem`)
+    Rem(f32),
+    /// A fraction of the viewport's width (`vw`). As with percentages, `1.0` is the full width (`100vw`).
+    Vw(f32),
+    /// A fraction of the viewport's height (`vh`)
+    Vh(f32),
+    /// A fraction of the smaller of the viewport's width and height (`vmin`)
+    Vmin(f32),
+    /// A fraction of the larger of the viewport's width and height (`vmax`)
+    Vmax(f32),
+    /// The dimension is computed from a math expression of lengths and percentages (see [`CalcExpression::intern`])
+    #[cfg(feature = "std")]
+    Calc(CalcId),
 }
 impl TaffyZero for LengthPercentage {
     const ZERO: Self = Self::Length(0.0);
@@ -30,6 +50,25 @@ impl FromPercent for LengthPercentage {
     }
 }
 
+impl LengthPercentage {
+    /// Returns true if the value depends on the length that percentages are resolved against
+    #[inline(always)]
+    pub fn uses_percentage(&self) -> bool {
+        match self {
+            Self::Length(_)
+            | Self::Em(_)
+            | Self::Rem(_)
+            | Self::Vw(_)
+            | Self::Vh(_)
+            | Self::Vmin(_)
+            | Self::Vmax(_) => false,
+            Self::Percent(_) => true,
+            #[cfg(feature = "std")]
+            Self::Calc(expression) => expression.uses_percentage(),
+        }
+    }
+}
+
 /// A unit of linear measurement
 ///
 /// This is commonly combined with [`Rect`], [`Point`](crate::geometry::Point) and [`Size<T>`].
@@ -41,6 +80,21 @@ pub enum LengthPercentageAuto {
     Length(f32),
     /// The dimension is stored in percentage relative to the parent item.
     Percent(f32),
+    /// A multiple of the node's font size (`em`)
+    Em(f32),
+    /// A multiple of the root node's font size (`rem`)
+    Rem(f32),
+    /// A fraction of the viewport's width (`vw`). As with percentages, `1.0` is the full width (`100vw`).
+    Vw(f32),
+    /// A fraction of the viewport's height (`vh`)
+    Vh(f32),
+    /// A fraction of the smaller of the viewport's width and height (`vmin`)
+    Vmin(f32),
+    /// A fraction of the larger of the viewport's width and height (`vmax`)
+    Vmax(f32),
+    /// The dimension is computed from a math expression of lengths and percentages (see [`CalcExpression::intern`])
+    #[cfg(feature = "std")]
+    Calc(CalcId),
     /// The dimension should be automatically computed
     Auto,
 }
@@ -61,26 +115,53 @@ impl FromPercent for LengthPercentageAuto {
     }
 }
 
+#[cfg(feature = "std")]
+impl From<CalcExpression> for LengthPercentage {
+    fn from(expression: CalcExpression) -> Self {
+        Self::Calc(expression.intern())
+    }
+}
+
 impl From<LengthPercentage> for LengthPercentageAuto {
     fn from(input: LengthPercentage) -> Self {
         match input {
             LengthPercentage::Length(value) => Self::Length(value),
             LengthPercentage::Percent(value) => Self::Percent(value),
+            LengthPercentage::Em(value) => Self::Em(value),
+            LengthPercentage::Rem(value) => Self::Rem(value),
+            LengthPercentage::Vw(value) => Self::Vw(value),
+            LengthPercentage::Vh(value) => Self::Vh(value),
+            LengthPercentage::Vmin(value) => Self::Vmin(value),
+            LengthPercentage::Vmax(value) => Self::Vmax(value),
+            #[cfg(feature = "std")]
+            LengthPercentage::Calc(expression) => Self::Calc(expression),
         }
     }
 }
 
+#[cfg(feature = "std")]
+impl From<CalcExpression> for LengthPercentageAuto {
+    fn from(expression: CalcExpression) -> Self {
+        Self::Calc(expression.intern())
+    }
+}
+
 impl LengthPercentageAuto {
     /// Returns:
     ///   - Some(length) for Length variants
     ///   - Some(resolved) using the provided context for Percent variants
     ///   - None for Auto variants
+    ///   - Some(resolved) using the provided context for Calc variants
+    ///   - None for font-relative and viewport-relative variants, which must have been resolved beforehand
+    ///     (see [`Style::resolve_relative_lengths`](super::Style::resolve_relative_lengths))
     #[inline(always)]
     pub fn resolve_to_option(self, context: f32) -> Option<f32> {
         match self {
             Self::Length(length) => Some(length),
             Self::Percent(percent) => Some(context * percent),
-            Self::Auto => None,
+            #[cfg(feature = "std")]
+            Self::Calc(expression) => expression.resolve(Some(context)),
+            Self::Auto | Self::Em(_) | Self::Rem(_) | Self::Vw(_) | Self::Vh(_) | Self::Vmin(_) | Self::Vmax(_) => None,
         }
     }
 
@@ -102,8 +183,32 @@ pub enum Dimension {
     Length(f32),
     /// The dimension is stored in percentage relative to the parent item.
     Percent(f32),
+    /// A multiple of the node's font size (`em`)
+    Em(f32),
+    /// A multiple of the root node's font size (`rem`)
+    Rem(f32),
+    /// A fraction of the viewport's width (`vw`). As with percentages, `1.0` is the full width (`100vw`).
+    Vw(f32),
+    /// A fraction of the viewport's height (`vh`)
+    Vh(f32),
+    /// A fraction of the smaller of the viewport's width and height (`vmin`)
+    Vmin(f32),
+    /// A fraction of the larger of the viewport's width and height (`vmax`)
+    Vmax(f32),
+    /// The dimension is computed from a math expression of lengths and percentages (see [`CalcExpression::intern`])
+    #[cfg(feature = "std")]
+    Calc(CalcId),
     /// The dimension should be automatically computed
     Auto,
+    /// The node is sized to the smallest size it can take without its content overflowing (`min-content`)
+    MinContent,
+    /// The node is sized to the size its content would take if given unlimited space (`max-content`)
+    MaxContent,
+    /// The node is sized to its max-content size, but no larger than the specified limit and no smaller than its
+    /// min-content size (`fit-content(…)`)
+    FitContent(LengthPercentage),
+    /// The node fills the space available to it in its containing block, less its margins (`stretch`)
+    Stretch,
 }
 impl TaffyZero for Dimension {
     const ZERO: Self = Self::Length(0.0);
@@ -111,6 +216,17 @@ impl TaffyZero for Dimension {
 impl TaffyAuto for Dimension {
     const AUTO: Self = Self::Auto;
 }
+impl TaffyMinContent for Dimension {
+    const MIN_CONTENT: Self = Self::MinContent;
+}
+impl TaffyMaxContent for Dimension {
+    const MAX_CONTENT: Self = Self::MaxContent;
+}
+impl TaffyFitContent for Dimension {
+    fn fit_content(argument: LengthPercentage) -> Self {
+        Self::FitContent(argument)
+    }
+}
 impl FromLength for Dimension {
     fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
         Self::Length(value.into())
@@ -127,6 +243,14 @@ impl From<LengthPercentage> for Dimension {
         match input {
             LengthPercentage::Length(value) => Self::Length(value),
             LengthPercentage::Percent(value) => Self::Percent(value),
+            LengthPercentage::Em(value) => Self::Em(value),
+            LengthPercentage::Rem(value) => Self::Rem(value),
+            LengthPercentage::Vw(value) => Self::Vw(value),
+            LengthPercentage::Vh(value) => Self::Vh(value),
+            LengthPercentage::Vmin(value) => Self::Vmin(value),
+            LengthPercentage::Vmax(value) => Self::Vmax(value),
+            #[cfg(feature = "std")]
+            LengthPercentage::Calc(expression) => Self::Calc(expression),
         }
     }
 }
@@ -136,12 +260,34 @@ impl From<LengthPercentageAuto> for Dimension {
         match input {
             LengthPercentageAuto::Length(value) => Self::Length(value),
             LengthPercentageAuto::Percent(value) => Self::Percent(value),
+            LengthPercentageAuto::Em(value) => Self::Em(value),
+            LengthPercentageAuto::Rem(value) => Self::Rem(value),
+            LengthPercentageAuto::Vw(value) => Self::Vw(value),
+            LengthPercentageAuto::Vh(value) => Self::Vh(value),
+            LengthPercentageAuto::Vmin(value) => Self::Vmin(value),
+            LengthPercentageAuto::Vmax(value) => Self::Vmax(value),
+            #[cfg(feature = "std")]
+            LengthPercentageAuto::Calc(expression) => Self::Calc(expression),
             LengthPercentageAuto::Auto => Self::Auto,
         }
     }
 }
 
+#[cfg(feature = "std")]
+impl From<CalcExpression> for Dimension {
+    fn from(expression: CalcExpression) -> Self {
+        Self::Calc(expression.intern())
+    }
+}
+
 impl Dimension {
+    /// Returns true if the value is one of the intrinsic sizing keywords or `stretch`, which are resolved by the layout
+    /// algorithm (by measuring the node's content, or from the space available to it) rather than against the size of
+    /// its parent
+    pub fn is_intrinsic(&self) -> bool {
+        matches!(self, Self::MinContent | Self::MaxContent | Self::FitContent(_) | Self::Stretch)
+    }
+
     /// Get Length value if value is Length variant
     #[cfg(feature = "grid")]
     pub fn into_option(self) -> Option<f32> {
@@ -1,10 +1,11 @@
 //! Style types for CSS Grid layout
-use super::{AlignContent, LengthPercentage, Style};
-use crate::compute::grid::{GridCoordinate, GridLine, OriginZeroLine};
+use super::{AlignContent, Display, LengthPercentage, Style};
+use crate::compute::grid::{GridCoordinate, GridLine, NamedLineResolver, OriginZeroLine};
 use crate::geometry::{AbsoluteAxis, AbstractAxis};
 use crate::geometry::{Line, MinMax};
 use crate::style_helpers::*;
-use crate::util::sys::GridTrackVec;
+use crate::util::sys::{Arc, GridTrackVec, String};
+use crate::util::MaybeResolve;
 use core::cmp::{max, min};
 use core::convert::Infallible;
 
@@ -57,8 +58,9 @@ impl GridAutoFlow {
 /// A grid line placement specification which is generic over the coordinate system that it uses to define
 /// grid line positions.
 ///
-/// GenericGridPlacement<GridLine> is aliased as GridPlacement and is exposed to users of Taffy to define styles.
 /// GenericGridPlacement<OriginZeroLine> is aliased as OriginZeroGridPlacement and is used internally for placement computations.
+/// The user-facing [`GridPlacement`] additionally supports named lines and areas, which are resolved into an
+/// OriginZeroGridPlacement before placement.
 ///
 /// See [`crate::compute::grid::type::coordinates`] for documentation on the different coordinate systems.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
@@ -75,12 +77,33 @@ pub enum GenericGridPlacement<LineType: GridCoordinate> {
 /// A grid line placement using the normalized OriginZero coordinates to specify line positions.
 pub(crate) type OriginZeroGridPlacement = GenericGridPlacement<OriginZeroLine>;
 
-/// A grid line placement specification. Used for grid-[row/column]-[start/end]. Named tracks are not implemented.
+/// A grid line placement specification. Used for grid-[row/column]-[start/end].
+///
+/// Named lines are resolved against the line names in the grid container's `grid_template_rows`/`grid_template_columns`
+/// (see [`TrackSizingFunction::LineNames`]) and the implicit `<area>-start`/`<area>-end` lines defined by its `grid_template_areas`.
 ///
 /// Defaults to `GridPlacement::Auto`
 ///
 /// [Specification](https://www.w3.org/TR/css3-grid-layout/#typedef-grid-row-start-grid-line)
-pub type GridPlacement = GenericGridPlacement<GridLine>;
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GridPlacement {
+    /// Place item according to the auto-placement algorithm, and the parent's grid_auto_flow property
+    Auto,
+    /// Place item at specified line (column or row) index
+    Line(GridLine),
+    /// Place item at the Nth line with the specified name (counting backwards from the end if N is negative).
+    /// If there are not enough lines with that name, all implicit grid lines are assumed to have that name.
+    NamedLine(Arc<str>, i16),
+    /// Place item at the start (or end) of the named area, by resolving to the first line named `<name>-start`
+    /// (or `<name>-end`). Falls back to the first line named `<name>` if there is no such line.
+    NamedArea(Arc<str>),
+    /// Item should span specified number of tracks (columns or rows)
+    Span(u16),
+    /// Item should span until the Nth line with the specified name is reached.
+    /// Behaves like `Span(1)` if the opposite edge of the item is not a definite line.
+    NamedSpan(Arc<str>, u16),
+}
 impl TaffyAuto for GridPlacement {
     const AUTO: Self = Self::Auto;
 }
@@ -112,18 +135,129 @@ impl Default for GridPlacement {
 }
 
 impl GridPlacement {
-    /// Apply a mapping function if the [`GridPlacement`] is a `Track`. Otherwise return `self` unmodified.
-    pub fn into_origin_zero_placement(self, explicit_track_count: u16) -> OriginZeroGridPlacement {
+    /// Whether the placement resolves to a definite grid line. Named lines and areas are always definite
+    /// (falling back to implicit grid lines if necessary).
+    #[inline]
+    fn is_definite_line(&self) -> bool {
         match self {
-            Self::Auto => OriginZeroGridPlacement::Auto,
-            Self::Span(span) => OriginZeroGridPlacement::Span(span),
-            // Grid line zero is an invalid index, so it gets treated as Auto
-            // See: https://developer.mozilla.org/en-US/docs/Web/CSS/grid-row-start#values
-            Self::Line(line) => match line.as_i16() {
-                0 => OriginZeroGridPlacement::Auto,
-                _ => OriginZeroGridPlacement::Line(line.into_origin_zero_line(explicit_track_count)),
-            },
+            Self::Line(line) => line.as_i16() != 0,
+            Self::NamedLine(_, index) => *index != 0,
+            Self::NamedArea(_) => true,
+            Self::Auto | Self::Span(_) | Self::NamedSpan(_, _) => false,
+        }
+    }
+}
+
+impl GridPlacement {
+    /// Convert the placement into OriginZero coordinates, given the number of tracks in the explicit grid. As there is no
+    /// grid container to define line names, named lines resolve to the implicit lines after (or before) the explicit grid,
+    /// and named spans are treated like `Span(1)`.
+    pub fn into_origin_zero_placement(&self, explicit_track_count: u16) -> OriginZeroGridPlacement {
+        Line { start: self.clone(), end: GridPlacement::Auto }.into_origin_zero(explicit_track_count).start
+    }
+}
+
+impl Line<GridPlacement> {
+    #[inline]
+    /// Whether the track position is definite in this axis (or the item will need auto placement)
+    /// The track position is definite if least one of the start and end positions is a (possibly named) line
+    pub fn is_definite(&self) -> bool {
+        self.start.is_definite_line() || self.end.is_definite_line()
+    }
+
+    /// Convert the placement into OriginZero coordinates, given the number of tracks in the explicit grid. As there is no
+    /// grid container to define line names, named lines resolve to the implicit lines after (or before) the explicit grid.
+    pub fn into_origin_zero(&self, explicit_track_count: u16) -> Line<OriginZeroGridPlacement> {
+        NamedLineResolver::new(&Style::DEFAULT, explicit_track_count, 0)
+            .resolve_placement(self, AbsoluteAxis::Horizontal)
+    }
+}
+
+/// A named grid area as defined by the `grid-template-areas` property. Each area implicitly defines
+/// the grid lines `<name>-start` and `<name>-end` in both axes.
+///
+/// Lines are specified in "CSS Grid Line" coordinates (the first line of the explicit grid is line 1) and the
+/// end lines are exclusive. So an area covering only the first row has `row_start: 1` and `row_end: 2`.
+///
+/// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-areas)
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridTemplateArea {
+    /// The name of the area
+    pub name: String,
+    /// The line at the start of the area in the vertical axis
+    pub row_start: u16,
+    /// The line at the end of the area in the vertical axis
+    pub row_end: u16,
+    /// The line at the start of the area in the horizontal axis
+    pub column_start: u16,
+    /// The line at the end of the area in the horizontal axis
+    pub column_end: u16,
+}
+
+impl GridTemplateArea {
+    /// Parse a list of CSS `grid-template-areas` row strings such as `["header header", "sidebar main"]` into named areas.
+    ///
+    /// Each string is one row and each whitespace-separated name is one column. Cells named `.` (or any sequence of dots)
+    /// are left unnamed. Every row must contain the same number of columns and every named area must be rectangular.
+    pub fn from_strings(rows: &[&str]) -> Result<GridTrackVec<GridTemplateArea>, InvalidGridTemplateAreas> {
+        let cells: GridTrackVec<GridTrackVec<&str>> = rows.iter().map(|row| row.split_whitespace().collect()).collect();
+        let column_count = cells.first().map(|row| row.len()).unwrap_or(0);
+        if cells.iter().any(|row| row.len() != column_count) {
+            return Err(InvalidGridTemplateAreas);
+        }
+
+        // Compute the bounding box of the cells with each name
+        let mut areas: GridTrackVec<GridTemplateArea> = GridTrackVec::new();
+        for (row_index, row) in cells.iter().enumerate() {
+            for (column_index, name) in row.iter().enumerate() {
+                // Sequences of dots denote unnamed cells
+                if name.chars().all(|c| c == '.') {
+                    continue;
+                }
+                let (row_line, column_line) = (row_index as u16 + 1, column_index as u16 + 1);
+                match areas.iter_mut().find(|area| area.name == *name) {
+                    Some(area) => {
+                        area.row_start = min(area.row_start, row_line);
+                        area.row_end = max(area.row_end, row_line + 1);
+                        area.column_start = min(area.column_start, column_line);
+                        area.column_end = max(area.column_end, column_line + 1);
+                    }
+                    None => areas.push(GridTemplateArea {
+                        name: String::from(*name),
+                        row_start: row_line,
+                        row_end: row_line + 1,
+                        column_start: column_line,
+                        column_end: column_line + 1,
+                    }),
+                }
+            }
         }
+
+        // An area is only valid if every cell within its bounding box has its name
+        let area_is_rectangular = |area: &GridTemplateArea| {
+            (area.row_start..area.row_end).all(|row| {
+                (area.column_start..area.column_end)
+                    .all(|column| cells[row as usize - 1][column as usize - 1] == area.name)
+            })
+        };
+        if !areas.iter().all(area_is_rectangular) {
+            return Err(InvalidGridTemplateAreas);
+        }
+
+        Ok(areas)
+    }
+}
+
+/// Error returned when trying to parse a list of `grid-template-areas` strings that do not describe
+/// a valid set of rectangular named areas.
+#[derive(Debug)]
+pub struct InvalidGridTemplateAreas;
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidGridTemplateAreas {}
+impl core::fmt::Display for InvalidGridTemplateAreas {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("grid-template-areas must have the same number of columns in each row and every named area must be rectangular")
     }
 }
 
@@ -153,16 +287,6 @@ impl<T: GridCoordinate> Line<GenericGridPlacement<T>> {
     }
 }
 
-impl Line<GridPlacement> {
-    /// Apply a mapping function if the [`GridPlacement`] is a `Track`. Otherwise return `self` unmodified.
-    pub fn into_origin_zero(&self, explicit_track_count: u16) -> Line<OriginZeroGridPlacement> {
-        Line {
-            start: self.start.into_origin_zero_placement(explicit_track_count),
-            end: self.end.into_origin_zero_placement(explicit_track_count),
-        }
-    }
-}
-
 impl Line<OriginZeroGridPlacement> {
     /// If at least one of the of the start and end positions is a track index then the other end can be resolved
     /// into a track index purely based on the information contained with the placement specification
@@ -316,8 +440,7 @@ impl MaxTrackSizingFunction {
     pub fn definite_value(self, parent_size: Option<f32>) -> Option<f32> {
         use MaxTrackSizingFunction::*;
         match self {
-            Fixed(LengthPercentage::Length(size)) => Some(size),
-            Fixed(LengthPercentage::Percent(fraction)) => parent_size.map(|size| fraction * size),
+            Fixed(length_percentage) => length_percentage.maybe_resolve(parent_size),
             MinContent | MaxContent | FitContent(_) | Auto | Fraction(_) => None,
         }
     }
@@ -332,8 +455,7 @@ impl MaxTrackSizingFunction {
     pub fn definite_limit(self, parent_size: Option<f32>) -> Option<f32> {
         use MaxTrackSizingFunction::FitContent;
         match self {
-            FitContent(LengthPercentage::Length(size)) => Some(size),
-            FitContent(LengthPercentage::Percent(fraction)) => parent_size.map(|size| fraction * size),
+            FitContent(length_percentage) => length_percentage.maybe_resolve(parent_size),
             _ => self.definite_value(parent_size),
         }
     }
@@ -344,8 +466,10 @@ impl MaxTrackSizingFunction {
     pub fn resolved_percentage_size(self, parent_size: f32) -> Option<f32> {
         use MaxTrackSizingFunction::*;
         match self {
-            Fixed(LengthPercentage::Percent(fraction)) => Some(fraction * parent_size),
-            Fixed(LengthPercentage::Length(_)) | MinContent | MaxContent | FitContent(_) | Auto | Fraction(_) => None,
+            Fixed(length_percentage) if length_percentage.uses_percentage() => {
+                length_percentage.maybe_resolve(parent_size)
+            }
+            Fixed(_) | MinContent | MaxContent | FitContent(_) | Auto | Fraction(_) => None,
         }
     }
 
@@ -353,7 +477,10 @@ impl MaxTrackSizingFunction {
     #[inline(always)]
     pub fn uses_percentage(self) -> bool {
         use MaxTrackSizingFunction::*;
-        matches!(self, Fixed(LengthPercentage::Percent(_)) | FitContent(LengthPercentage::Percent(_)))
+        match self {
+            Fixed(length_percentage) | FitContent(length_percentage) => length_percentage.uses_percentage(),
+            MinContent | MaxContent | Auto | Fraction(_) => false,
+        }
     }
 }
 
@@ -411,8 +538,7 @@ impl MinTrackSizingFunction {
     pub fn definite_value(self, parent_size: Option<f32>) -> Option<f32> {
         use MinTrackSizingFunction::*;
         match self {
-            Fixed(LengthPercentage::Length(size)) => Some(size),
-            Fixed(LengthPercentage::Percent(fraction)) => parent_size.map(|size| fraction * size),
+            Fixed(length_percentage) => length_percentage.maybe_resolve(parent_size),
             MinContent | MaxContent | Auto => None,
         }
     }
@@ -423,8 +549,10 @@ impl MinTrackSizingFunction {
     pub fn resolved_percentage_size(self, parent_size: f32) -> Option<f32> {
         use MinTrackSizingFunction::*;
         match self {
-            Fixed(LengthPercentage::Percent(fraction)) => Some(fraction * parent_size),
-            Fixed(LengthPercentage::Length(_)) | MinContent | MaxContent | Auto => None,
+            Fixed(length_percentage) if length_percentage.uses_percentage() => {
+                length_percentage.maybe_resolve(parent_size)
+            }
+            Fixed(_) | MinContent | MaxContent | Auto => None,
         }
     }
 
@@ -432,7 +560,7 @@ impl MinTrackSizingFunction {
     #[inline(always)]
     pub fn uses_percentage(self) -> bool {
         use MinTrackSizingFunction::*;
-        matches!(self, Fixed(LengthPercentage::Percent(_)))
+        matches!(self, Fixed(length_percentage) if length_percentage.uses_percentage())
     }
 }
 
@@ -543,7 +671,22 @@ pub enum TrackSizingFunction {
     Single(NonRepeatedTrackSizingFunction),
     /// Automatically generate grid tracks to fit the available space using the specified definite track lengths
     /// Only valid if every track in template (not just the repitition) has a fixed size.
-    Repeat(GridTrackRepetition, GridTrackVec<NonRepeatedTrackSizingFunction>),
+    ///
+    /// The repeated track list may only contain `Single` tracks and `LineNames`, and must contain at least one track.
+    Repeat(GridTrackRepetition, GridTrackVec<TrackSizingFunction>),
+    /// Names the grid line at this position in the track list (between the tracks before and after it), like the
+    /// bracketed `[name]` syntax of CSS. Within a repeated track list, the names are repeated along with the tracks.
+    /// The names of adjacent entries apply to the same line.
+    LineNames(GridTrackVec<String>),
+    /// Adopt the tracks of the parent grid that the node spans instead of defining its own tracks.
+    /// Only valid as the sole entry of a grid template on a node that is itself a grid item.
+    /// See <https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Grid_Layout/Subgrid>
+    Subgrid,
+    /// Don't divide the axis into tracks: instead, each item is placed in the column(s) whose content ends earliest and
+    /// is packed up against the items above it, as in a masonry layout. Only valid as the sole entry of
+    /// `grid_template_rows`: anywhere else it is ignored, and the template it appears in is treated as empty.
+    /// See <https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Grid_Layout/Masonry_layout>
+    Masonry,
 }
 impl TrackSizingFunction {
     /// Whether the track definition is a auto-repeated fragment
@@ -599,12 +742,17 @@ impl Style {
         }
     }
 
-    /// Get a grid item's row or column placement depending on the axis passed
-    pub(crate) fn grid_placement(&self, axis: AbsoluteAxis) -> Line<GridPlacement> {
-        match axis {
-            AbsoluteAxis::Horizontal => self.grid_column,
-            AbsoluteAxis::Vertical => self.grid_row,
-        }
+    /// Whether the node is a subgrid in the specified axis. That is, whether it is a grid container whose
+    /// template in that axis is `subgrid`. Whether it is actually a grid item must be checked separately.
+    pub(crate) fn is_subgrid(&self, axis: AbsoluteAxis) -> bool {
+        self.display == Display::Grid
+            && !self.position.is_out_of_flow()
+            && matches!(self.grid_template_tracks(axis).as_slice(), [TrackSizingFunction::Subgrid])
+    }
+
+    /// Whether the node is a masonry grid container. That is, whether its `grid_template_rows` is `masonry`.
+    pub(crate) fn is_masonry(&self) -> bool {
+        matches!(self.grid_template_rows.as_slice(), [TrackSizingFunction::Masonry])
     }
 
     /// Get a grid container's align-content or justify-content alignment depending on the axis passed
@@ -615,3 +763,43 @@ impl Style {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{GridTemplateArea, InvalidGridTemplateAreas};
+
+    #[test]
+    fn template_areas_from_strings() {
+        let areas = GridTemplateArea::from_strings(&["header header header", "sidebar main main", ". main main"]);
+        let areas = areas.unwrap();
+        assert_eq!(areas.len(), 3);
+        assert_eq!(
+            areas[0],
+            GridTemplateArea { name: "header".into(), row_start: 1, row_end: 2, column_start: 1, column_end: 4 }
+        );
+        assert_eq!(
+            areas[1],
+            GridTemplateArea { name: "sidebar".into(), row_start: 2, row_end: 3, column_start: 1, column_end: 2 }
+        );
+        assert_eq!(
+            areas[2],
+            GridTemplateArea { name: "main".into(), row_start: 2, row_end: 4, column_start: 2, column_end: 4 }
+        );
+    }
+
+    #[test]
+    fn template_areas_from_strings_ignores_null_cells() {
+        let areas = GridTemplateArea::from_strings(&["... a", ". ."]).unwrap();
+        assert_eq!(
+            areas,
+            [GridTemplateArea { name: "a".into(), row_start: 1, row_end: 2, column_start: 2, column_end: 3 }]
+        );
+    }
+
+    #[test]
+    fn template_areas_from_strings_rejects_invalid_areas() {
+        assert!(matches!(GridTemplateArea::from_strings(&["a a", "a"]), Err(InvalidGridTemplateAreas)));
+        assert!(matches!(GridTemplateArea::from_strings(&["a b", "a a"]), Err(InvalidGridTemplateAreas)));
+        assert!(matches!(GridTemplateArea::from_strings(&["a b a"]), Err(InvalidGridTemplateAreas)));
+    }
+}
@@ -1,24 +1,35 @@
 //! A typed representation of [CSS style properties](https://css-tricks.com/snippets/css/a-guide-to-flexbox/) in Rust. Used as input to layout computation.
 mod alignment;
+#[cfg(feature = "std")]
+mod calc;
 mod dimension;
+mod relative_length;
 
 #[cfg(feature = "flexbox")]
 mod flex;
 
-pub use self::alignment::{AlignContent, AlignItems, AlignSelf, JustifyContent, JustifyItems, JustifySelf};
+pub use self::alignment::{AlignContent, AlignItems, AlignSelf, JustifyContent, JustifyItems, JustifySelf, TextAlign};
+#[cfg(feature = "std")]
+pub use self::calc::{CalcExpression, CalcId};
 pub use self::dimension::{AvailableSpace, Dimension, LengthPercentage, LengthPercentageAuto};
+pub use self::relative_length::{RelativeLengthContext, DEFAULT_FONT_SIZE};
 
 #[cfg(feature = "flexbox")]
 pub use self::flex::{FlexDirection, FlexWrap};
 
+#[cfg(feature = "stack_layout")]
+mod stack;
+#[cfg(feature = "stack_layout")]
+pub use self::stack::{StackDirection, StackSize, StackSpace};
+
 #[cfg(feature = "grid")]
 mod grid;
 #[cfg(feature = "grid")]
 pub(crate) use self::grid::{GenericGridPlacement, OriginZeroGridPlacement};
 #[cfg(feature = "grid")]
 pub use self::grid::{
-    GridAutoFlow, GridPlacement, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
-    NonRepeatedTrackSizingFunction, TrackSizingFunction,
+    GridAutoFlow, GridPlacement, GridTemplateArea, GridTrackRepetition, InvalidGridTemplateAreas,
+    MaxTrackSizingFunction, MinTrackSizingFunction, NonRepeatedTrackSizingFunction, TrackSizingFunction,
 };
 use crate::geometry::{Point, Rect, Size};
 
@@ -38,12 +49,50 @@ pub enum Display {
     /// The children will follow the block layout algorithm
     #[cfg(feature = "block_layout")]
     Block,
+    /// The node is placed on a line alongside its inline-level siblings when its parent is a block container. A leaf
+    /// node is split across lines if its measure function splits it into fragments (see [`Measurable::measure_fragments`](crate::tree::Measurable::measure_fragments)),
+    /// and the children of any other node are placed on the lines as if they were its siblings. Elsewhere (and
+    /// whenever it is absolutely positioned) the node behaves like [`Display::Block`].
+    #[cfg(feature = "block_layout")]
+    Inline,
+    /// The node is placed on a line alongside its inline-level siblings as a single box when its parent is a block
+    /// container. Its children follow the block layout algorithm.
+    #[cfg(feature = "block_layout")]
+    InlineBlock,
     /// The children will follow the flexbox layout algorithm
     #[cfg(feature = "flexbox")]
     Flex,
     /// The children will follow the CSS Grid layout algorithm
     #[cfg(feature = "grid")]
     Grid,
+    /// The node is a table: its rows (and row groups) are laid out by the table layout algorithm. Children that are not
+    /// rows or row groups are placed in anonymous rows, as cells.
+    #[cfg(feature = "table_layout")]
+    Table,
+    /// The node groups consecutive rows of its parent table. A `row_span` of `0` extends a cell to the end of its group.
+    #[cfg(feature = "table_layout")]
+    TableRowGroup,
+    /// The node is a row of its parent table (or row group), and its children are the cells of that row
+    #[cfg(feature = "table_layout")]
+    TableRow,
+    /// The node is a cell of its parent table row. It is sized and placed by the table, and its children follow the
+    /// block layout algorithm.
+    #[cfg(feature = "table_layout")]
+    TableCell,
+    /// The children are stacked in a single row or column (see [`Style::stack_direction`]), following the layout model
+    /// of [Morphorm](https://github.com/vizia/morphorm): each child is surrounded by spaces (see [`Style::space`]) that,
+    /// like its `size`, may stretch to fill the free space in the container.
+    #[cfg(feature = "stack_layout")]
+    Stack,
+    /// The children are laid out by a user-defined layout algorithm with the given id: in the [`Taffy`](crate::Taffy)
+    /// tree, the one registered with [`Taffy::register_layout_algorithm`](crate::Taffy::register_layout_algorithm).
+    /// Its parent's layout algorithm treats the node like any other box whose contents are laid out independently. A
+    /// node whose id has no registered algorithm is laid out like `Display::Block` (or, without the `block_layout`
+    /// feature, as if it had no children, which are hidden).
+    Custom(u16),
+    /// The node generates no box of its own: its children are laid out as if they were children of its parent.
+    /// The node itself is given a zero-sized layout at the origin of its parent.
+    Contents,
     /// The children will not be laid out, and will follow absolute positioning
     None,
 }
@@ -72,6 +121,38 @@ impl Default for Display {
     }
 }
 
+/// Whether the node is painted, and whether a flex item takes up space in the main axis of its container
+///
+/// Unlike in CSS, `visibility` is not inherited: the children of a hidden node are painted unless they are hidden too.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Visibility {
+    /// The node is painted
+    #[default]
+    Visible,
+    /// The node is laid out as usual, but it is not painted: its [`Layout::is_visible`](crate::tree::Layout::is_visible) is `false`
+    Hidden,
+    /// A flex item with this value takes up no space in the main axis of its flex container, but still contributes its
+    /// cross size to its flex line (as a "strut"), so that collapsing it doesn't change the cross size of the container.
+    /// It is given a zero main size and is not painted. Other nodes treat this value like [`Visibility::Hidden`].
+    Collapse,
+}
+
+impl Visibility {
+    /// Returns true if the node should be painted (`Visibility::Visible`)
+    #[cfg(any(
+        feature = "block_layout",
+        feature = "flexbox",
+        feature = "grid",
+        feature = "stack_layout",
+        feature = "taffy_tree"
+    ))]
+    #[inline(always)]
+    pub(crate) fn is_visible(self) -> bool {
+        self == Visibility::Visible
+    }
+}
+
 /// The positioning strategy for this item.
 ///
 /// This controls both how the origin is determined for the [`Style::position`] field,
@@ -93,6 +174,210 @@ pub enum Position {
     ///
     /// WARNING: to opt-out of layouting entirely, you must use [`Display::None`] instead on your [`Style`] object.
     Absolute,
+    /// The offset is computed relative to the viewport: the available space passed to [`Taffy::compute_layout`](crate::Taffy::compute_layout)
+    /// (or, in axes in which the available space is not definite, the size of the root node if it is set by an absolute
+    /// length in its styles, or else zero). Viewport-relative lengths resolve against the same viewport.
+    /// No space is created for the item in the page layout. If both insets in an axis are `auto` then the item is
+    /// placed at the inline-start or top edge of the content box of its parent in that axis.
+    ///
+    /// Fixed nodes are laid out by [`Taffy::compute_layout`](crate::Taffy::compute_layout) once the rest of the tree
+    /// has been laid out: the layout algorithms skip them.
+    Fixed,
+    /// The node is laid out as if it were [`Position::Relative`], except that its offsets are not applied. Instead they
+    /// define how the node is shifted to stay in view as its nearest scroll container is scrolled.
+    /// See [`Taffy::sticky_constraint`](crate::Taffy::sticky_constraint).
+    Sticky,
+}
+
+impl Position {
+    /// Returns true if the node is taken out of the normal flow of its parent (`Position::Absolute` or `Position::Fixed`)
+    #[inline(always)]
+    pub(crate) fn is_out_of_flow(self) -> bool {
+        matches!(self, Position::Absolute | Position::Fixed)
+    }
+}
+
+/// Whether a child of a block container is taken out of the normal flow and placed against the left or right side of
+/// its container, with the content that follows it (such as lines of text) flowing around it
+///
+/// A floated node is always contained by its parent block container, whose height grows to enclose it. The floated
+/// children of flexbox and grid containers, and of inline boxes, are laid out as if this property was
+/// [`Float::None`].
+///
+/// Floats are placed against the sides of the content box of their container as if it was left-to-right, so
+/// [`Float::Left`] and [`Float::Right`] keep their physical meaning in a right-to-left container.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/float>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Float {
+    /// The node is not floated
+    #[default]
+    None,
+    /// The node is placed as far to the left and as high as possible
+    Left,
+    /// The node is placed as far to the right and as high as possible
+    Right,
+}
+
+/// Whether a block-level child of a block container (or a float) must be placed below the preceding floats of its
+/// container
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/clear>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Clear {
+    /// The node may be placed alongside preceding floats
+    #[default]
+    None,
+    /// The node is placed below any preceding left floats
+    Left,
+    /// The node is placed below any preceding right floats
+    Right,
+    /// The node is placed below all preceding floats
+    Both,
+}
+
+/// How the content of a multi-column block container is distributed between its columns
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/column-fill>
+#[cfg(feature = "block_layout")]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ColumnFill {
+    /// The content is divided between the columns so that they are as short (and so as equal in height) as possible
+    #[default]
+    Balance,
+    /// Each column is filled to the height of the container before the next one is started. Behaves like `Balance` if
+    /// the height of the container is not definite.
+    Auto,
+}
+
+/// Whether the borders of adjacent table cells are kept separate or collapsed into a single border
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/border-collapse>
+#[cfg(feature = "table_layout")]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BorderCollapse {
+    /// Each cell has its own border, and cells are separated by the table's `border_spacing`
+    #[default]
+    Separate,
+    /// Adjacent cells share a single border as wide as the widest of their borders (or of the table's border, at the
+    /// edges of the table). The table's padding and `border_spacing` are ignored.
+    Collapse,
+}
+
+/// The inline base direction of a node's content
+///
+/// This controls which side the inline axis starts from: the side that `start` alignment, the first flex item of a `row`
+/// container, the first grid column and block-level children are placed against, as well as which side a vertical
+/// scrollbar is reserved on.
+///
+/// Like in CSS, this property is inherited: a node whose direction is [`Direction::Inherit`] (the default) takes the
+/// direction of its parent, and the root node is left-to-right unless told otherwise. The [`Taffy`](crate::Taffy) tree
+/// resolves inherited directions in [`Taffy::compute_layout`](crate::Taffy::compute_layout) when the `std` or `alloc`
+/// feature is enabled. Other [`LayoutTree`](crate::LayoutTree) implementations must resolve them (using
+/// [`Direction::resolve`]) before handing styles to the layout algorithms, which treat `Inherit` as `Ltr`.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/direction>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Direction {
+    /// Content flows in the same direction as in the node's parent
+    #[default]
+    Inherit,
+    /// Content flows from left to right
+    Ltr,
+    /// Content flows from right to left
+    Rtl,
+}
+
+impl Direction {
+    /// The direction of a node with this direction style whose parent's direction is `parent_direction`
+    #[inline]
+    pub fn resolve(self, parent_direction: Direction) -> Direction {
+        match self {
+            Direction::Inherit => parent_direction,
+            direction => direction,
+        }
+    }
+
+    /// Returns true if the direction is right-to-left
+    #[inline(always)]
+    pub(crate) fn is_rtl(self) -> bool {
+        self == Direction::Rtl
+    }
+}
+
+/// The orientation of the inline and block axes of a node's content
+///
+/// In the vertical writing modes the inline axis is vertical and the block axis is horizontal. This changes how the
+/// node's children are laid out: block-level children are stacked horizontally, a `FlexDirection::Row` container
+/// places its items from top to bottom, and grid columns are stacked vertically (`grid_template_columns`,
+/// `grid_template_rows` and `gap` keep referring to the inline and block axes). Physical styles such as `size`,
+/// `margin`, `padding`, `border` and `inset` keep their physical meaning, and measure functions always receive
+/// physical (width and height) constraints.
+///
+/// Unlike [`Direction`], this property is not inherited and only applies to the layout of the node's own children.
+/// The margins of a vertical block container don't collapse with the margins of its children, and a subgrid always
+/// uses the writing mode of its parent grid.
+///
+/// Vertical writing modes need the `std` or `alloc` feature to store the transposed styles: without them, all nodes are
+/// laid out in the `HorizontalTb` writing mode.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/writing-mode>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WritingMode {
+    /// Lines of content are horizontal and stack from top to bottom
+    #[default]
+    HorizontalTb,
+    /// Lines of content are vertical and stack from right to left
+    VerticalRl,
+    /// Lines of content are vertical and stack from left to right
+    VerticalLr,
+}
+
+impl WritingMode {
+    /// Returns true if the inline axis is vertical
+    #[cfg(any(
+        feature = "block_layout",
+        all(any(feature = "std", feature = "alloc"), any(feature = "flexbox", feature = "grid"))
+    ))]
+    #[inline(always)]
+    pub(crate) fn is_vertical(self) -> bool {
+        self != WritingMode::HorizontalTb
+    }
+
+    /// The vertical writing modes, in the order in which the transposed styles of a node are cached
+    #[cfg(all(feature = "taffy_tree", any(feature = "std", feature = "alloc")))]
+    pub(crate) const VERTICAL: [WritingMode; 2] = [WritingMode::VerticalRl, WritingMode::VerticalLr];
+}
+
+/// Which box the `size`, `min_size` and `max_size` styles of a node (and its `aspect_ratio`) apply to
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/box-sizing>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BoxSizing {
+    /// Sizes include the node's padding and border
+    #[default]
+    BorderBox,
+    /// Sizes exclude the node's padding and border
+    ContentBox,
+}
+
+impl BoxSizing {
+    /// The amount that must be added to a resolved size style to get a border-box size, given the sum of the
+    /// node's padding and border in each axis
+    #[inline(always)]
+    pub(crate) fn adjustment(self, padding_border_sum: Size<f32>) -> Size<f32> {
+        match self {
+            BoxSizing::BorderBox => Size::ZERO,
+            BoxSizing::ContentBox => padding_border_sum,
+        }
+    }
 }
 
 impl Default for Position {
@@ -107,7 +392,7 @@ impl Default for Position {
 /// be displayed anyway, be clipped, or trigger the container to become a scroll container. However it also has secondary effects on layout,
 /// the main ones being:
 ///
-///   - The automatic minimum size Flexbox/CSS Grid items with non-`Visible` overflow is `0` rather than being content based
+///   - The automatic minimum size Flexbox/CSS Grid items that are scroll containers (`Hidden` or `Scroll` overflow) is `0` rather than being content based
 ///   - `Overflow::Scroll` nodes have space in the layout reserved for a scrollbar (width controlled by the `scrollbar_width` property)
 ///
 /// In Taffy, we only implement the layout related secondary effects as we are not concerned with drawing/painting. The amount of space reserved for
@@ -125,13 +410,25 @@ pub enum Overflow {
     /// The automatic minimum size of this node as a flexbox/grid item should be `0`. Additionally, space should be reserved
     /// for a scrollbar. The amount of space reserved is controlled by the `scrollbar_width` property.
     Scroll,
+    /// The node's contents are clipped like `Hidden`, but the node is not a scroll container. So the automatic minimum
+    /// size of this node as a flexbox/grid item is still based on the size of its content, and it does not prevent the
+    /// margins of its children from collapsing through it.
+    Clip,
 }
 
 impl Overflow {
-    /// Returns true for overflow modes that contain their contents (`Overflow::Hidden`, `Overflow::Scroll`, `Overflow::Auto`)
-    /// or else false for overflow modes that allow their contains to spill (`Overflow::Visible`).
+    /// Returns true for overflow modes that make the node a scroll container (`Overflow::Hidden`, `Overflow::Scroll`)
+    /// or else false for overflow modes that don't (`Overflow::Visible`, `Overflow::Clip`).
     #[inline(always)]
     pub(crate) fn is_scroll_container(self) -> bool {
+        matches!(self, Overflow::Hidden | Overflow::Scroll)
+    }
+
+    /// Returns true for overflow modes whose overflowing contents are not visible outside of the node (all but
+    /// `Overflow::Visible`), and which therefore don't contribute to the scrollable overflow of its ancestors.
+    #[cfg(any(feature = "block_layout", feature = "flexbox", feature = "grid", feature = "stack_layout"))]
+    #[inline(always)]
+    pub(crate) fn clips_contents(self) -> bool {
         self != Overflow::Visible
     }
 
@@ -146,6 +443,26 @@ impl Overflow {
     }
 }
 
+/// Whether space should be reserved for the vertical scrollbar of a node before its contents overflow
+///
+/// By default space is only reserved for the scrollbars of `Overflow::Scroll` nodes. A stable gutter reserves
+/// `scrollbar_width` on the inline-end side of any scroll container, so that its contents don't move when a scrollbar
+/// appears.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/scrollbar-gutter>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScrollbarGutter {
+    /// Space is reserved for the vertical scrollbar only if `overflow.y` is `Overflow::Scroll`
+    #[default]
+    Auto,
+    /// Space is reserved for the vertical scrollbar on the inline-end side if the node is a scroll container
+    Stable,
+    /// Like `Stable`, but the same amount of space is also reserved on the inline-start side so that the contents stay
+    /// centred
+    StableBothEdges,
+}
+
 /// The flexbox layout information for a single node.
 ///
 /// The most important idea in flexbox is the notion of a "main" and "cross" axis, which are always perpendicular to each other.
@@ -166,12 +483,23 @@ impl Overflow {
 pub struct Style {
     /// What layout strategy should be used?
     pub display: Display,
+    /// Should the node be painted? Collapsed flex items also take up no space in the main axis.
+    pub visibility: Visibility,
+    /// Which side does the inline axis of this node's content start from?
+    pub direction: Direction,
+    /// Is the inline axis of this node's content horizontal or vertical?
+    pub writing_mode: WritingMode,
+    /// The font size that `em` lengths in this node's style are multiples of. Percentages and `em` lengths are relative to
+    /// the parent's font size. If `None`, the parent's font size is inherited ([`DEFAULT_FONT_SIZE`] for the root).
+    pub font_size: Option<LengthPercentage>,
 
     // Overflow properties
     /// How children overflowing their container should affect layout
     pub overflow: Point<Overflow>,
-    /// How much space (in points) should be reserved for the scrollbars of `Overflow::Scroll` and `Overflow::Auto` nodes.
+    /// How much space (in points) should be reserved for the scrollbars of `Overflow::Scroll` nodes.
     pub scrollbar_width: f32,
+    /// Whether space should be reserved for the vertical scrollbar of scroll containers that aren't `Overflow::Scroll`
+    pub scrollbar_gutter: ScrollbarGutter,
 
     // Position properties
     /// What should the `position` value of this struct use as a base offset?
@@ -181,6 +509,8 @@ pub struct Style {
     pub inset: Rect<LengthPercentageAuto>,
 
     // Size properies
+    /// Whether `size`, `min_size` and `max_size` include the padding and border of the item
+    pub box_sizing: BoxSizing,
     /// Sets the initial size of the item
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
     pub size: Size<Dimension>,
@@ -215,23 +545,101 @@ pub struct Style {
     #[cfg(any(feature = "flexbox", feature = "grid"))]
     pub align_self: Option<AlignSelf>,
     /// How this node's children should be aligned in the inline axis
-    #[cfg(feature = "grid")]
+    #[cfg(any(feature = "grid", feature = "block_layout"))]
     pub justify_items: Option<AlignItems>,
     /// How this node should be aligned in the inline axis
     /// Falls back to the parents [`JustifyItems`] if not set
-    #[cfg(feature = "grid")]
+    #[cfg(any(feature = "grid", feature = "block_layout"))]
     pub justify_self: Option<AlignSelf>,
     /// How should content contained within this item be aligned in the cross/block axis
-    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
     pub align_content: Option<AlignContent>,
     /// How should contained within this item be aligned in the main/inline axis
     #[cfg(any(feature = "flexbox", feature = "grid"))]
     pub justify_content: Option<JustifyContent>,
-    /// How large should the gaps between items in a grid or flex container be?
-    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    /// How large should the gaps between items in a grid or flex container be? The horizontal gap (`width`, the CSS
+    /// `column-gap` property) is also the gap between the columns of a multi-column block container.
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::zero"))]
     pub gap: Size<LengthPercentage>,
 
+    // Block container properties
+    /// How should the inline-level children of this block container be aligned within each line?
+    #[cfg(feature = "block_layout")]
+    pub text_align: TextAlign,
+
+    /// How many columns should the content of this block container be divided into? `None` (`column-count: auto`) fits
+    /// as many columns as possible into the container given its `column_width`. `Some(0)` is treated as `Some(1)`.
+    #[cfg(feature = "block_layout")]
+    pub column_count: Option<u16>,
+    /// What is the ideal width of the columns of this block container? The columns may be wider (to fill the container)
+    /// or narrower (if the container is narrower than a single column).
+    #[cfg(feature = "block_layout")]
+    #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
+    pub column_width: LengthPercentageAuto,
+    /// How should the content of this multi-column block container be distributed between its columns?
+    #[cfg(feature = "block_layout")]
+    pub column_fill: ColumnFill,
+
+    // Block item properties
+    /// Should this node be floated to one side of its block container?
+    #[cfg(feature = "block_layout")]
+    pub float: Float,
+    /// Should this node be placed below the preceding floats of its block container?
+    #[cfg(feature = "block_layout")]
+    pub clear: Clear,
+
+    // Table properties
+    /// Are the borders of the cells of this table kept separate or collapsed together?
+    #[cfg(feature = "table_layout")]
+    pub border_collapse: BorderCollapse,
+    /// The space between the cells of this table (and between the cells and the padding edge of the table), horizontally
+    /// and vertically. Only used if `border_collapse` is `Separate`.
+    #[cfg(feature = "table_layout")]
+    #[cfg_attr(feature = "serde", serde(default = "style_helpers::zero"))]
+    pub border_spacing: Size<LengthPercentage>,
+    /// The number of columns this table cell spans (HTML's `colspan` attribute). `0` is treated as `1`.
+    #[cfg(feature = "table_layout")]
+    pub col_span: u16,
+    /// The number of rows this table cell spans (HTML's `rowspan` attribute). `0` spans the remaining rows of the cell's
+    /// row group.
+    #[cfg(feature = "table_layout")]
+    pub row_span: u16,
+
+    // Stack container properties
+    /// Are the children of this stack container placed in a row or a column?
+    #[cfg(feature = "stack_layout")]
+    pub stack_direction: StackDirection,
+    /// The space on each side of the children of this stack container, for children whose own `space` on that side is
+    /// `Auto`. In the stacking axis, the space before the first child and after the last child.
+    #[cfg(feature = "stack_layout")]
+    #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
+    pub child_space: Rect<StackSpace>,
+    /// The space between adjacent children of this stack container, for children whose own `space` before them is `Auto`
+    #[cfg(feature = "stack_layout")]
+    #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
+    pub child_between: StackSpace,
+
+    // Stack item properties
+    /// The space on each side of this node in its stack container (or within the container, if it is absolutely
+    /// positioned). `Auto` sides take their space from the container's `child_space` or `child_between`, or are zero
+    /// if that is `Auto` too. The margins of the children of a stack container are ignored.
+    #[cfg(feature = "stack_layout")]
+    #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
+    pub space: Rect<StackSpace>,
+    /// Whether the size of this node stretches into the free space of its stack container in each axis, and with what
+    /// weight. This overrides the node's `size` in axes in which it is `Stretch`.
+    #[cfg(feature = "stack_layout")]
+    #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
+    pub stack_size: Size<StackSize>,
+
+    // Flexbox and grid item properties
+    /// The order in which this node is laid out relative to its siblings in a flexbox or grid container. Items are laid
+    /// out (and given a [`Layout::order`](crate::tree::Layout::order)) in ascending order, with ties broken by their order
+    /// in the tree.
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    pub order: i32,
+
     // Flexbox properies
     /// Which direction does the main axis flow in?
     #[cfg(feature = "flexbox")]
@@ -260,6 +668,9 @@ pub struct Style {
     /// Defines the track sizing functions (heights) of the grid columns
     #[cfg(feature = "grid")]
     pub grid_template_columns: GridTrackVec<TrackSizingFunction>,
+    /// Defines named grid areas. The explicit grid is expanded to contain every area.
+    #[cfg(feature = "grid")]
+    pub grid_template_areas: GridTrackVec<GridTemplateArea>,
     /// Defines the size of implicitly created rows
     #[cfg(feature = "grid")]
     pub grid_auto_rows: GridTrackVec<NonRepeatedTrackSizingFunction>,
@@ -283,32 +694,73 @@ impl Style {
     /// The [`Default`] layout, in a form that can be used in const functions
     pub const DEFAULT: Style = Style {
         display: Display::DEFAULT,
+        visibility: Visibility::Visible,
+        direction: Direction::Inherit,
+        writing_mode: WritingMode::HorizontalTb,
+        font_size: None,
         overflow: Point { x: Overflow::Visible, y: Overflow::Visible },
         scrollbar_width: 0.0,
+        scrollbar_gutter: ScrollbarGutter::Auto,
         position: Position::Relative,
         inset: Rect::auto(),
         margin: Rect::zero(),
         padding: Rect::zero(),
         border: Rect::zero(),
+        box_sizing: BoxSizing::BorderBox,
         size: Size::auto(),
         min_size: Size::auto(),
         max_size: Size::auto(),
         aspect_ratio: None,
-        #[cfg(any(feature = "flexbox", feature = "grid"))]
+        #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
         gap: Size::zero(),
         // Aligment
         #[cfg(any(feature = "flexbox", feature = "grid"))]
         align_items: None,
         #[cfg(any(feature = "flexbox", feature = "grid"))]
         align_self: None,
-        #[cfg(feature = "grid")]
+        #[cfg(any(feature = "grid", feature = "block_layout"))]
         justify_items: None,
-        #[cfg(feature = "grid")]
+        #[cfg(any(feature = "grid", feature = "block_layout"))]
         justify_self: None,
-        #[cfg(any(feature = "flexbox", feature = "grid"))]
+        #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
         align_content: None,
         #[cfg(any(feature = "flexbox", feature = "grid"))]
         justify_content: None,
+        // Block
+        #[cfg(feature = "block_layout")]
+        text_align: TextAlign::Start,
+        #[cfg(feature = "block_layout")]
+        column_count: None,
+        #[cfg(feature = "block_layout")]
+        column_width: LengthPercentageAuto::Auto,
+        #[cfg(feature = "block_layout")]
+        column_fill: ColumnFill::Balance,
+        #[cfg(feature = "block_layout")]
+        float: Float::None,
+        #[cfg(feature = "block_layout")]
+        clear: Clear::None,
+        // Table
+        #[cfg(feature = "table_layout")]
+        border_collapse: BorderCollapse::Separate,
+        #[cfg(feature = "table_layout")]
+        border_spacing: Size::zero(),
+        #[cfg(feature = "table_layout")]
+        col_span: 1,
+        #[cfg(feature = "table_layout")]
+        row_span: 1,
+        // Stack
+        #[cfg(feature = "stack_layout")]
+        stack_direction: StackDirection::Column,
+        #[cfg(feature = "stack_layout")]
+        child_space: Rect::auto(),
+        #[cfg(feature = "stack_layout")]
+        child_between: StackSpace::Auto,
+        #[cfg(feature = "stack_layout")]
+        space: Rect::auto(),
+        #[cfg(feature = "stack_layout")]
+        stack_size: Size::auto(),
+        #[cfg(any(feature = "flexbox", feature = "grid"))]
+        order: 0,
         // Flexbox
         #[cfg(feature = "flexbox")]
         flex_direction: FlexDirection::Row,
@@ -326,6 +778,8 @@ impl Style {
         #[cfg(feature = "grid")]
         grid_template_columns: GridTrackVec::new(),
         #[cfg(feature = "grid")]
+        grid_template_areas: GridTrackVec::new(),
+        #[cfg(feature = "grid")]
         grid_auto_rows: GridTrackVec::new(),
         #[cfg(feature = "grid")]
         grid_auto_columns: GridTrackVec::new(),
@@ -338,6 +792,71 @@ impl Style {
     };
 }
 
+impl Style {
+    /// The style with its physical properties transposed, as seen by the layout algorithms when the node is laid out
+    /// in a vertical `writing_mode` (see [`LayoutTree::transposed_style`](crate::tree::LayoutTree::transposed_style)).
+    /// Flow-relative styles (such as grid templates, alignment and gaps) are unaffected.
+    pub fn transposed(&self, writing_mode: WritingMode) -> Style {
+        /// Transpose the sides of a rect. The block-start side of `vertical-rl` content is its right side.
+        fn transpose_rect<T>(rect: Rect<T>, writing_mode: WritingMode) -> Rect<T> {
+            match writing_mode {
+                WritingMode::HorizontalTb => rect,
+                WritingMode::VerticalRl => {
+                    Rect { left: rect.top, right: rect.bottom, top: rect.right, bottom: rect.left }
+                }
+                WritingMode::VerticalLr => {
+                    Rect { left: rect.top, right: rect.bottom, top: rect.left, bottom: rect.right }
+                }
+            }
+        }
+
+        Style {
+            overflow: self.overflow.transpose(),
+            inset: transpose_rect(self.inset, writing_mode),
+            size: self.size.transpose(),
+            min_size: self.min_size.transpose(),
+            max_size: self.max_size.transpose(),
+            aspect_ratio: self.aspect_ratio.map(|ratio| 1.0 / ratio),
+            margin: transpose_rect(self.margin, writing_mode),
+            padding: transpose_rect(self.padding, writing_mode),
+            border: transpose_rect(self.border, writing_mode),
+            #[cfg(feature = "table_layout")]
+            border_spacing: self.border_spacing.transpose(),
+            ..self.clone()
+        }
+    }
+
+    /// The space reserved for scrollbars on each side of the node, between its border and its padding.
+    ///
+    /// Space is reserved for `Overflow::Scroll` nodes, and for other scroll containers if `scrollbar_gutter` is stable.
+    /// The vertical scrollbar is on the inline-end side and the horizontal scrollbar is at the bottom. The returned inset
+    /// puts the vertical scrollbar on the right, so right-to-left nodes must mirror it onto the left: the flexbox, grid
+    /// and block algorithms get this for free by mirroring their whole horizontal axis, while leaves swap the sides.
+    pub(crate) fn scrollbar_gutter_inset(&self) -> Rect<f32> {
+        // A node that scrolls vertically needs *horizontal* space to be reserved for its scrollbar, and vice versa
+        let vertical_scrollbar = match self.overflow.y {
+            Overflow::Scroll => self.scrollbar_width,
+            overflow if overflow.is_scroll_container() && self.scrollbar_gutter != ScrollbarGutter::Auto => {
+                self.scrollbar_width
+            }
+            _ => 0.0,
+        };
+        let horizontal_scrollbar = match self.overflow.x {
+            Overflow::Scroll => self.scrollbar_width,
+            _ => 0.0,
+        };
+        Rect {
+            left: match self.scrollbar_gutter {
+                ScrollbarGutter::StableBothEdges => vertical_scrollbar,
+                _ => 0.0,
+            },
+            right: vertical_scrollbar,
+            top: 0.0,
+            bottom: horizontal_scrollbar,
+        }
+    }
+}
+
 impl Default for Style {
     fn default() -> Self {
         Style::DEFAULT
@@ -356,9 +875,16 @@ mod tests {
 
         let old_defaults = Style {
             display: Default::default(),
+            visibility: Default::default(),
+            direction: Default::default(),
+            writing_mode: Default::default(),
+            font_size: None,
             overflow: Default::default(),
             scrollbar_width: 0.0,
+            scrollbar_gutter: Default::default(),
             position: Default::default(),
+            #[cfg(any(feature = "flexbox", feature = "grid"))]
+            order: 0,
             #[cfg(feature = "flexbox")]
             flex_direction: Default::default(),
             #[cfg(feature = "flexbox")]
@@ -367,14 +893,44 @@ mod tests {
             align_items: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid"))]
             align_self: Default::default(),
-            #[cfg(feature = "grid")]
+            #[cfg(any(feature = "grid", feature = "block_layout"))]
             justify_items: Default::default(),
-            #[cfg(feature = "grid")]
+            #[cfg(any(feature = "grid", feature = "block_layout"))]
             justify_self: Default::default(),
-            #[cfg(any(feature = "flexbox", feature = "grid"))]
+            #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
             align_content: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid"))]
             justify_content: Default::default(),
+            #[cfg(feature = "block_layout")]
+            text_align: Default::default(),
+            #[cfg(feature = "block_layout")]
+            column_count: None,
+            #[cfg(feature = "block_layout")]
+            column_width: super::LengthPercentageAuto::Auto,
+            #[cfg(feature = "block_layout")]
+            column_fill: Default::default(),
+            #[cfg(feature = "block_layout")]
+            float: Default::default(),
+            #[cfg(feature = "block_layout")]
+            clear: Default::default(),
+            #[cfg(feature = "table_layout")]
+            border_collapse: Default::default(),
+            #[cfg(feature = "table_layout")]
+            border_spacing: Size::zero(),
+            #[cfg(feature = "table_layout")]
+            col_span: 1,
+            #[cfg(feature = "table_layout")]
+            row_span: 1,
+            #[cfg(feature = "stack_layout")]
+            stack_direction: Default::default(),
+            #[cfg(feature = "stack_layout")]
+            child_space: Rect::auto(),
+            #[cfg(feature = "stack_layout")]
+            child_between: super::StackSpace::Auto,
+            #[cfg(feature = "stack_layout")]
+            space: Rect::auto(),
+            #[cfg(feature = "stack_layout")]
+            stack_size: Size::auto(),
             inset: Rect::auto(),
             margin: Rect::zero(),
             padding: Rect::zero(),
@@ -386,6 +942,7 @@ mod tests {
             flex_shrink: 1.0,
             #[cfg(feature = "flexbox")]
             flex_basis: super::Dimension::Auto,
+            box_sizing: Default::default(),
             size: Size::auto(),
             min_size: Size::auto(),
             max_size: Size::auto(),
@@ -395,6 +952,8 @@ mod tests {
             #[cfg(feature = "grid")]
             grid_template_columns: Default::default(),
             #[cfg(feature = "grid")]
+            grid_template_areas: Default::default(),
+            #[cfg(feature = "grid")]
             grid_auto_rows: Default::default(),
             #[cfg(feature = "grid")]
             grid_auto_columns: Default::default(),
@@ -434,10 +993,16 @@ mod tests {
             );
         }
 
-        // Display and Position
-        assert_type_size::<Display>(1);
+        // Display, Position, Float, Clear, Direction, WritingMode and BoxSizing
+        assert_type_size::<Display>(4);
         assert_type_size::<Position>(1);
+        assert_type_size::<Float>(1);
+        assert_type_size::<Clear>(1);
+        assert_type_size::<Direction>(1);
+        assert_type_size::<WritingMode>(1);
+        assert_type_size::<BoxSizing>(1);
         assert_type_size::<Overflow>(1);
+        assert_type_size::<ScrollbarGutter>(1);
 
         // Dimensions and aggregations of Dimensions
         assert_type_size::<f32>(4);
@@ -455,6 +1020,7 @@ mod tests {
         assert_type_size::<AlignContent>(1);
         assert_type_size::<AlignItems>(1);
         assert_type_size::<Option<AlignItems>>(1);
+        assert_type_size::<TextAlign>(1);
 
         // Flexbox Container
         assert_type_size::<FlexDirection>(1);
@@ -470,10 +1036,18 @@ mod tests {
         assert_type_size::<Vec<TrackSizingFunction>>(24);
 
         // CSS Grid Item
-        assert_type_size::<GridPlacement>(4);
-        assert_type_size::<Line<GridPlacement>>(8);
+        assert_type_size::<GridPlacement>(24);
+        assert_type_size::<Line<GridPlacement>>(48);
+        assert_type_size::<GridTemplateArea>(32);
 
         // Overall
-        assert_type_size::<Style>(352);
+        #[cfg(all(not(feature = "table_layout"), not(feature = "stack_layout")))]
+        assert_type_size::<Style>(488);
+        #[cfg(all(feature = "table_layout", not(feature = "stack_layout")))]
+        assert_type_size::<Style>(512);
+        #[cfg(all(not(feature = "table_layout"), feature = "stack_layout"))]
+        assert_type_size::<Style>(576);
+        #[cfg(all(feature = "table_layout", feature = "stack_layout"))]
+        assert_type_size::<Style>(600);
     }
 }
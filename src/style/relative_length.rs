@@ -0,0 +1,468 @@
+//! Resolution of font-relative (`em`, `rem`) and viewport-relative (`vw`, `vh`, `vmin`, `vmax`) lengths
+use super::{Dimension, LengthPercentage, LengthPercentageAuto, Style};
+use crate::geometry::{Rect, Size};
+use crate::util::sys::{f32_max, f32_min};
+use crate::util::MaybeResolve;
+
+#[cfg(feature = "std")]
+use super::{CalcExpression, CalcId};
+#[cfg(feature = "grid")]
+use super::{MaxTrackSizingFunction, MinTrackSizingFunction, TrackSizingFunction};
+#[cfg(feature = "grid")]
+use crate::geometry::MinMax;
+
+/// The font size of the root node if its [`Style::font_size`] is `None`, matching the `medium` font size of web browsers
+pub const DEFAULT_FONT_SIZE: f32 = 16.0;
+
+/// The lengths that the font-relative and viewport-relative lengths of a node's style are resolved against
+///
+/// The [`Taffy`](crate::Taffy) tree resolves these lengths automatically in
+/// [`Taffy::compute_layout`](crate::Taffy::compute_layout). Other [`LayoutTree`](crate::LayoutTree) implementations
+/// must resolve them (using [`Style::resolve_relative_lengths`]) before handing styles to the layout algorithms, which
+/// treat unresolved relative lengths like percentages of an unknown length.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RelativeLengthContext {
+    /// The computed font size of the node: the length of `1em`
+    pub font_size: f32,
+    /// The computed font size of the root node: the length of `1rem`
+    pub root_font_size: f32,
+    /// The size of the viewport: the lengths of `100vw` and `100vh`
+    pub viewport_size: Size<f32>,
+}
+
+impl RelativeLengthContext {
+    /// Creates the context for the root node of a tree that is laid out in a viewport of the given size
+    pub fn new(root_style: &Style, viewport_size: Size<f32>) -> Self {
+        let initial = Self { font_size: DEFAULT_FONT_SIZE, root_font_size: DEFAULT_FONT_SIZE, viewport_size };
+        let font_size = initial.computed_font_size(root_style);
+        Self { font_size, root_font_size: font_size, viewport_size }
+    }
+
+    /// Creates the context for a child of the node that this is the context of
+    pub fn for_child(&self, child_style: &Style) -> Self {
+        Self { font_size: self.computed_font_size(child_style), ..*self }
+    }
+
+    /// The font size of a node with the given style whose parent has this context. Percentages and `em` lengths in
+    /// `font_size` are relative to the parent's font size.
+    fn computed_font_size(&self, style: &Style) -> f32 {
+        match &style.font_size {
+            None => self.font_size,
+            Some(font_size) => {
+                let mut font_size = *font_size;
+                font_size.resolve_relative_lengths(self);
+                font_size.maybe_resolve(self.font_size).unwrap_or(self.font_size)
+            }
+        }
+    }
+
+    /// The absolute length of `value` in the given font-relative or viewport-relative unit
+    fn resolve_unit(&self, unit: RelativeUnit, value: f32) -> f32 {
+        let unit_length = match unit {
+            RelativeUnit::Em => self.font_size,
+            RelativeUnit::Rem => self.root_font_size,
+            RelativeUnit::Vw => self.viewport_size.width,
+            RelativeUnit::Vh => self.viewport_size.height,
+            RelativeUnit::Vmin => f32_min(self.viewport_size.width, self.viewport_size.height),
+            RelativeUnit::Vmax => f32_max(self.viewport_size.width, self.viewport_size.height),
+        };
+        value * unit_length
+    }
+}
+
+/// The font-relative and viewport-relative units, which [`RelativeLengthContext::resolve_unit`] resolves
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum RelativeUnit {
+    /// The node's font size
+    Em,
+    /// The root node's font size
+    Rem,
+    /// The viewport's width
+    Vw,
+    /// The viewport's height
+    Vh,
+    /// The smaller of the viewport's width and height
+    Vmin,
+    /// The larger of the viewport's width and height
+    Vmax,
+}
+
+/// Converts the font-relative and viewport-relative lengths within a style value into absolute lengths
+pub(crate) trait ResolveRelativeLengths {
+    /// Whether the value contains a font-relative or viewport-relative length
+    fn uses_relative_lengths(&self) -> bool;
+    /// Replaces any font-relative or viewport-relative lengths in the value with absolute lengths
+    fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext);
+}
+
+impl ResolveRelativeLengths for LengthPercentage {
+    fn uses_relative_lengths(&self) -> bool {
+        match self {
+            Self::Length(_) | Self::Percent(_) => false,
+            #[cfg(feature = "std")]
+            Self::Calc(expression) => expression.uses_relative_lengths(),
+            Self::Em(_) | Self::Rem(_) | Self::Vw(_) | Self::Vh(_) | Self::Vmin(_) | Self::Vmax(_) => true,
+        }
+    }
+
+    fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext) {
+        match self {
+            Self::Em(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Em, *value)),
+            Self::Rem(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Rem, *value)),
+            Self::Vw(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vw, *value)),
+            Self::Vh(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vh, *value)),
+            Self::Vmin(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vmin, *value)),
+            Self::Vmax(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vmax, *value)),
+            #[cfg(feature = "std")]
+            Self::Calc(expression) => expression.resolve_relative_lengths(context),
+            Self::Length(_) | Self::Percent(_) => {}
+        }
+    }
+}
+
+impl ResolveRelativeLengths for LengthPercentageAuto {
+    fn uses_relative_lengths(&self) -> bool {
+        match self {
+            Self::Length(_) | Self::Percent(_) | Self::Auto => false,
+            #[cfg(feature = "std")]
+            Self::Calc(expression) => expression.uses_relative_lengths(),
+            Self::Em(_) | Self::Rem(_) | Self::Vw(_) | Self::Vh(_) | Self::Vmin(_) | Self::Vmax(_) => true,
+        }
+    }
+
+    fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext) {
+        match self {
+            Self::Em(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Em, *value)),
+            Self::Rem(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Rem, *value)),
+            Self::Vw(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vw, *value)),
+            Self::Vh(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vh, *value)),
+            Self::Vmin(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vmin, *value)),
+            Self::Vmax(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vmax, *value)),
+            #[cfg(feature = "std")]
+            Self::Calc(expression) => expression.resolve_relative_lengths(context),
+            Self::Length(_) | Self::Percent(_) | Self::Auto => {}
+        }
+    }
+}
+
+impl ResolveRelativeLengths for Dimension {
+    fn uses_relative_lengths(&self) -> bool {
+        match self {
+            Self::Length(_) | Self::Percent(_) | Self::Auto | Self::MinContent | Self::MaxContent | Self::Stretch => {
+                false
+            }
+            Self::FitContent(limit) => limit.uses_relative_lengths(),
+            #[cfg(feature = "std")]
+            Self::Calc(expression) => expression.uses_relative_lengths(),
+            Self::Em(_) | Self::Rem(_) | Self::Vw(_) | Self::Vh(_) | Self::Vmin(_) | Self::Vmax(_) => true,
+        }
+    }
+
+    fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext) {
+        match self {
+            Self::Em(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Em, *value)),
+            Self::Rem(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Rem, *value)),
+            Self::Vw(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vw, *value)),
+            Self::Vh(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vh, *value)),
+            Self::Vmin(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vmin, *value)),
+            Self::Vmax(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vmax, *value)),
+            #[cfg(feature = "std")]
+            Self::Calc(expression) => expression.resolve_relative_lengths(context),
+            Self::FitContent(limit) => limit.resolve_relative_lengths(context),
+            Self::Length(_) | Self::Percent(_) | Self::Auto | Self::MinContent | Self::MaxContent | Self::Stretch => {}
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ResolveRelativeLengths for CalcExpression {
+    fn uses_relative_lengths(&self) -> bool {
+        match self {
+            Self::Length(_) | Self::Percent(_) => false,
+            Self::Em(_) | Self::Rem(_) | Self::Vw(_) | Self::Vh(_) | Self::Vmin(_) | Self::Vmax(_) => true,
+            Self::Sum(expressions) | Self::Min(expressions) | Self::Max(expressions) => {
+                expressions.uses_relative_lengths()
+            }
+            Self::Product(expression, _) => expression.uses_relative_lengths(),
+            Self::Clamp { min, value, max } => {
+                min.uses_relative_lengths() || value.uses_relative_lengths() || max.uses_relative_lengths()
+            }
+        }
+    }
+
+    fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext) {
+        match self {
+            Self::Em(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Em, *value)),
+            Self::Rem(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Rem, *value)),
+            Self::Vw(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vw, *value)),
+            Self::Vh(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vh, *value)),
+            Self::Vmin(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vmin, *value)),
+            Self::Vmax(value) => *self = Self::Length(context.resolve_unit(RelativeUnit::Vmax, *value)),
+            Self::Sum(expressions) | Self::Min(expressions) | Self::Max(expressions) => {
+                expressions.resolve_relative_lengths(context)
+            }
+            Self::Product(expression, _) => expression.resolve_relative_lengths(context),
+            Self::Clamp { min, value, max } => {
+                min.resolve_relative_lengths(context);
+                value.resolve_relative_lengths(context);
+                max.resolve_relative_lengths(context);
+            }
+            Self::Length(_) | Self::Percent(_) => {}
+        }
+    }
+}
+
+/// Interned expressions are shared, so an expression that uses relative lengths is replaced by a resolved copy
+#[cfg(feature = "std")]
+impl ResolveRelativeLengths for CalcId {
+    fn uses_relative_lengths(&self) -> bool {
+        (**self).uses_relative_lengths()
+    }
+
+    fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext) {
+        if self.uses_relative_lengths() {
+            let mut expression = (**self).clone();
+            expression.resolve_relative_lengths(context);
+            *self = expression.intern();
+        }
+    }
+}
+
+#[cfg(feature = "grid")]
+impl ResolveRelativeLengths for MinTrackSizingFunction {
+    fn uses_relative_lengths(&self) -> bool {
+        match self {
+            Self::Fixed(length) => length.uses_relative_lengths(),
+            Self::MinContent | Self::MaxContent | Self::Auto => false,
+        }
+    }
+
+    fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext) {
+        if let Self::Fixed(length) = self {
+            length.resolve_relative_lengths(context)
+        }
+    }
+}
+
+#[cfg(feature = "grid")]
+impl ResolveRelativeLengths for MaxTrackSizingFunction {
+    fn uses_relative_lengths(&self) -> bool {
+        match self {
+            Self::Fixed(length) | Self::FitContent(length) => length.uses_relative_lengths(),
+            Self::MinContent | Self::MaxContent | Self::Auto | Self::Fraction(_) => false,
+        }
+    }
+
+    fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext) {
+        if let Self::Fixed(length) | Self::FitContent(length) = self {
+            length.resolve_relative_lengths(context)
+        }
+    }
+}
+
+#[cfg(feature = "grid")]
+impl ResolveRelativeLengths for MinMax<MinTrackSizingFunction, MaxTrackSizingFunction> {
+    fn uses_relative_lengths(&self) -> bool {
+        self.min.uses_relative_lengths() || self.max.uses_relative_lengths()
+    }
+
+    fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext) {
+        self.min.resolve_relative_lengths(context);
+        self.max.resolve_relative_lengths(context);
+    }
+}
+
+#[cfg(feature = "grid")]
+impl ResolveRelativeLengths for TrackSizingFunction {
+    fn uses_relative_lengths(&self) -> bool {
+        match self {
+            Self::Single(track) => track.uses_relative_lengths(),
+            Self::Repeat(_, tracks) => tracks.uses_relative_lengths(),
+            Self::LineNames(_) | Self::Subgrid | Self::Masonry => false,
+        }
+    }
+
+    fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext) {
+        match self {
+            Self::Single(track) => track.resolve_relative_lengths(context),
+            Self::Repeat(_, tracks) => tracks.resolve_relative_lengths(context),
+            Self::LineNames(_) | Self::Subgrid | Self::Masonry => {}
+        }
+    }
+}
+
+impl<T: ResolveRelativeLengths> ResolveRelativeLengths for [T] {
+    fn uses_relative_lengths(&self) -> bool {
+        self.iter().any(T::uses_relative_lengths)
+    }
+
+    fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext) {
+        self.iter_mut().for_each(|value| value.resolve_relative_lengths(context))
+    }
+}
+
+impl<T: ResolveRelativeLengths> ResolveRelativeLengths for Size<T> {
+    fn uses_relative_lengths(&self) -> bool {
+        self.width.uses_relative_lengths() || self.height.uses_relative_lengths()
+    }
+
+    fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext) {
+        self.width.resolve_relative_lengths(context);
+        self.height.resolve_relative_lengths(context);
+    }
+}
+
+impl<T: ResolveRelativeLengths> ResolveRelativeLengths for Rect<T> {
+    fn uses_relative_lengths(&self) -> bool {
+        self.left.uses_relative_lengths()
+            || self.right.uses_relative_lengths()
+            || self.top.uses_relative_lengths()
+            || self.bottom.uses_relative_lengths()
+    }
+
+    fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext) {
+        self.left.resolve_relative_lengths(context);
+        self.right.resolve_relative_lengths(context);
+        self.top.resolve_relative_lengths(context);
+        self.bottom.resolve_relative_lengths(context);
+    }
+}
+
+impl Style {
+    /// Whether any of the lengths in the style (other than [`Style::font_size`]) are font-relative or viewport-relative
+    pub fn uses_relative_lengths(&self) -> bool {
+        if self.inset.uses_relative_lengths()
+            || self.size.uses_relative_lengths()
+            || self.min_size.uses_relative_lengths()
+            || self.max_size.uses_relative_lengths()
+            || self.margin.uses_relative_lengths()
+            || self.padding.uses_relative_lengths()
+            || self.border.uses_relative_lengths()
+        {
+            return true;
+        }
+        #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
+        if self.gap.uses_relative_lengths() {
+            return true;
+        }
+        #[cfg(feature = "table_layout")]
+        if self.border_spacing.uses_relative_lengths() {
+            return true;
+        }
+        #[cfg(feature = "flexbox")]
+        if self.flex_basis.uses_relative_lengths() {
+            return true;
+        }
+        #[cfg(feature = "grid")]
+        if self.grid_template_rows.uses_relative_lengths()
+            || self.grid_template_columns.uses_relative_lengths()
+            || self.grid_auto_rows.uses_relative_lengths()
+            || self.grid_auto_columns.uses_relative_lengths()
+        {
+            return true;
+        }
+        false
+    }
+
+    /// Replaces the font-relative and viewport-relative lengths in the style (other than [`Style::font_size`]) with
+    /// absolute lengths. The `context` should be the context of the node that the style belongs to.
+    pub fn resolve_relative_lengths(&mut self, context: &RelativeLengthContext) {
+        self.inset.resolve_relative_lengths(context);
+        self.size.resolve_relative_lengths(context);
+        self.min_size.resolve_relative_lengths(context);
+        self.max_size.resolve_relative_lengths(context);
+        self.margin.resolve_relative_lengths(context);
+        self.padding.resolve_relative_lengths(context);
+        self.border.resolve_relative_lengths(context);
+        #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
+        self.gap.resolve_relative_lengths(context);
+        #[cfg(feature = "table_layout")]
+        self.border_spacing.resolve_relative_lengths(context);
+        #[cfg(feature = "flexbox")]
+        self.flex_basis.resolve_relative_lengths(context);
+        #[cfg(feature = "grid")]
+        {
+            self.grid_template_rows.resolve_relative_lengths(context);
+            self.grid_template_columns.resolve_relative_lengths(context);
+            self.grid_auto_rows.resolve_relative_lengths(context);
+            self.grid_auto_columns.resolve_relative_lengths(context);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RelativeLengthContext, ResolveRelativeLengths, DEFAULT_FONT_SIZE};
+    use crate::geometry::Size;
+    use crate::style::{Dimension, LengthPercentage, LengthPercentageAuto, Style};
+
+    fn context() -> RelativeLengthContext {
+        RelativeLengthContext {
+            font_size: 20.0,
+            root_font_size: 10.0,
+            viewport_size: Size { width: 800.0, height: 600.0 },
+        }
+    }
+
+    #[test]
+    fn resolves_each_unit() {
+        let mut style = Style {
+            size: Size { width: Dimension::Em(2.0), height: Dimension::Rem(3.0) },
+            min_size: Size { width: Dimension::Vw(0.5), height: Dimension::Vh(0.5) },
+            max_size: Size { width: Dimension::Vmin(0.1), height: Dimension::Vmax(0.1) },
+            ..Style::DEFAULT
+        };
+        assert!(style.uses_relative_lengths());
+        style.resolve_relative_lengths(&context());
+        assert!(!style.uses_relative_lengths());
+        assert_eq!(style.size, Size { width: Dimension::Length(40.0), height: Dimension::Length(30.0) });
+        assert_eq!(style.min_size, Size { width: Dimension::Length(400.0), height: Dimension::Length(300.0) });
+        assert_eq!(style.max_size, Size { width: Dimension::Length(60.0), height: Dimension::Length(80.0) });
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn resolves_units_within_calc_expressions() {
+        use crate::style::CalcExpression;
+        let mut margin: LengthPercentageAuto = (CalcExpression::Vh(1.0) - CalcExpression::Em(2.0)).into();
+        margin.resolve_relative_lengths(&context());
+        assert!(!margin.uses_relative_lengths());
+        assert_eq!(margin.resolve_to_option(0.0), Some(560.0));
+        assert!(!Style::DEFAULT.uses_relative_lengths());
+    }
+
+    #[test]
+    #[cfg(feature = "table_layout")]
+    fn resolves_border_spacing() {
+        let mut style = Style {
+            border_spacing: Size { width: LengthPercentage::Em(0.5), height: LengthPercentage::Length(2.0) },
+            ..Style::DEFAULT
+        };
+        assert!(style.uses_relative_lengths());
+        style.resolve_relative_lengths(&context());
+        assert_eq!(
+            style.border_spacing,
+            Size { width: LengthPercentage::Length(10.0), height: LengthPercentage::Length(2.0) }
+        );
+    }
+
+    #[test]
+    fn font_size_is_inherited_and_relative_to_the_parent() {
+        let viewport_size = Size { width: 800.0, height: 600.0 };
+        let root = RelativeLengthContext::new(&Style::DEFAULT, viewport_size);
+        assert_eq!(root.font_size, DEFAULT_FONT_SIZE);
+
+        let root = RelativeLengthContext::new(
+            &Style { font_size: Some(LengthPercentage::Em(2.0)), ..Style::DEFAULT },
+            viewport_size,
+        );
+        assert_eq!((root.font_size, root.root_font_size), (32.0, 32.0));
+
+        let child = root.for_child(&Style { font_size: Some(LengthPercentage::Percent(0.5)), ..Style::DEFAULT });
+        assert_eq!((child.font_size, child.root_font_size), (16.0, 32.0));
+        let grandchild = child.for_child(&Style::DEFAULT);
+        assert_eq!(grandchild.font_size, 16.0);
+        let grandchild = child.for_child(&Style { font_size: Some(LengthPercentage::Rem(0.5)), ..Style::DEFAULT });
+        assert_eq!(grandchild.font_size, 16.0);
+    }
+}
@@ -0,0 +1,98 @@
+//! Style types for the stack layout algorithm, which follows the row/column model of [Morphorm](https://github.com/vizia/morphorm)
+
+use super::Dimension;
+use crate::style_helpers::{FromLength, FromPercent, TaffyAuto, TaffyZero};
+
+/// The axis along which the children of a [`Display::Stack`](super::Display::Stack) container are stacked
+///
+/// Corresponds to Morphorm's `LayoutType::Row` and `LayoutType::Column`. Defaults to [`StackDirection::Column`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StackDirection {
+    /// Children are placed from left to right
+    Row,
+    /// Children are placed from top to bottom
+    #[default]
+    Column,
+}
+
+impl StackDirection {
+    #[inline]
+    /// Is the direction [`StackDirection::Row`]?
+    pub(crate) fn is_row(self) -> bool {
+        self == Self::Row
+    }
+}
+
+/// The size of the space on one side of a child of a [`Display::Stack`](super::Display::Stack) container
+///
+/// Corresponds to Morphorm's `Units`. Percentages are resolved against the size of the container's content box in the
+/// same axis (and are zero if that isn't definite).
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StackSpace {
+    /// An absolute length in some abstract units
+    Length(f32),
+    /// A percentage of the size of the container's content box
+    Percent(f32),
+    /// A share of the free space in the container (Morphorm's `Units::Stretch`). The free space in each axis is divided
+    /// between the stretched spaces and sizes in that axis in proportion to their weights (see [`StackSize::Stretch`]).
+    Stretch(f32),
+    /// The space is inherited from the container's `child_space` (or `child_between`)
+    Auto,
+}
+impl TaffyZero for StackSpace {
+    const ZERO: Self = Self::Length(0.0);
+}
+impl TaffyAuto for StackSpace {
+    const AUTO: Self = Self::Auto;
+}
+impl FromLength for StackSpace {
+    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
+        Self::Length(value.into())
+    }
+}
+impl FromPercent for StackSpace {
+    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
+        Self::Percent(percent.into())
+    }
+}
+
+impl StackSpace {
+    /// Returns `fallback` if the space is `Auto`, and the space itself otherwise
+    #[inline]
+    pub(crate) fn or(self, fallback: StackSpace) -> StackSpace {
+        match self {
+            Self::Auto => fallback,
+            _ => self,
+        }
+    }
+}
+
+/// Whether the size of a child of a [`Display::Stack`](super::Display::Stack) container stretches, and with what weight
+///
+/// Corresponds to Morphorm's `Units::Stretch` for sizes. Defaults to [`StackSize::Auto`].
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StackSize {
+    /// The size is set by the node's `size` style, where a `Dimension::Stretch` size stretches with a weight of `1.0`
+    #[default]
+    Auto,
+    /// The size takes a share of the free space in the container in proportion to this weight (like
+    /// [`StackSpace::Stretch`]), whatever the node's `size` style. It is still limited by `min_size` and `max_size`.
+    Stretch(f32),
+}
+impl TaffyAuto for StackSize {
+    const AUTO: Self = Self::Auto;
+}
+
+impl StackSize {
+    /// The weight with which a size stretches, given the `size` style in the same axis, or `None` if it doesn't stretch
+    #[inline]
+    pub(crate) fn stretch_weight(self, size: Dimension) -> Option<f32> {
+        match self {
+            Self::Stretch(weight) => Some(weight),
+            Self::Auto => (size == Dimension::Stretch).then_some(1.0),
+        }
+    }
+}
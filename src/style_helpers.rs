@@ -8,17 +8,17 @@ use crate::{
 use crate::{
     geometry::MinMax,
     style::{
-        GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction, NonRepeatedTrackSizingFunction,
+        GridPlacement, GridTemplateArea, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
         TrackSizingFunction,
     },
-    util::sys::Vec,
+    util::sys::{Arc, GridTrackVec, String, Vec},
 };
 #[cfg(feature = "grid")]
 use core::fmt::Debug;
 
-/// Returns an auto-repeated track definition
+/// Returns an auto-repeated track definition. The track list may contain line names (see [`line_names`]).
 #[cfg(feature = "grid")]
-pub fn repeat<Input>(repetition_kind: Input, track_list: Vec<NonRepeatedTrackSizingFunction>) -> TrackSizingFunction
+pub fn repeat<Input>(repetition_kind: Input, track_list: Vec<TrackSizingFunction>) -> TrackSizingFunction
 where
     Input: TryInto<GridTrackRepetition>,
     <Input as TryInto<GridTrackRepetition>>::Error: Debug,
@@ -26,13 +26,19 @@ where
     TrackSizingFunction::Repeat(repetition_kind.try_into().unwrap(), track_list)
 }
 
+/// Returns an entry of a track list that names the grid line at its position, like `[name1 name2]` in CSS
+#[cfg(feature = "grid")]
+pub fn line_names(names: &[&str]) -> TrackSizingFunction {
+    TrackSizingFunction::LineNames(names.iter().map(|&name| String::from(name)).collect())
+}
+
 #[cfg(feature = "grid")]
 #[cfg(test)]
 mod repeat_fn_tests {
     use super::repeat;
-    use crate::style::{GridTrackRepetition, NonRepeatedTrackSizingFunction, TrackSizingFunction};
+    use crate::style::{GridTrackRepetition, TrackSizingFunction};
 
-    const TEST_VEC: Vec<NonRepeatedTrackSizingFunction> = Vec::new();
+    const TEST_VEC: Vec<TrackSizingFunction> = Vec::new();
 
     #[test]
     fn test_repeat_u16() {
@@ -61,6 +67,25 @@ pub fn evenly_sized_tracks(count: u16) -> Vec<TrackSizingFunction> {
     tracks
 }
 
+#[cfg(feature = "grid")]
+/// Returns a grid template which makes a grid container adopt the tracks of its parent grid in that axis
+pub fn subgrid() -> Vec<TrackSizingFunction> {
+    use crate::util::sys::new_vec_with_capacity;
+    let mut tracks = new_vec_with_capacity(1);
+    tracks.push(TrackSizingFunction::Subgrid);
+    tracks
+}
+
+#[cfg(feature = "grid")]
+/// Returns a grid template which makes a grid container lay out its items in a masonry layout in that axis. Only
+/// supported in `grid_template_rows`.
+pub fn masonry() -> Vec<TrackSizingFunction> {
+    use crate::util::sys::new_vec_with_capacity;
+    let mut tracks = new_vec_with_capacity(1);
+    tracks.push(TrackSizingFunction::Masonry);
+    tracks
+}
+
 /// Specifies a grid line to place a grid item between in CSS Grid Line coordinates:
 ///  - Positive indicies count upwards from the start (top or left) of the explicit grid
 ///  - Negative indicies count downwards from the end (bottom or right) of the explicit grid
@@ -84,6 +109,34 @@ pub trait TaffyGridSpan {
     fn from_span(span: u16) -> Self;
 }
 
+/// Specifies the `index`th grid line with the name `name`. Negative indicies count backwards from the
+/// end of the explicit grid. If there are not enough lines with that name then implicit lines are used.
+#[cfg(feature = "grid")]
+pub fn named_line(name: impl Into<Arc<str>>, index: i16) -> GridPlacement {
+    GridPlacement::NamedLine(name.into(), index)
+}
+
+/// Returns a GridPlacement::NamedSpan which spans until the `span`th line with the name `name`
+#[cfg(feature = "grid")]
+pub fn named_span(name: impl Into<Arc<str>>, span: u16) -> GridPlacement {
+    GridPlacement::NamedSpan(name.into(), span)
+}
+
+/// Places a grid item into the named grid area `name` (or the lines `name-start` and `name-end`)
+#[cfg(feature = "grid")]
+pub fn area(name: impl Into<Arc<str>>) -> Line<GridPlacement> {
+    let name = name.into();
+    Line { start: GridPlacement::NamedArea(name.clone()), end: GridPlacement::NamedArea(name) }
+}
+
+/// Parses a list of `grid-template-areas` rows such as `["header header", "sidebar main"]`
+///
+/// Panics if the rows do not describe a valid set of rectangular areas
+#[cfg(feature = "grid")]
+pub fn template_areas(rows: &[&str]) -> GridTrackVec<GridTemplateArea> {
+    GridTemplateArea::from_strings(rows).unwrap()
+}
+
 /// Returns a MinMax with min value of min and max value of max
 #[cfg(feature = "grid")]
 pub fn minmax<Output>(min: MinTrackSizingFunction, max: MaxTrackSizingFunction) -> Output
@@ -533,6 +586,39 @@ impl<T: FromPercent> Rect<T> {
     }
 }
 
+/// Returns a value of the inferred type which represents a multiple of the node's font size (`em` in CSS)
+pub fn em<Input: Into<f32> + Copy, T: From<LengthPercentage>>(value: Input) -> T {
+    LengthPercentage::Em(value.into()).into()
+}
+
+/// Returns a value of the inferred type which represents a multiple of the root node's font size (`rem` in CSS)
+pub fn rem<Input: Into<f32> + Copy, T: From<LengthPercentage>>(value: Input) -> T {
+    LengthPercentage::Rem(value.into()).into()
+}
+
+/// Returns a value of the inferred type which represents a fraction of the viewport's width (`vw` in CSS).
+/// `vw(1.0)` is the full width of the viewport.
+pub fn vw<Input: Into<f32> + Copy, T: From<LengthPercentage>>(fraction: Input) -> T {
+    LengthPercentage::Vw(fraction.into()).into()
+}
+
+/// Returns a value of the inferred type which represents a fraction of the viewport's height (`vh` in CSS)
+pub fn vh<Input: Into<f32> + Copy, T: From<LengthPercentage>>(fraction: Input) -> T {
+    LengthPercentage::Vh(fraction.into()).into()
+}
+
+/// Returns a value of the inferred type which represents a fraction of the smaller of the viewport's width and height
+/// (`vmin` in CSS)
+pub fn vmin<Input: Into<f32> + Copy, T: From<LengthPercentage>>(fraction: Input) -> T {
+    LengthPercentage::Vmin(fraction.into()).into()
+}
+
+/// Returns a value of the inferred type which represents a fraction of the larger of the viewport's width and height
+/// (`vmax` in CSS)
+pub fn vmax<Input: Into<f32> + Copy, T: From<LengthPercentage>>(fraction: Input) -> T {
+    LengthPercentage::Vmax(fraction.into()).into()
+}
+
 /// Create a `Fraction` track sizing function (`fr` in CSS)
 #[cfg(feature = "grid")]
 pub fn fr<Input: Into<f32> + Copy, T: FromFlex>(flex: Input) -> T {
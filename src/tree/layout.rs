@@ -1,7 +1,8 @@
 //! Final data structures that represent the high-level UI layout
 
 use crate::{
-    geometry::{Point, Size},
+    geometry::{Point, Rect, Size},
+    tree::NodeId,
     util::sys::{f32_max, f32_min},
 };
 
@@ -70,18 +71,21 @@ impl CollapsibleMarginSet {
     }
 }
 
-/// A struct containing both the size of a node and it's first baseline in each dimension (if it has any)
+/// A struct containing both the size of a node and it's first and last baselines in each dimension (if it has any)
 ///
 /// A baseline is the line on which text sits. Your node likely has a baseline if it is a text node, or contains
 /// children that may be text nodes. See <https://www.w3.org/TR/css-writing-modes-3/#intro-baselines> for details.
 /// If your node does not have a baseline (or you are unsure how to compute it), then simply return `Point::NONE`
-/// for the first_baselines field
+/// for the first_baselines and last_baselines fields. A node with a single line of text has the same first and
+/// last baseline.
 #[derive(Debug, Copy, Clone)]
 pub struct SizeBaselinesAndMargins {
     /// The size of the node
     pub size: Size<f32>,
     /// The first baseline of the node in each dimension, if any
     pub first_baselines: Point<Option<f32>>,
+    /// The last baseline of the node in each dimension, if any
+    pub last_baselines: Point<Option<f32>>,
     /// Top margin that can be collapsed with. This is used for CSS block layout and can be set to
     /// `CollapsibleMarginSet::ZERO` for other layout modes that don't support margin collapsing
     pub top_margin: CollapsibleMarginSet,
@@ -91,6 +95,9 @@ pub struct SizeBaselinesAndMargins {
     /// Whether margins can be collapsed through this node. This is used for CSS block layout and can
     /// be set to `false` for other layout modes that don't support margin collapsing
     pub margins_can_collapse_through: bool,
+    /// The size of the node's scrollable overflow (see [`Layout::content_size`]). This can be set to `Size::ZERO`
+    /// for nodes without children.
+    pub content_size: Size<f32>,
 }
 
 impl SizeBaselinesAndMargins {
@@ -98,19 +105,27 @@ impl SizeBaselinesAndMargins {
     pub const HIDDEN: Self = Self {
         size: Size::ZERO,
         first_baselines: Point::NONE,
+        last_baselines: Point::NONE,
         top_margin: CollapsibleMarginSet::ZERO,
         bottom_margin: CollapsibleMarginSet::ZERO,
         margins_can_collapse_through: false,
+        content_size: Size::ZERO,
     };
 
     /// Constructor to create a `SizeBaselinesAndMargins` from just the size and baselines
-    pub fn from_size_and_baselines(size: Size<f32>, first_baselines: Point<Option<f32>>) -> Self {
+    pub fn from_size_and_baselines(
+        size: Size<f32>,
+        first_baselines: Point<Option<f32>>,
+        last_baselines: Point<Option<f32>>,
+    ) -> Self {
         Self {
             size,
             first_baselines,
+            last_baselines,
             top_margin: CollapsibleMarginSet::ZERO,
             bottom_margin: CollapsibleMarginSet::ZERO,
             margins_can_collapse_through: false,
+            content_size: Size::ZERO,
         }
     }
 }
@@ -120,9 +135,11 @@ impl From<Size<f32>> for SizeBaselinesAndMargins {
         Self {
             size,
             first_baselines: Point::NONE,
+            last_baselines: Point::NONE,
             top_margin: CollapsibleMarginSet::ZERO,
             bottom_margin: CollapsibleMarginSet::ZERO,
             margins_can_collapse_through: false,
+            content_size: Size::ZERO,
         }
     }
 }
@@ -139,6 +156,14 @@ pub struct Layout {
     pub size: Size<f32>,
     /// The top-left corner of the node
     pub location: Point<f32>,
+    /// Whether the node should be painted. This is `false` for nodes whose
+    /// [`Style::visibility`](crate::style::Style::visibility) is `Hidden` or `Collapse`.
+    pub is_visible: bool,
+    /// The size of the node's scrollable overflow rectangle, measured from the top-left corner of its border box. This
+    /// covers the node's padding box and the margin boxes of its children (followed by the node's end padding), and
+    /// extends to the scrollable overflow of any children that don't clip it. Scroll containers can be scrolled by
+    /// the amount that this exceeds the size of their padding box. It is zero for nodes without children.
+    pub content_size: Size<f32>,
 }
 
 impl Layout {
@@ -149,7 +174,7 @@ impl Layout {
     /// This means it should be rendered below all other [`Layout`]s.
     #[must_use]
     pub const fn new() -> Self {
-        Self { order: 0, size: Size::zero(), location: Point::ZERO }
+        Self { order: 0, size: Size::zero(), location: Point::ZERO, is_visible: true, content_size: Size::ZERO }
     }
 
     /// Creates a new zero-[`Layout`] with the supplied `order` value.
@@ -158,6 +183,68 @@ impl Layout {
     /// The Zero-layout has size and location set to ZERO.
     #[must_use]
     pub const fn with_order(order: u32) -> Self {
-        Self { order, size: Size::zero(), location: Point::ZERO }
+        Self { order, size: Size::zero(), location: Point::ZERO, is_visible: true, content_size: Size::ZERO }
+    }
+}
+
+/// The constraint that determines how a [`Position::Sticky`](crate::style::Position::Sticky) node is shifted as its
+/// nearest scroll container is scrolled. Sticky nodes are laid out as if they were relatively positioned without any
+/// offset: the offset depends on the scroll position, which is outside of the scope of layout.
+///
+/// All rects are expressed relative to the padding box of the scroll container when it is not scrolled.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StickyConstraint {
+    /// The nearest ancestor that is a scroll container (or the root node if there isn't one)
+    pub scroll_container: NodeId,
+    /// The edges of the scrollport, inset by the sticky node's insets, that the node is kept within. `None` for the
+    /// edges whose inset is `auto`.
+    pub sticky_view_rect: Rect<Option<f32>>,
+    /// The content box of the parent of the sticky node, which the node is never moved out of
+    pub containing_block: Rect<f32>,
+    /// The border box of the sticky node
+    pub border_box: Rect<f32>,
+    /// The location of the node relative to its parent, as stored in its [`Layout`]
+    pub location: Point<f32>,
+}
+
+impl StickyConstraint {
+    /// The location of the node relative to its parent once its scroll container has been scrolled by `scroll_offset`
+    pub fn location_for_scroll_offset(&self, scroll_offset: Point<f32>) -> Point<f32> {
+        /// The offset of the node in a single axis. The start edge of the sticky view rect takes precedence over the end.
+        fn sticky_offset(
+            border_box: (f32, f32),
+            containing_block: (f32, f32),
+            sticky_view: (Option<f32>, Option<f32>),
+            scroll_offset: f32,
+        ) -> f32 {
+            let mut offset = 0.0;
+            if let Some(view_end) = sticky_view.1 {
+                let overflow = border_box.1 - (view_end + scroll_offset);
+                if overflow > 0.0 {
+                    offset = -f32_min(overflow, f32_max(0.0, border_box.0 - containing_block.0));
+                }
+            }
+            if let Some(view_start) = sticky_view.0 {
+                let underflow = (view_start + scroll_offset) - border_box.0;
+                if underflow > 0.0 {
+                    offset = f32_min(underflow, f32_max(0.0, containing_block.1 - border_box.1));
+                }
+            }
+            offset
+        }
+
+        let x = sticky_offset(
+            (self.border_box.left, self.border_box.right),
+            (self.containing_block.left, self.containing_block.right),
+            (self.sticky_view_rect.left, self.sticky_view_rect.right),
+            scroll_offset.x,
+        );
+        let y = sticky_offset(
+            (self.border_box.top, self.border_box.bottom),
+            (self.containing_block.top, self.containing_block.bottom),
+            (self.sticky_view_rect.top, self.sticky_view_rect.bottom),
+            scroll_offset.y,
+        );
+        Point { x: self.location.x + x, y: self.location.y + y }
     }
 }
@@ -4,6 +4,7 @@ use crate::geometry::Size;
 use crate::style::AvailableSpace;
 #[cfg(any(feature = "std", feature = "alloc"))]
 use crate::util::sys::Box;
+use crate::util::sys::Vec;
 
 /// A function type that can be used in a [`MeasureFunc`]
 ///
@@ -11,6 +12,16 @@ use crate::util::sys::Box;
 pub trait Measurable: Send + Sync {
     /// Measure node
     fn measure(&self, known_dimensions: Size<Option<f32>>, available_space: Size<AvailableSpace>) -> Size<f32>;
+
+    /// Split the content of an inline-level node (such as a run of text) into the fragments that may be placed on
+    /// separate lines (such as words), returning the size of each fragment in order. A line may be broken between any
+    /// two fragments.
+    ///
+    /// Returns `None` if the content can't be split, in which case the node is placed on a line as a single box of its
+    /// measured size. This is what the default implementation does.
+    fn measure_fragments(&self, _available_space: Size<AvailableSpace>) -> Option<Vec<Size<f32>>> {
+        None
+    }
 }
 
 /// A function that can be used to compute the intrinsic size of a node
@@ -33,6 +44,17 @@ impl Measurable for MeasureFunc {
             Self::Boxed(measurable) => measurable.measure(known_dimensions, available_space),
         }
     }
+
+    /// Call the measure function to split the node into fragments
+    #[inline(always)]
+    #[cfg_attr(not(any(feature = "std", feature = "alloc")), allow(unused_variables))]
+    fn measure_fragments(&self, available_space: Size<AvailableSpace>) -> Option<Vec<Size<f32>>> {
+        match self {
+            Self::Raw(_) => None,
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            Self::Boxed(measurable) => measurable.measure_fragments(available_space),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,8 @@
 //! Contains both [a high-level interface to Taffy](crate::Taffy) using a ready-made node tree, and [a trait for defining a custom node trees](crate::tree::LayoutTree) / utility types to help with that.
 
 use crate::geometry::{Line, Size};
-use crate::style::{AvailableSpace, Style};
+use crate::style::{AvailableSpace, Style, WritingMode};
+use crate::util::sys::Vec;
 
 // Submodules
 mod cache;
@@ -17,7 +18,7 @@ mod taffy_tree;
 #[cfg(feature = "taffy_tree")]
 pub use taffy_tree::{Taffy, TaffyChildIter, TaffyError, TaffyResult};
 mod layout;
-pub use layout::{CollapsibleMarginSet, Layout, RunMode, SizeBaselinesAndMargins, SizingMode};
+pub use layout::{CollapsibleMarginSet, Layout, RunMode, SizeBaselinesAndMargins, SizingMode, StickyConstraint};
 
 /// Any item that implements the LayoutTree can be layed out using Taffy's algorithms.
 ///
@@ -39,8 +40,22 @@ pub trait LayoutTree {
     fn child(&self, node: NodeId, index: usize) -> NodeId;
 
     /// Get the [`Style`] for this node.
+    ///
+    /// Font-relative and viewport-relative lengths (such as [`Dimension::Em`](crate::style::Dimension::Em) or
+    /// [`Dimension::Vw`](crate::style::Dimension::Vw)) must already have been resolved to absolute lengths, which
+    /// [`Style::resolve_relative_lengths`] does. The layout algorithms treat any that remain like percentages of an
+    /// unknown length: a size of `em(2.0)` behaves like `auto`, and a padding of `vw(0.1)` like zero.
     fn style(&self, node: NodeId) -> &Style;
 
+    /// Get the style of this node transposed for the vertical `writing_mode` (see [`Style::transposed`]), which is
+    /// what the layout algorithms see when the node is laid out by (or is) a node with that writing mode. Trees can
+    /// cache these styles to avoid transposing them each time such a node is laid out.
+    ///
+    /// The default implementation returns `None`, in which case the styles are transposed when they are needed.
+    fn transposed_style(&self, _node: NodeId, _writing_mode: WritingMode) -> Option<&Style> {
+        None
+    }
+
     /// Get a reference to the node's output layout
     fn layout(&self, node: NodeId) -> &Layout;
 
@@ -68,4 +83,21 @@ pub trait LayoutTree {
         sizing_mode: SizingMode,
         vertical_margins_are_collapsible: Line<bool>,
     ) -> SizeBaselinesAndMargins;
+
+    /// Split the content of an inline-level leaf node into fragments that may be placed on separate lines, returning
+    /// the size of each fragment in order (see [`Measurable::measure_fragments`]). Returns `None` if the node can't be
+    /// split, which is what the default implementation does.
+    fn measure_child_fragments(
+        &mut self,
+        _node: NodeId,
+        _available_space: Size<AvailableSpace>,
+    ) -> Option<Vec<Size<f32>>> {
+        None
+    }
+
+    /// Set the final layouts of the fragments of a leaf node that was split by [`LayoutTree::measure_child_fragments`].
+    /// The location of each fragment is relative to the location of the node, whose layout has already been set.
+    ///
+    /// The default implementation discards them.
+    fn set_fragment_layouts(&mut self, _node: NodeId, _fragment_layouts: Vec<Layout>) {}
 }
@@ -1,9 +1,15 @@
 //! UI node types and related data structures.
 //!
 //! Layouts are composed of multiple nodes, which live in a tree-like data structure.
+#[cfg(feature = "taffy_tree")]
 use crate::style::Style;
-use crate::tree::Cache;
-use crate::tree::Layout;
+#[cfg(feature = "taffy_tree")]
+use crate::tree::{Cache, Layout};
+#[cfg(all(feature = "taffy_tree", any(feature = "std", feature = "alloc")))]
+use crate::{
+    style::{Direction, RelativeLengthContext, WritingMode},
+    util::sys::Box,
+};
 
 #[cfg(feature = "taffy_tree")]
 use slotmap::{DefaultKey, Key, KeyData};
@@ -65,9 +71,24 @@ impl From<NodeId> for DefaultKey {
 /// Layout information for a given [`Node`](crate::node::Node)
 ///
 /// Stored in a [`Taffy`].
+#[cfg(feature = "taffy_tree")]
 pub(crate) struct NodeData {
     /// The layout strategy used by this node
     pub(crate) style: Style,
+    /// The node's style with its font-relative and viewport-relative lengths resolved to absolute lengths and its
+    /// inherited direction resolved, as of the most recent layout. `None` if the style doesn't need resolving.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub(crate) computed_style: Option<Box<Style>>,
+    /// The context that the computed style was resolved in, or `None` if the style has changed since then
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub(crate) relative_length_context: Option<RelativeLengthContext>,
+    /// The direction of the node's parent that the computed style was resolved with
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub(crate) parent_direction: Direction,
+    /// The computed style transposed for each of the vertical writing modes (in the order of [`WritingMode::VERTICAL`])
+    /// that the node is laid out in, as of the most recent layout
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub(crate) transposed_styles: [Option<Box<Style>>; 2],
     /// The results of the layout computation
     pub(crate) layout: Layout,
 
@@ -78,11 +99,48 @@ pub(crate) struct NodeData {
     pub(crate) cache: Cache,
 }
 
+#[cfg(feature = "taffy_tree")]
 impl NodeData {
     /// Create the data for a new node
     #[must_use]
     pub const fn new(style: Style) -> Self {
-        Self { style, cache: Cache::new(), layout: Layout::new(), needs_measure: false }
+        Self {
+            style,
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            computed_style: None,
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            relative_length_context: None,
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            parent_direction: Direction::Ltr,
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            transposed_styles: [None, None],
+            cache: Cache::new(),
+            layout: Layout::new(),
+            needs_measure: false,
+        }
+    }
+
+    /// The style that the layout algorithms should use for this node
+    #[inline]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn computed_style(&self) -> &Style {
+        self.computed_style.as_deref().unwrap_or(&self.style)
+    }
+
+    /// The style that the layout algorithms should use for this node. Relative lengths are only resolved when an
+    /// allocator is available, so this is the node's style.
+    #[inline]
+    #[cfg(not(any(feature = "std", feature = "alloc")))]
+    pub fn computed_style(&self) -> &Style {
+        &self.style
+    }
+
+    /// The cached transposed style for laying this node out in the vertical `writing_mode`, if there is one
+    #[inline]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn transposed_style(&self, writing_mode: WritingMode) -> Option<&Style> {
+        let index = WritingMode::VERTICAL.iter().position(|&vertical| vertical == writing_mode)?;
+        self.transposed_styles[index].as_deref()
     }
 
     /// Marks a node and all of its parents (recursively) as dirty
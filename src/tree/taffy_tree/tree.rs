@@ -3,13 +3,22 @@
 //! Layouts are composed of multiple nodes, which live in a tree-like data structure.
 use slotmap::{DefaultKey, SlotMap, SparseSecondaryMap};
 
-use crate::compute::taffy_tree::{compute_layout, measure_node_size, perform_node_layout};
+use crate::compute::taffy_tree::{compute_layout, compute_sticky_constraint, measure_node_size, perform_node_layout};
 use crate::geometry::{Line, Size};
 use crate::prelude::LayoutTree;
-use crate::style::{AvailableSpace, Style};
-use crate::tree::{Layout, MeasureFunc, NodeData, NodeId, SizeBaselinesAndMargins, SizingMode};
+use crate::style::{AvailableSpace, Position, Style, WritingMode};
+use crate::tree::{
+    Layout, Measurable, MeasureFunc, NodeData, NodeId, SizeBaselinesAndMargins, SizingMode, StickyConstraint,
+};
 use crate::util::sys::{new_vec_with_capacity, ChildrenVec, Vec};
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::compute::CustomLayoutAlgorithm;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::style::Display;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::util::sys::Arc;
+
 use super::{TaffyError, TaffyResult};
 
 /// Global configuration values for a Taffy instance
@@ -32,6 +41,9 @@ pub struct Taffy {
     /// Functions/closures that compute the intrinsic size of leaf nodes
     pub(crate) measure_funcs: SparseSecondaryMap<DefaultKey, MeasureFunc>,
 
+    /// The layouts of the fragments of leaf nodes that have been split across lines
+    pub(crate) fragment_layouts: SparseSecondaryMap<DefaultKey, Vec<Layout>>,
+
     /// The children of each node
     ///
     /// The indexes in the outer vector correspond to the position of the parent [`NodeData`]
@@ -42,6 +54,22 @@ pub struct Taffy {
     /// The indexes in the outer vector correspond to the position of the child [`NodeData`]
     pub(crate) parents: SlotMap<DefaultKey, Option<NodeId>>,
 
+    /// The layout algorithms registered for each [`Display::Custom`] id
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub(crate) custom_layout_algorithms: Vec<(u16, Arc<dyn CustomLayoutAlgorithm>)>,
+
+    /// The number of nodes whose writing mode is vertical. The styles of the tree are only transposed for layout if
+    /// there are any.
+    #[cfg(all(
+        any(feature = "std", feature = "alloc"),
+        any(feature = "block_layout", feature = "flexbox", feature = "grid")
+    ))]
+    pub(crate) vertical_writing_mode_count: usize,
+
+    /// The number of nodes whose position is [`Position::Fixed`]. The tree is only searched for fixed position nodes
+    /// to lay out if there are any.
+    pub(crate) fixed_position_count: usize,
+
     /// Layout mode configuration
     pub(crate) config: TaffyConfig,
 }
@@ -77,7 +105,13 @@ impl LayoutTree for Taffy {
 
     #[inline(always)]
     fn style(&self, node: NodeId) -> &Style {
-        &self.nodes[node.into()].style
+        self.nodes[node.into()].computed_style()
+    }
+
+    #[inline(always)]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn transposed_style(&self, node: NodeId, writing_mode: WritingMode) -> Option<&Style> {
+        self.nodes[node.into()].transposed_style(writing_mode)
     }
 
     #[inline(always)]
@@ -136,6 +170,28 @@ impl LayoutTree for Taffy {
             vertical_margins_are_collapsible,
         )
     }
+
+    #[inline(always)]
+    fn measure_child_fragments(
+        &mut self,
+        node: NodeId,
+        available_space: Size<AvailableSpace>,
+    ) -> Option<Vec<Size<f32>>> {
+        let node_key = node.into();
+        if !self.nodes[node_key].needs_measure {
+            return None;
+        }
+        self.measure_funcs[node_key].measure_fragments(available_space)
+    }
+
+    #[inline(always)]
+    fn set_fragment_layouts(&mut self, node: NodeId, fragment_layouts: Vec<Layout>) {
+        if fragment_layouts.is_empty() {
+            self.fragment_layouts.remove(node.into());
+        } else {
+            self.fragment_layouts.insert(node.into(), fragment_layouts);
+        }
+    }
 }
 
 #[allow(clippy::iter_cloned_collect)] // due to no-std support, we need to use `iter_cloned` instead of `collect`
@@ -158,6 +214,15 @@ impl Taffy {
             children: SlotMap::with_capacity(capacity),
             parents: SlotMap::with_capacity(capacity),
             measure_funcs: SparseSecondaryMap::with_capacity(capacity),
+            fragment_layouts: SparseSecondaryMap::new(),
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            custom_layout_algorithms: Vec::new(),
+            #[cfg(all(
+                any(feature = "std", feature = "alloc"),
+                any(feature = "block_layout", feature = "flexbox", feature = "grid")
+            ))]
+            vertical_writing_mode_count: 0,
+            fixed_position_count: 0,
             config: TaffyConfig::default(),
         }
     }
@@ -172,8 +237,81 @@ impl Taffy {
         self.config.use_rounding = false;
     }
 
+    /// Registers `algorithm` as the layout algorithm of the nodes whose `display` is [`Display::Custom`] with the given
+    /// `id`, replacing any algorithm that was previously registered with it
+    ///
+    /// These nodes are laid out by `algorithm` as part of [`Taffy::compute_layout`], with the same caching, rounding
+    /// and handling of hidden nodes as the built-in layout algorithms. Any [`LayoutAlgorithm`](crate::LayoutAlgorithm)
+    /// can be registered, as can algorithms that hold state by implementing [`CustomLayoutAlgorithm`]. A node whose id
+    /// has no registered algorithm is laid out as a block container.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn register_layout_algorithm(&mut self, id: u16, algorithm: impl CustomLayoutAlgorithm + 'static) {
+        self.unregister_layout_algorithm(id);
+        self.custom_layout_algorithms.push((id, Arc::new(algorithm)));
+    }
+
+    /// Removes the layout algorithm registered for the [`Display::Custom`] `id`, if any. Returns whether an algorithm
+    /// was registered.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn unregister_layout_algorithm(&mut self, id: u16) -> bool {
+        let algorithm_count = self.custom_layout_algorithms.len();
+        self.custom_layout_algorithms.retain(|(algorithm_id, _)| *algorithm_id != id);
+
+        // The cached layouts of the nodes that used the algorithm (and of their ancestors) are no longer valid
+        let nodes: Vec<NodeId> = self
+            .nodes
+            .iter()
+            .filter(|(_, data)| data.style.display == Display::Custom(id))
+            .map(|(key, _)| key.into())
+            .collect();
+        for node in nodes {
+            let _ = self.mark_dirty(node);
+        }
+
+        self.custom_layout_algorithms.len() != algorithm_count
+    }
+
+    /// The layout algorithm registered for the [`Display::Custom`] `id`, if any. It is shared so that it can be used
+    /// while the tree is borrowed mutably to lay out the node.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub(crate) fn custom_layout_algorithm(&self, id: u16) -> Option<Arc<dyn CustomLayoutAlgorithm>> {
+        self.custom_layout_algorithms
+            .iter()
+            .find(|(algorithm_id, _)| *algorithm_id == id)
+            .map(|(_, algorithm)| Arc::clone(algorithm))
+    }
+
+    /// Updates the count of the nodes whose writing mode is vertical when the writing mode of a node changes from
+    /// `old_writing_mode` to `new_writing_mode`. Nodes that are created or removed change from or to a horizontal
+    /// writing mode.
+    fn update_vertical_writing_mode_count(&mut self, old_writing_mode: WritingMode, new_writing_mode: WritingMode) {
+        #[cfg(all(
+            any(feature = "std", feature = "alloc"),
+            any(feature = "block_layout", feature = "flexbox", feature = "grid")
+        ))]
+        {
+            self.vertical_writing_mode_count = self.vertical_writing_mode_count
+                + usize::from(new_writing_mode.is_vertical())
+                - usize::from(old_writing_mode.is_vertical());
+        }
+        #[cfg(not(all(
+            any(feature = "std", feature = "alloc"),
+            any(feature = "block_layout", feature = "flexbox", feature = "grid")
+        )))]
+        let _ = (old_writing_mode, new_writing_mode);
+    }
+
+    /// Updates the count of the nodes whose position is [`Position::Fixed`] when the position of a node changes from
+    /// `old_position` to `new_position`. Nodes that are created or removed change from or to a relative position.
+    fn update_fixed_position_count(&mut self, old_position: Position, new_position: Position) {
+        self.fixed_position_count = self.fixed_position_count + usize::from(new_position == Position::Fixed)
+            - usize::from(old_position == Position::Fixed);
+    }
+
     /// Creates and adds a new unattached leaf node to the tree, and returns the node of the new node
     pub fn new_leaf(&mut self, layout: Style) -> TaffyResult<NodeId> {
+        self.update_vertical_writing_mode_count(WritingMode::HorizontalTb, layout.writing_mode);
+        self.update_fixed_position_count(Position::Relative, layout.position);
         let id = self.nodes.insert(NodeData::new(layout));
         let _ = self.children.insert(new_vec_with_capacity(0));
         let _ = self.parents.insert(None);
@@ -185,6 +323,8 @@ impl Taffy {
     ///
     /// Creates and adds a new leaf node with a supplied [`MeasureFunc`]
     pub fn new_leaf_with_measure(&mut self, layout: Style, measure: MeasureFunc) -> TaffyResult<NodeId> {
+        self.update_vertical_writing_mode_count(WritingMode::HorizontalTb, layout.writing_mode);
+        self.update_fixed_position_count(Position::Relative, layout.position);
         let mut data = NodeData::new(layout);
         data.needs_measure = true;
 
@@ -199,6 +339,8 @@ impl Taffy {
 
     /// Creates and adds a new node, which may have any number of `children`
     pub fn new_with_children(&mut self, layout: Style, children: &[NodeId]) -> TaffyResult<NodeId> {
+        self.update_vertical_writing_mode_count(WritingMode::HorizontalTb, layout.writing_mode);
+        self.update_fixed_position_count(Position::Relative, layout.position);
         let id = NodeId::from(self.nodes.insert(NodeData::new(layout)));
 
         for child in children {
@@ -216,6 +358,15 @@ impl Taffy {
         self.nodes.clear();
         self.children.clear();
         self.parents.clear();
+        self.fragment_layouts.clear();
+        self.fixed_position_count = 0;
+        #[cfg(all(
+            any(feature = "std", feature = "alloc"),
+            any(feature = "block_layout", feature = "flexbox", feature = "grid")
+        ))]
+        {
+            self.vertical_writing_mode_count = 0;
+        }
     }
 
     /// Remove a specific node from the tree and drop it
@@ -231,7 +382,11 @@ impl Taffy {
 
         let _ = self.children.remove(key);
         let _ = self.parents.remove(key);
-        let _ = self.nodes.remove(key);
+        if let Some(node_data) = self.nodes.remove(key) {
+            self.update_vertical_writing_mode_count(node_data.style.writing_mode, WritingMode::HorizontalTb);
+            self.update_fixed_position_count(node_data.style.position, Position::Relative);
+        }
+        let _ = self.fragment_layouts.remove(key);
 
         Ok(node)
     }
@@ -376,7 +531,15 @@ impl Taffy {
 
     /// Sets the [`Style`] of the provided `node`
     pub fn set_style(&mut self, node: NodeId, style: Style) -> TaffyResult<()> {
-        self.nodes[node.into()].style = style;
+        self.update_vertical_writing_mode_count(self.nodes[node.into()].style.writing_mode, style.writing_mode);
+        self.update_fixed_position_count(self.nodes[node.into()].style.position, style.position);
+        let node_data = &mut self.nodes[node.into()];
+        node_data.style = style;
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        {
+            node_data.relative_length_context = None;
+            node_data.transposed_styles = [None, None];
+        }
         self.mark_dirty(node)?;
         Ok(())
     }
@@ -391,6 +554,15 @@ impl Taffy {
         Ok(&self.nodes[node.into()].layout)
     }
 
+    /// Gets the layouts of the fragments that a leaf node was split into when it was placed on lines, in order.
+    /// The location of each fragment is relative to the location of the node.
+    ///
+    /// This is empty unless the node has an inline display mode and a [`MeasureFunc`] that splits it into fragments, and
+    /// is cleared once the node is laid out in any other way (such as after it is moved into a flex container).
+    pub fn fragment_layouts(&self, node: NodeId) -> TaffyResult<&[Layout]> {
+        Ok(self.fragment_layouts.get(node.into()).map(|layouts| layouts.as_slice()).unwrap_or(&[]))
+    }
+
     /// Marks the layout computation of this node and its children as outdated
     ///
     /// Performs a recursive depth-first search up the tree until the root node is reached
@@ -424,6 +596,14 @@ impl Taffy {
     pub fn compute_layout(&mut self, node: NodeId, available_space: Size<AvailableSpace>) -> Result<(), TaffyError> {
         compute_layout(self, node, available_space)
     }
+
+    /// Gets the constraint that determines how the provided `node` is shifted as its nearest scroll container is
+    /// scrolled, computed from the current layout of the tree
+    ///
+    /// Returns `None` if the node does not have [`Position::Sticky`](crate::style::Position::Sticky) or has no parent.
+    pub fn sticky_constraint(&self, node: NodeId) -> TaffyResult<Option<StickyConstraint>> {
+        Ok(compute_sticky_constraint(self, node))
+    }
 }
 
 #[cfg(test)]
@@ -18,13 +18,29 @@ fn print_node(tree: &impl LayoutTree, node: NodeId, has_sibling: bool, lines_str
 
     let display = match (num_children, style.display) {
         (_, style::Display::None) => "NONE",
+        (_, style::Display::Contents) => "CONTENTS",
         (0, _) => "LEAF",
         #[cfg(feature = "block_layout")]
         (_, style::Display::Block) => "BLOCK",
+        #[cfg(feature = "block_layout")]
+        (_, style::Display::Inline) => "INLINE",
+        #[cfg(feature = "block_layout")]
+        (_, style::Display::InlineBlock) => "INLINE-BLOCK",
         #[cfg(feature = "flexbox")]
         (_, style::Display::Flex) => "FLEX",
         #[cfg(feature = "grid")]
         (_, style::Display::Grid) => "GRID",
+        #[cfg(feature = "table_layout")]
+        (_, style::Display::Table) => "TABLE",
+        #[cfg(feature = "table_layout")]
+        (_, style::Display::TableRowGroup) => "TABLE-ROW-GROUP",
+        #[cfg(feature = "table_layout")]
+        (_, style::Display::TableRow) => "TABLE-ROW",
+        #[cfg(feature = "table_layout")]
+        (_, style::Display::TableCell) => "TABLE-CELL",
+        #[cfg(feature = "stack_layout")]
+        (_, style::Display::Stack) => "STACK",
+        (_, style::Display::Custom(_)) => "CUSTOM",
     };
 
     let fork_string = if has_sibling { "├── " } else { "└── " };
@@ -31,6 +31,16 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentage {
         match self {
             LengthPercentage::Length(length) => Some(length),
             LengthPercentage::Percent(percent) => context.map(|dim| dim * percent),
+            // Font-relative and viewport-relative lengths are resolved to absolute lengths before layout
+            // (see `Style::resolve_relative_lengths`), so they can't be resolved here
+            LengthPercentage::Em(_)
+            | LengthPercentage::Rem(_)
+            | LengthPercentage::Vw(_)
+            | LengthPercentage::Vh(_)
+            | LengthPercentage::Vmin(_)
+            | LengthPercentage::Vmax(_) => None,
+            #[cfg(feature = "std")]
+            LengthPercentage::Calc(expression) => expression.resolve(context),
         }
     }
 }
@@ -42,6 +52,14 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentageAuto {
         match self {
             LengthPercentageAuto::Length(length) => Some(length),
             LengthPercentageAuto::Percent(percent) => context.map(|dim| dim * percent),
+            LengthPercentageAuto::Em(_)
+            | LengthPercentageAuto::Rem(_)
+            | LengthPercentageAuto::Vw(_)
+            | LengthPercentageAuto::Vh(_)
+            | LengthPercentageAuto::Vmin(_)
+            | LengthPercentageAuto::Vmax(_) => None,
+            #[cfg(feature = "std")]
+            LengthPercentageAuto::Calc(expression) => expression.resolve(context),
             LengthPercentageAuto::Auto => None,
         }
     }
@@ -55,7 +73,19 @@ impl MaybeResolve<Option<f32>, Option<f32>> for Dimension {
         match self {
             Dimension::Length(length) => Some(length),
             Dimension::Percent(percent) => context.map(|dim| dim * percent),
-            Dimension::Auto => None,
+            Dimension::Em(_)
+            | Dimension::Rem(_)
+            | Dimension::Vw(_)
+            | Dimension::Vh(_)
+            | Dimension::Vmin(_)
+            | Dimension::Vmax(_) => None,
+            #[cfg(feature = "std")]
+            Dimension::Calc(expression) => expression.resolve(context),
+            Dimension::Auto
+            | Dimension::MinContent
+            | Dimension::MaxContent
+            | Dimension::FitContent(_)
+            | Dimension::Stretch => None,
         }
     }
 }
@@ -159,7 +189,7 @@ mod tests {
 
     mod maybe_resolve_dimension {
         use super::mr_case;
-        use crate::style::Dimension;
+        use crate::style::{CalcExpression, Dimension, LengthPercentage};
 
         /// `Dimension::Auto` should always return `None`
         ///
@@ -172,6 +202,17 @@ mod tests {
             mr_case(Dimension::Auto, Some(0.), None);
         }
 
+        /// The intrinsic sizing keywords should always return `None`, as they're resolved by measuring the node
+        ///
+        /// The parent / context should not affect the outcome.
+        #[test]
+        fn resolve_intrinsic_keywords() {
+            mr_case(Dimension::MinContent, None, None);
+            mr_case(Dimension::MaxContent, Some(5.0), None);
+            mr_case(Dimension::FitContent(LengthPercentage::Length(1.0)), Some(5.0), None);
+            mr_case(Dimension::Stretch, Some(5.0), None);
+        }
+
         /// `Dimension::Length` should always return `Some(f32)`
         /// where the f32 value is the inner absolute length.
         ///
@@ -196,6 +237,15 @@ mod tests {
             mr_case(Dimension::Percent(1.0), Some(-5.0), Some(-5.0));
             mr_case(Dimension::Percent(1.0), Some(50.0), Some(50.0));
         }
+
+        #[test]
+        fn resolve_calc() {
+            let calc = Dimension::from(CalcExpression::Percent(0.5) - CalcExpression::Length(10.0));
+            mr_case(calc, None, None);
+            mr_case(calc, Some(100.0), Some(40.0));
+            mr_case(calc, Some(0.0), Some(-10.0));
+            mr_case(Dimension::from(CalcExpression::Length(1.0) * 2.0), None, Some(2.0));
+        }
     }
 
     mod maybe_resolve_size_dimension {
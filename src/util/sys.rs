@@ -17,6 +17,9 @@ pub(crate) use self::core::*;
 mod std {
     /// An allocation-backend agnostic [`Box`] type
     pub(crate) type Box<A> = std::boxed::Box<A>;
+    /// An allocation-backend agnostic reference-counted pointer type
+    #[cfg(any(feature = "taffy_tree", feature = "grid"))]
+    pub(crate) type Arc<A> = std::sync::Arc<A>;
     /// An allocation-backend agnostic vector type
     pub(crate) type Vec<A> = std::vec::Vec<A>;
     /// A vector of child nodes
@@ -24,6 +27,9 @@ mod std {
     #[cfg(feature = "grid")]
     /// A vector of grid tracks
     pub(crate) type GridTrackVec<A> = std::vec::Vec<A>;
+    #[cfg(feature = "grid")]
+    /// An allocation-backend agnostic string type
+    pub(crate) type String = std::string::String;
 
     /// Creates a new vector with the capacity for the specified number of items before it must be resized
     #[must_use]
@@ -31,12 +37,31 @@ mod std {
         Vec::with_capacity(capacity)
     }
 
+    /// Sorts a slice by the key extracted from each element, preserving the order of elements with equal keys
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "table_layout"))]
+    pub(crate) fn stable_sort_by_key<T, K: Ord>(slice: &mut [T], key: impl FnMut(&T) -> K) {
+        slice.sort_by_key(key)
+    }
+
+    /// Sorts a slice with a comparator function, preserving the order of elements that compare as equal
+    #[cfg(feature = "block_layout")]
+    pub(crate) fn stable_sort_by<T>(slice: &mut [T], compare: impl FnMut(&T, &T) -> std::cmp::Ordering) {
+        slice.sort_by(compare)
+    }
+
     /// Rounds to the nearest whole number
     #[must_use]
     pub(crate) fn round(value: f32) -> f32 {
         value.round()
     }
 
+    /// Rounds down to the nearest whole number
+    #[must_use]
+    #[cfg(feature = "block_layout")]
+    pub(crate) fn floor(value: f32) -> f32 {
+        value.floor()
+    }
+
     /// Computes the absolute value
     #[must_use]
     pub(crate) fn abs(value: f32) -> f32 {
@@ -62,6 +87,9 @@ mod alloc {
 
     /// An allocation-backend agnostic `Box` type
     pub(crate) type Box<A> = alloc::boxed::Box<A>;
+    /// An allocation-backend agnostic reference-counted pointer type
+    #[cfg(any(feature = "taffy_tree", feature = "grid"))]
+    pub(crate) type Arc<A> = alloc::sync::Arc<A>;
     /// An allocation-backend agnostic vector type
     pub(crate) type Vec<A> = alloc::vec::Vec<A>;
     /// A vector of child nodes
@@ -69,6 +97,9 @@ mod alloc {
     #[cfg(feature = "grid")]
     /// A vector of grid tracks
     pub(crate) type GridTrackVec<A> = alloc::vec::Vec<A>;
+    #[cfg(feature = "grid")]
+    /// An allocation-backend agnostic string type
+    pub(crate) type String = alloc::string::String;
 
     /// Creates a new vector with the capacity for the specified number of items before it must be resized
     #[must_use]
@@ -76,12 +107,31 @@ mod alloc {
         Vec::with_capacity(capacity)
     }
 
+    /// Sorts a slice by the key extracted from each element, preserving the order of elements with equal keys
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "table_layout"))]
+    pub(crate) fn stable_sort_by_key<T, K: Ord>(slice: &mut [T], key: impl FnMut(&T) -> K) {
+        slice.sort_by_key(key)
+    }
+
+    /// Sorts a slice with a comparator function, preserving the order of elements that compare as equal
+    #[cfg(feature = "block_layout")]
+    pub(crate) fn stable_sort_by<T>(slice: &mut [T], compare: impl FnMut(&T, &T) -> Ordering) {
+        slice.sort_by(compare)
+    }
+
     /// Rounds to the nearest whole number
     #[must_use]
     pub(crate) fn round(value: f32) -> f32 {
         num_traits::float::FloatCore::round(value)
     }
 
+    /// Rounds down to the nearest whole number
+    #[must_use]
+    #[cfg(feature = "block_layout")]
+    pub(crate) fn floor(value: f32) -> f32 {
+        num_traits::float::FloatCore::floor(value)
+    }
+
     /// Computes the absolute value
     #[must_use]
     pub(crate) fn abs(value: f32) -> f32 {
@@ -134,6 +184,36 @@ mod core {
         arrayvec::ArrayVec::new()
     }
 
+    /// Sorts a slice by the key extracted from each element, preserving the order of elements with equal keys
+    ///
+    /// The stable sort of the standard library requires an allocator, so this is an insertion sort. This is fast
+    /// enough for the short slices that can be stored without one.
+    #[cfg(any(feature = "flexbox", feature = "table_layout"))]
+    pub(crate) fn stable_sort_by_key<T, K: Ord>(slice: &mut [T], mut key: impl FnMut(&T) -> K) {
+        for index in 1..slice.len() {
+            let mut position = index;
+            while position > 0 && key(&slice[position - 1]) > key(&slice[position]) {
+                slice.swap(position - 1, position);
+                position -= 1;
+            }
+        }
+    }
+
+    /// Sorts a slice with a comparator function, preserving the order of elements that compare as equal
+    ///
+    /// Like [`stable_sort_by_key`], this is an insertion sort because the stable sort of the standard library requires
+    /// an allocator.
+    #[cfg(feature = "block_layout")]
+    pub(crate) fn stable_sort_by<T>(slice: &mut [T], mut compare: impl FnMut(&T, &T) -> Ordering) {
+        for index in 1..slice.len() {
+            let mut position = index;
+            while position > 0 && compare(&slice[position - 1], &slice[position]) == Ordering::Greater {
+                slice.swap(position - 1, position);
+                position -= 1;
+            }
+        }
+    }
+
     /// Rounds to the nearest whole number
     #[inline]
     #[must_use]
@@ -141,6 +221,14 @@ mod core {
         num_traits::float::FloatCore::round(value)
     }
 
+    /// Rounds down to the nearest whole number
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "block_layout")]
+    pub(crate) fn floor(value: f32) -> f32 {
+        num_traits::float::FloatCore::floor(value)
+    }
+
     /// Computes the absolute value
     #[inline]
     #[must_use]
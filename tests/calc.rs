@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod calc {
+    use taffy::geometry::{MinMax, Point};
+    use taffy::prelude::*;
+
+    fn px(value: f32) -> CalcExpression {
+        CalcExpression::from_length(value)
+    }
+
+    fn pct(value: f32) -> CalcExpression {
+        CalcExpression::from_percent(value)
+    }
+
+    #[test]
+    fn calc_sizes_and_spacing_resolve_against_the_parent() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                size: Size { width: (pct(1.0) - px(48.0)).into(), height: (px(10.0) * 2.0).into() },
+                margin: Rect { left: (pct(0.1) + px(4.0)).into(), right: auto(), top: zero(), bottom: zero() },
+                padding: Rect { left: (pct(0.05) / 2.0).into(), right: zero(), top: zero(), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(200.0), height: auto() },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 200.0, height: 20.0 });
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 24.0, y: 0.0 });
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 152.0, height: 20.0 });
+    }
+
+    #[test]
+    fn clamp_flex_basis() {
+        for (container_width, expected_width) in [(300.0, 200.0), (1000.0, 500.0), (2000.0, 600.0)] {
+            let mut taffy = Taffy::new();
+            let child = taffy
+                .new_leaf(Style {
+                    flex_basis: CalcExpression::clamp(px(200.0), pct(0.5), px(600.0)).into(),
+                    flex_shrink: 0.0,
+                    size: Size { width: auto(), height: length(10.0) },
+                    ..Default::default()
+                })
+                .unwrap();
+            let root = taffy
+                .new_with_children(
+                    Style { size: Size { width: length(container_width), height: auto() }, ..Default::default() },
+                    &[child],
+                )
+                .unwrap();
+            taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+            assert_eq!(taffy.layout(child).unwrap().location, Point { x: 0.0, y: 0.0 });
+            assert_eq!(taffy.layout(child).unwrap().size, Size { width: expected_width, height: 10.0 });
+        }
+    }
+
+    #[test]
+    fn percentages_in_calc_are_unresolvable_without_a_definite_basis() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size {
+                    width: CalcExpression::min([pct(0.5), px(30.0)]).into(),
+                    height: CalcExpression::max([px(10.0), px(20.0)]).into(),
+                },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The width is treated as auto as the container's width is indefinite
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 0.0, height: 20.0 });
+    }
+
+    #[test]
+    fn calc_grid_tracks_and_gaps() {
+        let mut taffy = Taffy::new();
+        let fixed_track = |expression: CalcExpression| {
+            let size = LengthPercentage::from(expression);
+            TrackSizingFunction::Single(MinMax {
+                min: MinTrackSizingFunction::Fixed(size),
+                max: MaxTrackSizingFunction::Fixed(size),
+            })
+        };
+        let children: Vec<_> = (0..3).map(|_| taffy.new_leaf(Style::default()).unwrap()).collect();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    size: Size { width: length(200.0), height: length(100.0) },
+                    grid_template_columns: vec![
+                        fixed_track(pct(0.5) - px(10.0)),
+                        fr(1.0),
+                        fit_content((px(30.0) - px(10.0)).into()),
+                    ],
+                    gap: Size { width: (pct(0.05)).into(), height: zero() },
+                    ..Default::default()
+                },
+                &children,
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The fixed 90px column and the fit-content column (which is as wide as its empty item) are separated from the
+        // flexible column by 10px gaps
+        assert_eq!(taffy.layout(children[0]).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(children[0]).unwrap().size, Size { width: 90.0, height: 100.0 });
+        assert_eq!(taffy.layout(children[1]).unwrap().location, Point { x: 100.0, y: 0.0 });
+        assert_eq!(taffy.layout(children[1]).unwrap().size, Size { width: 90.0, height: 100.0 });
+        assert_eq!(taffy.layout(children[2]).unwrap().location, Point { x: 200.0, y: 0.0 });
+        assert_eq!(taffy.layout(children[2]).unwrap().size, Size { width: 0.0, height: 100.0 });
+    }
+}
@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod content_size {
+    use taffy::geometry::Point;
+    use taffy::prelude::*;
+    use taffy::style::Overflow;
+
+    fn tall_block(taffy: &mut Taffy, overflow: Overflow) -> NodeId {
+        let contents = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                size: Size { width: auto(), height: length(200.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    overflow: Point { x: overflow, y: overflow },
+                    size: Size { width: auto(), height: length(20.0) },
+                    ..Default::default()
+                },
+                &[contents],
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn scroll_container_includes_margin_box_and_end_padding() {
+        for display in [Display::Flex, Display::Grid, Display::Block] {
+            let mut taffy = Taffy::new();
+            let child = taffy
+                .new_leaf(Style {
+                    flex_shrink: 0.0,
+                    size: Size { width: length(50.0), height: length(300.0) },
+                    margin: Rect { left: zero(), right: zero(), top: zero(), bottom: length(5.0) },
+                    ..Default::default()
+                })
+                .unwrap();
+            let container = taffy
+                .new_with_children(
+                    Style {
+                        display,
+                        overflow: Point { x: Overflow::Visible, y: Overflow::Scroll },
+                        scrollbar_width: 0.0,
+                        size: Size { width: length(100.0), height: length(100.0) },
+                        padding: length(10.0),
+                        ..Default::default()
+                    },
+                    &[child],
+                )
+                .unwrap();
+
+            taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+            let layout = taffy.layout(container).unwrap();
+            assert_eq!(layout.size, Size { width: 100.0, height: 100.0 }, "{display:?}");
+            assert_eq!(layout.content_size, Size { width: 100.0, height: 325.0 }, "{display:?}");
+            assert_eq!(taffy.layout(child).unwrap().content_size, Size::ZERO, "{display:?}");
+        }
+    }
+
+    #[test]
+    fn rtl_scroll_container_includes_overflow_toward_the_inline_start() {
+        for display in [Display::Flex, Display::Grid, Display::Block] {
+            let mut taffy = Taffy::new();
+            let child = taffy
+                .new_leaf(Style {
+                    flex_shrink: 0.0,
+                    size: Size { width: length(300.0), height: length(50.0) },
+                    ..Default::default()
+                })
+                .unwrap();
+            let container = taffy
+                .new_with_children(
+                    Style {
+                        display,
+                        direction: Direction::Rtl,
+                        overflow: Point { x: Overflow::Scroll, y: Overflow::Visible },
+                        scrollbar_width: 0.0,
+                        size: Size { width: length(100.0), height: length(100.0) },
+                        padding: length(4.0),
+                        ..Default::default()
+                    },
+                    &[child],
+                )
+                .unwrap();
+
+            taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+            // The child overflows the left edge of the container, and the scrollable overflow includes the end padding
+            assert_eq!(taffy.layout(child).unwrap().location.x, -204.0, "{display:?}");
+            assert_eq!(
+                taffy.layout(container).unwrap().content_size,
+                Size { width: 308.0, height: 100.0 },
+                "{display:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn overflow_propagates_through_children_that_do_not_clip() {
+        let mut taffy = Taffy::new();
+        let visible = tall_block(&mut taffy, Overflow::Visible);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(100.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[visible],
+            )
+            .unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(visible).unwrap().content_size, Size { width: 100.0, height: 200.0 });
+        assert_eq!(taffy.layout(root).unwrap().content_size, Size { width: 100.0, height: 200.0 });
+    }
+
+    #[test]
+    fn overflow_does_not_propagate_through_scroll_containers() {
+        let mut taffy = Taffy::new();
+        let hidden = tall_block(&mut taffy, Overflow::Hidden);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(100.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[hidden],
+            )
+            .unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(hidden).unwrap().content_size, Size { width: 100.0, height: 200.0 });
+        assert_eq!(taffy.layout(root).unwrap().content_size, Size { width: 100.0, height: 100.0 });
+    }
+
+    #[test]
+    fn clipped_overflow_does_not_propagate() {
+        let mut taffy = Taffy::new();
+        let clipped = tall_block(&mut taffy, Overflow::Clip);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(100.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[clipped],
+            )
+            .unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(clipped).unwrap().content_size, Size { width: 100.0, height: 200.0 });
+        assert_eq!(taffy.layout(root).unwrap().content_size, Size { width: 100.0, height: 100.0 });
+    }
+}
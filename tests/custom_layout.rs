@@ -0,0 +1,280 @@
+#[cfg(test)]
+mod custom_layout {
+    use taffy::geometry::Point;
+    use taffy::prelude::*;
+    use taffy::tree::{SizeBaselinesAndMargins, SizingMode};
+    use taffy::{CustomLayoutAlgorithm, LayoutAlgorithm};
+
+    const DIAGONAL: u16 = 1;
+
+    /// Places each child (at its max-content size) below and to the right of the previous one
+    struct DiagonalAlgorithm;
+    impl LayoutAlgorithm for DiagonalAlgorithm {
+        const NAME: &'static str = "DIAGONAL";
+
+        fn perform_layout(
+            tree: &mut impl LayoutTree,
+            node: NodeId,
+            known_dimensions: Size<Option<f32>>,
+            _parent_size: Size<Option<f32>>,
+            _available_space: Size<AvailableSpace>,
+            _sizing_mode: SizingMode,
+            _vertical_margins_are_collapsible: Line<bool>,
+        ) -> SizeBaselinesAndMargins {
+            let mut location = Point { x: 0.0, y: 0.0 };
+            for order in 0..tree.child_count(node) {
+                let child = tree.child(node, order);
+                let output = tree.perform_child_layout(
+                    child,
+                    Size::NONE,
+                    Size::NONE,
+                    Size::MAX_CONTENT,
+                    SizingMode::InherentSize,
+                    Line::FALSE,
+                );
+                *tree.layout_mut(child) = Layout {
+                    order: order as u32,
+                    size: output.size,
+                    location,
+                    is_visible: true,
+                    content_size: output.content_size,
+                };
+                location = Point { x: location.x + output.size.width, y: location.y + output.size.height };
+            }
+            known_dimensions.unwrap_or(Size { width: location.x, height: location.y }).into()
+        }
+
+        fn measure_size(
+            tree: &mut impl LayoutTree,
+            node: NodeId,
+            known_dimensions: Size<Option<f32>>,
+            _parent_size: Size<Option<f32>>,
+            _available_space: Size<AvailableSpace>,
+            _sizing_mode: SizingMode,
+            _vertical_margins_are_collapsible: Line<bool>,
+        ) -> Size<f32> {
+            let mut size = Size::ZERO;
+            for order in 0..tree.child_count(node) {
+                let child = tree.child(node, order);
+                let child_size = tree.measure_child_size(
+                    child,
+                    Size::NONE,
+                    Size::NONE,
+                    Size::MAX_CONTENT,
+                    SizingMode::InherentSize,
+                    Line::FALSE,
+                );
+                size = Size { width: size.width + child_size.width, height: size.height + child_size.height };
+            }
+            known_dimensions.unwrap_or(size)
+        }
+    }
+
+    #[test]
+    fn registered_algorithm_lays_out_custom_nodes() {
+        let mut taffy = Taffy::new();
+        taffy.register_layout_algorithm(DIAGONAL, DiagonalAlgorithm);
+        let first = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 20.0), ..Default::default() }).unwrap();
+        let second = taffy.new_leaf(Style { size: Size::from_lengths(30.0, 40.0), ..Default::default() }).unwrap();
+        let diagonal = taffy
+            .new_with_children(Style { display: Display::Custom(DIAGONAL), ..Default::default() }, &[first, second])
+            .unwrap();
+        let sibling = taffy.new_leaf(Style { size: Size::from_lengths(50.0, 10.0), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(Style { display: Display::Flex, ..Default::default() }, &[diagonal, sibling])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The custom node is sized by the algorithm and placed by its flexbox parent like any other item
+        assert_eq!(taffy.layout(diagonal).unwrap().size, Size { width: 40.0, height: 60.0 });
+        assert_eq!(taffy.layout(sibling).unwrap().location, Point { x: 40.0, y: 0.0 });
+        assert_eq!(taffy.layout(first).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 10.0, y: 20.0 });
+    }
+
+    #[test]
+    fn custom_layouts_are_rounded() {
+        let mut taffy = Taffy::new();
+        taffy.register_layout_algorithm(DIAGONAL, DiagonalAlgorithm);
+        let first = taffy.new_leaf(Style { size: Size::from_lengths(10.4, 10.4), ..Default::default() }).unwrap();
+        let second = taffy.new_leaf(Style { size: Size::from_lengths(10.4, 10.4), ..Default::default() }).unwrap();
+        let diagonal = taffy
+            .new_with_children(Style { display: Display::Custom(DIAGONAL), ..Default::default() }, &[first, second])
+            .unwrap();
+        taffy.compute_layout(diagonal, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 10.0, y: 10.0 });
+        assert_eq!(taffy.layout(second).unwrap().size, Size { width: 11.0, height: 11.0 });
+    }
+
+    #[test]
+    fn custom_nodes_are_cached() {
+        let mut taffy = Taffy::new();
+        taffy.register_layout_algorithm(DIAGONAL, DiagonalAlgorithm);
+        let child = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let diagonal = taffy
+            .new_with_children(Style { display: Display::Custom(DIAGONAL), ..Default::default() }, &[child])
+            .unwrap();
+        taffy.compute_layout(diagonal, Size::MAX_CONTENT).unwrap();
+        assert!(!taffy.dirty(diagonal).unwrap());
+
+        taffy.set_style(child, Style { size: Size::from_lengths(20.0, 20.0), ..Default::default() }).unwrap();
+        assert!(taffy.dirty(diagonal).unwrap());
+        taffy.compute_layout(diagonal, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(diagonal).unwrap().size, Size { width: 20.0, height: 20.0 });
+    }
+
+    #[test]
+    fn hidden_custom_nodes_are_not_laid_out() {
+        let mut taffy = Taffy::new();
+        taffy.register_layout_algorithm(DIAGONAL, DiagonalAlgorithm);
+        let child = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let diagonal = taffy
+            .new_with_children(Style { display: Display::Custom(DIAGONAL), ..Default::default() }, &[child])
+            .unwrap();
+        let root =
+            taffy.new_with_children(Style { display: Display::None, ..Default::default() }, &[diagonal]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(diagonal).unwrap().size, Size::ZERO);
+        assert_eq!(taffy.layout(child).unwrap().size, Size::ZERO);
+    }
+
+    #[test]
+    fn unregistered_custom_nodes_are_laid_out_as_blocks() {
+        let mut taffy = Taffy::new();
+        let first = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let second = taffy.new_leaf(Style { size: Size::from_lengths(20.0, 10.0), ..Default::default() }).unwrap();
+        let diagonal = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Custom(DIAGONAL),
+                    size: Size { width: length(30.0), height: auto() },
+                    ..Default::default()
+                },
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(diagonal, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(diagonal).unwrap().size, Size { width: 30.0, height: 20.0 });
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 10.0 });
+
+        // Registering and unregistering the algorithm invalidates the cached layout of the node
+        taffy.register_layout_algorithm(DIAGONAL, DiagonalAlgorithm);
+        assert!(taffy.dirty(diagonal).unwrap());
+        taffy.compute_layout(diagonal, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 10.0, y: 10.0 });
+
+        assert!(taffy.unregister_layout_algorithm(DIAGONAL));
+        assert!(taffy.dirty(diagonal).unwrap());
+        taffy.compute_layout(diagonal, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(diagonal).unwrap().size, Size { width: 30.0, height: 20.0 });
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 10.0 });
+        assert!(!taffy.unregister_layout_algorithm(DIAGONAL));
+    }
+
+    /// Places the children (at their max-content size) in a row, separated by a configurable gap
+    struct RowAlgorithm {
+        gap: f32,
+    }
+    impl RowAlgorithm {
+        /// Lays out the children of `node`, returning its size
+        fn lay_out_row(
+            &self,
+            tree: &mut impl LayoutTree,
+            node: NodeId,
+            known_dimensions: Size<Option<f32>>,
+        ) -> Size<f32> {
+            let mut size = Size::ZERO;
+            for order in 0..tree.child_count(node) {
+                let child = tree.child(node, order);
+                let output = tree.perform_child_layout(
+                    child,
+                    Size::NONE,
+                    Size::NONE,
+                    Size::MAX_CONTENT,
+                    SizingMode::InherentSize,
+                    Line::FALSE,
+                );
+                let x = if order == 0 { 0.0 } else { size.width + self.gap };
+                *tree.layout_mut(child) = Layout {
+                    order: order as u32,
+                    size: output.size,
+                    location: Point { x, y: 0.0 },
+                    is_visible: true,
+                    content_size: output.content_size,
+                };
+                size = Size { width: x + output.size.width, height: size.height.max(output.size.height) };
+            }
+            known_dimensions.unwrap_or(size)
+        }
+    }
+    impl CustomLayoutAlgorithm for RowAlgorithm {
+        fn perform_layout(
+            &self,
+            tree: &mut Taffy,
+            node: NodeId,
+            known_dimensions: Size<Option<f32>>,
+            _parent_size: Size<Option<f32>>,
+            _available_space: Size<AvailableSpace>,
+            _sizing_mode: SizingMode,
+            _vertical_margins_are_collapsible: Line<bool>,
+        ) -> SizeBaselinesAndMargins {
+            self.lay_out_row(tree, node, known_dimensions).into()
+        }
+
+        fn measure_size(
+            &self,
+            tree: &mut Taffy,
+            node: NodeId,
+            known_dimensions: Size<Option<f32>>,
+            _parent_size: Size<Option<f32>>,
+            _available_space: Size<AvailableSpace>,
+            _sizing_mode: SizingMode,
+            _vertical_margins_are_collapsible: Line<bool>,
+        ) -> Size<f32> {
+            self.lay_out_row(tree, node, known_dimensions)
+        }
+    }
+
+    #[test]
+    fn registered_algorithms_can_hold_state() {
+        const NARROW_ROW: u16 = 2;
+        const WIDE_ROW: u16 = 3;
+
+        let mut taffy = Taffy::new();
+        taffy.register_layout_algorithm(NARROW_ROW, RowAlgorithm { gap: 5.0 });
+        taffy.register_layout_algorithm(WIDE_ROW, RowAlgorithm { gap: 20.0 });
+        let narrow_children = [
+            taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap(),
+            taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap(),
+        ];
+        let wide_children = [
+            taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap(),
+            taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap(),
+        ];
+        let narrow = taffy
+            .new_with_children(Style { display: Display::Custom(NARROW_ROW), ..Default::default() }, &narrow_children)
+            .unwrap();
+        let wide = taffy
+            .new_with_children(Style { display: Display::Custom(WIDE_ROW), ..Default::default() }, &wide_children)
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    align_items: Some(AlignItems::Start),
+                    ..Default::default()
+                },
+                &[narrow, wide],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(narrow).unwrap().size, Size { width: 25.0, height: 10.0 });
+        assert_eq!(taffy.layout(narrow_children[1]).unwrap().location, Point { x: 15.0, y: 0.0 });
+        assert_eq!(taffy.layout(wide).unwrap().size, Size { width: 40.0, height: 10.0 });
+        assert_eq!(taffy.layout(wide_children[1]).unwrap().location, Point { x: 30.0, y: 0.0 });
+    }
+}
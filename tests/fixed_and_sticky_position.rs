@@ -0,0 +1,317 @@
+#[cfg(test)]
+mod fixed_and_sticky_position {
+    use taffy::geometry::Point;
+    use taffy::prelude::*;
+    use taffy::style::Overflow;
+
+    fn sized(width: f32, height: f32) -> Size<Dimension> {
+        Size { width: length(width), height: length(height) }
+    }
+
+    const VIEWPORT: Size<AvailableSpace> =
+        Size { width: AvailableSpace::Definite(400.0), height: AvailableSpace::Definite(300.0) };
+
+    #[test]
+    fn fixed_is_positioned_against_the_viewport() {
+        let mut taffy = Taffy::new();
+        let top_left = taffy
+            .new_leaf(Style {
+                position: Position::Fixed,
+                inset: Rect { left: length(10.0), right: auto(), top: length(5.0), bottom: auto() },
+                size: sized(50.0, 40.0),
+                ..Default::default()
+            })
+            .unwrap();
+        let bottom_right = taffy
+            .new_leaf(Style {
+                position: Position::Fixed,
+                inset: Rect { left: auto(), right: length(10.0), top: auto(), bottom: length(10.0) },
+                size: sized(30.0, 30.0),
+                ..Default::default()
+            })
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                Style { display: Display::Block, size: sized(100.0, 100.0), ..Default::default() },
+                &[top_left, bottom_right],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Block, padding: length(20.0), ..Default::default() },
+                &[container],
+            )
+            .unwrap();
+        taffy.compute_layout(root, VIEWPORT).unwrap();
+
+        assert_eq!(taffy.layout(container).unwrap().location, Point { x: 20.0, y: 20.0 });
+        assert_eq!(taffy.layout(container).unwrap().size, Size { width: 100.0, height: 100.0 });
+        assert_eq!(taffy.layout(top_left).unwrap().location, Point { x: -10.0, y: -15.0 });
+        assert_eq!(taffy.layout(top_left).unwrap().size, Size { width: 50.0, height: 40.0 });
+        assert_eq!(taffy.layout(bottom_right).unwrap().location, Point { x: 340.0, y: 240.0 });
+        assert_eq!(taffy.layout(bottom_right).unwrap().size, Size { width: 30.0, height: 30.0 });
+    }
+
+    #[test]
+    fn fixed_is_laid_out_after_a_node_is_made_fixed() {
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(Style { size: sized(50.0, 40.0), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(Style { display: Display::Block, padding: length(20.0), ..Default::default() }, &[child])
+            .unwrap();
+        taffy.compute_layout(root, VIEWPORT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 20.0, y: 20.0 });
+
+        let fixed_style = Style {
+            position: Position::Fixed,
+            inset: Rect { left: auto(), right: length(10.0), top: auto(), bottom: length(10.0) },
+            ..taffy.style(child).unwrap().clone()
+        };
+        taffy.set_style(child, fixed_style).unwrap();
+        taffy.compute_layout(root, VIEWPORT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 340.0, y: 250.0 });
+    }
+
+    #[test]
+    fn fixed_is_sized_between_its_insets() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                size: Size { width: auto(), height: length(10.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let fixed = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    position: Position::Fixed,
+                    inset: Rect { left: length(10.0), right: length(20.0), top: zero(), bottom: zero() },
+                    margin: Rect { left: zero(), right: zero(), top: length(5.0), bottom: auto() },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Block, size: sized(100.0, 100.0), ..Default::default() },
+                &[fixed],
+            )
+            .unwrap();
+        taffy.compute_layout(root, VIEWPORT).unwrap();
+
+        assert_eq!(taffy.layout(fixed).unwrap().location, Point { x: 10.0, y: 5.0 });
+        assert_eq!(taffy.layout(fixed).unwrap().size, Size { width: 370.0, height: 295.0 });
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 370.0, height: 10.0 });
+    }
+
+    #[test]
+    fn fixed_with_auto_insets_is_placed_at_the_start_of_its_parent() {
+        let mut taffy = Taffy::new();
+        let fixed_style = Style {
+            position: Position::Fixed,
+            size: sized(20.0, 20.0),
+            margin: Rect { left: length(3.0), right: length(4.0), top: zero(), bottom: zero() },
+            ..Default::default()
+        };
+        let sibling =
+            taffy.new_leaf(Style { display: Display::Block, size: sized(50.0, 50.0), ..Default::default() }).unwrap();
+        let fixed = taffy.new_leaf(fixed_style.clone()).unwrap();
+        let container = taffy
+            .new_with_children(
+                Style { display: Display::Block, padding: length(5.0), ..Default::default() },
+                &[sibling, fixed],
+            )
+            .unwrap();
+        let rtl_fixed = taffy.new_leaf(fixed_style).unwrap();
+        let rtl_container = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    direction: Direction::Rtl,
+                    padding: length(5.0),
+                    ..Default::default()
+                },
+                &[rtl_fixed],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(100.0), height: auto() },
+                    ..Default::default()
+                },
+                &[container, rtl_container],
+            )
+            .unwrap();
+        taffy.compute_layout(root, VIEWPORT).unwrap();
+
+        // Fixed nodes don't take up space in their parent
+        assert_eq!(taffy.layout(container).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(container).unwrap().size, Size { width: 100.0, height: 60.0 });
+        assert_eq!(taffy.layout(fixed).unwrap().location, Point { x: 8.0, y: 5.0 });
+        assert_eq!(taffy.layout(fixed).unwrap().size, Size { width: 20.0, height: 20.0 });
+        assert_eq!(taffy.layout(rtl_container).unwrap().location, Point { x: 0.0, y: 60.0 });
+        assert_eq!(taffy.layout(rtl_container).unwrap().size, Size { width: 100.0, height: 10.0 });
+        assert_eq!(taffy.layout(rtl_fixed).unwrap().location, Point { x: 71.0, y: 5.0 });
+        assert_eq!(taffy.layout(rtl_fixed).unwrap().size, Size { width: 20.0, height: 20.0 });
+    }
+
+    #[test]
+    fn fixed_with_auto_insets_resolves_percentage_padding_of_its_parent_against_its_containing_block() {
+        let mut taffy = Taffy::new();
+        let fixed =
+            taffy.new_leaf(Style { position: Position::Fixed, size: sized(20.0, 20.0), ..Default::default() }).unwrap();
+        let container = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(100.0), height: auto() },
+                    padding: percent(0.1),
+                    ..Default::default()
+                },
+                &[fixed],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Block, size: sized(200.0, 200.0), ..Default::default() },
+                &[container],
+            )
+            .unwrap();
+        taffy.compute_layout(root, VIEWPORT).unwrap();
+
+        // The padding of the container is 10% of the width of the root, not of the container itself
+        assert_eq!(taffy.layout(container).unwrap().size, Size { width: 100.0, height: 40.0 });
+        assert_eq!(taffy.layout(fixed).unwrap().location, Point { x: 20.0, y: 20.0 });
+    }
+
+    #[test]
+    fn fixed_uses_the_root_size_when_the_available_space_is_indefinite() {
+        let mut taffy = Taffy::new();
+        let fixed = taffy
+            .new_leaf(Style {
+                position: Position::Fixed,
+                inset: Rect { left: auto(), right: zero(), top: auto(), bottom: zero() },
+                size: Size { width: length(10.0), height: percent(0.1) },
+                ..Default::default()
+            })
+            .unwrap();
+        let root =
+            taffy.new_with_children(Style { size: sized(200.0, 150.0), ..Default::default() }, &[fixed]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(fixed).unwrap().location, Point { x: 190.0, y: 135.0 });
+        assert_eq!(taffy.layout(fixed).unwrap().size, Size { width: 10.0, height: 15.0 });
+    }
+
+    /// A scroll container whose second child is a section containing a sticky header (or footer, if `sticky_is_last`)
+    fn sticky_tree(taffy: &mut Taffy, inset: Rect<LengthPercentageAuto>, sticky_is_last: bool) -> (NodeId, NodeId) {
+        let spacer = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                size: Size { width: auto(), height: length(50.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let sticky = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                position: Position::Sticky,
+                inset,
+                size: Size { width: auto(), height: length(20.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let content = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                size: Size { width: auto(), height: length(100.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let section = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: auto(), height: length(200.0) },
+                    ..Default::default()
+                },
+                &(if sticky_is_last { [content, sticky] } else { [sticky, content] }),
+            )
+            .unwrap();
+        let trailer = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                size: Size { width: auto(), height: length(300.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: sized(100.0, 100.0),
+                    overflow: Point { x: Overflow::Visible, y: Overflow::Scroll },
+                    scrollbar_width: 0.0,
+                    ..Default::default()
+                },
+                &[spacer, section, trailer],
+            )
+            .unwrap();
+        (root, sticky)
+    }
+
+    #[test]
+    fn sticky_is_not_offset_by_layout() {
+        let mut taffy = Taffy::new();
+        let inset = Rect { left: length(10.0), right: auto(), top: length(10.0), bottom: auto() };
+        let (root, sticky) = sticky_tree(&mut taffy, inset, false);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(sticky).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(sticky).unwrap().size, Size { width: 100.0, height: 20.0 });
+        assert_eq!(taffy.sticky_constraint(root).unwrap(), None);
+    }
+
+    #[test]
+    fn sticky_top_sticks_to_the_scrollport_within_its_containing_block() {
+        let mut taffy = Taffy::new();
+        let (root, sticky) =
+            sticky_tree(&mut taffy, Rect { left: auto(), right: auto(), top: zero(), bottom: auto() }, false);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let constraint = taffy.sticky_constraint(sticky).unwrap().unwrap();
+        assert_eq!(constraint.scroll_container, root);
+        assert_eq!(constraint.sticky_view_rect, Rect { left: None, right: None, top: Some(0.0), bottom: None });
+        assert_eq!(constraint.containing_block, Rect { left: 0.0, right: 100.0, top: 50.0, bottom: 250.0 });
+        assert_eq!(constraint.border_box, Rect { left: 0.0, right: 100.0, top: 50.0, bottom: 70.0 });
+
+        assert_eq!(constraint.location_for_scroll_offset(Point { x: 0.0, y: 0.0 }), Point { x: 0.0, y: 0.0 });
+        assert_eq!(constraint.location_for_scroll_offset(Point { x: 0.0, y: 100.0 }), Point { x: 0.0, y: 50.0 });
+        // The sticky node stays within its containing block
+        assert_eq!(constraint.location_for_scroll_offset(Point { x: 0.0, y: 300.0 }), Point { x: 0.0, y: 180.0 });
+    }
+
+    #[test]
+    fn sticky_bottom_sticks_to_the_scrollport_within_its_containing_block() {
+        let mut taffy = Taffy::new();
+        let (root, sticky) =
+            sticky_tree(&mut taffy, Rect { left: auto(), right: auto(), top: auto(), bottom: length(10.0) }, true);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let constraint = taffy.sticky_constraint(sticky).unwrap().unwrap();
+        assert_eq!(constraint.sticky_view_rect, Rect { left: None, right: None, top: None, bottom: Some(90.0) });
+
+        assert_eq!(constraint.border_box, Rect { left: 0.0, right: 100.0, top: 150.0, bottom: 170.0 });
+
+        // The bottom of the node is kept 10 above the bottom of the scrollport, but not above its containing block
+        assert_eq!(constraint.location_for_scroll_offset(Point { x: 0.0, y: 100.0 }), Point { x: 0.0, y: 100.0 });
+        assert_eq!(constraint.location_for_scroll_offset(Point { x: 0.0, y: 0.0 }), Point { x: 0.0, y: 20.0 });
+        assert_eq!(constraint.location_for_scroll_offset(Point { x: 0.0, y: -100.0 }), Point { x: 0.0, y: 0.0 });
+    }
+}
@@ -6,6 +6,7 @@ fn grid_aspect_ratio_fill_child_max_height() {
     let node0 = taffy
         .new_leaf_with_measure(
             taffy::style::Style {
+                writing_mode: taffy::style::WritingMode::VerticalLr,
                 max_size: taffy::geometry::Size { width: taffy::style::Dimension::Length(40f32), height: auto() },
                 aspect_ratio: Some(2f32),
                 ..Default::default()
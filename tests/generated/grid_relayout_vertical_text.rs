@@ -5,7 +5,7 @@ fn grid_relayout_vertical_text() {
     let mut taffy = taffy::Taffy::new();
     let node0 = taffy
         .new_leaf_with_measure(
-            taffy::style::Style { ..Default::default() },
+            taffy::style::Style { writing_mode: taffy::style::WritingMode::VerticalLr, ..Default::default() },
             taffy::tree::MeasureFunc::Raw(|known_dimensions, available_space| {
                 const TEXT: &str = "HH\u{200b}HH\u{200b}HH\u{200b}HH\u{200b}HH\u{200b}HH\u{200b}HH";
                 super::measure_standard_text(
@@ -5,7 +5,11 @@ fn intrinsic_sizing_main_size_column_wrap() {
     let mut taffy = taffy::Taffy::new();
     let node0 = taffy
         .new_leaf_with_measure(
-            taffy::style::Style { flex_direction: taffy::style::FlexDirection::Column, ..Default::default() },
+            taffy::style::Style {
+                writing_mode: taffy::style::WritingMode::VerticalLr,
+                flex_direction: taffy::style::FlexDirection::Column,
+                ..Default::default()
+            },
             taffy::tree::MeasureFunc::Raw(|known_dimensions, available_space| {
                 const TEXT: &str = "HH\u{200b}HH";
                 super::measure_standard_text(
@@ -20,7 +24,11 @@ fn intrinsic_sizing_main_size_column_wrap() {
         .unwrap();
     let node1 = taffy
         .new_leaf_with_measure(
-            taffy::style::Style { flex_direction: taffy::style::FlexDirection::Column, ..Default::default() },
+            taffy::style::Style {
+                writing_mode: taffy::style::WritingMode::VerticalLr,
+                flex_direction: taffy::style::FlexDirection::Column,
+                ..Default::default()
+            },
             taffy::tree::MeasureFunc::Raw(|known_dimensions, available_space| {
                 const TEXT: &str = "HH\u{200b}HH";
                 super::measure_standard_text(
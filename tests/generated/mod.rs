@@ -106,6 +106,7 @@ mod absolute_minmax_top_left_bottom_right_max;
 mod absolute_minmax_top_left_bottom_right_min_max;
 mod absolute_padding_border_overrides_max_size;
 mod absolute_padding_border_overrides_size;
+mod absolute_width_max_content;
 mod align_baseline;
 mod align_baseline_child;
 mod align_baseline_child_margin;
@@ -266,6 +267,9 @@ mod block_align_baseline_child_padding;
 mod block_align_baseline_child_top;
 mod block_align_baseline_child_top2;
 mod block_align_baseline_double_nested_child;
+mod block_align_content_center;
+mod block_align_content_end_contains_child_margins;
+mod block_align_content_space_around_overflow;
 mod block_aspect_ratio_fill_height;
 mod block_aspect_ratio_fill_max_height;
 mod block_aspect_ratio_fill_max_width;
@@ -282,9 +286,20 @@ mod block_display_none_with_child;
 mod block_display_none_with_inset;
 mod block_display_none_with_margin;
 mod block_display_none_with_position_absolute;
+mod block_float_block_formatting_context_beside_float;
+mod block_float_clear;
+mod block_float_clear_float;
+mod block_float_in_nested_block_wraps_following_lines;
+mod block_float_left_and_right;
+mod block_float_margin;
+mod block_float_placed_below_when_no_room;
+mod block_float_rtl;
+mod block_float_wraps_lines_of_nested_block;
 mod block_inset_fixed;
 mod block_inset_percentage;
 mod block_intrinsic_width;
+mod block_justify_items_center;
+mod block_justify_self_start_rtl;
 mod block_margin_auto_bottom;
 mod block_margin_auto_bottom_and_top;
 mod block_margin_auto_left;
@@ -339,6 +354,7 @@ mod block_margin_y_first_child_collapse_negative_equal;
 mod block_margin_y_first_child_collapse_negative_parent_larger;
 mod block_margin_y_first_child_collapse_negative_parent_smaller;
 mod block_margin_y_first_child_collapse_not_blocked_by_border_bottom;
+mod block_margin_y_first_child_collapse_not_blocked_by_overflow_y_clip;
 mod block_margin_y_first_child_collapse_not_blocked_by_padding_bottom;
 mod block_margin_y_first_child_collapse_positive_and_negative;
 mod block_margin_y_first_child_collapse_positive_equal;
@@ -376,6 +392,13 @@ mod block_margin_y_simple_positive_percentage_other;
 mod block_margin_y_simple_positive_percentage_self;
 mod block_margin_y_total_collapse;
 mod block_margin_y_total_collapse_complex;
+mod block_multicol_balances_unequal_heights;
+mod block_multicol_column_count;
+mod block_multicol_column_fill_auto;
+mod block_multicol_column_width;
+mod block_multicol_content_based_width;
+mod block_multicol_overflowing_columns;
+mod block_multicol_rtl;
 mod block_overflow_scrollbars_overriden_by_available_space;
 mod block_overflow_scrollbars_overriden_by_max_size;
 mod block_overflow_scrollbars_overriden_by_size;
@@ -393,6 +416,12 @@ mod block_padding_fixed_size;
 mod block_padding_intrinsic_size;
 mod block_padding_percentage_fixed_size;
 mod block_padding_percentage_intrinsic_size;
+mod block_scrollbar_gutter_stable;
+#[cfg(feature = "table_layout")]
+mod block_table_shrinks_to_fit;
+mod block_width_fit_content;
+mod block_width_max_content;
+mod block_width_min_content;
 mod blockflex_block_in_flex_column;
 mod blockflex_block_in_flex_row;
 mod blockflex_flex_in_block;
@@ -420,9 +449,25 @@ mod border_flex_child;
 mod border_no_child;
 mod border_no_size;
 mod border_stretch_child;
+mod box_sizing_content_box_absolute_aspect_ratio;
+mod box_sizing_content_box_aspect_ratio;
+mod box_sizing_content_box_block;
+mod box_sizing_content_box_flex;
+mod box_sizing_content_box_grid;
 mod child_min_max_width_flexing;
 mod child_with_padding_align_end;
 mod container_with_unsized_child;
+mod direction_rtl_block;
+mod direction_rtl_block_overflow_scroll;
+mod direction_rtl_flex_absolute;
+mod direction_rtl_flex_column;
+mod direction_rtl_flex_overflow_scroll;
+mod direction_rtl_flex_row;
+mod direction_rtl_inherited;
+mod display_contents_block;
+mod display_contents_flex;
+mod display_contents_grid;
+mod display_contents_nested;
 mod display_none;
 mod display_none_absolute_child;
 mod display_none_fixed_size;
@@ -432,6 +477,9 @@ mod display_none_with_margin;
 mod display_none_with_position;
 mod display_none_with_position_absolute;
 mod do_not_clamp_height_of_absolute_node_to_height_of_its_overflow_hidden_parent;
+mod flex_align_content_safe_end_overflow;
+mod flex_align_items_last_baseline;
+mod flex_align_items_safe_center_overflow;
 mod flex_basis_and_main_dimen_set_when_flexing;
 mod flex_basis_flex_grow_column;
 mod flex_basis_flex_grow_row;
@@ -475,12 +523,24 @@ mod flex_grow_within_constrained_min_column;
 mod flex_grow_within_constrained_min_max_column;
 mod flex_grow_within_constrained_min_row;
 mod flex_grow_within_max_width;
+mod flex_justify_content_safe_center_fits;
+mod flex_justify_content_safe_center_overflow;
+mod flex_justify_content_unsafe_center_overflow;
+mod flex_min_height_min_content;
+mod flex_min_width_overflow_x_clip;
+mod flex_order;
+mod flex_order_row_reverse;
 mod flex_root_ignored;
 mod flex_row_relative_all_sides;
+mod flex_scrollbar_gutter_stable_both_edges;
 mod flex_shrink_by_outer_margin_with_max_size;
 mod flex_shrink_flex_grow_child_flex_shrink_other_child;
 mod flex_shrink_flex_grow_row;
 mod flex_shrink_to_zero;
+mod flex_visibility_collapse;
+mod flex_visibility_collapse_wrap;
+mod flex_visibility_hidden;
+mod flex_width_stretch;
 mod flex_wrap_align_stretch_fits_one_row;
 mod flex_wrap_children_with_min_main_overriding_flex_basis;
 mod flex_wrap_wrap_to_child_height;
@@ -616,6 +676,8 @@ mod grid_align_items_baseline_multiline_row_and_column;
 #[cfg(feature = "grid")]
 mod grid_align_items_baseline_nested_column;
 #[cfg(feature = "grid")]
+mod grid_align_items_last_baseline;
+#[cfg(feature = "grid")]
 mod grid_align_items_sized_center;
 #[cfg(feature = "grid")]
 mod grid_align_items_sized_end;
@@ -624,6 +686,10 @@ mod grid_align_items_sized_start;
 #[cfg(feature = "grid")]
 mod grid_align_items_sized_stretch;
 #[cfg(feature = "grid")]
+mod grid_align_self_baseline_mixed;
+#[cfg(feature = "grid")]
+mod grid_align_self_safe_center_overflow;
+#[cfg(feature = "grid")]
 mod grid_align_self_sized_all;
 #[cfg(feature = "grid")]
 mod grid_aspect_ratio_absolute_aspect_ratio_overrides_height_of_full_inset;
@@ -684,6 +750,8 @@ mod grid_basic_with_overflow;
 #[cfg(feature = "grid")]
 mod grid_basic_with_padding;
 #[cfg(feature = "grid")]
+mod grid_direction_rtl;
+#[cfg(feature = "grid")]
 mod grid_display_none_fixed_size;
 #[cfg(feature = "grid")]
 mod grid_fit_content_percent_definite_argument;
@@ -742,6 +810,8 @@ mod grid_justify_content_end;
 #[cfg(feature = "grid")]
 mod grid_justify_content_end_with_padding_border;
 #[cfg(feature = "grid")]
+mod grid_justify_content_safe_center_overflow;
+#[cfg(feature = "grid")]
 mod grid_justify_content_space_around;
 #[cfg(feature = "grid")]
 mod grid_justify_content_space_around_with_padding_border;
@@ -758,6 +828,8 @@ mod grid_justify_content_start;
 #[cfg(feature = "grid")]
 mod grid_justify_content_start_with_padding_border;
 #[cfg(feature = "grid")]
+mod grid_justify_items_baseline_vertical_lr;
+#[cfg(feature = "grid")]
 mod grid_justify_items_sized_center;
 #[cfg(feature = "grid")]
 mod grid_justify_items_sized_end;
@@ -788,6 +860,18 @@ mod grid_margins_percent_start;
 #[cfg(feature = "grid")]
 mod grid_margins_percent_stretch;
 #[cfg(feature = "grid")]
+mod grid_masonry_align_content_center;
+#[cfg(feature = "grid")]
+mod grid_masonry_auto_columns;
+#[cfg(feature = "grid")]
+mod grid_masonry_basic;
+#[cfg(feature = "grid")]
+mod grid_masonry_definite_column;
+#[cfg(feature = "grid")]
+mod grid_masonry_direction_rtl;
+#[cfg(feature = "grid")]
+mod grid_masonry_gap_and_column_span;
+#[cfg(feature = "grid")]
 mod grid_max_content_maximum_single_item;
 #[cfg(feature = "grid")]
 mod grid_max_content_single_item;
@@ -868,6 +952,12 @@ mod grid_minmax_min_content_percent_definite;
 #[cfg(feature = "grid")]
 mod grid_minmax_min_content_percent_indefinite;
 #[cfg(feature = "grid")]
+mod grid_named_lines_basic;
+#[cfg(feature = "grid")]
+mod grid_named_lines_implicit;
+#[cfg(feature = "grid")]
+mod grid_order_auto_placement;
+#[cfg(feature = "grid")]
 mod grid_out_of_order_items;
 #[cfg(feature = "grid")]
 mod grid_overflow_inline_axis_hidden;
@@ -928,6 +1018,8 @@ mod grid_placement_auto_negative;
 #[cfg(feature = "grid")]
 mod grid_placement_definite_in_secondary_axis_with_fully_definite_negative;
 #[cfg(feature = "grid")]
+mod grid_placement_definite_primary_axis_span;
+#[cfg(feature = "grid")]
 mod grid_relative_all_sides;
 #[cfg(feature = "grid")]
 mod grid_relayout_vertical_text;
@@ -936,6 +1028,8 @@ mod grid_repeat_integer;
 #[cfg(feature = "grid")]
 mod grid_repeat_mixed;
 #[cfg(feature = "grid")]
+mod grid_scrollbar_gutter_stable_rtl;
+#[cfg(feature = "grid")]
 mod grid_size_child_fixed_tracks;
 #[cfg(feature = "grid")]
 mod grid_span_13_most_non_flex_with_minmax_indefinite;
@@ -982,6 +1076,28 @@ mod grid_span_6_all_non_flex_indefinite_hidden;
 #[cfg(feature = "grid")]
 mod grid_span_8_all_track_types_indefinite;
 #[cfg(feature = "grid")]
+mod grid_subgrid_columns_basic;
+#[cfg(feature = "grid")]
+mod grid_subgrid_direction_rtl;
+#[cfg(feature = "grid")]
+mod grid_subgrid_empty;
+#[cfg(feature = "grid")]
+mod grid_subgrid_gap_and_padding;
+#[cfg(feature = "grid")]
+mod grid_subgrid_nested;
+#[cfg(feature = "grid")]
+mod grid_subgrid_outside_explicit_grid;
+#[cfg(feature = "grid")]
+mod grid_subgrid_rows_align_across_siblings;
+#[cfg(feature = "grid")]
+mod grid_template_areas_absolute;
+#[cfg(feature = "grid")]
+mod grid_template_areas_basic;
+#[cfg(feature = "grid")]
+mod grid_template_areas_extend_explicit_grid;
+#[cfg(feature = "grid")]
+mod grid_width_min_content;
+#[cfg(feature = "grid")]
 mod gridflex_column_integration;
 #[cfg(feature = "grid")]
 mod gridflex_kitchen_sink;
@@ -1182,6 +1298,24 @@ mod size_defined_by_child;
 mod size_defined_by_child_with_border;
 mod size_defined_by_child_with_padding;
 mod size_defined_by_grand_child;
+#[cfg(feature = "table_layout")]
+mod table_anonymous_row;
+#[cfg(feature = "table_layout")]
+mod table_auto_column_widths;
+#[cfg(feature = "table_layout")]
+mod table_border_spacing;
+#[cfg(feature = "table_layout")]
+mod table_colspan_rowspan;
+#[cfg(feature = "table_layout")]
+mod table_empty_row;
+#[cfg(feature = "table_layout")]
+mod table_rows_without_cells;
+#[cfg(feature = "table_layout")]
+mod table_rtl;
+#[cfg(feature = "table_layout")]
+mod table_width_between_min_and_max_content;
+#[cfg(feature = "table_layout")]
+mod table_width_distributes_to_columns;
 mod undefined_height_with_min_max;
 mod undefined_width_with_min_max;
 mod undefined_width_with_min_max_row;
@@ -1210,3 +1344,7 @@ mod wrapped_column_max_height_flex;
 mod wrapped_row_within_align_items_center;
 mod wrapped_row_within_align_items_flex_end;
 mod wrapped_row_within_align_items_flex_start;
+mod writing_mode_vertical_lr_block;
+mod writing_mode_vertical_lr_flex_row;
+mod writing_mode_vertical_rl_block;
+mod writing_mode_vertical_rl_grid;
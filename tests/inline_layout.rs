@@ -0,0 +1,438 @@
+#[cfg(test)]
+mod inline_layout {
+    use taffy::geometry::Point;
+    use taffy::prelude::*;
+    use taffy::tree::{Measurable, MeasureFunc};
+
+    /// A run of text in which each character is 10x10 and lines may be broken after each space
+    struct Text(&'static str);
+
+    impl Measurable for Text {
+        fn measure(&self, known_dimensions: Size<Option<f32>>, _available_space: Size<AvailableSpace>) -> Size<f32> {
+            Size {
+                width: known_dimensions.width.unwrap_or(self.0.len() as f32 * 10.0),
+                height: known_dimensions.height.unwrap_or(10.0),
+            }
+        }
+
+        fn measure_fragments(&self, _available_space: Size<AvailableSpace>) -> Option<Vec<Size<f32>>> {
+            Some(
+                self.0
+                    .split_inclusive(' ')
+                    .map(|word| Size { width: word.len() as f32 * 10.0, height: 10.0 })
+                    .collect(),
+            )
+        }
+    }
+
+    fn text(taffy: &mut Taffy, content: &'static str) -> NodeId {
+        let style = Style { display: Display::Inline, ..Default::default() };
+        taffy.new_leaf_with_measure(style, MeasureFunc::Boxed(Box::new(Text(content)))).unwrap()
+    }
+
+    fn fragment_locations(taffy: &Taffy, node: NodeId) -> Vec<(f32, f32)> {
+        taffy.fragment_layouts(node).unwrap().iter().map(|layout| (layout.location.x, layout.location.y)).collect()
+    }
+
+    #[test]
+    fn text_wraps_at_container_width() {
+        let mut taffy = Taffy::new();
+        let text = text(&mut taffy, "aaa bbb ccc");
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(100.0), height: auto() },
+                    ..Default::default()
+                },
+                &[text],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 100.0, height: 20.0 });
+        assert_eq!(taffy.layout(text).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(text).unwrap().size, Size { width: 80.0, height: 20.0 });
+        assert_eq!(fragment_locations(&taffy, text), vec![(0.0, 0.0), (40.0, 0.0), (0.0, 10.0)]);
+        let fragment_sizes: Vec<_> = taffy.fragment_layouts(text).unwrap().iter().map(|layout| layout.size).collect();
+        assert_eq!(
+            fragment_sizes,
+            vec![
+                Size { width: 40.0, height: 10.0 },
+                Size { width: 40.0, height: 10.0 },
+                Size { width: 30.0, height: 10.0 }
+            ]
+        );
+    }
+
+    #[test]
+    fn fragments_are_cleared_once_text_is_not_placed_on_lines() {
+        let mut taffy = Taffy::new();
+        let text = text(&mut taffy, "aaa bbb ccc");
+        let block_style =
+            Style { display: Display::Block, size: Size::from_lengths(100.0, 100.0), ..Default::default() };
+        let block = taffy.new_with_children(block_style, &[text]).unwrap();
+        let flex = taffy.new_leaf(Style { size: Size::from_lengths(100.0, 100.0), ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[block, flex]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.fragment_layouts(text).unwrap().len(), 3);
+
+        // A block-level leaf is laid out as a whole
+        taffy.set_style(text, Style { display: Display::Block, ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert!(taffy.fragment_layouts(text).unwrap().is_empty());
+
+        taffy.set_style(text, Style { display: Display::Inline, ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.fragment_layouts(text).unwrap().len(), 3);
+
+        // So is an inline-level leaf that is moved into a flex container, even though its style hasn't changed
+        taffy.remove_child(block, text).unwrap();
+        taffy.add_child(flex, text).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert!(taffy.fragment_layouts(text).unwrap().is_empty());
+        assert_eq!(taffy.layout(text).unwrap().size, Size { width: 110.0, height: 100.0 });
+    }
+
+    #[test]
+    fn inline_content_determines_intrinsic_width() {
+        let mut taffy = Taffy::new();
+        let text = text(&mut taffy, "aaa bbb ccc");
+        let root = taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[text]).unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 110.0, height: 10.0 });
+
+        taffy.compute_layout(root, Size::MIN_CONTENT).unwrap();
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 40.0, height: 30.0 });
+        assert_eq!(fragment_locations(&taffy, text), vec![(0.0, 0.0), (0.0, 10.0), (0.0, 20.0)]);
+    }
+
+    #[test]
+    fn inline_block_is_aligned_by_its_baseline() {
+        let mut taffy = Taffy::new();
+        let before = text(&mut taffy, "aa ");
+        let label = text(&mut taffy, "ok");
+        let button = taffy
+            .new_with_children(
+                Style { display: Display::InlineBlock, padding: length(5.0), ..Default::default() },
+                &[label],
+            )
+            .unwrap();
+        let after = text(&mut taffy, "bb");
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(200.0), height: auto() },
+                    ..Default::default()
+                },
+                &[before, button, after],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The baseline of the button is the baseline of its label, 15 from its top
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 200.0, height: 20.0 });
+        assert_eq!(taffy.layout(before).unwrap().location, Point { x: 0.0, y: 5.0 });
+        assert_eq!(taffy.layout(before).unwrap().size, Size { width: 30.0, height: 10.0 });
+        assert_eq!(taffy.layout(button).unwrap().location, Point { x: 30.0, y: 0.0 });
+        assert_eq!(taffy.layout(button).unwrap().size, Size { width: 30.0, height: 20.0 });
+        assert_eq!(taffy.layout(label).unwrap().location, Point { x: 5.0, y: 5.0 });
+        assert_eq!(taffy.layout(label).unwrap().size, Size { width: 20.0, height: 10.0 });
+        assert_eq!(taffy.layout(after).unwrap().location, Point { x: 60.0, y: 5.0 });
+        assert_eq!(taffy.layout(after).unwrap().size, Size { width: 20.0, height: 10.0 });
+    }
+
+    #[test]
+    fn chat_bubbles_are_aligned_by_their_last_lines() {
+        let mut taffy = Taffy::new();
+        let long_message = text(&mut taffy, "aa bb cc");
+        let long_bubble = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(40.0), height: auto() },
+                    padding: length(5.0),
+                    ..Default::default()
+                },
+                &[long_message],
+            )
+            .unwrap();
+        let short_message = text(&mut taffy, "hi");
+        let short_bubble = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(60.0), height: auto() },
+                    padding: Rect { left: zero(), right: zero(), top: zero(), bottom: length(15.0) },
+                    ..Default::default()
+                },
+                &[short_message],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { align_items: Some(AlignItems::LastBaseline), ..Default::default() },
+                &[long_bubble, short_bubble],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The last line of both bubbles has its baseline 35 from the top of the container
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 100.0, height: 50.0 });
+        assert_eq!(taffy.layout(long_bubble).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(long_bubble).unwrap().size, Size { width: 40.0, height: 40.0 });
+        assert_eq!(taffy.layout(long_message).unwrap().location, Point { x: 5.0, y: 5.0 });
+        assert_eq!(taffy.layout(long_message).unwrap().size, Size { width: 30.0, height: 30.0 });
+        assert_eq!(fragment_locations(&taffy, long_message), vec![(0.0, 0.0), (0.0, 10.0), (0.0, 20.0)]);
+        assert_eq!(taffy.layout(short_bubble).unwrap().location, Point { x: 40.0, y: 25.0 });
+        assert_eq!(taffy.layout(short_bubble).unwrap().size, Size { width: 60.0, height: 25.0 });
+        assert_eq!(taffy.layout(short_message).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(short_message).unwrap().size, Size { width: 20.0, height: 10.0 });
+    }
+
+    #[test]
+    fn inline_blocks_wrap_and_stack_with_block_siblings() {
+        let mut taffy = Taffy::new();
+        let inline_block_style = Style {
+            display: Display::InlineBlock,
+            size: Size { width: length(40.0), height: length(20.0) },
+            margin: Rect { left: zero(), right: length(10.0), top: zero(), bottom: zero() },
+            ..Default::default()
+        };
+        let first = taffy.new_leaf(inline_block_style.clone()).unwrap();
+        let second = taffy.new_leaf(inline_block_style.clone()).unwrap();
+        let third = taffy.new_leaf(inline_block_style).unwrap();
+        let block_child = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                size: Size { width: auto(), height: length(15.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(110.0), height: auto() },
+                    ..Default::default()
+                },
+                &[first, second, third, block_child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 110.0, height: 55.0 });
+        assert_eq!(taffy.layout(first).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(first).unwrap().size, Size { width: 40.0, height: 20.0 });
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 50.0, y: 0.0 });
+        assert_eq!(taffy.layout(second).unwrap().size, Size { width: 40.0, height: 20.0 });
+        assert_eq!(taffy.layout(third).unwrap().location, Point { x: 0.0, y: 20.0 });
+        assert_eq!(taffy.layout(third).unwrap().size, Size { width: 40.0, height: 20.0 });
+        assert_eq!(taffy.layout(block_child).unwrap().location, Point { x: 0.0, y: 40.0 });
+        assert_eq!(taffy.layout(block_child).unwrap().size, Size { width: 110.0, height: 15.0 });
+    }
+
+    #[test]
+    fn inline_box_contents_are_placed_on_the_lines() {
+        let mut taffy = Taffy::new();
+        let before = text(&mut taffy, "aa ");
+        let inner = text(&mut taffy, "bbb ccc");
+        let span = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Inline,
+                    padding: Rect { left: length(5.0), right: length(5.0), top: zero(), bottom: zero() },
+                    ..Default::default()
+                },
+                &[inner],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(100.0), height: auto() },
+                    ..Default::default()
+                },
+                &[before, span],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 100.0, height: 20.0 });
+        assert_eq!(taffy.layout(before).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(before).unwrap().size, Size { width: 30.0, height: 10.0 });
+        assert_eq!(taffy.layout(span).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(span).unwrap().size, Size { width: 75.0, height: 20.0 });
+        assert_eq!(taffy.layout(inner).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(inner).unwrap().size, Size { width: 75.0, height: 20.0 });
+        assert_eq!(fragment_locations(&taffy, inner), vec![(35.0, 0.0), (0.0, 10.0)]);
+    }
+
+    #[test]
+    fn text_align() {
+        let expected_fragment_locations = [
+            (TextAlign::Start, vec![(0.0, 0.0), (40.0, 0.0), (0.0, 10.0)], 0.0),
+            (TextAlign::Right, vec![(0.0, 0.0), (40.0, 0.0), (50.0, 10.0)], 20.0),
+            (TextAlign::Center, vec![(0.0, 0.0), (40.0, 0.0), (25.0, 10.0)], 10.0),
+            (TextAlign::Justify, vec![(0.0, 0.0), (60.0, 0.0), (0.0, 10.0)], 0.0),
+        ];
+        for (text_align, expected_locations, expected_x) in expected_fragment_locations {
+            let mut taffy = Taffy::new();
+            let text = text(&mut taffy, "aaa bbb ccc");
+            let root = taffy
+                .new_with_children(
+                    Style {
+                        display: Display::Block,
+                        size: Size { width: length(100.0), height: auto() },
+                        text_align,
+                        ..Default::default()
+                    },
+                    &[text],
+                )
+                .unwrap();
+            taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+            assert_eq!(taffy.layout(text).unwrap().location.x, expected_x, "{text_align:?}");
+            assert_eq!(fragment_locations(&taffy, text), expected_locations, "{text_align:?}");
+        }
+    }
+
+    #[test]
+    fn right_to_left_lines_start_from_the_right() {
+        let mut taffy = Taffy::new();
+        let text = text(&mut taffy, "aaa bbb ccc");
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    direction: Direction::Rtl,
+                    size: Size { width: length(100.0), height: auto() },
+                    ..Default::default()
+                },
+                &[text],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(text).unwrap().location, Point { x: 20.0, y: 0.0 });
+        assert_eq!(taffy.layout(text).unwrap().size, Size { width: 80.0, height: 20.0 });
+        assert_eq!(fragment_locations(&taffy, text), vec![(40.0, 0.0), (0.0, 0.0), (50.0, 10.0)]);
+    }
+
+    #[test]
+    fn lines_are_shortened_beside_floats() {
+        let mut taffy = Taffy::new();
+        let float = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                float: Float::Left,
+                size: Size { width: length(30.0), height: length(15.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let text = text(&mut taffy, "aaa bbb ccc ddd");
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(100.0), height: auto() },
+                    ..Default::default()
+                },
+                &[float, text],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 100.0, height: 30.0 });
+        assert_eq!(taffy.layout(float).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(float).unwrap().size, Size { width: 30.0, height: 15.0 });
+        assert_eq!(taffy.layout(text).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(text).unwrap().size, Size { width: 70.0, height: 30.0 });
+        assert_eq!(fragment_locations(&taffy, text), vec![(30.0, 0.0), (30.0, 10.0), (0.0, 20.0), (40.0, 20.0)]);
+    }
+
+    #[test]
+    fn lines_of_nested_blocks_are_shortened_beside_floats() {
+        let mut taffy = Taffy::new();
+        let float = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                float: Float::Left,
+                size: Size { width: length(30.0), height: length(18.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let text = text(&mut taffy, "aaa bbb ccc ddd");
+        // The margins of the nested blocks collapse together, so the text starts 10px below the top of the float
+        let inner = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    margin: Rect { top: length(10.0), ..Rect::zero() },
+                    ..Default::default()
+                },
+                &[text],
+            )
+            .unwrap();
+        let outer = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    margin: Rect { top: length(5.0), ..Rect::zero() },
+                    ..Default::default()
+                },
+                &[inner],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(100.0), height: auto() },
+                    ..Default::default()
+                },
+                &[float, outer],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 100.0, height: 40.0 });
+        assert_eq!(taffy.layout(outer).unwrap().location, Point { x: 0.0, y: 10.0 });
+        assert_eq!(taffy.layout(outer).unwrap().size, Size { width: 100.0, height: 30.0 });
+        assert_eq!(taffy.layout(inner).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(fragment_locations(&taffy, text), vec![(30.0, 0.0), (0.0, 10.0), (40.0, 10.0), (0.0, 20.0)]);
+    }
+
+    #[test]
+    fn floats_are_beside_inline_content_in_intrinsic_width() {
+        let mut taffy = Taffy::new();
+        let float = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                float: Float::Left,
+                size: Size { width: length(30.0), height: length(10.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let text = text(&mut taffy, "aaa bbb");
+        let root =
+            taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[float, text]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 100.0, height: 10.0 });
+        assert_eq!(taffy.layout(text).unwrap().location, Point { x: 30.0, y: 0.0 });
+        assert_eq!(taffy.layout(text).unwrap().size, Size { width: 70.0, height: 10.0 });
+    }
+}
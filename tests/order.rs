@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod order {
+    use taffy::prelude::*;
+
+    fn item(taffy: &mut Taffy, order: i32) -> NodeId {
+        taffy
+            .new_leaf(Style { order, size: Size { width: length(10.0), height: length(10.0) }, ..Default::default() })
+            .unwrap()
+    }
+
+    #[test]
+    fn layout_order_follows_the_order_style() {
+        let mut taffy = Taffy::new();
+        let first = item(&mut taffy, 1);
+        let second = item(&mut taffy, 0);
+        let third = item(&mut taffy, -1);
+        let root = taffy.new_with_children(Style::default(), &[first, second, third]).unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        let placement = |taffy: &Taffy, node| {
+            let layout = taffy.layout(node).unwrap();
+            (layout.order, layout.location.x)
+        };
+        assert_eq!(placement(&taffy, third), (0, 0.0));
+        assert_eq!(placement(&taffy, second), (1, 10.0));
+        assert_eq!(placement(&taffy, first), (2, 20.0));
+
+        // Items can be reordered without changing the children of the container
+        taffy.set_style(first, Style { order: -2, ..taffy.style(first).unwrap().clone() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(placement(&taffy, first), (0, 0.0));
+        assert_eq!(placement(&taffy, third), (1, 10.0));
+        assert_eq!(placement(&taffy, second), (2, 20.0));
+    }
+}
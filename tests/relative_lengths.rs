@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod relative_lengths {
+    use taffy::geometry::Point;
+    use taffy::prelude::*;
+    use taffy::tree::MeasureFunc;
+
+    fn viewport(width: f32, height: f32) -> Size<AvailableSpace> {
+        Size { width: AvailableSpace::Definite(width), height: AvailableSpace::Definite(height) }
+    }
+
+    #[test]
+    fn relative_lengths_resolve_against_the_font_size_and_viewport() {
+        let mut taffy = Taffy::new();
+        let inherits_font_size = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                size: Size { width: em(2.0), height: rem(1.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let sets_font_size = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                font_size: Some(em(1.5)),
+                size: Size { width: em(2.0), height: vh(0.1) },
+                margin: Rect { left: vmin(0.01), right: auto(), top: zero(), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    font_size: Some(length(20.0)),
+                    size: Size { width: vw(0.5), height: auto() },
+                    ..Default::default()
+                },
+                &[inherits_font_size, sets_font_size],
+            )
+            .unwrap();
+
+        taffy.compute_layout(root, viewport(800.0, 600.0)).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 400.0, height: 80.0 });
+        assert_eq!(taffy.layout(inherits_font_size).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(inherits_font_size).unwrap().size, Size { width: 40.0, height: 20.0 });
+        assert_eq!(taffy.layout(sets_font_size).unwrap().location, Point { x: 6.0, y: 20.0 });
+        assert_eq!(taffy.layout(sets_font_size).unwrap().size, Size { width: 60.0, height: 60.0 });
+    }
+
+    #[test]
+    fn changing_the_root_font_size_updates_rem_lengths() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style { size: Size { width: rem(3.0), height: length(10.0) }, ..Default::default() })
+            .unwrap();
+        let root_style = |font_size: f32| Style { font_size: Some(length(font_size)), ..Default::default() };
+        let root = taffy.new_with_children(root_style(10.0), &[child]).unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size.width, 30.0);
+
+        taffy.set_style(root, root_style(20.0)).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size.width, 60.0);
+
+        // The style that was set is left untouched
+        assert_eq!(taffy.style(child).unwrap().size.width, rem(3.0));
+    }
+
+    #[test]
+    fn changing_a_font_size_updates_the_em_lengths_of_descendants() {
+        let mut taffy = Taffy::new();
+        let em_sized = || Style { size: Size { width: em(2.0), height: length(10.0) }, ..Default::default() };
+        let grandchild = taffy.new_leaf(em_sized()).unwrap();
+        let cousin = taffy.new_leaf(em_sized()).unwrap();
+        let child_style = |font_size: f32| Style { font_size: Some(length(font_size)), ..Default::default() };
+        let child = taffy.new_with_children(child_style(10.0), &[grandchild]).unwrap();
+        let sibling = taffy.new_with_children(Style::default(), &[cousin]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child, sibling]).unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(grandchild).unwrap().size.width, 20.0);
+        assert_eq!(taffy.layout(cousin).unwrap().size.width, 32.0);
+
+        taffy.set_style(child, child_style(20.0)).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(grandchild).unwrap().size.width, 40.0);
+        assert_eq!(taffy.layout(cousin).unwrap().size.width, 32.0);
+        assert!(!taffy.dirty(sibling).unwrap());
+
+        // Changing the style of a node whose ancestors have unchanged styles still updates it
+        taffy
+            .set_style(cousin, Style { size: Size { width: em(3.0), height: length(10.0) }, ..Default::default() })
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(cousin).unwrap().size.width, 48.0);
+    }
+
+    #[test]
+    fn viewport_lengths_resolve_against_zero_with_indefinite_available_space() {
+        let mut taffy = Taffy::new();
+        let node = taffy
+            .new_leaf(Style { size: Size { width: vw(0.5), height: length(10.0) }, ..Default::default() })
+            .unwrap();
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size.width, 0.0);
+    }
+
+    #[test]
+    fn viewport_lengths_resolve_against_the_root_size_with_indefinite_available_space() {
+        let mut taffy = Taffy::new();
+        let in_flow =
+            taffy.new_leaf(Style { size: Size { width: vw(0.5), height: vh(0.2) }, ..Default::default() }).unwrap();
+        // Fixed position nodes are laid out against the same viewport
+        let fixed = taffy
+            .new_leaf(Style {
+                position: Position::Fixed,
+                size: Size { width: vw(0.25), height: vh(0.5) },
+                inset: Rect { left: auto(), right: length(0.0), top: auto(), bottom: length(0.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { size: Size::from_lengths(200.0, 100.0), ..Default::default() },
+                &[in_flow, fixed],
+            )
+            .unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(in_flow).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(in_flow).unwrap().size, Size { width: 100.0, height: 20.0 });
+        assert_eq!(taffy.layout(fixed).unwrap().location, Point { x: 150.0, y: 50.0 });
+        assert_eq!(taffy.layout(fixed).unwrap().size, Size { width: 50.0, height: 50.0 });
+    }
+
+    #[test]
+    fn resizing_the_viewport_only_relayouts_nodes_with_viewport_lengths() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        static NUM_MEASURES: AtomicU32 = AtomicU32::new(0);
+
+        let mut taffy = Taffy::new();
+        let viewport_sized = taffy
+            .new_leaf(Style { size: Size { width: vw(0.5), height: length(10.0) }, ..Default::default() })
+            .unwrap();
+        let measured = taffy
+            .new_leaf_with_measure(
+                Style { size: Size { width: length(100.0), height: auto() }, ..Default::default() },
+                MeasureFunc::Raw(|known_dimensions, _available_space| {
+                    NUM_MEASURES.fetch_add(1, Ordering::SeqCst);
+                    Size { width: known_dimensions.width.unwrap_or(50.0), height: 20.0 }
+                }),
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    flex_direction: FlexDirection::Column,
+                    align_items: Some(AlignItems::Start),
+                    size: Size { width: length(500.0), height: length(500.0) },
+                    ..Default::default()
+                },
+                &[viewport_sized, measured],
+            )
+            .unwrap();
+
+        taffy.compute_layout(root, viewport(800.0, 600.0)).unwrap();
+        assert_eq!(taffy.layout(viewport_sized).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(viewport_sized).unwrap().size, Size { width: 400.0, height: 10.0 });
+        let num_measures = NUM_MEASURES.load(Ordering::SeqCst);
+        assert!(num_measures > 0);
+
+        taffy.compute_layout(root, viewport(1000.0, 600.0)).unwrap();
+        assert_eq!(taffy.layout(viewport_sized).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(viewport_sized).unwrap().size, Size { width: 500.0, height: 10.0 });
+        assert_eq!(taffy.layout(measured).unwrap().location, Point { x: 0.0, y: 10.0 });
+        assert_eq!(taffy.layout(measured).unwrap().size, Size { width: 100.0, height: 20.0 });
+        assert_eq!(NUM_MEASURES.load(Ordering::SeqCst), num_measures);
+        assert!(!taffy.dirty(measured).unwrap());
+    }
+
+    #[test]
+    fn calc_expressions_are_resolved_again_whenever_the_viewport_changes() {
+        let mut taffy = Taffy::new();
+        let margin: LengthPercentageAuto = (CalcExpression::Vh(0.1) - CalcExpression::Em(1.0)).into();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size::from_lengths(10.0, 10.0),
+                margin: Rect { left: margin, right: zero(), top: zero(), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+
+        for height in [600.0, 800.0, 600.0, 1000.0] {
+            taffy.compute_layout(root, viewport(800.0, height)).unwrap();
+            assert_eq!(taffy.layout(child).unwrap().location, Point { x: height * 0.1 - 16.0, y: 0.0 });
+        }
+        // The expression in the node's own style is left unresolved
+        assert_eq!(taffy.style(child).unwrap().margin.left, margin);
+    }
+}
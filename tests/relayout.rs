@@ -319,3 +319,69 @@ fn toggle_grid_container_display_none() {
     assert_eq!(layout.size.width, 0.0);
     assert_eq!(layout.size.height, 0.0);
 }
+
+#[test]
+fn relayout_vertical_writing_mode() {
+    let mut taffy = taffy::Taffy::new();
+    let child = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 20.0), ..Default::default() }).unwrap();
+    let vertical_style = |writing_mode| Style {
+        display: Display::Block,
+        writing_mode,
+        size: Size::from_lengths(100.0, 100.0),
+        ..Default::default()
+    };
+    let root = taffy.new_with_children(vertical_style(taffy::style::WritingMode::VerticalRl), &[child]).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().location, taffy::geometry::Point { x: 90.0, y: 0.0 });
+
+    // The transposed styles of the nodes are updated when their styles change
+    taffy.set_style(child, Style { size: Size::from_lengths(30.0, 40.0), ..Default::default() }).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().size, Size { width: 30.0, height: 40.0 });
+    assert_eq!(taffy.layout(child).unwrap().location, taffy::geometry::Point { x: 70.0, y: 0.0 });
+
+    taffy.set_style(root, vertical_style(taffy::style::WritingMode::VerticalLr)).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().size, Size { width: 30.0, height: 40.0 });
+    assert_eq!(taffy.layout(child).unwrap().location, taffy::geometry::Point { x: 0.0, y: 0.0 });
+
+    // Styles aren't transposed while no node is in a vertical writing mode, but are again once one is
+    taffy.set_style(root, vertical_style(taffy::style::WritingMode::HorizontalTb)).unwrap();
+    taffy.set_style(child, Style { size: Size::from_lengths(50.0, 60.0), ..Default::default() }).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().size, Size { width: 50.0, height: 60.0 });
+    assert_eq!(taffy.layout(child).unwrap().location, taffy::geometry::Point { x: 0.0, y: 0.0 });
+
+    taffy.set_style(root, vertical_style(taffy::style::WritingMode::VerticalRl)).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().size, Size { width: 50.0, height: 60.0 });
+    assert_eq!(taffy.layout(child).unwrap().location, taffy::geometry::Point { x: 50.0, y: 0.0 });
+}
+
+#[test]
+fn toggle_inherited_direction() {
+    let mut taffy = taffy::Taffy::new();
+    let grandchild = taffy
+        .new_leaf(Style { size: Size { width: length(20.0), height: length(10.0) }, ..Default::default() })
+        .unwrap();
+    let child = taffy
+        .new_with_children(
+            Style { size: Size { width: length(50.0), height: auto() }, ..Default::default() },
+            &[grandchild],
+        )
+        .unwrap();
+    let root_style =
+        |direction| Style { direction, size: Size { width: length(100.0), height: auto() }, ..Default::default() };
+    let root = taffy.new_with_children(root_style(Direction::Rtl), &[child]).unwrap();
+
+    // The child and grandchild inherit the direction of the root
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().location.x, 50.0);
+    assert_eq!(taffy.layout(grandchild).unwrap().location.x, 30.0);
+
+    // Changing the direction of the root lays its descendants out again
+    taffy.set_style(root, root_style(Direction::Ltr)).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().location.x, 0.0);
+    assert_eq!(taffy.layout(grandchild).unwrap().location.x, 0.0);
+}
@@ -11,6 +11,28 @@ mod serde {
         let _ = serde_json::to_string(&style).unwrap();
     }
 
+    #[test]
+    fn serde_can_round_trip_calc_expressions() {
+        use taffy::prelude::*;
+
+        let width = CalcExpression::clamp(
+            CalcExpression::from_length(200.0),
+            CalcExpression::from_percent(0.5),
+            CalcExpression::from_length(600.0),
+        );
+        let style = Style {
+            size: Size { width: width.into(), height: auto() },
+            margin: Rect {
+                left: (CalcExpression::from_percent(1.0) - CalcExpression::from_length(48.0)).into(),
+                ..Rect::zero()
+            },
+            ..Style::DEFAULT
+        };
+        let json = serde_json::to_string(&style).unwrap();
+        let deserialized: Style = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, style);
+    }
+
     #[test]
     fn serde_can_deserialize_partial_values() {
         use serde_json;
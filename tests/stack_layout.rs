@@ -0,0 +1,307 @@
+#[cfg(feature = "stack_layout")]
+mod stack_layout {
+    use taffy::geometry::Point;
+    use taffy::prelude::*;
+
+    fn spaces(left: StackSpace, right: StackSpace, top: StackSpace, bottom: StackSpace) -> Rect<StackSpace> {
+        Rect { left, right, top, bottom }
+    }
+
+    #[test]
+    fn stretched_sizes_share_the_free_space() {
+        let mut taffy = Taffy::new();
+        let fixed = taffy
+            .new_leaf(Style { size: Size { width: length(50.0), height: Dimension::Stretch }, ..Default::default() })
+            .unwrap();
+        let first = taffy
+            .new_leaf(Style { size: Size { width: Dimension::Stretch, height: length(20.0) }, ..Default::default() })
+            .unwrap();
+        let second = taffy
+            .new_leaf(Style { size: Size { width: Dimension::Stretch, height: length(20.0) }, ..Default::default() })
+            .unwrap();
+        let stack = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Stack,
+                    stack_direction: StackDirection::Row,
+                    child_between: StackSpace::Length(10.0),
+                    size: Size::from_lengths(300.0, 100.0),
+                    ..Default::default()
+                },
+                &[fixed, first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(stack, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(fixed).unwrap().size, Size { width: 50.0, height: 100.0 });
+        assert_eq!(taffy.layout(first).unwrap().location, Point { x: 60.0, y: 0.0 });
+        assert_eq!(taffy.layout(first).unwrap().size, Size { width: 115.0, height: 20.0 });
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 185.0, y: 0.0 });
+        assert_eq!(taffy.layout(second).unwrap().size, Size { width: 115.0, height: 20.0 });
+    }
+
+    #[test]
+    fn stretched_spaces_are_weighted() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size::from_lengths(50.0, 50.0),
+                space: spaces(
+                    StackSpace::Stretch(1.0),
+                    StackSpace::Stretch(1.0),
+                    StackSpace::Stretch(1.0),
+                    StackSpace::Stretch(2.0),
+                ),
+                ..Default::default()
+            })
+            .unwrap();
+        let stack = taffy
+            .new_with_children(
+                Style { display: Display::Stack, size: Size::from_lengths(200.0, 200.0), ..Default::default() },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(stack, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 75.0, y: 50.0 });
+    }
+
+    #[test]
+    fn stretched_sizes_are_weighted() {
+        let mut taffy = Taffy::new();
+        let single = taffy
+            .new_leaf(Style { size: Size { width: Dimension::Stretch, height: length(20.0) }, ..Default::default() })
+            .unwrap();
+        let double = taffy
+            .new_leaf(Style {
+                // The stack size overrides the `size` style in the axes in which it stretches
+                size: Size::from_lengths(10.0, 20.0),
+                stack_size: Size { width: StackSize::Stretch(2.0), height: StackSize::Auto },
+                ..Default::default()
+            })
+            .unwrap();
+        let cross = taffy
+            .new_leaf(Style {
+                size: Size { width: length(30.0), height: Dimension::Stretch },
+                stack_size: Size { width: StackSize::Auto, height: StackSize::Stretch(3.0) },
+                space: Rect { top: StackSpace::Stretch(1.0), ..Rect::auto() },
+                ..Default::default()
+            })
+            .unwrap();
+        let stack = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Stack,
+                    stack_direction: StackDirection::Row,
+                    size: Size::from_lengths(330.0, 100.0),
+                    ..Default::default()
+                },
+                &[single, double, cross],
+            )
+            .unwrap();
+        taffy.compute_layout(stack, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(single).unwrap().size, Size { width: 100.0, height: 20.0 });
+        assert_eq!(taffy.layout(double).unwrap().location, Point { x: 100.0, y: 0.0 });
+        assert_eq!(taffy.layout(double).unwrap().size, Size { width: 200.0, height: 20.0 });
+        assert_eq!(taffy.layout(cross).unwrap().location, Point { x: 300.0, y: 25.0 });
+        assert_eq!(taffy.layout(cross).unwrap().size, Size { width: 30.0, height: 75.0 });
+    }
+
+    #[test]
+    fn child_space_and_child_between_apply_to_auto_spaces() {
+        let mut taffy = Taffy::new();
+        let first = taffy
+            .new_leaf(Style { size: Size { width: Dimension::Stretch, height: length(20.0) }, ..Default::default() })
+            .unwrap();
+        let second = taffy
+            .new_leaf(Style { size: Size { width: Dimension::Stretch, height: length(20.0) }, ..Default::default() })
+            .unwrap();
+        let third = taffy
+            .new_leaf(Style {
+                size: Size { width: Dimension::Stretch, height: length(20.0) },
+                space: Rect { top: StackSpace::Length(0.0), ..Rect::auto() },
+                ..Default::default()
+            })
+            .unwrap();
+        let stack = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Stack,
+                    child_space: length(10.0),
+                    child_between: StackSpace::Length(5.0),
+                    size: Size { width: length(100.0), height: auto() },
+                    ..Default::default()
+                },
+                &[first, second, third],
+            )
+            .unwrap();
+        taffy.compute_layout(stack, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(stack).unwrap().size, Size { width: 100.0, height: 85.0 });
+        assert_eq!(taffy.layout(first).unwrap().location, Point { x: 10.0, y: 10.0 });
+        assert_eq!(taffy.layout(first).unwrap().size, Size { width: 80.0, height: 20.0 });
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 10.0, y: 35.0 });
+        assert_eq!(taffy.layout(third).unwrap().location, Point { x: 10.0, y: 55.0 });
+    }
+
+    #[test]
+    fn stretched_sizes_are_clamped_by_min_and_max_sizes() {
+        let mut taffy = Taffy::new();
+        let limited = taffy
+            .new_leaf(Style {
+                size: Size { width: Dimension::Stretch, height: length(10.0) },
+                max_size: Size { width: length(50.0), height: auto() },
+                ..Default::default()
+            })
+            .unwrap();
+        let other = taffy
+            .new_leaf(Style { size: Size { width: Dimension::Stretch, height: length(10.0) }, ..Default::default() })
+            .unwrap();
+        let stack = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Stack,
+                    stack_direction: StackDirection::Row,
+                    size: Size::from_lengths(300.0, 10.0),
+                    ..Default::default()
+                },
+                &[limited, other],
+            )
+            .unwrap();
+        taffy.compute_layout(stack, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(limited).unwrap().size.width, 50.0);
+        assert_eq!(taffy.layout(other).unwrap().location.x, 50.0);
+        assert_eq!(taffy.layout(other).unwrap().size.width, 250.0);
+    }
+
+    #[test]
+    fn self_directed_children_are_placed_by_their_spaces() {
+        let mut taffy = Taffy::new();
+        let in_flow = taffy
+            .new_leaf(Style { size: Size { width: length(20.0), height: length(20.0) }, ..Default::default() })
+            .unwrap();
+        let self_directed = taffy
+            .new_leaf(Style {
+                position: Position::Absolute,
+                size: Size::from_lengths(30.0, 30.0),
+                space: spaces(
+                    StackSpace::Stretch(1.0),
+                    StackSpace::Length(10.0),
+                    StackSpace::Percent(0.5),
+                    StackSpace::Auto,
+                ),
+                ..Default::default()
+            })
+            .unwrap();
+        let stack = taffy
+            .new_with_children(
+                Style { display: Display::Stack, size: Size::from_lengths(200.0, 200.0), ..Default::default() },
+                &[self_directed, in_flow],
+            )
+            .unwrap();
+        taffy.compute_layout(stack, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(self_directed).unwrap().location, Point { x: 160.0, y: 100.0 });
+        assert_eq!(taffy.layout(in_flow).unwrap().location, Point { x: 0.0, y: 0.0 });
+    }
+
+    #[test]
+    fn stretched_sizes_do_not_contribute_to_auto_sized_containers() {
+        let mut taffy = Taffy::new();
+        let fixed = taffy
+            .new_leaf(Style { size: Size { width: length(40.0), height: length(10.0) }, ..Default::default() })
+            .unwrap();
+        let stretched = taffy
+            .new_leaf(Style {
+                size: Size { width: Dimension::Stretch, height: Dimension::Stretch },
+                ..Default::default()
+            })
+            .unwrap();
+        let stack = taffy
+            .new_with_children(
+                Style { display: Display::Stack, stack_direction: StackDirection::Row, ..Default::default() },
+                &[fixed, stretched],
+            )
+            .unwrap();
+        taffy.compute_layout(stack, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(stack).unwrap().size, Size { width: 40.0, height: 10.0 });
+        assert_eq!(taffy.layout(stretched).unwrap().size, Size { width: 0.0, height: 10.0 });
+    }
+
+    #[test]
+    fn auto_sized_stacks_are_sized_to_their_content_in_definite_available_space() {
+        let mut taffy = Taffy::new();
+        let fixed = taffy
+            .new_leaf(Style { size: Size { width: length(30.0), height: length(20.0) }, ..Default::default() })
+            .unwrap();
+        let stretched = taffy
+            .new_leaf(Style { size: Size { width: Dimension::Stretch, height: length(10.0) }, ..Default::default() })
+            .unwrap();
+        let stack = taffy
+            .new_with_children(
+                Style { display: Display::Stack, stack_direction: StackDirection::Row, ..Default::default() },
+                &[fixed, stretched],
+            )
+            .unwrap();
+        let available_space = Size { width: AvailableSpace::Definite(300.0), height: AvailableSpace::Definite(200.0) };
+        taffy.compute_layout(stack, available_space).unwrap();
+
+        assert_eq!(taffy.layout(stack).unwrap().size, Size { width: 30.0, height: 20.0 });
+        assert_eq!(taffy.layout(stretched).unwrap().size, Size { width: 0.0, height: 10.0 });
+
+        // Stretched children fill a stack that is sized to the available space
+        taffy
+            .set_style(
+                stack,
+                Style {
+                    display: Display::Stack,
+                    stack_direction: StackDirection::Row,
+                    size: Size { width: percent(1.0), height: percent(1.0) },
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        taffy.compute_layout(stack, available_space).unwrap();
+
+        assert_eq!(taffy.layout(stack).unwrap().size, Size { width: 300.0, height: 200.0 });
+        assert_eq!(taffy.layout(stretched).unwrap().size, Size { width: 270.0, height: 10.0 });
+    }
+
+    #[test]
+    fn intrinsic_sizes_of_stacks_are_resolved_by_their_parent() {
+        let keywords = [Dimension::MinContent, Dimension::MaxContent, Dimension::FitContent(length(15.0))];
+        for keyword in keywords {
+            let sizes = Size { width: keyword, height: keyword };
+            let styles = [
+                Style { size: sizes, ..Default::default() },
+                Style { min_size: sizes, ..Default::default() },
+                Style { max_size: sizes, ..Default::default() },
+            ];
+            for style in styles {
+                for parent_display in [Display::Flex, Display::Stack] {
+                    let mut taffy = Taffy::new();
+                    let child = taffy
+                        .new_leaf(Style {
+                            size: Size { width: length(20.0), height: length(10.0) },
+                            ..Default::default()
+                        })
+                        .unwrap();
+                    let stack =
+                        taffy.new_with_children(Style { display: Display::Stack, ..style.clone() }, &[child]).unwrap();
+                    let root = taffy
+                        .new_with_children(Style { display: parent_display, ..Default::default() }, &[stack])
+                        .unwrap();
+                    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+                    assert_eq!(taffy.layout(stack).unwrap().size, Size { width: 20.0, height: 10.0 });
+
+                    // The root's intrinsic sizes are resolved before it is laid out
+                    taffy.compute_layout(stack, Size::MAX_CONTENT).unwrap();
+                    assert_eq!(taffy.layout(stack).unwrap().size, Size { width: 20.0, height: 10.0 });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+#[cfg(feature = "table_layout")]
+mod table_layout {
+    use taffy::geometry::Point;
+    use taffy::prelude::*;
+
+    fn cell(taffy: &mut Taffy, style: Style, width: f32, height: f32) -> NodeId {
+        let contents = taffy.new_leaf(Style { size: Size::from_lengths(width, height), ..Default::default() }).unwrap();
+        taffy.new_with_children(Style { display: Display::TableCell, ..style }, &[contents]).unwrap()
+    }
+
+    #[test]
+    fn collapsed_borders_are_shared_by_adjacent_cells() {
+        let mut taffy = Taffy::new();
+        let cell_style = || Style {
+            border: Rect { left: length(2.0), right: length(2.0), top: length(2.0), bottom: length(2.0) },
+            ..Default::default()
+        };
+        let first = cell(&mut taffy, cell_style(), 20.0, 10.0);
+        let second = cell(&mut taffy, cell_style(), 20.0, 10.0);
+        let table = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Table,
+                    border_collapse: BorderCollapse::Collapse,
+                    border: Rect { left: length(4.0), right: length(4.0), top: length(4.0), bottom: length(4.0) },
+                    // The padding of a table with collapsed borders is ignored
+                    padding: Rect { left: length(10.0), right: length(10.0), top: length(10.0), bottom: length(10.0) },
+                    ..Default::default()
+                },
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(table, Size::MAX_CONTENT).unwrap();
+
+        // The columns are separated by the widest of the borders on either side of them (4px at the edges of the table
+        // and 2px between the cells), and each cell's own border overlaps the collapsed borders around it
+        assert_eq!(taffy.layout(table).unwrap().size, Size { width: 50.0, height: 18.0 });
+        let first_layout = taffy.layout(first).unwrap();
+        assert_eq!(first_layout.location, Point { x: 2.0, y: 2.0 });
+        assert_eq!(first_layout.size, Size { width: 24.0, height: 14.0 });
+        let second_layout = taffy.layout(second).unwrap();
+        assert_eq!(second_layout.location, Point { x: 24.0, y: 2.0 });
+        assert_eq!(second_layout.size, Size { width: 24.0, height: 14.0 });
+    }
+
+    #[test]
+    fn zero_row_span_spans_the_rest_of_the_row_group() {
+        let mut taffy = Taffy::new();
+        let spanning = taffy
+            .new_leaf(Style {
+                display: Display::TableCell,
+                row_span: 0,
+                size: Size::from_lengths(10.0, 10.0),
+                ..Default::default()
+            })
+            .unwrap();
+        let cells: Vec<NodeId> = (0..3)
+            .map(|_| {
+                taffy
+                    .new_leaf(Style {
+                        display: Display::TableCell,
+                        size: Size::from_lengths(10.0, 10.0),
+                        ..Default::default()
+                    })
+                    .unwrap()
+            })
+            .collect();
+        let row_style = || Style { display: Display::TableRow, ..Default::default() };
+        let first_row = taffy.new_with_children(row_style(), &[spanning, cells[0]]).unwrap();
+        let second_row = taffy.new_with_children(row_style(), &[cells[1]]).unwrap();
+        let third_row = taffy.new_with_children(row_style(), &[cells[2]]).unwrap();
+        let group = taffy
+            .new_with_children(
+                Style { display: Display::TableRowGroup, ..Default::default() },
+                &[first_row, second_row, third_row],
+            )
+            .unwrap();
+        let table = taffy.new_with_children(Style { display: Display::Table, ..Default::default() }, &[group]).unwrap();
+        taffy.compute_layout(table, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(table).unwrap().size, Size { width: 20.0, height: 30.0 });
+        assert_eq!(taffy.layout(spanning).unwrap().size, Size { width: 10.0, height: 30.0 });
+        for (row, cell) in [second_row, third_row].into_iter().zip(&cells[1..]) {
+            assert_eq!(taffy.layout(row).unwrap().size, Size { width: 20.0, height: 10.0 });
+            assert_eq!(taffy.layout(*cell).unwrap().location, Point { x: 10.0, y: 0.0 });
+        }
+    }
+
+    #[test]
+    fn absolutely_positioned_child_is_placed_at_content_box_start() {
+        let mut taffy = Taffy::new();
+        let content = cell(&mut taffy, Style::default(), 20.0, 10.0);
+        let absolute = taffy
+            .new_leaf(Style { position: Position::Absolute, size: Size::from_lengths(5.0, 5.0), ..Default::default() })
+            .unwrap();
+        let table = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Table,
+                    border: Rect { left: length(2.0), right: length(2.0), top: length(2.0), bottom: length(2.0) },
+                    padding: Rect { left: length(5.0), right: length(5.0), top: length(5.0), bottom: length(5.0) },
+                    ..Default::default()
+                },
+                &[content, absolute],
+            )
+            .unwrap();
+        taffy.compute_layout(table, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(table).unwrap().size, Size { width: 34.0, height: 24.0 });
+        assert_eq!(taffy.layout(content).unwrap().location, Point { x: 7.0, y: 7.0 });
+        let absolute_layout = taffy.layout(absolute).unwrap();
+        assert_eq!(absolute_layout.location, Point { x: 7.0, y: 7.0 });
+        assert_eq!(absolute_layout.size, Size { width: 5.0, height: 5.0 });
+    }
+
+    #[test]
+    fn intrinsic_sizes_of_tables_and_rows_are_resolved_by_their_parent() {
+        let keywords = [Dimension::MinContent, Dimension::MaxContent, Dimension::FitContent(length(15.0))];
+        for display in [Display::Table, Display::TableRow] {
+            for keyword in &keywords {
+                let sizes = Size { width: *keyword, height: *keyword };
+                let styles = [
+                    Style { size: sizes, ..Default::default() },
+                    Style { min_size: sizes, ..Default::default() },
+                    Style { max_size: sizes, ..Default::default() },
+                ];
+                for style in styles {
+                    let mut taffy = Taffy::new();
+                    let content = cell(&mut taffy, Style::default(), 20.0, 10.0);
+                    let table = taffy.new_with_children(Style { display, ..style }, &[content]).unwrap();
+                    let root = taffy.new_with_children(Style::default(), &[table]).unwrap();
+                    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+                    assert_eq!(taffy.layout(table).unwrap().size, Size { width: 20.0, height: 10.0 });
+
+                    // The root's intrinsic sizes are resolved before it is laid out
+                    taffy.compute_layout(table, Size::MAX_CONTENT).unwrap();
+                    assert_eq!(taffy.layout(table).unwrap().size, Size { width: 20.0, height: 10.0 });
+                }
+            }
+        }
+    }
+}
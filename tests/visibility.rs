@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod visibility {
+    use taffy::geometry::Point;
+    use taffy::prelude::*;
+
+    fn toolbar_item(taffy: &mut Taffy, height: f32, visibility: Visibility) -> NodeId {
+        taffy
+            .new_leaf(Style {
+                visibility,
+                size: Size { width: length(40.0), height: length(height) },
+                ..Default::default()
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn layout_is_visible_follows_the_visibility_style() {
+        let mut taffy = Taffy::new();
+        let visible = toolbar_item(&mut taffy, 10.0, Visibility::Visible);
+        let hidden = toolbar_item(&mut taffy, 10.0, Visibility::Hidden);
+        let collapsed = toolbar_item(&mut taffy, 10.0, Visibility::Collapse);
+        let hidden_block = taffy
+            .new_leaf(Style { display: Display::Block, visibility: Visibility::Hidden, ..Default::default() })
+            .unwrap();
+        let block =
+            taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[hidden_block]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[visible, hidden, collapsed, block]).unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert!(taffy.layout(root).unwrap().is_visible);
+        assert!(taffy.layout(visible).unwrap().is_visible);
+        assert!(!taffy.layout(hidden).unwrap().is_visible);
+        assert!(!taffy.layout(collapsed).unwrap().is_visible);
+        assert!(taffy.layout(block).unwrap().is_visible);
+        assert!(!taffy.layout(hidden_block).unwrap().is_visible);
+
+        // A hidden item keeps its box, whereas a collapsed item takes up no space in the main axis
+        assert_eq!(taffy.layout(hidden).unwrap().location.x, 40.0);
+        assert_eq!(taffy.layout(block).unwrap().location.x, 80.0);
+    }
+
+    #[test]
+    fn collapsing_a_flex_item_does_not_change_the_cross_size_of_the_container() {
+        let mut taffy = Taffy::new();
+        let small = toolbar_item(&mut taffy, 20.0, Visibility::Visible);
+        let tall = toolbar_item(&mut taffy, 32.0, Visibility::Visible);
+        let toolbar = taffy.new_with_children(Style::default(), &[small, tall]).unwrap();
+
+        taffy.compute_layout(toolbar, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(toolbar).unwrap().size, Size { width: 80.0, height: 32.0 });
+
+        let collapsed_style = Style { visibility: Visibility::Collapse, ..taffy.style(tall).unwrap().clone() };
+        taffy.set_style(tall, collapsed_style).unwrap();
+        taffy.compute_layout(toolbar, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(toolbar).unwrap().size, Size { width: 40.0, height: 32.0 });
+        assert_eq!(taffy.layout(tall).unwrap().size, Size { width: 0.0, height: 32.0 });
+        assert!(!taffy.layout(tall).unwrap().is_visible);
+    }
+
+    #[test]
+    fn collapsed_flex_items_are_placed_at_their_position_in_the_line() {
+        for (flex_direction, direction) in [
+            (FlexDirection::Row, Direction::Ltr),
+            (FlexDirection::RowReverse, Direction::Ltr),
+            (FlexDirection::Row, Direction::Rtl),
+        ] {
+            let mut taffy = Taffy::new();
+            let first = toolbar_item(&mut taffy, 10.0, Visibility::Visible);
+            let collapsed = toolbar_item(&mut taffy, 10.0, Visibility::Collapse);
+            let last = toolbar_item(&mut taffy, 10.0, Visibility::Visible);
+            let toolbar = taffy
+                .new_with_children(
+                    Style { flex_direction, direction, padding: length(5.0), ..Default::default() },
+                    &[first, collapsed, last],
+                )
+                .unwrap();
+
+            taffy.compute_layout(toolbar, Size::MAX_CONTENT).unwrap();
+
+            // The collapsed item sits between the two visible items, which are at 5..45 and 45..85 in either order
+            assert_eq!(taffy.layout(toolbar).unwrap().size.width, 90.0);
+            assert_eq!(taffy.layout(collapsed).unwrap().location, Point { x: 45.0, y: 5.0 });
+            assert_eq!(taffy.layout(collapsed).unwrap().size, Size { width: 0.0, height: 10.0 });
+        }
+    }
+}